@@ -0,0 +1,13 @@
+use std::process::Command;
+
+// Versión de git con la que se compila, para los manifiestos de las ejecuciones
+fn main(){
+    let git = Command::new("git").args(["describe", "--always", "--dirty", "--abbrev=40"]).output().ok()
+        .filter(|salida| salida.status.success())
+        .map(|salida| String::from_utf8_lossy(&salida.stdout).trim().to_string())
+        .unwrap_or_else(|| "desconocida".to_string());
+    println!("cargo:rustc-env=CALCULADORA_GIT={}", git);
+    for fichero in [".git/HEAD", ".git/index", ".git/refs", "src"]{
+        println!("cargo:rerun-if-changed={}", fichero);
+    }
+}
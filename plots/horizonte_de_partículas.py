@@ -1,9 +0,0 @@
-
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot([-13.797964550125789, -13.795204405186777, -13.79244426024776, -13.789684115308749, -13.786923970369736, -13.784163825430724, -13.78140368049171, -13.778643535552696, -13.775883390613684, -13.773123245674672, -13.77036310073566, -13.767602955796646, -13.764842810857632, -13.76208266591862, -13.759322520979607, -13.756562376040593, -13.75380223110158, -13.751042086162567, -13.748281941223555, -13.745521796284542, -13.742761651345528, -13.740001506406516, -13.737241361467502, -13.73448121652849, -13.731721071589478, -13.728960926650464, -13.726200781711452, -13.723440636772438, -13.720680491833425, -13.717920346894411, -13.715160201955399, -13.712400057016387, -13.709639912077373, -13.70687976713836, -13.704119622199347, -13.701359477260334, -13.698599332321322, -13.695839187382308, -13.693079042443296, -13.690318897504282, -13.68755875256527, -13.684798607626258, -13.682038462687244, -13.67927831774823, -13.676518172809217, -13.673758027870205, -13.670997882931193, -13.668237737992179, -13.665477593053165, -13.662717448114153, -13.65995730317514, -13.657197158236128, -13.654437013297114, -13.6516768683581, -13.648916723419088, -13.646156578480076, -13.643396433541064, -13.640636288602048, -13.637876143663036, -13.635115998724023, -13.632355853785011, -13.629595708845997, -13.626835563906985, -13.624075418967971, -13.621315274028959, -13.618555129089946, -13.615794984150932, -13.61303483921192, -13.610274694272906, -13.607514549333894, -13.604754404394882, -13.601994259455868, -13.599234114516856, -13.596473969577842, -13.59371382463883, -13.590953679699815, -13.588193534760803, -13.585433389821791, -13.582673244882777, -13.579913099943765, -13.57715295500475, -13.574392810065739, -13.571632665126726, -13.568872520187712, -13.5661123752487, -13.563352230309686, -13.560592085370674, -13.557831940431662, -13.555071795492648, -13.552311650553634, -13.549551505614621, -13.54679136067561, -13.544031215736597, -13.541271070797583, -13.538510925858569, -13.535750780919557, -13.532990635980545, -13.530230491041532, -13.527470346102518, -13.524710201163504, -13.521950056224492, -13.51918991128548, -13.516429766346468, -13.513669621407452, -13.51090947646844, -13.508149331529427, -13.505389186590415, -13.502629041651401, -13.499868896712387, -13.497108751773375, -13.494348606834363, -13.49158846189535, -13.488828316956337, -13.486068172017323, -13.48330802707831, -13.480547882139298, -13.477787737200286, -13.475027592261272, -13.472267447322258, -13.469507302383246, -13.466747157444233, -13.46398701250522, -13.461226867566207, -13.458466722627193, -13.455706577688181, -13.452946432749169, -13.450186287810155, -13.447426142871143, -13.444665997932129, -13.441905852993116, -13.439145708054102, -13.43638556311509, -13.433625418176078, -13.430865273237064, -13.428105128298052, -13.425344983359038, -13.422584838420025, -13.419824693481013, -13.417064548542, -13.414304403602987, -13.411544258663973, -13.40878411372496, -13.406023968785949, -13.403263823846935, -13.40050367890792, -13.397743533968908, -13.394983389029896, -13.392223244090884, -13.38946309915187, -13.386702954212856, -13.383942809273844, -13.381182664334832, -13.37842251939582, -13.375662374456805, -13.372902229517791, -13.370142084578779, -13.367381939639767, -13.364621794700755, -13.361861649761739, -13.359101504822727, -13.356341359883714, -13.353581214944702, -13.350821070005688, -13.348060925066674, -13.345300780127662, -13.34254063518865, -13.339780490249638, -13.337020345310624, -13.33426020037161, -13.331500055432597, -13.328739910493585, -13.325979765554573, -13.323219620615559, -13.320459475676545, -13.317699330737533, -13.31493918579852, -13.312179040859506, -13.309418895920494, -13.30665875098148, -13.303898606042468, -13.301138461103456, -13.298378316164442, -13.29561817122543, -13.292858026286416, -13.290097881347403, -13.287337736408391, -13.284577591469377, -13.281817446530365, -13.279057301591353, -13.276297156652339, -13.273537011713325, -13.270776866774312, -13.2680167218353, -13.265256576896288, -13.262496431957274, -13.25973628701826, -13.256976142079248, -13.254215997140236, -13.251455852201223, -13.24869570726221, -13.245935562323195, -13.243175417384183, -13.240415272445171, -13.237655127506159, -13.234894982567143, -13.23213483762813, -13.229374692689118, -13.226614547750106, -13.223854402811092, -13.221094257872078, -13.218334112933066, -13.215573967994054, -13.212813823055042, -13.210053678116028, -13.207293533177014, -13.204533388238001, -13.20177324329899, -13.199013098359977, -13.196252953420963, -13.193492808481949, -13.190732663542937, -13.187972518603924, -13.18521237366491, -13.182452228725898, -13.179692083786884, -13.176931938847872, -13.17417179390886, -13.171411648969846, -13.168651504030834, -13.16589135909182, -13.163131214152807, -13.160371069213795, -13.157610924274781, -13.154850779335769, -13.152090634396755, -13.149330489457743, -13.146570344518729, -13.143810199579717, -13.141050054640704, -13.13828990970169, -13.135529764762678, -13.132769619823664, -13.130009474884652, -13.12724932994564, -13.124489185006626, -13.121729040067612, -13.1189688951286, -13.116208750189587, -13.113448605250575, -13.110688460311561, -13.107928315372547, -13.105168170433535, -13.102408025494523, -13.09964788055551, -13.096887735616496, -13.094127590677482, -13.09136744573847, -13.088607300799458, -13.085847155860446, -13.08308701092143, -13.080326865982418, -13.077566721043405, -13.074806576104393, -13.072046431165381, -13.069286286226365, -13.066526141287353, -13.06376599634834, -13.061005851409329, -13.058245706470315, -13.0554855615313, -13.052725416592288, -13.049965271653276, -13.047205126714264, -13.04444498177525, -13.041684836836236, -13.038924691897224, -13.036164546958211, -13.033404402019197, -13.030644257080185, -13.027884112141171, -13.025123967202159, -13.022363822263147, -13.019603677324133, -13.01684353238512, -13.014083387446107, -13.011323242507094, -13.008563097568082, -13.005802952629068, -13.003042807690056, -13.000282662751042, -12.99752251781203, -12.994762372873016, -12.992002227934003, -12.989242082994991, -12.986481938055977, -12.983721793116965, -12.980961648177951, -12.978201503238939, -12.975441358299927, -12.972681213360913, -12.9699210684219, -12.967160923482886, -12.964400778543874, -12.961640633604862, -12.958880488665848, -12.956120343726834, -12.953360198787822, -12.95060005384881, -12.947839908909797, -12.945079763970783, -12.94231961903177, -12.939559474092757, -12.936799329153745, -12.934039184214733, -12.931279039275719, -12.928518894336705, -12.925758749397692, -12.92299860445868, -12.920238459519668, -12.917478314580654, -12.91471816964164, -12.911958024702628, -12.909197879763616, -12.906437734824602, -12.90367758988559, -12.900917444946575, -12.898157300007563, -12.89539715506855, -12.892637010129537, -12.889876865190525, -12.88711672025151, -12.884356575312498, -12.881596430373486, -12.878836285434472, -12.87607614049546, -12.873315995556446, -12.870555850617434, -12.86779570567842, -12.865035560739408, -12.862275415800395, -12.859515270861381, -12.85675512592237, -12.853994980983355, -12.851234836044343, -12.84847469110533, -12.845714546166317, -12.842954401227304, -12.84019425628829, -12.837434111349278, -12.834673966410266, -12.831913821471252, -12.829153676532238, -12.826393531593226, -12.823633386654214, -12.820873241715201, -12.818113096776187, -12.815352951837173, -12.812592806898161, -12.809832661959149, -12.807072517020137, -12.804312372081121, -12.801552227142109, -12.798792082203096, -12.796031937264084, -12.793271792325072, -12.790511647386056, -12.787751502447044, -12.784991357508032, -12.78223121256902, -12.779471067630006, -12.776710922690992, -12.77395077775198, -12.771190632812967, -12.768430487873955, -12.765670342934941, -12.762910197995927, -12.760150053056915, -12.757389908117903, -12.75462976317889, -12.751869618239876, -12.749109473300862, -12.74634932836185, -12.743589183422838, -12.740829038483824, -12.738068893544812, -12.735308748605798, -12.732548603666785, -12.729788458727773, -12.72702831378876, -12.724268168849747, -12.721508023910733, -12.71874787897172, -12.715987734032707, -12.713227589093695, -12.710467444154682, -12.707707299215668, -12.704947154276656, -12.702187009337642, -12.69942686439863, -12.696666719459618, -12.693906574520604, -12.691146429581591, -12.688386284642577, -12.685626139703565, -12.682865994764553, -12.680105849825539, -12.677345704886525, -12.674585559947513, -12.6718254150085, -12.669065270069488, -12.666305125130474, -12.66354498019146, -12.660784835252448, -12.658024690313436, -12.655264545374424, -12.65250440043541, -12.649744255496396, -12.646984110557383, -12.644223965618371, -12.641463820679359, -12.638703675740343, -12.635943530801331, -12.633183385862319, -12.630423240923307, -12.627663095984293, -12.624902951045279, -12.622142806106266, -12.619382661167254, -12.616622516228242, -12.613862371289228, -12.611102226350214, -12.608342081411202, -12.60558193647219, -12.602821791533177, -12.600061646594163, -12.59730150165515, -12.594541356716137, -12.591781211777125, -12.58902106683811, -12.586260921899099, -12.583500776960086, -12.580740632021072, -12.57798048708206, -12.575220342143046, -12.572460197204034, -12.569700052265022, -12.566939907326008, -12.564179762386996, -12.561419617447982, -12.55865947250897, -12.555899327569957, -12.553139182630943, -12.550379037691929, -12.547618892752917, -12.544858747813905, -12.542098602874892, -12.539338457935878, -12.536578312996864, -12.533818168057852, -12.53105802311884, -12.528297878179828, -12.525537733240814, -12.5227775883018, -12.520017443362788, -12.517257298423775, -12.514497153484763, -12.511737008545747, -12.508976863606735, -12.506216718667723, -12.50345657372871, -12.500696428789697, -12.497936283850683, -12.49517613891167, -12.492415993972658, -12.489655849033646, -12.486895704094632, -12.484135559155618, -12.481375414216606, -12.478615269277594, -12.475855124338581, -12.473094979399567, -12.470334834460553, -12.467574689521541, -12.464814544582529, -12.462054399643515, -12.459294254704503, -12.456534109765489, -12.453773964826476, -12.451013819887464, -12.44825367494845, -12.445493530009438, -12.442733385070424, -12.439973240131412, -12.4372130951924, -12.434452950253386, -12.431692805314373, -12.42893266037536, -12.426172515436347, -12.423412370497333, -12.420652225558321, -12.417892080619309, -12.415131935680295, -12.412371790741282, -12.409611645802268, -12.406851500863256, -12.404091355924244, -12.40133121098523, -12.398571066046216, -12.395810921107204, -12.393050776168192, -12.39029063122918, -12.387530486290165, -12.384770341351151, -12.38201019641214, -12.379250051473127, -12.376489906534115, -12.3737297615951, -12.370969616656087, -12.368209471717075, -12.365449326778062, -12.36268918183905, -12.359929036900034, -12.357168891961022, -12.35440874702201, -12.351648602082998, -12.348888457143984, -12.34612831220497, -12.343368167265957, -12.340608022326945, -12.337847877387933, -12.335087732448919, -12.332327587509905, -12.329567442570893, -12.32680729763188, -12.324047152692868, -12.321287007753854, -12.31852686281484, -12.315766717875828, -12.313006572936816, -12.310246427997802, -12.30748628305879, -12.304726138119776, -12.301965993180763, -12.299205848241751, -12.296445703302737, -12.293685558363725, -12.290925413424711, -12.288165268485699, -12.285405123546687, -12.282644978607673, -12.27988483366866, -12.277124688729646, -12.274364543790634, -12.27160439885162, -12.268844253912608, -12.266084108973596, -12.263323964034582, -12.26056381909557, -12.257803674156555, -12.255043529217543, -12.252283384278531, -12.249523239339517, -12.246763094400505, -12.24400294946149, -12.241242804522479, -12.238482659583466, -12.235722514644454, -12.232962369705438, -12.230202224766426, -12.227442079827414, -12.224681934888402, -12.221921789949388, -12.219161645010374, -12.216401500071361, -12.21364135513235, -12.210881210193337, -12.208121065254323, -12.205360920315309, -12.202600775376297, -12.199840630437285, -12.197080485498272, -12.194320340559258, -12.191560195620244, -12.188800050681232, -12.18603990574222, -12.183279760803206, -12.180519615864194, -12.17775947092518, -12.174999325986168, -12.172239181047155, -12.169479036108141, -12.166718891169129, -12.163958746230115, -12.161198601291103, -12.15843845635209, -12.155678311413077, -12.152918166474064, -12.15015802153505, -12.147397876596038, -12.144637731657024, -12.141877586718012, -12.139117441779, -12.136357296839986, -12.133597151900974, -12.13083700696196, -12.128076862022947, -12.125316717083935, -12.122556572144921, -12.119796427205909, -12.117036282266895, -12.114276137327883, -12.11151599238887, -12.108755847449856, -12.105995702510842, -12.10323555757183, -12.100475412632818, -12.097715267693806, -12.094955122754792, -12.092194977815778, -12.089434832876766, -12.086674687937753, -12.083914542998741, -12.081154398059725, -12.078394253120713, -12.075634108181701, -12.072873963242689, -12.070113818303676, -12.06735367336466, -12.064593528425648, -12.061833383486636, -12.059073238547624, -12.05631309360861, -12.053552948669596, -12.050792803730584, -12.048032658791572, -12.04527251385256, -12.042512368913545, -12.039752223974531, -12.03699207903552, -12.034231934096507, -12.031471789157493, -12.02871164421848, -12.025951499279467, -12.023191354340454, -12.020431209401442, -12.017671064462428, -12.014910919523416, -12.012150774584402, -12.00939062964539, -12.006630484706378, -12.003870339767364, -12.001110194828351, -11.998350049889337, -11.995589904950325, -11.992829760011311, -11.990069615072299, -11.987309470133287, -11.984549325194273, -11.98178918025526, -11.979029035316247, -11.976268890377234, -11.973508745438222, -11.970748600499208, -11.967988455560196, -11.965228310621182, -11.96246816568217, -11.959708020743157, -11.956947875804143, -11.95418773086513, -11.951427585926117, -11.948667440987105, -11.945907296048093, -11.943147151109079, -11.940387006170065, -11.937626861231053, -11.93486671629204, -11.932106571353028, -11.929346426414014, -11.926586281475, -11.923826136535988, -11.921065991596976, -11.918305846657963, -11.915545701718948, -11.912785556779935, -11.910025411840923, -11.907265266901911, -11.904505121962897, -11.901744977023885, -11.89898483208487, -11.896224687145859, -11.893464542206846, -11.890704397267832, -11.88794425232882, -11.885184107389806, -11.882423962450794, -11.879663817511782, -11.876903672572768, -11.874143527633755, -11.871383382694741, -11.86862323775573, -11.865863092816715, -11.863102947877703, -11.86034280293869, -11.857582657999677, -11.854822513060665, -11.85206236812165, -11.849302223182638, -11.846542078243626, -11.843781933304612, -11.8410217883656, -11.838261643426586, -11.835501498487574, -11.832741353548561, -11.829981208609547, -11.827221063670533, -11.824460918731521, -11.821700773792509, -11.818940628853497, -11.816180483914483, -11.813420338975469, -11.810660194036457, -11.807900049097444, -11.805139904158432, -11.802379759219418, -11.799619614280404, -11.796859469341392, -11.79409932440238, -11.791339179463368, -11.788579034524352, -11.78581888958534, -11.783058744646327, -11.780298599707315, -11.777538454768301, -11.774778309829287, -11.772018164890275, -11.769258019951263, -11.76649787501225, -11.763737730073236, -11.760977585134222, -11.75821744019521, -11.755457295256198, -11.752697150317186, -11.749937005378172, -11.747176860439158, -11.744416715500146, -11.741656570561133, -11.73889642562212, -11.736136280683107, -11.733376135744093, -11.73061599080508, -11.727855845866069, -11.725095700927055, -11.722335555988042, -11.719575411049028, -11.716815266110016, -11.714055121171002, -11.71129497623199, -11.708534831292978, -11.705774686353964, -11.703014541414952, -11.700254396475938, -11.697494251536925, -11.694734106597913, -11.6919739616589, -11.689213816719887, -11.686453671780873, -11.68369352684186, -11.680933381902848, -11.678173236963834, -11.67541309202482, -11.672652947085808, -11.669892802146796, -11.667132657207784, -11.66437251226877, -11.661612367329756, -11.658852222390744, -11.656092077451731, -11.65333193251272, -11.650571787573705, -11.647811642634691, -11.645051497695679, -11.642291352756667, -11.639531207817654, -11.636771062878639, -11.634010917939626, -11.631250773000614, -11.628490628061602, -11.625730483122588, -11.622970338183574, -11.620210193244562, -11.61745004830555, -11.614689903366537, -11.611929758427523, -11.60916961348851, -11.606409468549497, -11.603649323610485, -11.600889178671473, -11.598129033732459, -11.595368888793445, -11.592608743854433, -11.58984859891542, -11.587088453976406, -11.584328309037394, -11.58156816409838, -11.578808019159368, -11.576047874220356, -11.573287729281342, -11.57052758434233, -11.567767439403315, -11.565007294464303, -11.562247149525291, -11.559487004586277, -11.556726859647265, -11.553966714708253, -11.551206569769239, -11.548446424830225, -11.545686279891212, -11.5429261349522, -11.540165990013188, -11.537405845074174, -11.53464570013516, -11.531885555196148, -11.529125410257135, -11.526365265318123, -11.52360512037911, -11.520844975440095, -11.518084830501083, -11.51532468556207, -11.512564540623059, -11.509804395684043, -11.50704425074503, -11.504284105806018, -11.501523960867006, -11.498763815927992, -11.496003670988978, -11.493243526049966, -11.490483381110954, -11.487723236171941, -11.484963091232927, -11.482202946293913, -11.479442801354901, -11.476682656415889, -11.473922511476877, -11.471162366537863, -11.468402221598849, -11.465642076659837, -11.462881931720824, -11.46012178678181, -11.457361641842798, -11.454601496903784, -11.451841351964772, -11.44908120702576, -11.446321062086746, -11.443560917147733, -11.44080077220872, -11.438040627269707, -11.435280482330695, -11.432520337391681, -11.429760192452669, -11.427000047513655, -11.424239902574643, -11.421479757635629, -11.418719612696616, -11.415959467757604, -11.41319932281859, -11.410439177879578, -11.407679032940564, -11.404918888001552, -11.40215874306254, -11.399398598123526, -11.396638453184512, -11.3938783082455, -11.391118163306487, -11.388358018367475, -11.38559787342846, -11.382837728489447, -11.380077583550435, -11.377317438611422, -11.37455729367241, -11.371797148733396, -11.369037003794382, -11.36627685885537, -11.363516713916358, -11.360756568977346, -11.35799642403833, -11.355236279099318, -11.352476134160305, -11.349715989221293, -11.34695584428228, -11.344195699343265, -11.341435554404253, -11.33867540946524, -11.335915264526228, -11.333155119587214, -11.3303949746482, -11.327634829709188, -11.324874684770176, -11.322114539831164, -11.31935439489215, -11.316594249953136, -11.313834105014124, -11.311073960075111, -11.308313815136097, -11.305553670197085, -11.302793525258071, -11.300033380319059, -11.297273235380047, -11.294513090441033, -11.29175294550202, -11.288992800563006, -11.286232655623994, -11.283472510684982, -11.280712365745968, -11.277952220806956, -11.275192075867942, -11.27243193092893, -11.269671785989916, -11.266911641050903, -11.264151496111891, -11.261391351172877, -11.258631206233865, -11.255871061294851, -11.253110916355839, -11.250350771416826, -11.247590626477812, -11.2448304815388, -11.242070336599786, -11.239310191660774, -11.236550046721762, -11.233789901782748, -11.231029756843734, -11.228269611904722, -11.22550946696571, -11.222749322026697, -11.219989177087683, -11.21722903214867, -11.214468887209657, -11.211708742270645, -11.208948597331633, -11.206188452392619, -11.203428307453605, -11.200668162514592, -11.19790801757558, -11.195147872636568, -11.192387727697554, -11.18962758275854, -11.186867437819528, -11.184107292880515, -11.181347147941501, -11.17858700300249, -11.175826858063475, -11.173066713124463, -11.17030656818545, -11.167546423246437, -11.164786278307425, -11.16202613336841, -11.159265988429398, -11.156505843490386, -11.153745698551372, -11.15098555361236, -11.148225408673346, -11.145465263734334, -11.14270511879532, -11.139944973856307, -11.137184828917295, -11.134424683978281, -11.131664539039269, -11.128904394100255, -11.126144249161243, -11.12338410422223, -11.120623959283217, -11.117863814344204, -11.11510366940519, -11.112343524466178, -11.109583379527166, -11.106823234588152, -11.104063089649138, -11.101302944710126, -11.098542799771113, -11.095782654832101, -11.093022509893087, -11.090262364954073, -11.087502220015061, -11.084742075076049, -11.081981930137037, -11.07922178519802, -11.076461640259009, -11.073701495319996, -11.070941350380984, -11.068181205441972, -11.065421060502956, -11.062660915563944, -11.059900770624932, -11.05714062568592, -11.054380480746905, -11.051620335807891, -11.04886019086888, -11.046100045929867, -11.043339900990855, -11.04057975605184, -11.037819611112827, -11.035059466173815, -11.032299321234802, -11.02953917629579, -11.026779031356776, -11.024018886417762, -11.02125874147875, -11.018498596539738, -11.015738451600724, -11.012978306661712, -11.010218161722698, -11.007458016783685, -11.004697871844673, -11.001937726905659, -10.999177581966647, -10.996417437027633, -10.99365729208862, -10.990897147149607, -10.988137002210594, -10.985376857271582, -10.982616712332568, -10.979856567393556, -10.977096422454542, -10.97433627751553, -10.971576132576518, -10.968815987637504, -10.966055842698491, -10.963295697759477, -10.960535552820465, -10.957775407881453, -10.955015262942439, -10.952255118003425, -10.949494973064413, -10.9467348281254, -10.943974683186388, -10.941214538247374, -10.93845439330836, -10.935694248369348, -10.932934103430336, -10.930173958491324, -10.92741381355231, -10.924653668613296, -10.921893523674283, -10.919133378735271, -10.916373233796259, -10.913613088857243, -10.910852943918231, -10.908092798979219, -10.905332654040206, -10.902572509101192, -10.899812364162178, -10.897052219223166, -10.894292074284154, -10.891531929345142, -10.888771784406128, -10.886011639467114, -10.883251494528102, -10.88049134958909, -10.877731204650077, -10.874971059711063, -10.87221091477205, -10.869450769833037, -10.866690624894025, -10.86393047995501, -10.861170335015998, -10.858410190076986, -10.855650045137972, -10.85288990019896, -10.850129755259946, -10.847369610320934, -10.844609465381922, -10.841849320442908, -10.839089175503895, -10.836329030564881, -10.83356888562587, -10.830808740686857, -10.828048595747843, -10.825288450808829, -10.822528305869817, -10.819768160930805, -10.817008015991792, -10.814247871052778, -10.811487726113764, -10.808727581174752, -10.80596743623574, -10.803207291296728, -10.800447146357714, -10.7976870014187, -10.794926856479687, -10.792166711540675, -10.789406566601663, -10.786646421662647, -10.783886276723635, -10.781126131784623, -10.77836598684561, -10.775605841906597, -10.772845696967583, -10.77008555202857, -10.767325407089558, -10.764565262150546, -10.761805117211532, -10.759044972272518, -10.756284827333506, -10.753524682394493, -10.750764537455481, -10.748004392516467, -10.745244247577453, -10.742484102638441, -10.739723957699429, -10.736963812760415, -10.734203667821403, -10.731443522882389, -10.728683377943376, -10.725923233004364, -10.72316308806535, -10.720402943126338, -10.717642798187324, -10.714882653248312, -10.7121225083093, -10.709362363370285, -10.706602218431273, -10.70384207349226, -10.701081928553247, -10.698321783614233, -10.69556163867522, -10.692801493736209, -10.690041348797195, -10.687281203858182, -10.684521058919168, -10.681760913980156, -10.679000769041144, -10.67624062410213, -10.673480479163116, -10.670720334224104, -10.667960189285091, -10.66520004434608, -10.662439899407065, -10.659679754468051, -10.656919609529039, -10.654159464590027, -10.651399319651015, -10.648639174712, -10.645879029772987, -10.643118884833974, -10.640358739894962, -10.63759859495595, -10.634838450016934, -10.632078305077922, -10.62931816013891, -10.626558015199898, -10.623797870260884, -10.62103772532187, -10.618277580382857, -10.615517435443845, -10.612757290504833, -10.609997145565819, -10.607237000626805, -10.604476855687793, -10.60171671074878, -10.598956565809768, -10.596196420870754, -10.59343627593174, -10.590676130992728, -10.587915986053716, -10.585155841114702, -10.58239569617569, -10.579635551236676, -10.576875406297663, -10.574115261358651, -10.571355116419637, -10.568594971480625, -10.56583482654161, -10.563074681602599, -10.560314536663586, -10.557554391724572, -10.55479424678556, -10.552034101846546, -10.549273956907534, -10.54651381196852, -10.543753667029508, -10.540993522090496, -10.538233377151482, -10.53547323221247, -10.532713087273455, -10.529952942334443, -10.527192797395431, -10.524432652456417, -10.521672507517405, -10.51891236257839, -10.516152217639378, -10.513392072700366, -10.510631927761354, -10.507871782822338, -10.505111637883326, -10.502351492944314, -10.499591348005302, -10.496831203066288, -10.494071058127274, -10.491310913188261, -10.48855076824925, -10.485790623310237, -10.483030478371223, -10.480270333432209, -10.477510188493197, -10.474750043554184, -10.471989898615172, -10.469229753676158, -10.466469608737144, -10.463709463798132, -10.46094931885912, -10.458189173920106, -10.455429028981094, -10.45266888404208, -10.449908739103067, -10.447148594164055, -10.444388449225041, -10.441628304286029, -10.438868159347015, -10.436108014408003, -10.43334786946899, -10.430587724529977, -10.427827579590964, -10.42506743465195, -10.422307289712938, -10.419547144773924, -10.416786999834912, -10.4140268548959, -10.411266709956886, -10.408506565017873, -10.40574642007886, -10.402986275139847, -10.400226130200835, -10.397465985261821, -10.394705840322809, -10.391945695383795, -10.389185550444783, -10.38642540550577, -10.383665260566756, -10.380905115627742, -10.37814497068873, -10.375384825749718, -10.372624680810706, -10.369864535871692, -10.367104390932678, -10.364344245993665, -10.361584101054653, -10.358823956115641, -10.356063811176625, -10.353303666237613, -10.3505435212986, -10.347783376359589, -10.345023231420576, -10.34226308648156, -10.339502941542548, -10.336742796603536, -10.333982651664524, -10.33122250672551, -10.328462361786496, -10.325702216847484, -10.322942071908471, -10.32018192696946, -10.317421782030445, -10.314661637091431, -10.311901492152419, -10.309141347213407, -10.306381202274393, -10.30362105733538, -10.300860912396367, -10.298100767457354, -10.295340622518342, -10.292580477579328, -10.289820332640316, -10.287060187701302, -10.28430004276229, -10.281539897823277, -10.278779752884263, -10.276019607945251, -10.273259463006237, -10.270499318067225, -10.267739173128211, -10.264979028189199, -10.262218883250187, -10.259458738311173, -10.25669859337216, -10.253938448433146, -10.251178303494134, -10.248418158555122, -10.245658013616108, -10.242897868677096, -10.240137723738082, -10.23737757879907, -10.234617433860057, -10.231857288921043, -10.22909714398203, -10.226336999043017, -10.223576854104005, -10.220816709164993, -10.218056564225979, -10.215296419286965, -10.212536274347952, -10.20977612940894, -10.207015984469928, -10.204255839530914, -10.2014956945919, -10.198735549652888, -10.195975404713876, -10.193215259774863, -10.190455114835848, -10.187694969896835, -10.184934824957823, -10.18217468001881, -10.179414535079797, -10.176654390140785, -10.17389424520177, -10.171134100262758, -10.168373955323746, -10.165613810384732, -10.16285366544572, -10.160093520506706, -10.157333375567694, -10.154573230628682, -10.151813085689668, -10.149052940750655, -10.146292795811641, -10.14353265087263, -10.140772505933615, -10.138012360994603, -10.13525221605559, -10.132492071116577, -10.129731926177564, -10.12697178123855, -10.124211636299538, -10.121451491360526, -10.118691346421512, -10.1159312014825, -10.113171056543486, -10.110410911604474, -10.107650766665461, -10.104890621726447, -10.102130476787433, -10.099370331848421, -10.096610186909409, -10.093850041970397, -10.091089897031383, -10.088329752092369, -10.085569607153356, -10.082809462214344, -10.080049317275332, -10.077289172336318, -10.074529027397304, -10.071768882458292, -10.06900873751928, -10.066248592580267, -10.063488447641252, -10.06072830270224, -10.057968157763227, -10.055208012824215, -10.052447867885201, -10.049687722946187, -10.046927578007175, -10.044167433068163, -10.04140728812915, -10.038647143190136, -10.035886998251122, -10.03312685331211, -10.030366708373098, -10.027606563434086, -10.024846418495072, -10.022086273556058, -10.019326128617045, -10.016565983678033, -10.01380583873902, -10.011045693800007, -10.008285548860993, -10.00552540392198, -10.002765258982969, -10.000005114043955, -9.997244969104942, -9.994484824165928, -9.991724679226916, -9.988964534287902, -9.98620438934889, -9.983444244409878, -9.980684099470864, -9.977923954531851, -9.975163809592837, -9.972403664653825, -9.969643519714813, -9.966883374775799, -9.964123229836787, -9.961363084897773, -9.95860293995876, -9.955842795019748, -9.953082650080734, -9.95032250514172, -9.947562360202708, -9.944802215263696, -9.942042070324684, -9.93928192538567, -9.936521780446656, -9.933761635507643, -9.931001490568631, -9.928241345629619, -9.925481200690605, -9.922721055751591, -9.919960910812579, -9.917200765873567, -9.914440620934554, -9.911680475995539, -9.908920331056526, -9.906160186117514, -9.903400041178502, -9.900639896239488, -9.897879751300474, -9.895119606361462, -9.89235946142245, -9.889599316483437, -9.886839171544423, -9.88407902660541, -9.881318881666397, -9.878558736727385, -9.875798591788373, -9.873038446849359, -9.870278301910345, -9.867518156971332, -9.86475801203232, -9.861997867093306, -9.859237722154294, -9.85647757721528, -9.853717432276268, -9.850957287337256, -9.848197142398242, -9.84543699745923, -9.842676852520215, -9.839916707581203, -9.83715656264219, -9.834396417703177, -9.831636272764165, -9.828876127825152, -9.826115982886138, -9.823355837947124, -9.820595693008112, -9.8178355480691, -9.815075403130088, -9.812315258191074, -9.80955511325206, -9.806794968313048, -9.804034823374035, -9.801274678435023, -9.798514533496009, -9.795754388556995, -9.792994243617983, -9.79023409867897, -9.787473953739958, -9.784713808800943, -9.78195366386193, -9.779193518922918, -9.776433373983906, -9.773673229044892, -9.770913084105878, -9.768152939166866, -9.765392794227854, -9.762632649288841, -9.759872504349827, -9.757112359410813, -9.754352214471801, -9.751592069532789, -9.748831924593777, -9.746071779654763, -9.743311634715749, -9.740551489776736, -9.737791344837724, -9.73503119989871, -9.732271054959698, -9.729510910020684, -9.726750765081672, -9.72399062014266, -9.721230475203646, -9.718470330264633, -9.71571018532562, -9.712950040386607, -9.710189895447595, -9.707429750508581, -9.704669605569569, -9.701909460630555, -9.699149315691542, -9.696389170752528, -9.693629025813516, -9.690868880874504, -9.68810873593549, -9.685348590996478, -9.682588446057464, -9.679828301118452, -9.67706815617944, -9.674308011240425, -9.671547866301411, -9.6687877213624, -9.666027576423387, -9.663267431484375, -9.66050728654536, -9.657747141606347, -9.654986996667335, -9.652226851728322, -9.64946670678931, -9.646706561850296, -9.643946416911282, -9.64118627197227, -9.638426127033258, -9.635665982094245, -9.63290583715523, -9.630145692216217, -9.627385547277205, -9.624625402338193, -9.62186525739918, -9.619105112460165, -9.616344967521153, -9.61358482258214, -9.610824677643128, -9.608064532704114, -9.6053043877651, -9.602544242826088, -9.599784097887076, -9.597023952948064, -9.59426380800905, -9.591503663070036, -9.588743518131023, -9.585983373192011, -9.583223228252997, -9.580463083313985, -9.577702938374971, -9.574942793435959, -9.572182648496947, -9.569422503557933, -9.56666235861892, -9.563902213679906, -9.561142068740894, -9.558381923801882, -9.555621778862868, -9.552861633923856, -9.550101488984842, -9.54734134404583, -9.544581199106815, -9.541821054167803, -9.539060909228791, -9.536300764289777, -9.533540619350765, -9.53078047441175, -9.528020329472739, -9.525260184533726, -9.522500039594712, -9.5197398946557, -9.516979749716686, -9.514219604777674, -9.511459459838662, -9.508699314899648, -9.505939169960634, -9.503179025021621, -9.50041888008261, -9.497658735143597, -9.494898590204583, -9.492138445265569, -9.489378300326557, -9.486618155387545, -9.483858010448532, -9.481097865509518, -9.478337720570504, -9.475577575631492, -9.47281743069248, -9.470057285753468, -9.467297140814454, -9.46453699587544, -9.461776850936428, -9.459016705997415, -9.456256561058401, -9.453496416119389, -9.450736271180375, -9.447976126241363, -9.44521598130235, -9.442455836363337, -9.439695691424324, -9.43693554648531, -9.434175401546298, -9.431415256607286, -9.428655111668272, -9.42589496672926, -9.423134821790246, -9.420374676851234, -9.41761453191222, -9.414854386973207, -9.412094242034195, -9.409334097095181, -9.406573952156169, -9.403813807217155, -9.401053662278143, -9.39829351733913, -9.395533372400116, -9.392773227461104, -9.39001308252209, -9.387252937583078, -9.384492792644066, -9.381732647705052, -9.378972502766038, -9.376212357827026, -9.373452212888013, -9.370692067949001, -9.367931923009987, -9.365171778070973, -9.362411633131961, -9.359651488192949, -9.356891343253936, -9.35413119831492, -9.351371053375908, -9.348610908436896, -9.345850763497884, -9.343090618558872, -9.340330473619856, -9.337570328680844, -9.334810183741832, -9.33205003880282, -9.329289893863805, -9.326529748924791, -9.32376960398578, -9.321009459046767, -9.318249314107755, -9.31548916916874, -9.312729024229727, -9.309968879290714, -9.307208734351702, -9.30444858941269, -9.301688444473676, -9.298928299534662, -9.29616815459565, -9.293408009656638, -9.290647864717624, -9.287887719778611, -9.285127574839597, -9.282367429900585, -9.279607284961573, -9.276847140022559, -9.274086995083547, -9.271326850144533, -9.26856670520552, -9.265806560266507, -9.263046415327494, -9.260286270388482, -9.257526125449468, -9.254765980510456, -9.252005835571442, -9.24924569063243, -9.246485545693417, -9.243725400754403, -9.240965255815391, -9.238205110876377, -9.235444965937365, -9.232684820998353, -9.229924676059339, -9.227164531120325, -9.224404386181313, -9.2216442412423, -9.218884096303288, -9.216123951364274, -9.21336380642526, -9.210603661486248, -9.207843516547236, -9.205083371608223, -9.20232322666921, -9.199563081730195, -9.196802936791183, -9.194042791852171, -9.191282646913159, -9.188522501974143, -9.18576235703513, -9.183002212096119, -9.180242067157106, -9.177481922218092, -9.174721777279078, -9.171961632340066, -9.169201487401054, -9.166441342462042, -9.163681197523028, -9.160921052584014, -9.158160907645001, -9.15540076270599, -9.152640617766977, -9.149880472827963, -9.147120327888949, -9.144360182949937, -9.141600038010925, -9.13883989307191, -9.136079748132898, -9.133319603193886, -9.130559458254872, -9.12779931331586, -9.125039168376846, -9.122279023437834, -9.119518878498821, -9.116758733559807, -9.113998588620795, -9.111238443681781, -9.108478298742769, -9.105718153803757, -9.102958008864743, -9.100197863925729, -9.097437718986717, -9.094677574047704, -9.091917429108692, -9.089157284169678, -9.086397139230664, -9.083636994291652, -9.08087684935264, -9.078116704413628, -9.075356559474614, -9.0725964145356, -9.069836269596587, -9.067076124657575, -9.064315979718563, -9.061555834779547, -9.058795689840535, -9.056035544901523, -9.05327539996251, -9.050515255023496, -9.047755110084482, -9.04499496514547, -9.042234820206458, -9.039474675267446, -9.036714530328432, -9.033954385389418, -9.031194240450406, -9.028434095511393, -9.025673950572381, -9.022913805633367, -9.020153660694353, -9.01739351575534, -9.014633370816329, -9.011873225877315, -9.009113080938302, -9.006352935999288, -9.003592791060276, -9.000832646121264, -8.99807250118225, -8.995312356243238, -8.992552211304224, -8.989792066365212, -8.9870319214262, -8.984271776487185, -8.981511631548173, -8.97875148660916, -8.975991341670147, -8.973231196731133, -8.97047105179212, -8.967710906853108, -8.964950761914094, -8.962190616975082, -8.959430472036068, -8.956670327097056, -8.953910182158044, -8.95115003721903, -8.948389892280016, -8.945629747341004, -8.942869602401991, -8.94010945746298, -8.937349312523965, -8.934589167584951, -8.931829022645939, -8.929068877706927, -8.926308732767914, -8.9235485878289, -8.920788442889886, -8.918028297950874, -8.915268153011862, -8.91250800807285, -8.909747863133834, -8.906987718194822, -8.90422757325581, -8.901467428316797, -8.898707283377783, -8.89594713843877, -8.893186993499757, -8.890426848560745, -8.887666703621733, -8.884906558682719, -8.882146413743705, -8.879386268804693, -8.87662612386568, -8.873865978926668, -8.871105833987654, -8.86834568904864, -8.865585544109628, -8.862825399170616, -8.860065254231602, -8.85730510929259, -8.854544964353575, -8.851784819414563, -8.849024674475551, -8.846264529536537, -8.843504384597525, -8.84074423965851, -8.837984094719499, -8.835223949780486, -8.832463804841472, -8.82970365990246, -8.826943514963446, -8.824183370024434, -8.82142322508542, -8.818663080146408, -8.815902935207395, -8.813142790268381, -8.81038264532937, -8.807622500390355, -8.804862355451343, -8.80210221051233, -8.799342065573317, -8.796581920634305, -8.79382177569529, -8.791061630756278, -8.788301485817266, -8.785541340878254, -8.782781195939238, -8.780021051000226, -8.777260906061214, -8.774500761122201, -8.771740616183187, -8.768980471244173, -8.766220326305161, -8.763460181366149, -8.760700036427137, -8.757939891488123, -8.755179746549109, -8.752419601610097, -8.749659456671084, -8.746899311732072, -8.744139166793058, -8.741379021854044, -8.738618876915032, -8.73585873197602, -8.733098587037006, -8.730338442097993, -8.72757829715898, -8.724818152219967, -8.722058007280955, -8.719297862341941, -8.716537717402929, -8.713777572463915, -8.711017427524903, -8.70825728258589, -8.705497137646876, -8.702736992707864, -8.69997684776885, -8.697216702829838, -8.694456557890824, -8.691696412951812, -8.6889362680128, -8.686176123073786, -8.683415978134773, -8.68065583319576, -8.677895688256747, -8.675135543317735, -8.67237539837872, -8.669615253439709, -8.666855108500695, -8.664094963561682, -8.66133481862267, -8.658574673683656, -8.655814528744642, -8.65305438380563, -8.650294238866618, -8.647534093927606, -8.644773948988592, -8.642013804049578, -8.639253659110565, -8.636493514171553, -8.63373336923254, -8.630973224293525, -8.628213079354513, -8.6254529344155, -8.622692789476488, -8.619932644537476, -8.61717249959846, -8.614412354659448, -8.611652209720436, -8.608892064781424, -8.60613191984241, -8.603371774903396, -8.600611629964384, -8.597851485025371, -8.595091340086359, -8.592331195147345, -8.589571050208331, -8.586810905269319, -8.584050760330307, -8.581290615391293, -8.57853047045228, -8.575770325513266, -8.573010180574254, -8.570250035635242, -8.567489890696228, -8.564729745757216, -8.561969600818202, -8.55920945587919, -8.556449310940177, -8.553689166001163, -8.550929021062151, -8.548168876123137, -8.545408731184125, -8.542648586245111, -8.539888441306099, -8.537128296367086, -8.534368151428072, -8.53160800648906, -8.528847861550046, -8.526087716611034, -8.523327571672022, -8.520567426733008, -8.517807281793996, -8.515047136854982, -8.51228699191597, -8.509526846976957, -8.506766702037943, -8.50400655709893, -8.501246412159917, -8.498486267220905, -8.495726122281893, -8.492965977342879, -8.490205832403865, -8.487445687464852, -8.48468554252584, -8.481925397586828, -8.479165252647814, -8.4764051077088, -8.473644962769788, -8.470884817830775, -8.468124672891763, -8.465364527952747, -8.462604383013735, -8.459844238074723, -8.45708409313571, -8.454323948196697, -8.451563803257685, -8.44880365831867, -8.446043513379658, -8.443283368440646, -8.440523223501632, -8.43776307856262, -8.435002933623606, -8.432242788684594, -8.429482643745581, -8.426722498806567, -8.423962353867555, -8.421202208928541, -8.418442063989529, -8.415681919050515, -8.412921774111503, -8.41016162917249, -8.407401484233477, -8.404641339294464, -8.40188119435545, -8.399121049416438, -8.396360904477426, -8.393600759538412, -8.3908406145994, -8.388080469660386, -8.385320324721373, -8.382560179782361, -8.379800034843347, -8.377039889904333, -8.374279744965321, -8.371519600026309, -8.368759455087297, -8.365999310148283, -8.363239165209269, -8.360479020270256, -8.357718875331244, -8.354958730392232, -8.352198585453218, -8.349438440514204, -8.346678295575192, -8.34391815063618, -8.341158005697167, -8.338397860758151, -8.33563771581914, -8.332877570880127, -8.330117425941115, -8.3273572810021, -8.324597136063087, -8.321836991124075, -8.319076846185062, -8.31631670124605, -8.313556556307036, -8.310796411368022, -8.30803626642901, -8.305276121489998, -8.302515976550985, -8.299755831611972, -8.296995686672958, -8.294235541733945, -8.291475396794933, -8.288715251855919, -8.285955106916907, -8.283194961977893, -8.28043481703888, -8.277674672099868, -8.274914527160854, -8.272154382221842, -8.269394237282828, -8.266634092343816, -8.263873947404802, -8.26111380246579, -8.258353657526778, -8.255593512587764, -8.252833367648751, -8.250073222709737, -8.247313077770725, -8.244552932831713, -8.241792787892699, -8.239032642953687, -8.236272498014673, -8.23351235307566, -8.230752208136648, -8.227992063197634, -8.22523191825862, -8.222471773319608, -8.219711628380596, -8.216951483441584, -8.21419133850257, -8.211431193563556, -8.208671048624543, -8.205910903685531, -8.203150758746519, -8.200390613807505, -8.197630468868491, -8.194870323929479, -8.192110178990466, -8.189350034051454, -8.186589889112438, -8.183829744173426, -8.181069599234414, -8.178309454295402, -8.175549309356388, -8.172789164417374, -8.170029019478362, -8.16726887453935, -8.164508729600337, -8.161748584661323, -8.15898843972231, -8.156228294783297, -8.153468149844285, -8.150708004905272, -8.147947859966258, -8.145187715027244, -8.142427570088232, -8.13966742514922, -8.136907280210206, -8.134147135271194, -8.13138699033218, -8.128626845393168, -8.125866700454155, -8.123106555515141, -8.12034641057613, -8.117586265637115, -8.114826120698103, -8.11206597575909, -8.109305830820077, -8.106545685881065, -8.103785540942052, -8.101025396003038, -8.098265251064024, -8.095505106125012, -8.092744961186, -8.089984816246988, -8.087224671307974, -8.08446452636896, -8.081704381429947, -8.078944236490935, -8.076184091551923, -8.073423946612909, -8.070663801673895, -8.067903656734883, -8.06514351179587, -8.062383366856858, -8.059623221917843, -8.05686307697883, -8.054102932039818, -8.051342787100806, -8.048582642161792, -8.045822497222778, -8.043062352283766, -8.040302207344753, -8.037542062405741, -8.034781917466727, -8.032021772527713, -8.029261627588701, -8.026501482649689, -8.023741337710677, -8.020981192771663, -8.018221047832649, -8.015460902893636, -8.012700757954624, -8.00994061301561, -8.007180468076598, -8.004420323137584, -8.001660178198572, -7.998900033259559, -7.996139888320546, -7.993379743381533, -7.990619598442519, -7.987859453503507, -7.985099308564494, -7.982339163625482, -7.979579018686469, -7.976818873747455, -7.9740587288084415, -7.971298583869429, -7.968538438930416, -7.965778293991404, -7.96301814905239, -7.960258004113377, -7.957497859174365, -7.9547377142353515, -7.951977569296339, -7.949217424357324, -7.946457279418312, -7.9436971344793, -7.940936989540287, -7.938176844601275, -7.93541669966226, -7.9326565547232475, -7.929896409784234, -7.927136264845222, -7.924376119906209, -7.921615974967195, -7.918855830028183, -7.91609568508917, -7.9133355401501575, -7.910575395211144, -7.90781525027213, -7.905055105333117, -7.902294960394105, -7.899534815455092, -7.89677467051608, -7.894014525577066, -7.891254380638053, -7.88849423569904, -7.885734090760027, -7.882973945821015, -7.880213800882001, -7.877453655942988, -7.874693511003976, -7.871933366064963, -7.86917322112595, -7.8664130761869355, -7.863652931247923, -7.86089278630891, -7.858132641369898, -7.855372496430885, -7.852612351491871, -7.849852206552859, -7.8470920616138455, -7.844331916674833, -7.84157177173582, -7.838811626796806, -7.836051481857794, -7.833291336918781, -7.830531191979769, -7.8277710470407555, -7.8250109021017416, -7.822250757162728, -7.819490612223716, -7.816730467284703, -7.813970322345691, -7.811210177406677, -7.808450032467664, -7.805689887528652, -7.8029297425896385, -7.800169597650626, -7.797409452711612, -7.794649307772599, -7.791889162833587, -7.789129017894574, -7.786368872955562, -7.783608728016547, -7.7808485830775345, -7.778088438138521, -7.775328293199509, -7.772568148260496, -7.769808003321482, -7.76704785838247, -7.764287713443457, -7.7615275685044445, -7.758767423565431, -7.756007278626419, -7.753247133687405, -7.750486988748392, -7.74772684380938, -7.744966698870367, -7.7422065539313545, -7.73944640899234, -7.736686264053327, -7.733926119114314, -7.731165974175302, -7.728405829236289, -7.725645684297275, -7.722885539358263, -7.72012539441925, -7.717365249480237, -7.714605104541224, -7.71184495960221, -7.709084814663198, -7.706324669724185, -7.703564524785173, -7.70080437984616, -7.698044234907146, -7.6952840899681325, -7.69252394502912, -7.689763800090107, -7.687003655151095, -7.684243510212081, -7.681483365273068, -7.678723220334056, -7.6759630753950425, -7.67320293045603, -7.670442785517016, -7.667682640578003, -7.664922495638991, -7.662162350699978, -7.659402205760966, -7.656642060821951, -7.6538819158829385, -7.651121770943925, -7.648361626004913, -7.6456014810659, -7.642841336126886, -7.640081191187874, -7.637321046248861, -7.6345609013098485, -7.631800756370835, -7.629040611431821, -7.626280466492809, -7.623520321553796, -7.620760176614784, -7.618000031675771, -7.615239886736757, -7.612479741797744, -7.6097195968587314, -7.606959451919718, -7.604199306980706, -7.601439162041692, -7.598679017102679, -7.595918872163667, -7.593158727224654, -7.5903985822856415, -7.587638437346627, -7.584878292407614, -7.582118147468601, -7.579358002529589, -7.576597857590577, -7.573837712651562, -7.57107756771255, -7.568317422773537, -7.565557277834524, -7.562797132895511, -7.560036987956497, -7.557276843017485, -7.554516698078472, -7.55175655313946, -7.548996408200447, -7.546236263261433, -7.5434761183224195, -7.540715973383407, -7.537955828444394, -7.535195683505382, -7.532435538566368, -7.529675393627355, -7.526915248688343, -7.5241551037493295, -7.521394958810317, -7.518634813871303, -7.51587466893229, -7.513114523993278, -7.510354379054265, -7.507594234115253, -7.504834089176238, -7.5020739442372255, -7.499313799298212, -7.4965536543592, -7.493793509420187, -7.491033364481173, -7.488273219542161, -7.485513074603148, -7.4827529296641355, -7.479992784725122, -7.477232639786108, -7.474472494847096, -7.471712349908083, -7.468952204969071, -7.466192060030058, -7.463431915091044, -7.460671770152031, -7.457911625213018, -7.455151480274005, -7.452391335334993, -7.449631190395979, -7.446871045456966, -7.444110900517954, -7.441350755578941, -7.438590610639928, -7.435830465700914, -7.433070320761901, -7.430310175822889, -7.427550030883876, -7.424789885944864, -7.422029741005849, -7.419269596066837, -7.416509451127824, -7.413749306188811, -7.410989161249798, -7.408229016310786, -7.405468871371772, -7.402708726432759, -7.399948581493747, -7.397188436554734, -7.394428291615721, -7.391668146676707, -7.388908001737694, -7.386147856798682, -7.383387711859669, -7.380627566920657, -7.377867421981642, -7.37510727704263, -7.3723471321036165, -7.369586987164604, -7.366826842225591, -7.364066697286577, -7.361306552347565, -7.358546407408552, -7.35578626246954, -7.3530261175305265, -7.3502659725915125, -7.3475058276525, -7.344745682713487, -7.341985537774475, -7.339225392835462, -7.336465247896448, -7.333705102957435, -7.3309449580184225, -7.328184813079409, -7.325424668140397, -7.322664523201383, -7.31990437826237, -7.317144233323358, -7.314384088384345, -7.3116239434453325, -7.3088637985063185, -7.306103653567305, -7.303343508628293, -7.30058336368928, -7.297823218750268, -7.295063073811253, -7.292302928872241, -7.289542783933228, -7.286782638994215, -7.284022494055202, -7.281262349116188, -7.278502204177176, -7.275742059238163, -7.272981914299151, -7.270221769360138, -7.267461624421124, -7.2647014794821105, -7.261941334543098, -7.259181189604086, -7.256421044665073, -7.253660899726059, -7.250900754787046, -7.248140609848034, -7.2453804649090205, -7.242620319970008, -7.239860175030994, -7.237100030091981, -7.234339885152969, -7.231579740213956, -7.228819595274944, -7.226059450335929, -7.223299305396917, -7.2205391604579034, -7.217779015518891, -7.215018870579879, -7.212258725640864, -7.209498580701852, -7.206738435762839, -7.203978290823827, -7.2012181458848135, -7.1984580009457995, -7.195697856006787, -7.192937711067774, -7.190177566128762, -7.187417421189749, -7.184657276250735, -7.181897131311722, -7.1791369863727095, -7.176376841433696, -7.173616696494684, -7.17085655155567, -7.168096406616657, -7.165336261677645, -7.162576116738632, -7.1598159717996195, -7.1570558268606055, -7.154295681921592, -7.15153553698258, -7.148775392043567, -7.146015247104555, -7.14325510216554, -7.140494957226528, -7.137734812287515, -7.134974667348502, -7.132214522409489, -7.129454377470475, -7.126694232531463, -7.12393408759245, -7.121173942653438, -7.118413797714425, -7.115653652775411, -7.112893507836398, -7.110133362897385, -7.107373217958373, -7.10461307301936, -7.101852928080346, -7.099092783141333, -7.096332638202321, -7.0935724932633075, -7.090812348324295, -7.088052203385281, -7.085292058446268, -7.082531913507256, -7.079771768568243, -7.077011623629231, -7.074251478690217, -7.0714913337512035, -7.068731188812191, -7.065971043873178, -7.063210898934166, -7.060450753995153, -7.057690609056139, -7.054930464117126, -7.0521703191781135, -7.0494101742391, -7.046650029300088, -7.043889884361074, -7.041129739422061, -7.038369594483049, -7.035609449544036, -7.0328493046050236, -7.03008915966601, -7.0273290147269964, -7.024568869787984, -7.021808724848971, -7.019048579909959, -7.016288434970944, -7.013528290031932, -7.010768145092919, -7.0080080001539065, -7.005247855214893, -7.002487710275879, -6.999727565336867, -6.996967420397854, -6.994207275458842, -6.991447130519829, -6.988686985580815, -6.9859268406418025, -6.983166695702789, -6.980406550763777, -6.977646405824764, -6.97488626088575, -6.972126115946737, -6.969365971007725, -6.966605826068712, -6.963845681129699, -6.961085536190685, -6.958325391251672, -6.95556524631266, -6.952805101373647, -6.950044956434635, -6.947284811495621, -6.944524666556608, -6.941764521617595, -6.939004376678582, -6.93624423173957, -6.933484086800555, -6.930723941861543, -6.92796379692253, -6.925203651983518, -6.9224435070445045, -6.9196833621054905, -6.916923217166478, -6.914163072227465, -6.911402927288453, -6.90864278234944, -6.905882637410426, -6.903122492471413, -6.9003623475324005, -6.897602202593388, -6.894842057654374, -6.892081912715362, -6.889321767776348, -6.886561622837336, -6.883801477898323, -6.88104133295931, -6.8782811880202965, -6.875521043081283, -6.872760898142271, -6.870000753203258, -6.867240608264245, -6.864480463325232, -6.861720318386219, -6.858960173447206, -6.856200028508193, -6.85343988356918, -6.850679738630167, -6.847919593691154, -6.845159448752141, -6.842399303813129, -6.839639158874115, -6.8368790139351026, -6.8341188689960894, -6.831358724057076, -6.828598579118064, -6.82583843417905, -6.823078289240038, -6.820318144301024, -6.817557999362012, -6.814797854422999, -6.8120377094839855, -6.809277564544973, -6.80651741960596, -6.803757274666947, -6.800997129727934, -6.798236984788921, -6.795476839849908, -6.7927166949108955, -6.789956549971882, -6.787196405032869, -6.784436260093856, -6.781676115154843, -6.778915970215831, -6.776155825276817, -6.773395680337805, -6.7706355353987915, -6.767875390459778, -6.765115245520766, -6.762355100581752, -6.75959495564274, -6.756834810703726, -6.754074665764714, -6.751314520825701, -6.7485543758866875, -6.745794230947675, -6.743034086008661, -6.740273941069649, -6.737513796130636, -6.734753651191623, -6.73199350625261, -6.729233361313597, -6.726473216374584, -6.723713071435571, -6.720952926496558, -6.718192781557545, -6.715432636618532, -6.712672491679519, -6.709912346740507, -6.7071522018014935, -6.70439205686248, -6.701631911923467, -6.698871766984454, -6.696111622045442, -6.693351477106428, -6.690591332167416, -6.687831187228403, -6.6850710422893895, -6.682310897350377, -6.679550752411363, -6.676790607472351, -6.674030462533337, -6.671270317594325, -6.668510172655312, -6.665750027716299, -6.662989882777286, -6.660229737838272, -6.65746959289926, -6.654709447960247, -6.651949303021234, -6.649189158082221, -6.646429013143208, -6.6436688682041956, -6.6409087232651824, -6.638148578326169, -6.635388433387156, -6.632628288448144, -6.62986814350913, -6.627107998570118, -6.624347853631105, -6.621587708692092, -6.618827563753079, -6.616067418814065, -6.613307273875053, -6.610547128936039, -6.607786983997027, -6.605026839058014, -6.602266694119001, -6.5995065491799885, -6.5967464042409745, -6.593986259301962, -6.591226114362949, -6.588465969423936, -6.585705824484923, -6.58294567954591, -6.580185534606898, -6.5774253896678845, -6.574665244728871, -6.571905099789858, -6.569144954850845, -6.566384809911832, -6.56362466497282, -6.560864520033806, -6.558104375094794, -6.5553442301557805, -6.552584085216767, -6.549823940277755, -6.547063795338741, -6.544303650399729, -6.541543505460715, -6.538783360521703, -6.5360232155826905, -6.5332630706436765, -6.530502925704664, -6.52774278076565, -6.524982635826638, -6.522222490887625, -6.519462345948612, -6.516702201009599, -6.513942056070586, -6.511181911131573, -6.50842176619256, -6.505661621253547, -6.502901476314534, -6.500141331375521, -6.497381186436508, -6.494621041497496, -6.4918608965584825, -6.489100751619469, -6.486340606680456, -6.483580461741443, -6.480820316802431, -6.478060171863417, -6.475300026924405, -6.472539881985392, -6.4697797370463785, -6.467019592107366, -6.464259447168352, -6.46149930222934, -6.458739157290327, -6.455979012351314, -6.453218867412301, -6.450458722473288, -6.447698577534275, -6.444938432595262, -6.442178287656249, -6.439418142717236, -6.436657997778223, -6.43389785283921, -6.431137707900198, -6.428377562961185, -6.4256174180221715, -6.422857273083158, -6.420097128144145, -6.417336983205133, -6.414576838266119, -6.411816693327107, -6.409056548388094, -6.406296403449081, -6.403536258510068, -6.400776113571054, -6.398015968632042, -6.395255823693028, -6.392495678754016, -6.389735533815003, -6.38697538887599, -6.3842152439369775, -6.3814550989979635, -6.378694954058951, -6.375934809119938, -6.373174664180925, -6.370414519241912, -6.367654374302899, -6.364894229363887, -6.3621340844248735, -6.35937393948586, -6.356613794546847, -6.353853649607834, -6.351093504668821, -6.348333359729809, -6.345573214790796, -6.342813069851783, -6.3400529249127695, -6.337292779973756, -6.334532635034744, -6.33177249009573, -6.329012345156718, -6.326252200217705, -6.323492055278692, -6.3207319103396795, -6.3179717654006655, -6.315211620461653, -6.312451475522639, -6.309691330583627, -6.306931185644614, -6.304171040705601, -6.301410895766589, -6.298650750827575, -6.295890605888562, -6.293130460949549, -6.290370316010536, -6.287610171071523, -6.284850026132511, -6.282089881193498, -6.279329736254485, -6.2765695913154715, -6.273809446376458, -6.271049301437446, -6.268289156498432, -6.26552901155942, -6.262768866620407, -6.260008721681394, -6.2572485767423816, -6.2544884318033676, -6.251728286864355, -6.248968141925341, -6.246207996986329, -6.243447852047316, -6.240687707108303, -6.237927562169291, -6.235167417230277, -6.2324072722912645, -6.229647127352251, -6.226886982413238, -6.224126837474225, -6.221366692535212, -6.2186065475962, -6.215846402657187, -6.213086257718174, -6.2103261127791605, -6.207565967840147, -6.204805822901134, -6.202045677962122, -6.199285533023108, -6.196525388084096, -6.193765243145083, -6.19100509820607, -6.188244953267057, -6.185484808328043, -6.182724663389031, -6.179964518450017, -6.177204373511005, -6.174444228571992, -6.171684083632979, -6.1689239386939665, -6.1661637937549525, -6.16340364881594, -6.160643503876927, -6.157883358937914, -6.155123213998901, -6.152363069059888, -6.149602924120876, -6.1468427791818625, -6.144082634242849, -6.141322489303836, -6.138562344364823, -6.13580219942581, -6.133042054486798, -6.130281909547785, -6.127521764608772, -6.1247616196697585, -6.122001474730745, -6.119241329791733, -6.116481184852719, -6.113721039913707, -6.110960894974694, -6.108200750035681, -6.1054406050966685, -6.1026804601576545, -6.099920315218642, -6.097160170279629, -6.094400025340616, -6.091639880401603, -6.08887973546259, -6.086119590523578, -6.0833594455845645, -6.080599300645551, -6.077839155706538, -6.075079010767525, -6.072318865828512, -6.0695587208895, -6.066798575950487, -6.064038431011474, -6.0612782860724606, -6.058518141133447, -6.055757996194435, -6.052997851255421, -6.050237706316409, -6.047477561377396, -6.044717416438383, -6.041957271499371, -6.039197126560357, -6.036436981621344, -6.03367683668233, -6.030916691743318, -6.028156546804305, -6.025396401865292, -6.02263625692628, -6.019876111987266, -6.0171159670482535, -6.01435582210924, -6.011595677170227, -6.008835532231214, -6.006075387292201, -6.003315242353189, -6.000555097414176, -5.997794952475163, -5.9950348075361495, -5.992274662597136, -5.989514517658123, -5.986754372719111, -5.983994227780098, -5.981234082841085, -5.978473937902072, -5.975713792963059, -5.972953648024046, -5.970193503085032, -5.96743335814602, -5.964673213207007, -5.961913068267994, -5.959152923328982, -5.956392778389968, -5.9536326334509555, -5.950872488511942, -5.948112343572929, -5.945352198633916, -5.942592053694903, -5.939831908755891, -5.937071763816878, -5.934311618877865, -5.9315514739388515, -5.928791328999838, -5.926031184060825, -5.923271039121813, -5.9205108941828, -5.917750749243787, -5.914990604304774, -5.912230459365761, -5.909470314426748, -5.906710169487734, -5.903950024548722, -5.901189879609709, -5.898429734670696, -5.895669589731684, -5.89290944479267, -5.8901492998536575, -5.8873891549146435, -5.884629009975631, -5.881868865036618, -5.879108720097605, -5.876348575158593, -5.873588430219579, -5.870828285280567, -5.8680681403415536, -5.86530799540254, -5.862547850463527, -5.859787705524514, -5.857027560585501, -5.854267415646489, -5.851507270707476, -5.848747125768463, -5.84598698082945, -5.8432268358904365, -5.840466690951424, -5.83770654601241, -5.834946401073398, -5.832186256134385, -5.829426111195372, -5.82666596625636, -5.823905821317346, -5.821145676378333, -5.818385531439319, -5.815625386500307, -5.812865241561294, -5.810105096622281, -5.807344951683269, -5.804584806744255, -5.8018246618052425, -5.799064516866229, -5.796304371927216, -5.793544226988203, -5.79078408204919, -5.788023937110178, -5.785263792171165, -5.782503647232152, -5.7797435022931385, -5.776983357354126, -5.774223212415112, -5.7714630674761, -5.768702922537087, -5.765942777598074, -5.763182632659062, -5.760422487720048, -5.757662342781035, -5.754902197842021, -5.752142052903009, -5.749381907963996, -5.746621763024983, -5.743861618085971, -5.741101473146957, -5.7383413282079445, -5.735581183268931, -5.732821038329918, -5.730060893390905, -5.727300748451892, -5.72454060351288, -5.721780458573867, -5.719020313634854, -5.7162601686958405, -5.713500023756827, -5.710739878817814, -5.707979733878802, -5.705219588939789, -5.702459444000776, -5.699699299061763, -5.69693915412275, -5.694179009183737, -5.691418864244723, -5.688658719305711, -5.685898574366698, -5.683138429427685, -5.680378284488673, -5.677618139549659, -5.6748579946106465, -5.6720978496716326, -5.66933770473262, -5.666577559793607, -5.663817414854594, -5.661057269915582, -5.658297124976568, -5.655536980037556, -5.652776835098543, -5.6500166901595295, -5.647256545220516, -5.644496400281503, -5.641736255342491, -5.638976110403478, -5.636215965464465, -5.633455820525452, -5.630695675586439, -5.6279355306474255, -5.625175385708413, -5.6224152407694, -5.619655095830387, -5.616894950891374, -5.614134805952361, -5.611374661013349, -5.608614516074335, -5.605854371135322, -5.603094226196309, -5.600334081257296, -5.597573936318284, -5.59481379137927, -5.592053646440258, -5.589293501501245, -5.5865333565622315, -5.583773211623218, -5.581013066684205, -5.578252921745193, -5.57549277680618, -5.572732631867167, -5.569972486928154, -5.567212341989141, -5.5644521970501275, -5.561692052111115, -5.558931907172102, -5.556171762233089, -5.553411617294076, -5.550651472355063, -5.547891327416051, -5.545131182477037, -5.542371037538024, -5.53961089259901, -5.536850747659998, -5.534090602720986, -5.531330457781972, -5.52857031284296, -5.525810167903946, -5.5230500229649335, -5.52028987802592, -5.517529733086907, -5.514769588147895, -5.512009443208881, -5.509249298269869, -5.506489153330856, -5.503729008391843, -5.5009688634528295, -5.498208718513816, -5.495448573574803, -5.492688428635791, -5.489928283696778, -5.487168138757765, -5.484407993818752, -5.481647848879739, -5.478887703940726, -5.476127559001712, -5.4733674140627, -5.470607269123687, -5.467847124184674, -5.465086979245662, -5.462326834306648, -5.459566689367636, -5.456806544428622, -5.454046399489609, -5.451286254550596, -5.448526109611583, -5.445765964672571, -5.443005819733557, -5.440245674794545, -5.437485529855532, -5.4347253849165185, -5.431965239977505, -5.429205095038493, -5.42644495009948, -5.423684805160467, -5.420924660221454, -5.418164515282441, -5.4154043703434285, -5.4126442254044145, -5.409884080465402, -5.407123935526389, -5.404363790587376, -5.401603645648364, -5.39884350070935, -5.396083355770338, -5.393323210831324, -5.390563065892311, -5.387802920953298, -5.385042776014285, -5.382282631075273, -5.379522486136259, -5.376762341197247, -5.374002196258234, -5.3712420513192205, -5.368481906380207, -5.365721761441194, -5.362961616502182, -5.360201471563169, -5.357441326624156, -5.354681181685143, -5.35192103674613, -5.3491608918071165, -5.346400746868104, -5.343640601929091, -5.340880456990078, -5.338120312051065, -5.335360167112052, -5.33260002217304, -5.329839877234026, -5.327079732295013, -5.324319587356, -5.321559442416987, -5.318799297477975, -5.316039152538961, -5.313279007599949, -5.310518862660935, -5.3077587177219225, -5.304998572782909, -5.302238427843896, -5.299478282904884, -5.29671813796587, -5.293957993026858, -5.291197848087845, -5.288437703148832, -5.2856775582098185, -5.282917413270805, -5.280157268331793, -5.27739712339278, -5.274636978453767, -5.271876833514754, -5.269116688575741, -5.266356543636728, -5.2635963986977155, -5.260836253758702, -5.258076108819689, -5.255315963880677, -5.252555818941663, -5.249795674002651, -5.247035529063637, -5.244275384124625, -5.2415152391856115, -5.238755094246598, -5.235994949307586, -5.233234804368572, -5.23047465942956, -5.227714514490547, -5.224954369551534, -5.222194224612521, -5.2194340796735075, -5.216673934734495, -5.213913789795482, -5.211153644856469, -5.208393499917456, -5.205633354978443, -5.20287321003943, -5.2001130651004175, -5.197352920161404, -5.194592775222391, -5.191832630283378, -5.189072485344365, -5.186312340405353, -5.183552195466339, -5.180792050527327, -5.178031905588313, -5.1752717606493, -5.172511615710288, -5.169751470771274, -5.166991325832262, -5.164231180893248, -5.161471035954236, -5.158710891015223, -5.1559507460762095, -5.153190601137196, -5.150430456198183, -5.147670311259171, -5.144910166320158, -5.142150021381145, -5.139389876442132, -5.136629731503119, -5.1338695865641055, -5.131109441625093, -5.12834929668608, -5.125589151747067, -5.122829006808054, -5.120068861869041, -5.117308716930029, -5.114548571991015, -5.111788427052002, -5.109028282112989, -5.106268137173976, -5.103507992234964, -5.10074784729595, -5.097987702356938, -5.095227557417924, -5.0924674124789115, -5.089707267539898, -5.086947122600885, -5.084186977661873, -5.08142683272286, -5.078666687783847, -5.075906542844834, -5.073146397905821, -5.070386252966808, -5.067626108027795, -5.064865963088782, -5.062105818149769, -5.059345673210756, -5.056585528271743, -5.053825383332731, -5.051065238393717, -5.0483050934547045, -5.045544948515691, -5.042784803576678, -5.040024658637666, -5.037264513698652, -5.03450436875964, -5.031744223820626, -5.028984078881614, -5.0262239339426005, -5.023463789003587, -5.020703644064575, -5.017943499125561, -5.015183354186549, -5.012423209247536, -5.009663064308523, -5.00690291936951, -5.0041427744304965, -5.001382629491484, -4.998622484552471, -4.995862339613458, -4.993102194674445, -4.990342049735432, -4.987581904796419, -4.9848217598574065, -4.982061614918393, -4.97930146997938, -4.976541325040367, -4.973781180101354, -4.971021035162342, -4.968260890223328, -4.965500745284316, -4.9627406003453025, -4.959980455406289, -4.957220310467277, -4.954460165528263, -4.951700020589251, -4.948939875650237, -4.946179730711225, -4.943419585772212, -4.9406594408331985, -4.937899295894186, -4.935139150955172, -4.93237900601616, -4.929618861077147, -4.926858716138134, -4.924098571199121, -4.921338426260108, -4.918578281321095, -4.915818136382082, -4.913057991443069, -4.910297846504056, -4.907537701565044, -4.90477755662603, -4.902017411687018, -4.8992572667480045, -4.896497121808991, -4.893736976869979, -4.890976831930965, -4.888216686991953, -4.885456542052939, -4.882696397113927, -4.879936252174914, -4.877176107235901, -4.874415962296888, -4.871655817357874, -4.868895672418862, -4.866135527479849, -4.863375382540836, -4.860615237601823, -4.85785509266281, -4.8550949477237975, -4.852334802784784, -4.849574657845771, -4.846814512906758, -4.844054367967745, -4.841294223028732, -4.83853407808972, -4.835773933150706, -4.8330137882116935, -4.83025364327268, -4.827493498333667, -4.824733353394655, -4.821973208455641, -4.819213063516629, -4.816452918577615, -4.813692773638603, -4.81093262869959, -4.808172483760576, -4.805412338821564, -4.80265219388255, -4.799892048943538, -4.797131904004525, -4.794371759065512, -4.791611614126499, -4.7888514691874855, -4.786091324248473, -4.78333117930946, -4.780571034370447, -4.777810889431434, -4.775050744492421, -4.772290599553408, -4.7695304546143955, -4.766770309675382, -4.764010164736369, -4.761250019797356, -4.758489874858343, -4.755729729919331, -4.752969584980317, -4.750209440041305, -4.7474492951022915, -4.744689150163278, -4.741929005224266, -4.739168860285252, -4.73640871534624, -4.733648570407227, -4.730888425468214, -4.728128280529201, -4.7253681355901875, -4.722607990651175, -4.719847845712162, -4.717087700773149, -4.714327555834136, -4.711567410895123, -4.70880726595611, -4.7060471210170975, -4.703286976078084, -4.700526831139071, -4.697766686200058, -4.695006541261045, -4.692246396322033, -4.689486251383019, -4.686726106444007, -4.683965961504994, -4.6812058165659804, -4.678445671626968, -4.675685526687954, -4.672925381748942, -4.670165236809928, -4.667405091870916, -4.664644946931903, -4.66188480199289, -4.659124657053877, -4.656364512114863, -4.653604367175851, -4.650844222236838, -4.648084077297825, -4.645323932358812, -4.642563787419799, -4.6398036424807865, -4.637043497541773, -4.63428335260276, -4.631523207663747, -4.628763062724734, -4.626002917785721, -4.623242772846709, -4.620482627907696, -4.6177224829686825, -4.614962338029669, -4.612202193090656, -4.609442048151644, -4.60668190321263, -4.603921758273618, -4.601161613334605, -4.598401468395592, -4.595641323456579, -4.592881178517565, -4.590121033578553, -4.587360888639539, -4.584600743700527, -4.581840598761514, -4.579080453822501, -4.5763203088834885, -4.5735601639444745, -4.570800019005462, -4.568039874066449, -4.565279729127436, -4.562519584188423, -4.559759439249411, -4.556999294310398, -4.5542391493713845, -4.551479004432371, -4.548718859493358, -4.545958714554346, -4.543198569615332, -4.54043842467632, -4.537678279737307, -4.534918134798294, -4.532157989859281, -4.529397844920267, -4.526637699981255, -4.523877555042241, -4.521117410103229, -4.518357265164216, -4.515597120225203, -4.5128369752861905, -4.5100768303471765, -4.507316685408164, -4.504556540469151, -4.501796395530138, -4.499036250591125, -4.496276105652112, -4.4935159607131, -4.490755815774087, -4.4879956708350734, -4.48523552589606, -4.482475380957047, -4.479715236018034, -4.476955091079022, -4.474194946140008, -4.471434801200996, -4.468674656261983, -4.4659145113229695, -4.463154366383957, -4.460394221444943, -4.457634076505931, -4.454873931566917, -4.452113786627905, -4.449353641688892, -4.446593496749879, -4.443833351810866, -4.441073206871852, -4.43831306193284, -4.435552916993827, -4.432792772054814, -4.430032627115801, -4.427272482176788, -4.4245123372377755, -4.421752192298762, -4.418992047359749, -4.416231902420736, -4.413471757481723, -4.41071161254271, -4.407951467603698, -4.405191322664685, -4.4024311777256715, -4.399671032786658, -4.396910887847645, -4.394150742908633, -4.391390597969619, -4.388630453030607, -4.385870308091594, -4.383110163152581, -4.380350018213568, -4.377589873274554, -4.374829728335542, -4.372069583396529, -4.369309438457516, -4.366549293518503, -4.36378914857949, -4.3610290036404775, -4.358268858701464, -4.355508713762451, -4.352748568823438, -4.349988423884425, -4.347228278945412, -4.3444681340064, -4.341707989067387, -4.3389478441283735, -4.33618769918936, -4.333427554250347, -4.330667409311335, -4.327907264372321, -4.325147119433309, -4.322386974494296, -4.319626829555283, -4.31686668461627, -4.314106539677256, -4.311346394738244, -4.30858624979923, -4.305826104860218, -4.303065959921205, -4.300305814982192, -4.2975456700431796, -4.294785525104166, -4.292025380165153, -4.28926523522614, -4.286505090287127, -4.283744945348114, -4.280984800409101, -4.278224655470089, -4.275464510531076, -4.2727043655920625, -4.269944220653049, -4.267184075714036, -4.264423930775023, -4.261663785836011, -4.258903640896998, -4.256143495957985, -4.253383351018972, -4.2506232060799585, -4.247863061140946, -4.245102916201932, -4.24234277126292, -4.239582626323907, -4.236822481384894, -4.234062336445882, -4.231302191506868, -4.228542046567855, -4.225781901628842, -4.223021756689829, -4.220261611750816, -4.217501466811803, -4.214741321872791, -4.211981176933778, -4.2092210319947645, -4.206460887055751, -4.203700742116738, -4.200940597177725, -4.198180452238713, -4.1954203072997, -4.192660162360687, -4.189900017421674, -4.1871398724826605, -4.184379727543648, -4.181619582604634, -4.178859437665622, -4.176099292726609, -4.173339147787596, -4.170579002848584, -4.16781885790957, -4.165058712970557, -4.162298568031543, -4.159538423092531, -4.156778278153518, -4.154018133214505, -4.151257988275493, -4.148497843336479, -4.1457376983974665, -4.142977553458453, -4.14021740851944, -4.137457263580427, -4.134697118641414, -4.131936973702401, -4.129176828763389, -4.126416683824376, -4.1236565388853625, -4.120896393946349, -4.118136249007336, -4.115376104068324, -4.11261595912931, -4.109855814190298, -4.107095669251285, -4.104335524312272, -4.1015753793732594, -4.0988152344342454, -4.096055089495233, -4.093294944556219, -4.090534799617207, -4.087774654678194, -4.085014509739181, -4.082254364800169, -4.079494219861155, -4.076734074922142, -4.073973929983129, -4.071213785044116, -4.068453640105103, -4.06569349516609, -4.062933350227078, -4.060173205288065, -4.0574130603490515, -4.054652915410038, -4.051892770471026, -4.049132625532012, -4.046372480593, -4.043612335653987, -4.040852190714974, -4.0380920457759615, -4.0353319008369475, -4.032571755897935, -4.029811610958921, -4.027051466019909, -4.024291321080896, -4.021531176141883, -4.018771031202871, -4.016010886263857, -4.013250741324844, -4.010490596385831, -4.007730451446818, -4.004970306507805, -4.002210161568792, -3.9994500166297793, -3.9966898716907666, -3.9939297267517535, -3.991169581812741, -3.9884094368737273, -3.9856492919347146, -3.982889146995702, -3.9801290020566884, -3.9773688571176757, -3.974608712178662, -3.97184856723965, -3.9690884223006373, -3.9663282773616237, -3.963568132422611, -3.9608079874835975, -3.958047842544585, -3.955287697605572, -3.9525275526665586, -3.949767407727546, -3.947007262788533, -3.94424711784952, -3.9414869729105075, -3.938726827971494, -3.9359666830324813, -3.9332065380934678, -3.930446393154455, -3.9276862482154424, -3.9249261032764293, -3.9221659583374167, -3.919405813398403, -3.9166456684593904, -3.9138855235203778, -3.911125378581364, -3.9083652336423516, -3.9056050887033384, -3.902844943764326, -3.900084798825313, -3.8973246538862996, -3.894564508947287, -3.8918043640082733, -3.8890442190692607, -3.886284074130248, -3.883523929191235, -3.8807637842522222, -3.8780036393132096, -3.875243494374196, -3.8724833494351834, -3.86972320449617, -3.866963059557157, -3.8642029146181445, -3.8614427696791314, -3.8586826247401187, -3.855922479801105, -3.8531623348620925, -3.85040218992308, -3.8476420449840663, -3.8448819000450536, -3.8421217551060405, -3.839361610167028, -3.836601465228015, -3.8338413202890016, -3.831081175349989, -3.8283210304109754, -3.8255608854719627, -3.82280074053295, -3.820040595593937, -3.8172804506549243, -3.8145203057159107, -3.811760160776898, -3.8090000158378854, -3.806239870898872, -3.803479725959859, -3.800719581020846, -3.7979594360818334, -3.7951992911428207, -3.792439146203807, -3.7896790012647945, -3.786918856325781, -3.7841587113867683, -3.7813985664477556, -3.7786384215087425, -3.77587827656973, -3.7731181316307163, -3.7703579866917036, -3.767597841752691, -3.7648376968136774, -3.7620775518746647, -3.7593174069356516, -3.756557261996639, -3.7537971170576263, -3.7510369721186128, -3.7482768271796, -3.7455166822405865, -3.742756537301574, -3.739996392362561, -3.737236247423548, -3.7344761024845354, -3.731715957545522, -3.728955812606509, -3.7261956676674965, -3.723435522728483, -3.7206753777894703, -3.717915232850457, -3.7151550879114446, -3.712394942972432, -3.7096347980334183, -3.7068746530944057, -3.704114508155393, -3.7013543632163795, -3.698594218277367, -3.6958340733383537, -3.693073928399341, -3.6903137834603283, -3.687553638521315, -3.684793493582302, -3.6820333486432886, -3.679273203704276, -3.6765130587652632, -3.67375291382625, -3.6709927688872375, -3.668232623948224, -3.6654724790092112, -3.6627123340701986, -3.659952189131185, -3.6571920441921724, -3.6544318992531593, -3.6516717543141466, -3.648911609375134, -3.6461514644361204, -3.6433913194971077, -3.640631174558094, -3.6378710296190815, -3.635110884680069, -3.6323507397410553, -3.629590594802043, -3.6268304498630295, -3.624070304924017, -3.621310159985004, -3.6185500150459906, -3.615789870106978, -3.6130297251679644, -3.6102695802289517, -3.6075094352899395, -3.604749290350926, -3.6019891454119133, -3.5992290004728997, -3.596468855533887, -3.5937087105948744, -3.590948565655861, -3.588188420716848, -3.585428275777835, -3.5826681308388224, -3.5799079858998097, -3.577147840960796, -3.5743876960217835, -3.57162755108277, -3.5688674061437573, -3.5661072612047446, -3.5633471162657315, -3.560586971326719, -3.5578268263877053, -3.5550666814486926, -3.55230653650968, -3.5495463915706664, -3.5467862466316538, -3.5440261016926407, -3.541265956753628, -3.5385058118146153, -3.5357456668756018, -3.532985521936589, -3.5302253769975764, -3.527465232058563, -3.52470508711955, -3.521944942180537, -3.5191847972415244, -3.5164246523025118, -3.5136645073634982, -3.5109043624244856, -3.508144217485472, -3.5053840725464593, -3.5026239276074467, -3.4998637826684336, -3.497103637729421, -3.4943434927904073, -3.4915833478513947, -3.488823202912382, -3.4860630579733685, -3.483302913034356, -3.4805427680953427, -3.47778262315633, -3.4750224782173174, -3.472262333278304, -3.469502188339291, -3.4667420434002776, -3.463981898461265, -3.4612217535222523, -3.458461608583239, -3.4557014636442265, -3.452941318705213, -3.4501811737662003, -3.447421028827187, -3.444660883888174, -3.4419007389491614, -3.4391405940101483, -3.4363804490711356, -3.4336203041321225, -3.4308601591931094, -3.4281000142540967, -3.4253398693150836, -3.4225797243760705, -3.4198195794370574, -3.4170594344980447, -3.414299289559032, -3.411539144620019, -3.408778999681006, -3.4060188547419927, -3.40325870980298, -3.400498564863967, -3.397738419924954, -3.394978274985941, -3.392218130046928, -3.3894579851079154, -3.3866978401689023, -3.383937695229889, -3.381177550290876, -3.378417405351863, -3.3756572604128503, -3.3728971154738376, -3.3701369705348245, -3.3673768255958114, -3.3646166806567983, -3.3618565357177856, -3.3590963907787725, -3.3563362458397594, -3.3535761009007468, -3.3508159559617336, -3.348055811022721, -3.345295666083708, -3.3425355211446948, -3.3397753762056817, -3.3370152312666685, -3.334255086327656, -3.331494941388643, -3.32873479644963, -3.325974651510617, -3.323214506571604, -3.3204543616325912, -3.317694216693578, -3.314934071754565, -3.3121739268155523, -3.3094137818765397, -3.3066536369375266, -3.3038934919985135, -3.3011333470595003, -3.2983732021204872, -3.2956130571814746, -3.2928529122424615, -3.290092767303449, -3.2873326223644357, -3.2845724774254226, -3.28181233248641, -3.279052187547397, -3.2762920426083837, -3.2735318976693706, -3.2707717527303575, -3.2680116077913453, -3.265251462852332, -3.262491317913319, -3.259731172974306, -3.256971028035293, -3.25421088309628, -3.251450738157267, -3.248690593218254, -3.2459304482792413, -3.243170303340228, -3.2404101584012155, -3.2376500134622024, -3.2348898685231893, -3.232129723584176, -3.2293695786451635, -3.2266094337061504, -3.2238492887671377, -3.2210891438281246, -3.2183289988891115, -3.215568853950099, -3.2128087090110857, -3.2100485640720726, -3.2072884191330595, -3.204528274194047, -3.201768129255034, -3.199007984316021, -3.196247839377008, -3.193487694437995, -3.1907275494989817, -3.187967404559969, -3.185207259620956, -3.1824471146819433, -3.17968696974293, -3.176926824803917, -3.1741666798649044, -3.1714065349258913, -3.168646389986878, -3.165886245047865, -3.1631261001088524, -3.1603659551698398, -3.1576058102308266, -3.1548456652918135, -3.1520855203528004, -3.1493253754137873, -3.1465652304747747, -3.1438050855357615, -3.141044940596749, -3.1382847956577358, -3.135524650718723, -3.13276450577971, -3.130004360840697, -3.1272442159016838, -3.1244840709626707, -3.121723926023658, -3.1189637810846453, -3.1162036361456322, -3.113443491206619, -3.110683346267606, -3.1079232013285933, -3.1051630563895802, -3.102402911450567, -3.099642766511554, -3.0968826215725413, -3.0941224766335287, -3.0913623316945156, -3.0886021867555025, -3.0858420418164894, -3.0830818968774762, -3.0803217519384636, -3.0775616069994505, -3.074801462060438, -3.0720413171214247, -3.0692811721824116, -3.066521027243399, -3.063760882304386, -3.0610007373653727, -3.0582405924263596, -3.055480447487347, -3.0527203025483343, -3.049960157609321, -3.047200012670308, -3.044439867731295, -3.0416797227922823, -3.038919577853269, -3.036159432914256, -3.0333992879752434, -3.0306391430362303, -3.0278789980972176, -3.0251188531582045, -3.0223587082191914, -3.0195985632801783, -3.016838418341165, -3.0140782734021525, -3.01131812846314, -3.0085579835241267, -3.0057978385851136, -3.0030376936461005, -3.000277548707088, -2.9975174037680747, -2.9947572588290616, -2.991997113890049, -2.989236968951036, -2.986476824012023, -2.98371667907301, -2.980956534133997, -2.978196389194984, -2.975436244255971, -2.972676099316958, -2.9699159543779454, -2.9671558094389323, -2.964395664499919, -2.9616355195609065, -2.9588753746218934, -2.9561152296828803, -2.953355084743867, -2.9505949398048545, -2.947834794865842, -2.9450746499268288, -2.9423145049878157, -2.9395543600488026, -2.9367942151097894, -2.9340340701707768, -2.9312739252317637, -2.9285137802927506, -2.925753635353738, -2.922993490414725, -2.920233345475712, -2.917473200536699, -2.914713055597686, -2.911952910658673, -2.9091927657196597, -2.906432620780647, -2.9036724758416343, -2.9009123309026212, -2.898152185963608, -2.895392041024595, -2.8926318960855824, -2.8898717511465692, -2.887111606207556, -2.8843514612685435, -2.881591316329531, -2.8788311713905177, -2.8760710264515046, -2.8733108815124915, -2.8705507365734784, -2.8677905916344657, -2.8650304466954526, -2.86227030175644, -2.859510156817427, -2.8567500118784137, -2.853989866939401, -2.851229722000388, -2.848469577061375, -2.8457094321223617, -2.842949287183349, -2.8401891422443364, -2.8374289973053233, -2.83466885236631, -2.831908707427297, -2.829148562488284, -2.8263884175492713, -2.823628272610258, -2.8208681276712455, -2.8181079827322324, -2.8153478377932193, -2.8125876928542066, -2.8098275479151935, -2.8070674029761804, -2.8043072580371673, -2.8015471130981546, -2.798786968159142, -2.796026823220129, -2.7932666782811157, -2.7905065333421026, -2.78774638840309, -2.784986243464077, -2.7822260985250638, -2.779465953586051, -2.776705808647038, -2.7739456637080253, -2.771185518769012, -2.768425373829999, -2.765665228890986, -2.762905083951973, -2.76014493901296, -2.7573847940739475, -2.7546246491349344, -2.7518645041959213, -2.749104359256908, -2.7463442143178955, -2.7435840693788824, -2.7408239244398693, -2.738063779500856, -2.7353036345618436, -2.732543489622831, -2.729783344683818, -2.7270231997448047, -2.7242630548057916, -2.7215029098667785, -2.718742764927766, -2.7159826199887527, -2.71322247504974, -2.710462330110727, -2.7077021851717142, -2.704942040232701, -2.702181895293688, -2.699421750354675, -2.696661605415662, -2.693901460476649, -2.6911413155376365, -2.6883811705986234, -2.6856210256596103, -2.682860880720597, -2.6801007357815845, -2.6773405908425714, -2.6745804459035583, -2.6718203009645456, -2.6690601560255325, -2.66630001108652, -2.6635398661475067, -2.6607797212084936, -2.6580195762694805, -2.6552594313304674, -2.6524992863914547, -2.649739141452442, -2.646978996513429, -2.644218851574416, -2.6414587066354027, -2.63869856169639, -2.635938416757377, -2.633178271818364, -2.630418126879351, -2.6276579819403385, -2.6248978370013254, -2.6221376920623123, -2.619377547123299, -2.616617402184286, -2.6138572572452734, -2.6110971123062603, -2.6083369673672476, -2.6055768224282345, -2.6028166774892214, -2.6000565325502087, -2.5972963876111956, -2.5945362426721825, -2.5917760977331694, -2.5890159527941563, -2.586255807855144, -2.583495662916131, -2.580735517977118, -2.5779753730381048, -2.5752152280990916, -2.572455083160079, -2.569694938221066, -2.5669347932820528, -2.56417464834304, -2.561414503404027, -2.5586543584650143, -2.555894213526001, -2.553134068586988, -2.550373923647975, -2.547613778708962, -2.544853633769949, -2.5420934888309366, -2.5393333438919234, -2.5365731989529103, -2.5338130540138977, -2.5310529090748846, -2.5282927641358715, -2.5255326191968583, -2.5227724742578457, -2.520012329318833, -2.51725218437982, -2.514492039440807, -2.5117318945017937, -2.5089717495627806, -2.506211604623768, -2.503451459684755, -2.500691314745742, -2.497931169806729, -2.495171024867716, -2.4924108799287032, -2.48965073498969, -2.486890590050677, -2.484130445111664, -2.4813703001726513, -2.4786101552336386, -2.4758500102946255, -2.4730898653556124, -2.4703297204165993, -2.467569575477586, -2.4648094305385735, -2.4620492855995604, -2.4592891406605477, -2.4565289957215346, -2.453768850782522, -2.451008705843509, -2.4482485609044957, -2.4454884159654826, -2.4427282710264695, -2.439968126087457, -2.437207981148444, -2.434447836209431, -2.431687691270418, -2.428927546331405, -2.426167401392392, -2.423407256453379, -2.420647111514366, -2.417886966575353, -2.41512682163634, -2.4123666766973275, -2.4096065317583144, -2.4068463868193013, -2.404086241880288, -2.401326096941275, -2.3985659520022624, -2.3958058070632493, -2.3930456621242366, -2.3902855171852235, -2.3875253722462104, -2.3847652273071978, -2.3820050823681846, -2.3792449374291715, -2.3764847924901584, -2.3737246475511458, -2.370964502612133, -2.36820435767312, -2.365444212734107, -2.3626840677950938, -2.359923922856081, -2.357163777917068, -2.354403632978055, -2.351643488039042, -2.348883343100029, -2.3461231981610164, -2.3433630532220033, -2.3406029082829902, -2.337842763343977, -2.335082618404964, -2.3323224734659513, -2.3295623285269387, -2.3268021835879256, -2.3240420386489125, -2.3212818937098993, -2.3185217487708867, -2.3157616038318736, -2.3130014588928605, -2.310241313953848, -2.3074811690148347, -2.304721024075822, -2.301960879136809, -2.299200734197796, -2.2964405892587827, -2.2936804443197696, -2.290920299380757, -2.2881601544417443, -2.285400009502731, -2.282639864563718, -2.2798797196247054, -2.2771195746856923, -2.274359429746679, -2.271599284807666, -2.2688391398686534, -2.2660789949296407, -2.2633188499906276, -2.2605587050516145, -2.2577985601126014, -2.2550384151735883, -2.2522782702345756, -2.2495181252955625, -2.24675798035655, -2.2439978354175367, -2.2412376904785236, -2.238477545539511, -2.235717400600498, -2.2329572556614847, -2.2301971107224716, -2.2274369657834585, -2.224676820844446, -2.221916675905433, -2.21915653096642, -2.216396386027407, -2.213636241088394, -2.210876096149381, -2.208115951210368, -2.205355806271355, -2.2025956613323423, -2.199835516393329, -2.1970753714543165, -2.1943152265153034, -2.1915550815762903, -2.188794936637277, -2.1860347916982645, -2.1832746467592514, -2.1805145018202388, -2.1777543568812257, -2.1749942119422125, -2.1722340670032, -2.1694739220641868, -2.1667137771251737, -2.1639536321861605, -2.161193487247148, -2.158433342308135, -2.155673197369122, -2.152913052430109, -2.150152907491096, -2.147392762552083, -2.14463261761307, -2.141872472674057, -2.1391123277350443, -2.1363521827960312, -2.133592037857018, -2.1308318929180055, -2.1280717479789923, -2.1253116030399792, -2.122551458100966, -2.1197913131619535, -2.117031168222941, -2.1142710232839277, -2.1115108783449146, -2.1087507334059015, -2.105990588466889, -2.1032304435278757, -2.1004702985888626, -2.09771015364985, -2.094950008710837, -2.092189863771824, -2.089429718832811, -2.086669573893798, -2.083909428954785, -2.0811492840157717, -2.078389139076759, -2.0756289941377464, -2.0728688491987333, -2.07010870425972, -2.067348559320707, -2.0645884143816944, -2.0618282694426813, -2.059068124503668, -2.056307979564655, -2.0535478346256424, -2.0507876896866297, -2.0480275447476166, -2.0452673998086035, -2.0425072548695904, -2.0397471099305773, -2.0369869649915646, -2.0342268200525515, -2.031466675113539, -2.0287065301745257, -2.025946385235513, -2.0231862402965, -2.020426095357487, -2.0176659504184737, -2.0149058054794606, -2.012145660540448, -2.0093855156014353, -2.006625370662422, -2.003865225723409, -2.001105080784396, -1.9983449358453833, -1.9955847909063704, -1.9928246459673573, -1.9900645010283442, -1.987304356089331, -1.9845442111503186, -1.9817840662113055, -1.9790239212722924, -1.9762637763332793, -1.9735036313942664, -1.9707434864552538, -1.9679833415162407, -1.9652231965772275, -1.9624630516382147, -1.9597029066992016, -1.9569427617601889, -1.9541826168211758, -1.951422471882163, -1.9486623269431498, -1.9459021820041367, -1.943142037065124, -1.9403818921261111, -1.937621747187098, -1.934861602248085, -1.9321014573090722, -1.9293413123700593, -1.9265811674310462, -1.9238210224920331, -1.9210608775530202, -1.9183007326140076, -1.9155405876749945, -1.9127804427359814, -1.9100202977969685, -1.9072601528579554, -1.9045000079189427, -1.9017398629799296, -1.8989797180409167, -1.8962195731019036, -1.8934594281628905, -1.8906992832238778, -1.887939138284865, -1.8851789933458518, -1.8824188484068387, -1.8796587034678258, -1.8768985585288132, -1.8741384135898, -1.871378268650787, -1.868618123711774, -1.865857978772761, -1.8630978338337483, -1.8603376888947352, -1.8575775439557223, -1.8548173990167092, -1.8520572540776965, -1.8492971091386834, -1.8465369641996705, -1.8437768192606574, -1.8410166743216443, -1.8382565293826316, -1.8354963844436187, -1.8327362395046056, -1.8299760945655925, -1.8272159496265796, -1.824455804687567, -1.8216956597485539, -1.8189355148095407, -1.8161753698705276, -1.8134152249315147, -1.810655079992502, -1.807894935053489, -1.8051347901144759, -1.802374645175463, -1.7996145002364499, -1.7968543552974372, -1.794094210358424, -1.7913340654194112, -1.788573920480398, -1.785813775541385, -1.7830536306023723, -1.7802934856633594, -1.7775333407243463, -1.7747731957853332, -1.7720130508463203, -1.7692529059073077, -1.7664927609682946, -1.7637326160292814, -1.7609724710902686, -1.7582123261512559, -1.7554521812122428, -1.7526920362732297, -1.7499318913342168, -1.7471717463952037, -1.744411601456191, -1.741651456517178, -1.738891311578165, -1.736131166639152, -1.7333710217001388, -1.7306108767611261, -1.7278507318221132, -1.7250905868831001, -1.722330441944087, -1.7195702970050741, -1.7168101520660612, -1.7140500071270484, -1.7112898621880352, -1.7085297172490224, -1.7057695723100095, -1.7030094273709964, -1.7002492824319835, -1.6974891374929706, -1.6947289925539577, -1.6919688476149446, -1.6892087026759315, -1.6864485577369188, -1.6836884127979057, -1.6809282678588928, -1.6781681229198797, -1.6754079779808668, -1.672647833041854, -1.6698876881028408, -1.667127543163828, -1.664367398224815, -1.661607253285802, -1.658847108346789, -1.6560869634077762, -1.6533268184687633, -1.6505666735297502, -1.6478065285907373, -1.6450463836517244, -1.6422862387127113, -1.6395260937736984, -1.6367659488346853, -1.6340058038956726, -1.6312456589566595, -1.6284855140176464, -1.6257253690786335, -1.6229652241396206, -1.6202050792006077, -1.6174449342615946, -1.6146847893225817, -1.6119246443835689, -1.6091644994445558, -1.6064043545055429, -1.6036442095665298, -1.600884064627517, -1.598123919688504, -1.5953637747494909, -1.592603629810478, -1.589843484871465, -1.5870833399324522, -1.584323194993439, -1.5815630500544262, -1.5788029051154133, -1.5760427601764002, -1.5732826152373873, -1.5705224702983744, -1.5677623253593616, -1.5650021804203484, -1.5622420354813353, -1.5594818905423227, -1.5567217456033096, -1.5539616006642967, -1.5512014557252836, -1.5484413107862707, -1.5456811658472578, -1.5429210209082447, -1.5401608759692318, -1.537400731030219, -1.5346405860912058, -1.531880441152193, -1.5291202962131798, -1.5263601512741671, -1.523600006335154, -1.5208398613961411, -1.518079716457128, -1.5153195715181151, -1.5125594265791023, -1.5097992816400891, -1.5070391367010763, -1.5042789917620634, -1.5015188468230503, -1.4987587018840374, -1.4959985569450245, -1.4932384120060116, -1.4904782670669985, -1.4877181221279856, -1.4849579771889727, -1.4821978322499596, -1.4794376873109467, -1.4766775423719336, -1.473917397432921, -1.4711572524939078, -1.4683971075548947, -1.4656369626158818, -1.462876817676869, -1.460116672737856, -1.457356527798843, -1.4545963828598298, -1.4518362379208172, -1.449076092981804, -1.4463159480427912, -1.443555803103778, -1.4407956581647654, -1.4380355132257523, -1.4352753682867392, -1.4325152233477263, -1.4297550784087134, -1.4269949334697005, -1.4242347885306874, -1.4214746435916745, -1.4187144986526616, -1.4159543537136485, -1.4131942087746356, -1.4104340638356228, -1.4076739188966096, -1.4049137739575968, -1.4021536290185836, -1.399393484079571, -1.3966333391405579, -1.393873194201545, -1.3911130492625319, -1.388352904323519, -1.385592759384506, -1.382832614445493, -1.38007246950648, -1.3773123245674672, -1.374552179628454, -1.3717920346894412, -1.369031889750428, -1.3662717448114154, -1.3635115998724023, -1.3607514549333892, -1.3579913099943763, -1.3552311650553635, -1.3524710201163506, -1.3497108751773375, -1.3469507302383246, -1.3441905852993117, -1.3414304403602986, -1.3386702954212857, -1.3359101504822728, -1.33315000554326, -1.3303898606042468, -1.3276297156652337, -1.324869570726221, -1.322109425787208, -1.319349280848195, -1.316589135909182, -1.3138289909701693, -1.3110688460311561, -1.308308701092143, -1.3055485561531301, -1.3027884112141173, -1.3000282662751044, -1.2972681213360913, -1.2945079763970782, -1.2917478314580655, -1.2889876865190524, -1.2862275415800395, -1.2834673966410264, -1.2807072517020135, -1.2779471067630006, -1.2751869618239875, -1.2724268168849746, -1.2696666719459617, -1.2669065270069488, -1.2641463820679357, -1.2613862371289228, -1.25862609218991, -1.2558659472508968, -1.253105802311884, -1.250345657372871, -1.247585512433858, -1.244825367494845, -1.242065222555832, -1.2393050776168193, -1.2365449326778062, -1.233784787738793, -1.2310246427997802, -1.2282644978607673, -1.2255043529217544, -1.2227442079827413, -1.2199840630437284, -1.2172239181047155, -1.2144637731657024, -1.2117036282266895, -1.2089434832876764, -1.2061833383486638, -1.2034231934096506, -1.2006630484706375, -1.1979029035316247, -1.1951427585926118, -1.1923826136535989, -1.1896224687145858, -1.1868623237755729, -1.18410217883656, -1.1813420338975469, -1.178581888958534, -1.175821744019521, -1.1730615990805082, -1.1703014541414951, -1.167541309202482, -1.1647811642634693, -1.1620210193244562, -1.1592608743854433, -1.1565007294464302, -1.1537405845074173, -1.1509804395684045, -1.1482202946293913, -1.1454601496903785, -1.1427000047513656, -1.1399398598123527, -1.1371797148733396, -1.1344195699343267, -1.1316594249953138, -1.1288992800563007, -1.1261391351172878, -1.123378990178275, -1.1206188452392618, -1.117858700300249, -1.1150985553612358, -1.112338410422223, -1.10957826548321, -1.106818120544197, -1.104057975605184, -1.1012978306661712, -1.0985376857271583, -1.0957775407881452, -1.0930173958491323, -1.0902572509101194, -1.0874971059711063, -1.0847369610320934, -1.0819768160930803, -1.0792166711540676, -1.0764565262150545, -1.0736963812760414, -1.0709362363370285, -1.0681760913980156, -1.0654159464590027, -1.0626558015199896, -1.0598956565809767, -1.0571355116419638, -1.0543753667029507, -1.0516152217639378, -1.048855076824925, -1.046094931885912, -1.043334786946899, -1.0405746420078859, -1.0378144970688732, -1.03505435212986, -1.0322942071908472, -1.029534062251834, -1.0267739173128212, -1.0240137723738083, -1.0212536274347952, -1.0184934824957823, -1.0157333375567694, -1.0129731926177565, -1.0102130476787434, -1.0074529027397303, -1.0046927578007177, -1.0019326128617045, -0.9991724679226917, -0.9964123229836787, -0.9936521780446655, -0.9908920331056528, -0.9881318881666397, -0.9853717432276269, -0.9826115982886138, -0.9798514533496008, -0.9770913084105879, -0.9743311634715749, -0.971571018532562, -0.968810873593549, -0.9660507286545361, -0.9632905837155231, -0.9605304387765101, -0.9577702938374972, -0.9550101488984842, -0.9522500039594713, -0.9494898590204583, -0.9467297140814452, -0.9439695691424325, -0.9412094242034194, -0.9384492792644066, -0.9356891343253935, -0.9329289893863805, -0.9301688444473676, -0.9274086995083546, -0.9246485545693417, -0.9218884096303287, -0.9191282646913158, -0.9163681197523028, -0.9136079748132898, -0.9108478298742769, -0.9080876849352638, -0.905327539996251, -0.9025673950572379, -0.8998072501182249, -0.897047105179212, -0.894286960240199, -0.8915268153011862, -0.8887666703621732, -0.8860065254231602, -0.8832463804841473, -0.8804862355451343, -0.8777260906061214, -0.8749659456671084, -0.8722058007280955, -0.8694456557890825, -0.8666855108500694, -0.8639253659110566, -0.8611652209720435, -0.8584050760330306, -0.8556449310940176, -0.8528847861550047, -0.8501246412159917, -0.8473644962769789, -0.8446043513379657, -0.8418442063989529, -0.8390840614599399, -0.836323916520927, -0.833563771581914, -0.830803626642901, -0.8280434817038881, -0.8252833367648751, -0.8225231918258622, -0.8197630468868492, -0.8170029019478363, -0.8142427570088232, -0.8114826120698103, -0.8087224671307973, -0.8059623221917844, -0.8032021772527714, -0.8004420323137585, -0.7976818873747454, -0.7949217424357325, -0.7921615974967195, -0.7894014525577067, -0.7866413076186937, -0.7838811626796808, -0.7811210177406677, -0.7783608728016548, -0.7756007278626418, -0.7728405829236289, -0.7700804379846159, -0.7673202930456029, -0.7645601481065899, -0.761800003167577, -0.759039858228564, -0.7562797132895511, -0.7535195683505381, -0.7507594234115251, -0.7479992784725122, -0.7452391335334992, -0.7424789885944864, -0.7397188436554734, -0.7369586987164605, -0.7341985537774474, -0.7314384088384345, -0.7286782638994215, -0.7259181189604086, -0.7231579740213956, -0.7203978290823827, -0.7176376841433696, -0.7148775392043567, -0.7121173942653437, -0.7093572493263308, -0.7065971043873178, -0.7038369594483048, -0.7010768145092918, -0.6983166695702789, -0.6955565246312659, -0.692796379692253, -0.69003623475324, -0.687276089814227, -0.684515944875214, -0.6817557999362012, -0.6789956549971882, -0.6762355100581753, -0.6734753651191623, -0.6707152201801493, -0.6679550752411364, -0.6651949303021234, -0.6624347853631105, -0.6596746404240975, -0.6569144954850846, -0.6541543505460715, -0.6513942056070586, -0.6486340606680456, -0.6458739157290327, -0.6431137707900197, -0.6403536258510067, -0.6375934809119937, -0.6348333359729809, -0.6320731910339679, -0.629313046094955, -0.626552901155942, -0.623792756216929, -0.621032611277916, -0.6182724663389031, -0.6155123213998901, -0.6127521764608772, -0.6099920315218642, -0.6072318865828512, -0.6044717416438382, -0.6017115967048253, -0.5989514517658123, -0.5961913068267994, -0.5934311618877864, -0.5906710169487734, -0.5879108720097606, -0.5851507270707476, -0.5823905821317347, -0.5796304371927217, -0.5768702922537087, -0.5741101473146957, -0.5713500023756828, -0.5685898574366698, -0.5658297124976569, -0.5630695675586439, -0.5603094226196309, -0.5575492776806179, -0.554789132741605, -0.552028987802592, -0.5492688428635791, -0.5465086979245661, -0.5437485529855531, -0.5409884080465401, -0.5382282631075272, -0.5354681181685143, -0.5327079732295014, -0.5299478282904884, -0.5271876833514754, -0.5244275384124625, -0.5216673934734495, -0.5189072485344366, -0.5161471035954236, -0.5133869586564106, -0.5106268137173976, -0.5078666687783847, -0.5051065238393717, -0.5023463789003588, -0.4995862339613458, -0.4968260890223328, -0.49406594408331983, -0.4913057991443069, -0.48854565420529394, -0.485785509266281, -0.48302536432726806, -0.48026521938825506, -0.4775050744492421, -0.4747449295102292, -0.47198478457121623, -0.4692246396322033, -0.46646449469319023, -0.4637043497541773, -0.46094420481516435, -0.4581840598761514, -0.45542391493713846, -0.4526637699981255, -0.44990362505911247, -0.4471434801200995, -0.4443833351810866, -0.44162319024207364, -0.4388630453030607, -0.43610290036404775, -0.4333427554250347, -0.43058261048602176, -0.4278224655470088, -0.42506232060799587, -0.42230217566898287, -0.41954203072996993, -0.416781885790957, -0.41402174085194404, -0.4112615959129311, -0.40850145097391816, -0.40574130603490516, -0.4029811610958922, -0.4002210161568793, -0.3974608712178663, -0.39470072627885333, -0.3919405813398404, -0.3891804364008274, -0.38642029146181445, -0.38366014652280145, -0.3809000015837885, -0.37813985664477556, -0.37537971170576256, -0.3726195667667496, -0.3698594218277367, -0.3670992768887237, -0.36433913194971074, -0.3615789870106978, -0.3588188420716848, -0.35605869713267185, -0.3532985521936589, -0.3505384072546459, -0.34777826231563297, -0.34501811737662, -0.342257972437607, -0.3394978274985941, -0.33673768255958114, -0.3339775376205682, -0.33121739268155526, -0.3284572477425423, -0.3256971028035293, -0.32293695786451637, -0.3201768129255034, -0.31741666798649043, -0.3146565230474775, -0.3118963781084645, -0.30913623316945155, -0.3063760882304386, -0.3036159432914256, -0.30085579835241266, -0.2980956534133997, -0.2953355084743867, -0.2925753635353738, -0.28981521859636084, -0.28705507365734784, -0.2842949287183349, -0.28153478377932195, -0.27877463884030895, -0.276014493901296, -0.27325434896228307, -0.27049420402327007, -0.2677340590842571, -0.2649739141452442, -0.26221376920623124, -0.2594536242672183, -0.2566934793282053, -0.25393333438919236, -0.2511731894501794, -0.2484130445111664, -0.24565289957215344, -0.2428927546331405, -0.24013260969412753, -0.2373724647551146, -0.23461231981610164, -0.23185217487708865, -0.2290920299380757, -0.22633188499906276, -0.22357174006004976, -0.22081159512103682, -0.21805145018202388, -0.21529130524301088, -0.21253116030399793, -0.20977101536498496, -0.20701087042597202, -0.20425072548695908, -0.2014905805479461, -0.19873043560893314, -0.1959702906699202, -0.19321014573090722, -0.19045000079189425, -0.18768985585288128, -0.18492971091386834, -0.18216956597485537, -0.1794094210358424, -0.17664927609682946, -0.17388913115781648, -0.1711289862188035, -0.16836884127979057, -0.16560869634077763, -0.16284855140176466, -0.1600884064627517, -0.15732826152373874, -0.15456811658472577, -0.1518079716457128, -0.14904782670669986, -0.1462876817676869, -0.14352753682867392, -0.14076739188966098, -0.138007246950648, -0.13524710201163503, -0.1324869570726221, -0.12972681213360915, -0.12696666719459618, -0.1242065222555832, -0.12144637731657025, -0.1186862323775573, -0.11592608743854432, -0.11316594249953138, -0.11040579756051841, -0.10764565262150544, -0.10488550768249248, -0.10212536274347954, -0.09936521780446657, -0.09660507286545361, -0.09384492792644064, -0.09108478298742768, -0.08832463804841473, -0.08556449310940176, -0.08280434817038881, -0.08004420323137584, -0.07728405829236289, -0.07452391335334993, -0.07176376841433696, -0.069003623475324, -0.06624347853631105, -0.06348333359729809, -0.060723188658285125, -0.05796304371927216, -0.055202898780259205, -0.05244275384124624, -0.049682608902233284, -0.04692246396322032, -0.044162319024207364, -0.04140217408519441, -0.03864202914618144, -0.03588188420716848, -0.03312173926815552, -0.030361594329142563, -0.027601449390129602, -0.024841304451116642, -0.022081159512103682, -0.01932101457309072, -0.01656086963407776, -0.013800724695064801, -0.011040579756051841, -0.00828043481703888, -0.0055202898780259205, -0.0027601449390129602, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], [0, 1.0948051572582842, 2.0723903239153065, 3.0081038456815503, 3.922230770774128, 4.823175535323208, 5.715230380614708, 6.600883384985452, 7.481705799746163, 8.358753400762316, 9.232769816262572, 10.10429857398647, 10.973749001777591, 11.841437056982398, 12.707611723025641, 13.572472692102583, 14.436182572570386, 15.29887553711445, 16.160663588492028, 17.02164118939993, 17.881888743589514, 18.741475254122257, 19.600460381660767, 20.45889605827119, 21.31682776717387, 22.174295568115667, 23.03133492672679, 23.887977391179813, 24.7442511486797, 25.600181486530932, 26.45579117675196, 27.311100798975982, 28.166129013140804, 29.02089279105856, 29.875407614094115, 30.729687642723142, 31.583745862651536, 32.43759421126837, 33.29124368752944, 34.14470444781587, 34.99798588985009, 35.85109672642568, 36.70404505038802, 37.55683839208744, 38.40948377032939, 39.261987737683626, 40.11435642088308, 40.966595556942075, 41.818710525522235, 42.67070637800907, 43.522587863690404, 44.374359453373685, 45.22602536074204, 46.077589561699035, 46.92905581193023, 47.780427662872995, 48.63170847626186, 49.4829014374048, 50.33400956731218, 51.18503573381138, 52.035982661727175, 52.886852942235535, 53.73764904146142, 54.58837330840175, 55.43902798222309, 56.289615199007194, 57.14013699798167, 57.99059532728657, 58.84099204932344, 59.69132894570964, 60.541607721887594, 61.39183001140488, 62.241997379897725, 63.092111328807626, 63.942173298839386, 64.79218467319764, 65.64214678060242, 66.49206089811722, 67.34192825379097, 68.19175002913299, 69.04152736143897, 69.8912613459657, 70.74095303798137, 71.59060345468737, 72.44021357702583, 73.2897843513864, 74.13931669120602, 74.98881147848486, 75.83826956521104, 76.6876917747059, 77.53707890289681, 78.38643171952026, 79.23575096925693, 80.08503737280854, 80.93429162791486, 81.78351441031985, 82.63270637468777, 83.48186815546929, 84.33100036772629, 85.1801036079123, 86.02917845461666, 86.87822546927197, 87.72724519682423, 88.57623816637367, 89.42520489177892, 90.27414587224317, 91.1230615928621, 91.97195252515208, 92.82081912755066, 93.66966184589694, 94.51848111389, 95.36727735352424, 96.21605097550778, 97.06480237965982, 97.9135319552932, 98.76224008158037, 99.61092712790105, 100.45959345417755, 101.30823941119331, 102.15686534090075, 103.00547157671627, 103.85405844380107, 104.70262625932973, 105.55117533275342, 106.39970596604437, 107.24821845393865, 108.09671308416404, 108.94519013765876, 109.7936498887872, 110.64209260553922, 111.49051854973025, 112.33892797718539, 113.18732113792439, 114.03569827633446, 114.88405963133658, 115.73240543655106, 116.58073592044886, 117.4290513065056, 118.27735181334455, 119.12563765487447, 119.97390904042794, 120.8221661748867, 121.67040925881065, 122.51863848855702, 123.36685405639587, 124.21505615062377, 125.06324495567365, 125.91142065221823, 126.75958341727251, 127.60773342429036, 128.45587084326036, 129.3039958407986, 130.15210858023573, 131.0002092217039, 131.848297922218, 132.69637483575715, 133.54444011334363, 134.39249390311636, 135.2405363504056, 136.08856759779908, 136.93658778521703, 137.78459704997402, 138.632595526845, 139.48058334812575, 140.32856064369446, 141.17652754107135, 142.02448416547384, 142.87243063987265, 143.72036708504373, 144.56829361962113, 145.41621036014848, 146.2641174211263, 147.1120149150603, 147.95990295250624, 148.80778164211534, 149.6556510906792, 150.5035114031708, 151.35136268278387, 152.19920503097683, 153.047038547507, 153.89486333047225, 154.742679476346, 155.59048708001134, 156.43828623479936, 157.2860770325187, 158.13385956349217, 158.98163391658704, 159.8294001792446, 160.67715843751355, 161.52490877607804, 162.3726512782851, 163.22038602617383, 164.0681131005036, 164.91583258077907, 165.76354454527697, 166.61124907106975, 167.45894623405115, 168.30663610896124, 169.15431876940804, 170.0019942878914, 170.84966273582347, 171.69732418355147, 172.54497870037972, 173.39262635458866, 174.24026721345655, 175.0879013432746, 175.93552880937264, 176.78314967613335, 177.6307640070121, 178.47837186455308, 179.32597331040768, 180.17356840535248, 181.0211572093042, 181.8687397813368, 182.71631617969572, 183.56388646181423, 184.41145068432965, 185.25900890309634, 186.10656117320116, 186.95410754897583, 187.80164808401173, 188.64918283117441, 189.49671184261555, 190.34423516978413, 191.19175286344273, 192.03926497367547, 192.88677154990373, 193.7342726408962, 194.58176829477873, 195.42925855904934, 196.2767434805848, 197.1242231056551, 197.97169747993237, 198.8191666484995, 199.66663065586394, 200.5140895459632, 201.36154336217882, 202.20899214734143, 203.05643594374376, 203.90387479314808, 204.7513087367933, 205.5987378154076, 206.44616206921233, 207.29358153793433, 208.14099626081227, 208.98840627660363, 209.83581162359351, 210.68321233960347, 211.53060846199725, 212.3780000276893, 213.2253870731503, 214.07276963441532, 214.9201477470918, 215.76752144636467, 216.61489076700414, 217.46225574337052, 218.30961640942172, 219.15697279872057, 220.00432494443942, 220.85167287936764, 221.69901663591327, 222.54635624611532, 223.39369174164514, 224.241023153813, 225.08835051357465, 225.9356738515324, 226.78299319794692, 227.63030858273794, 228.47762003549101, 229.32492758546093, 230.17223126157756, 231.0195310924518, 231.8668271063787, 232.71411933134303, 233.5614077950223, 234.40869252479223, 235.25597354773228, 236.1032508906288, 236.9505245799776, 237.79779464199197, 238.64506110260228, 239.4923239874639, 240.3395833219594, 241.18683913120083, 242.0340914400373, 242.8813402730543, 243.72858565458122, 244.57582760869332, 245.42306615921356, 246.2703013297199, 247.11753314354436, 247.9647616237808, 248.81198679328367, 249.65920867467526, 250.5064272903471, 251.35364266246154, 252.2008548129586, 253.04806376355464, 253.89526953574912, 254.74247215082596, 255.58967162985468, 256.4368679936971, 257.28406126300575, 258.13125145823045, 258.97843859961927, 259.82562270722013, 260.6728038008846, 261.5199819002714, 262.3671570248473, 263.21432919389076, 264.0614984264927, 264.90866474155985, 265.7558281578185, 266.6029886938149, 267.45014636791876, 268.2973011983239, 269.14445320305146, 269.9916023999532, 270.83874880671203, 271.6858924408452, 272.5330333197047, 273.3801714604805, 274.22730688020346, 275.07443959574647, 275.9215696238246, 276.76869698100086, 277.61582168368375, 278.4629437481329, 279.31006319045935, 280.1571800266257, 281.00429427245166, 281.8514059436114, 282.6985150556392, 283.54562162392943, 284.3927256637367, 285.23982719018124, 286.0869262182461, 286.9340227627832, 287.7811168385105, 288.6282084600176, 289.4752976417652, 290.3223843980854, 291.1694687431866, 292.016550691151, 292.86363025593954, 293.71070745139195, 294.5577822912263, 295.40485478904435, 296.2519249583284, 297.0989928124466, 297.9460583646527, 298.7931216280858, 299.6401826157734, 300.48724134063326, 301.3342978154732, 302.18135205299336, 303.02840406578594, 303.8754538663373, 304.72250146703027, 305.5695468801438, 306.41659011785543, 307.2636311922386, 308.1106701152698, 308.9577068988255, 309.8047415546844, 310.6517740945295, 311.4988045299448, 312.3458328724229, 313.1928591333617, 314.03988332406703, 314.88690545575196, 315.733925539539, 316.58094358646196, 317.4279596074655, 318.27497361340704, 319.1219856150562, 319.9689956230965, 320.8160036481277, 321.663009700665, 322.5100137911407, 323.357015929904, 324.20401612722236, 325.05101439328394, 325.898010738197, 326.7450051719893, 327.59199770461265, 328.4389883459394, 329.2859771057669, 330.132963993817, 330.9799490197348, 331.8269321930935, 332.6739135233907, 333.52089302005345, 334.36787069243474, 335.214846549818, 336.0618206014162, 336.90879285637084, 337.7557633237567, 338.60273201257786, 339.44969893177245, 340.2966640902115, 341.1436274966981, 341.99058915997193, 342.8375490887054, 343.6845072915081, 344.53146377692605, 345.37841855344095, 346.22537162947197, 347.0723230133775, 347.91927271345423, 348.76622073793857, 349.6131670950058, 350.4601117927716, 351.30705483929404, 352.153996242572, 353.0009360105474, 353.84787415110344, 354.69481067206704, 355.54174558120974, 356.3886788862471, 357.2356105948406, 358.08254071459385, 358.9294692530598, 359.77639621773665, 360.6233216160702, 361.4702454554526, 362.3171677432239, 363.16408848667373, 364.0110076930402, 364.8579253695112, 365.7048415232235, 366.55175616126417, 367.3986692906722, 368.24558091843727, 369.0924910515013, 369.93939969675733, 370.7863068610509, 371.63321255118166, 372.4801167739028, 373.3270195359197, 374.1739208438936, 375.0208207044405, 375.8677191241299, 376.714616109489, 377.5615116669987, 378.4084058030978, 379.255298524182, 380.1021898366023, 380.9490797466694, 381.7959682606496, 382.64285538476923, 383.48974112521313, 384.336625488124, 385.183508479604, 386.0303901057158, 386.87727037248186, 387.72414928588535, 388.57102685186925, 389.41790307633755, 390.2647779651568, 391.11165152415464, 391.95852375912153, 392.8053946758091, 393.6522642799318, 394.49913257716815, 395.34599957315965, 396.1928652735125, 397.03972968379355, 397.88659280953743, 398.7334546562421, 399.5803152293711, 400.42717453435205, 401.2740325765781, 402.12088936140935, 402.96774489417135, 403.81459918015656, 404.6614522246231, 405.50830403279593, 406.3551546098684, 407.2020039610008, 408.04885209132163, 408.8956990059264, 409.7425447098789, 410.58938920821265, 411.4362325059298, 412.2830746080001, 413.1299155193647, 413.97675524493224, 414.82359378958273, 415.6704311581663, 416.5172673555018, 417.36410238638075, 418.21093625556335, 419.0577689677824, 419.90460052774205, 420.7514309401163, 421.59826020955296, 422.44508834066977, 423.29191533805874, 424.1387412062822, 424.9855659498766, 425.83238957335135, 426.67921208118725, 427.52603347784054, 428.37285376773883, 429.21967295528503, 430.0664910448559, 430.9133080408013, 431.76012394744544, 432.606938769088, 433.45375251000297, 434.3005651744397, 435.1473767666217, 435.99418729074756, 436.84099675099264, 437.6878051515074, 438.53461249641873, 439.3814187898285, 440.2282240358148, 441.0750282384332, 441.92183140171545, 442.7686335296711, 443.6154346262834, 444.46223469551603, 445.3090337413089, 446.15583176757997, 447.00262877822377, 447.8494247771127, 448.69621976809816, 449.5430137550092, 450.38980674165384, 451.2365987318174, 452.0833897292639, 452.93017973773726, 453.7769687609598, 454.6237568026336, 455.47054386643885, 456.3173299560352, 457.1641150750631, 458.01089922714254, 458.857682415872, 459.704464644832, 460.5512459175812, 461.3980262376601, 462.2448056085898, 463.09158403387033, 463.93836151698474, 464.7851380613947, 465.6319136705447, 466.47868834786027, 467.3254620967467, 468.172234920593, 469.0190068227674, 469.8657778066222, 470.7125478754893, 471.5593170326843, 472.40608528150483, 473.25285262522914, 474.0996190671201, 474.94638461042086, 475.7931492583588, 476.6399130141441, 477.48667588096816, 478.33343786200754, 479.1801989604197, 480.0269591793471, 480.87371852191484, 481.7204769912327, 482.56723459039074, 483.41399132246596, 484.2607471905181, 485.1075021975914, 485.95425634671295, 486.80100964089416, 487.64776208313174, 488.49451367640614, 489.3412644236829, 490.188014327911, 491.0347633920242, 491.88151161894217, 492.7282590115688, 493.57500557279366, 494.42175130549026, 495.2684962125174, 496.11524029672023, 496.9619835609293, 497.80872600795914, 498.65546764061196, 499.5022084616736, 500.3489484739173, 501.19568768010214, 502.04242608297164, 502.8891636852576, 503.7359004896758, 504.58263649893, 505.4293717157102, 506.27610614269133, 507.1228397825371, 507.9695726378955, 508.8163047114037, 509.66303600568324, 510.5097665233444, 511.35649626698444, 512.2032252391859, 513.049953442521, 513.8966808795466, 514.7434075528089, 515.5901334648413, 516.4368586181632, 517.2835830152837, 518.1303066586975, 518.9770295508885, 519.8237516943285, 520.6704730914763, 521.5171937447784, 522.3639136566705, 523.2106328295762, 524.0573512659075, 524.9040689680638, 525.7507859384328, 526.5975021793918, 527.444217693306, 528.2909324825303, 529.1376465494051, 529.9843598962626, 530.831072525423, 531.6777844391952, 532.5244956398781, 533.3712061297565, 534.2179159111075, 535.0646249861962, 535.9113333572777, 536.758041026595, 537.6047479963808, 538.451454268858, 539.2981598462387, 540.1448647307251, 540.9915689245078, 541.8382724297671, 542.6849752486744, 543.5316773833905, 544.3783788360649, 545.225079608839, 546.071779703842, 546.9184791231951, 547.7651778690092, 548.6118759433841, 549.458573348412, 550.305270086173, 551.1519661587395, 551.9986615681743, 552.8453563165289, 553.6920504058476, 554.5387438381632, 555.3854366155015, 556.2321287398764, 557.0788202132946, 557.9255110377536, 558.7722012152401, 559.6188907477339, 560.4655796372035, 561.3122678856105, 562.1589554949072, 563.0056424670357, 563.8523288039314, 564.6990145075188, 565.5456995797152, 566.3923840224296, 567.2390678375612, 568.0857510270006, 568.9324335926311, 569.7791155363271, 570.6257968599551, 571.4724775653722, 572.3191576544272, 573.1658371289617, 574.0125159908089, 574.8591942417941, 575.7058718837335, 576.5525489184355, 577.3992253477013, 578.2459011733239, 579.0925763970893, 579.9392510207724, 580.7859250461438, 581.632598474965, 582.4792713089904, 583.3259435499651, 584.172615199629, 585.019286259712, 585.8659567319381, 586.712626618024, 587.5592959196772, 588.4059646385999, 589.252632776485, 590.0993003350196, 590.9459673158835, 591.7926337207477, 592.6392995512781, 593.4859648091314, 594.3326294959593, 595.1792936134042, 596.0259571631033, 596.8726201466866, 597.7192825657758, 598.5659444219875, 599.4126057169296, 600.2592664522047, 601.1059266294084, 601.9525862501282, 602.799245315947, 603.6459038284388, 604.4925617891727, 605.3392191997109, 606.1858760616083, 607.0325323764131, 607.8791881456682, 608.7258433709094, 609.5724980536667, 610.4191521954625, 611.2658057978131, 612.1124588622293, 612.9591113902154, 613.8057633832697, 614.6524148428832, 615.499065770541, 616.3457161677231, 617.1923660359026, 618.0390153765478, 618.8856641911177, 619.7323124810686, 620.5789602478495, 621.4256074929044, 622.27225421767, 623.1189004235772, 623.9655461120522, 624.8121912845147, 625.6588359423794, 626.5054800870539, 627.3521237199403, 628.1987668424362, 629.0454094559325, 629.8920515618155, 630.7386931614647, 631.5853342562539, 632.4319748475526, 633.2786149367248, 634.1252545251273, 634.9718936141137, 635.8185322050299, 636.6651702992182, 637.5118078980155, 638.3584450027516, 639.2050816147533, 640.05171773534, 640.8983533658279, 641.7449885075259, 642.5916231617393, 643.4382573297682, 644.2848910129061, 645.1315242124432, 645.9781569296629, 646.8247891658448, 647.6714209222637, 648.5180522001878, 649.3646830008822, 650.2113133256053, 651.0579431756117, 651.9045725521515, 652.7512014564688, 653.5978298898027, 654.4444578533886, 655.2910853484566, 656.1377123762326, 656.9843389379365, 657.8309650347838, 658.6775906679861, 659.5242158387498, 660.3708405482773, 661.2174647977653, 662.0640885884058, 662.9107119213872, 663.7573347978932, 664.6039572191037, 665.4505791861908, 666.2972007003257, 667.1438217626737, 667.9904423743965, 668.8370625366499, 669.6836822505858, 670.5303015173524, 671.3769203380932, 672.223538713948, 673.0701566460508, 673.9167741355319, 674.7633911835179, 675.6100077911309, 676.4566239594893, 677.3032396897061, 678.1498549828901, 678.9964698401473, 679.8430842625792, 680.6896982512817, 681.5363118073489, 682.3829249318684, 683.2295376259257, 684.0761498906019, 684.9227617269727, 685.7693731361119, 686.6159841190872, 687.4625946769639, 688.3092048108034, 689.1558145216615, 690.0024238105917, 690.8490326786438, 691.6956411268625, 692.5422491562887, 693.3888567679605, 694.2354639629118, 695.0820707421732, 695.9286771067702, 696.775283057725, 697.6218885960568, 698.4684937227806, 699.3150984389085, 700.1617027454475, 701.0083066434012, 701.8549101337703, 702.7015132175518, 703.5481158957399, 704.3947181693219, 705.2413200392849, 706.0879215066115, 706.9345225722809, 707.7811232372682, 708.6277235025444, 709.4743233690788, 710.3209228378361, 711.1675219097783, 712.014120585863, 712.8607188670442, 713.7073167542735, 714.5539142484989, 715.4005113506654, 716.2471080617134, 717.0937043825802, 717.9403003142007, 718.7868958575066, 719.6334910134245, 720.4800857828802, 721.3266801667938, 722.1732741660838, 723.0198677816657, 723.8664610144498, 724.7130538653457, 725.5596463352575, 726.4062384250876, 727.2528301357356, 728.099421468096, 728.9460124230625, 729.7926030015234, 730.6391932043663, 731.4857830324734, 732.3323724867253, 733.1789615679999, 734.02555027717, 734.872138615108, 735.7187265826809, 736.5653141807542, 737.4119014101905, 738.2584882718484, 739.1050747665836, 739.9516608952498, 740.7982466586973, 741.6448320577739, 742.4914170933237, 743.3380017661875, 744.1845860772048, 745.0311700272113, 745.8777536170404, 746.7243368475218, 747.5709197194823, 748.4175022337469, 749.2640843911371, 750.1106661924731, 750.9572476385688, 751.8038287302387, 752.6504094682933, 753.4969898535405, 754.3435698867867, 755.1901495688319, 756.036728900477, 756.883307882519, 757.729886515753, 758.57646480097, 759.4230427389585, 760.2696203305055, 761.1161975763947, 761.9627744774078, 762.8093510343228, 763.6559272479152, 764.5025031189588, 765.349078648225, 766.1956538364809, 767.0422286844934, 767.8888031930244, 768.7353773628352, 769.5819511946843, 770.4285246893263, 771.2750978475153, 772.121670670001, 772.9682431575319, 773.8148153108544, 774.6613871307106, 775.5079586178424, 776.354529772987, 777.2011005968815, 778.0476710902583, 778.8942412538491, 779.7408110883835, 780.5873805945863, 781.4339497731829, 782.280518624894, 783.1270871504391, 783.973655350536, 784.8202232258981, 785.6667907772389, 786.5133580052675, 787.3599249106919, 788.2064914942183, 789.0530577565493, 789.8996236983852, 790.7461893204252, 791.5927546233657, 792.4393196079016, 793.285884274724, 794.1324486245223, 794.9790126579846, 795.8255763757961, 796.6721397786405, 797.5187028671982, 798.3652656421474, 799.2118281041653, 800.0583902539264, 800.9049520921036, 801.7515136193665, 802.5980748363826, 803.4446357438184, 804.2911963423381, 805.1377566326026, 805.9843166152726, 806.8308762910045, 807.6774356604544, 808.5239947242762, 809.3705534831204, 810.2171119376371, 811.0636700884728, 811.9102279362731, 812.756785481682, 813.6033427253393, 814.4498996678856, 815.296456309957, 816.1430126521898, 816.9895686952161, 817.8361244396679, 818.6826798861749, 819.5292350353635, 820.3757898878603, 821.2223444442875, 822.0688987052673, 822.91545267142, 823.762006343362, 824.6085597217104, 825.455112807078, 826.3016656000773, 827.148218101319, 827.9947703114109, 828.8413222309588, 829.6878738605677, 830.5344252008405, 831.3809762523783, 832.2275270157799, 833.0740774916417, 833.9206276805596, 834.7671775831272, 835.6137271999373, 836.4602765315777, 837.3068255786376, 838.1533743417033, 838.9999228213594, 839.8464710181898, 840.6930189327734, 841.5395665656905, 842.3861139175186, 843.2326609888341, 844.0792077802106, 844.9257542922196, 845.7723005254325, 846.618846480418, 847.465392157744, 848.3119375579752, 849.158482681675, 850.005027529406, 850.8515721017286, 851.698116399202, 852.5446604223829, 853.391204171826, 854.2377476480856, 855.0842908517144, 855.9308337832617, 856.7773764432776, 857.623918832308, 858.4704609508991, 859.3170027995953, 860.163544378938, 861.010085689469, 861.8566267317262, 862.7031675062485, 863.5497080135707, 864.3962482542277, 865.2427882287527, 866.0893279376761, 866.9358673815286, 867.7824065608373, 868.6289454761294, 869.4754841279303, 870.3220225167624, 871.1685606431491, 872.0150985076095, 872.8616361106632, 873.7081734528282, 874.5547105346197, 875.4012473565518, 876.247783919138, 877.0943202228896, 877.9408562683171, 878.7873920559285, 879.6339275862304, 880.4804628597286, 881.3269978769274, 882.17353263833, 883.020067144437, 883.8666013957476, 884.7131353927605, 885.5596691359727, 886.4062026258808, 887.2527358629766, 888.0992688477537, 888.9458015807035, 889.7923340623162, 890.6388662930797, 891.4853982734804, 892.3319300040046, 893.1784614851363, 894.024992717359, 894.8715237011538, 895.7180544370001, 896.5645849253772, 897.4111151667624, 898.2576451616325, 899.1041749104614, 899.9507044137221, 900.7972336718869, 901.6437626854274, 902.4902914548117, 903.3368199805082, 904.1833482629843, 905.0298763027042, 905.8764041001333, 906.7229316557331, 907.5694589699657, 908.4159860432918, 909.2625128761692, 910.1090394690568, 910.9555658224097, 911.8020919366835, 912.6486178123325, 913.4951434498089, 914.3416688495632, 915.1881940120462, 916.0347189377065, 916.8812436269923, 917.7277680803492, 918.5742922982217, 919.4208162810544, 920.2673400292896, 921.1138635433693, 921.9603868237333, 922.8069098708197, 923.653432685067, 924.4999552669116, 925.3464776167899, 926.1929997351339, 927.0395216223776, 927.8860432789528, 928.7325647052905, 929.5790859018197, 930.4256068689679, 931.2721276071627, 932.1186481168301, 932.9651683983951, 933.811688452281, 934.6582082789096, 935.5047278787025, 936.3512472520799, 937.1977663994614, 938.0442853212643, 938.8908040179045, 939.7373224897984, 940.5838407373609, 941.4303587610042, 942.2768765611415, 943.123394138183, 943.9699114925392, 944.8164286246193, 945.6629455348303, 946.5094622235798, 947.3559786912723, 948.2024949383128, 949.0490109651055, 949.8955267720514, 950.7420423595529, 951.5885577280092, 952.4350728778204, 953.2815878093835, 954.128102523096, 954.9746170193545, 955.8211312985526, 956.6676453610853, 957.5141592073444, 958.3606728377221, 959.2071862526095, 960.053699452396, 960.9002124374695, 961.7467252082181, 962.5932377650286, 963.4397501082868, 964.2862622383769, 965.1327741556819, 965.9792858605847, 966.825797353467, 967.6723086347097, 968.5188197046919, 969.3653305637913, 970.2118412123862, 971.0583516508531, 971.9048618795686, 972.751371898905, 973.597881709237, 974.4443913109372, 975.2909007043779, 976.1374098899291, 976.9839188679599, 977.8304276388398, 978.6769362029363, 979.5234445606169, 980.3699527122469, 981.2164606581906, 982.0629683988127, 982.9094759344761, 983.7559832655434, 984.6024903923752, 985.4489973153313, 986.2955040347715, 987.1420105510547, 987.9885168645371, 988.8350229755765, 989.6815288845274, 990.528034591745, 991.3745400975837, 992.2210454023951, 993.0675505065325, 993.9140554103457, 994.7605601141854, 995.6070646184015, 996.4535689233413, 997.3000730293534, 998.1465769367832, 998.9930806459776, 999.8395841572802, 1000.6860874710358, 1001.5325905875878, 1002.3790935072775, 1003.2255962304472, 1004.0720987574364, 1004.9186010885852, 1005.7651032242329, 1006.6116051647165, 1007.4581069103739, 1008.3046084615404, 1009.1511098185518, 1009.9976109817427, 1010.8441119514478, 1011.6906127279976, 1012.5371133117255, 1013.3836137029625, 1014.2301139020394, 1015.0766139092852, 1015.9231137250282, 1016.7696133495966, 1017.6161127833175, 1018.4626120265176, 1019.3091110795218, 1020.1556099426542, 1021.0021086162391, 1021.8486071005995, 1022.695105396058, 1023.5416035029356, 1024.3881014215522, 1025.2345991522282, 1026.081096695283, 1026.9275940510336, 1027.7740912197987, 1028.620588201894, 1029.4670849976355, 1030.313581607339, 1031.1600780313179, 1032.0065742698864, 1032.8530703233564, 1033.6995661920403, 1034.5460618762497, 1035.3925573762942, 1036.2390526924844, 1037.085547825128, 1037.9320427745345, 1038.77853754101, 1039.625032124862, 1040.4715265263965, 1041.3180207459177, 1042.1645147837312, 1043.0110086401396, 1043.8575023154463, 1044.703995809954, 1045.550489123963, 1046.396982257775, 1047.2434752116892, 1048.0899679860051, 1048.936460581022, 1049.782952997037, 1050.6294452343468, 1051.475937293248, 1052.3224291740366, 1053.1689208770078, 1054.0154124024555, 1054.8619037506724, 1055.7083949219518, 1056.554885916586, 1057.4013767348677, 1058.247867377085, 1059.094357843529, 1059.9408481344892, 1060.7873382502542, 1061.6338281911128, 1062.4803179573503, 1063.326807549254, 1064.17329696711, 1065.0197862112038, 1065.8662752818195, 1066.7127641792404, 1067.55925290375, 1068.4057414556307, 1069.252229835165, 1070.0987180426337, 1070.9452060783162, 1071.791693942493, 1072.638181635444, 1073.4846691574462, 1074.3311565087786, 1075.1776436897173, 1076.0241307005394, 1076.8706175415211, 1077.7171042129364, 1078.563590715061, 1079.410077048168, 1080.2565632125306, 1081.1030492084224, 1081.949535036114, 1082.796020695878, 1083.6425061879838, 1084.4889915127026, 1085.3354766703028, 1086.1819616610535, 1087.0284464852234, 1087.8749311430793, 1088.7214156348887, 1089.567899960917, 1090.4143841214304, 1091.2608681166944, 1092.1073519469724, 1092.953835612529, 1093.8003191136265, 1094.6468024505282, 1095.4932856234964, 1096.3397686327917, 1097.1862514786746, 1098.0327341614056, 1098.8792166812443, 1099.7256990384503, 1100.5721812332813, 1101.4186632659944, 1102.2651451368472, 1103.1116268460964, 1103.9581083939986, 1104.8045897808086, 1105.6510710067803, 1106.4975520721687, 1107.3440329772275, 1108.1905137222107, 1109.0369943073683, 1109.8834747329538, 1110.729954999218, 1111.5764351064124, 1112.422915054786, 1113.2693948445894, 1114.1158744760708, 1114.9623539494787, 1115.8088332650618, 1116.6553124230663, 1117.50179142374, 1118.348270267328, 1119.1947489540764, 1120.0412274842308, 1120.8877058580347, 1121.7341840757329, 1122.5806621375677, 1123.4271400437833, 1124.2736177946208, 1125.1200953903226, 1125.9665728311302, 1126.8130501172832, 1127.6595272490229, 1128.5060042265877, 1129.3524810502172, 1130.1989577201505, 1131.0454342366243, 1131.8919105998773, 1132.7383868101451, 1133.5848628676647, 1134.4313387726725, 1135.277814525403, 1136.1242901260907, 1136.9707655749703, 1137.8172408722755, 1138.66371601824, 1139.510191013096, 1140.3566658570749, 1141.203140550409, 1142.0496150933295, 1142.8960894860677, 1143.7425637288527, 1144.589037821914, 1145.435511765481, 1146.281985559782, 1147.1284592050465, 1147.9749327014997, 1148.82140604937, 1149.6678792488833, 1150.5143523002669, 1151.3608252037454, 1152.2072979595434, 1153.053770567886, 1153.900243028997, 1154.7467153431007, 1155.5931875104197, 1156.439659531176, 1157.2861314055922, 1158.1326031338897, 1158.9790747162904, 1159.8255461530143, 1160.672017444281, 1161.5184885903107, 1162.364959591323, 1163.2114304475356, 1164.0579011591678, 1164.9043717264362, 1165.7508421495588, 1166.5973124287527, 1167.4437825642333, 1168.2902525562174, 1169.1367224049193, 1169.983192110555, 1170.8296616733378, 1171.6761310934824, 1172.5226003712028, 1173.3690695067107, 1174.2155385002202, 1175.062007351942, 1175.9084760620883, 1176.754944630871, 1177.6014130584997, 1178.4478813451858, 1179.294349491138, 1180.1408174965663, 1180.9872853616803, 1181.8337530866877, 1182.6802206717962, 1183.5266881172138, 1184.3731554231476, 1185.2196225898049, 1186.0660896173915, 1186.9125565061127, 1187.759023256174, 1188.6054898677808, 1189.451956341138, 1190.298422676449, 1191.1448888739171, 1191.991354933746, 1192.837820856138, 1193.684286641297, 1194.5307522894225, 1195.377217800717, 1196.223683175382, 1197.070148413618, 1197.916613515625, 1198.763078481602, 1199.609543311749, 1200.4560080062647, 1201.3024725653486, 1202.1489369891979, 1202.9954012780097, 1203.8418654319817, 1204.688329451311, 1205.534793336194, 1206.3812570868267, 1207.227720703404, 1208.0741841861213, 1208.920647535174, 1209.7671107507556, 1210.6135738330609, 1211.4600367822823, 1212.3064995986135, 1213.1529622822477, 1213.9994248333762, 1214.845887252192, 1215.6923495388853, 1216.538811693648, 1217.3852737166712, 1218.2317356081442, 1219.0781973682572, 1219.9246589972006, 1220.7711204951627, 1221.6175818623317, 1222.4640430988966, 1223.3105042050452, 1224.1569651809657, 1225.0034260268446, 1225.8498867428682, 1226.6963473292233, 1227.5428077860959, 1228.3892681136722, 1229.2357283121369, 1230.082188381674, 1230.9286483224691, 1231.7751081347058, 1232.6215678185688, 1233.4680273742395, 1234.314486801902, 1235.1609461017385, 1236.007405273932, 1236.8538643186635, 1237.7003232361142, 1238.5467820264655, 1239.3932406898982, 1240.239699226593, 1241.0861576367295, 1241.9326159204866, 1242.779074078044, 1243.6255321095805, 1244.4719900152752, 1245.3184477953057, 1246.1649054498494, 1247.0113629790837, 1247.8578203831867, 1248.7042776623337, 1249.550734816702, 1250.3971918464667, 1251.243648751804, 1252.090105532889, 1252.9365621898962, 1253.783018723001, 1254.6294751323765, 1255.4759314181968, 1256.3223875806361, 1257.1688436198665, 1258.0152995360615, 1258.861755329393, 1259.7082110000335, 1260.554666548154, 1261.4011219739264, 1262.247577277522, 1263.0940324591106, 1263.9404875188636, 1264.7869424569499, 1265.6333972735395, 1266.4798519688024, 1267.326306542907, 1268.1727609960217, 1269.0192153283147, 1269.8656695399543, 1270.7121236311086, 1271.5585776019443, 1272.405031452628, 1273.2514851833266, 1274.0979387942064, 1274.9443922854339, 1275.7908456571743, 1276.6372989095921, 1277.483752042853, 1278.3302050571215, 1279.1766579525631, 1280.0231107293396, 1280.8695633876155, 1281.7160159275543, 1282.5624683493188, 1283.408920653073, 1284.2553728389769, 1285.1018249071938, 1285.9482768578853, 1286.7947286912133, 1287.6411804073382, 1288.4876320064204, 1289.3340834886208, 1290.1805348540995, 1291.026986103017, 1291.8734372355318, 1292.719888251803, 1293.5663391519897, 1294.412789936251, 1295.2592406047443, 1296.1056911576284, 1296.9521415950599, 1297.7985919171965, 1298.645042124196, 1299.4914922162136, 1300.3379421934071, 1301.1843920559313, 1302.0308418039426, 1302.877291437597, 1303.7237409570484, 1304.5701903624529, 1305.416639653964, 1306.263088831737, 1307.1095378959246, 1307.9559868466813, 1308.8024356841606, 1309.648884408515, 1310.4953330198978, 1311.3417815184607, 1312.1882299043564, 1313.034678177737, 1313.8811263387533, 1314.7275743875575, 1315.5740223242997, 1316.420470149131, 1317.2669178622023, 1318.1133654636633, 1318.9598129536632, 1319.8062603323522, 1320.6527075998792, 1321.499154756394, 1322.3456018020447, 1323.1920487369791, 1324.0384955613458, 1324.8849422752926, 1325.7313888789677, 1326.5778353725177, 1327.424281756089, 1328.270728029829, 1329.1171741938838, 1329.9636202484003, 1330.8100661935237, 1331.656512029399, 1332.5029577561722, 1333.3494033739887, 1334.1958488829923, 1335.0422942833284, 1335.88873957514, 1336.735184758572, 1337.581629833768, 1338.4280748008705, 1339.2745196600238, 1340.1209644113694, 1340.9674090550504, 1341.8138535912099, 1342.6602980199884, 1343.5067423415292, 1344.3531865559723, 1345.19963066346, 1346.0460746641324, 1346.8925185581306, 1347.7389623455954, 1348.5854060266659, 1349.431849601483, 1350.2782930701853, 1351.1247364329129, 1351.971179689805, 1352.8176228409995, 1353.6640658866363, 1354.5105088268522, 1355.356951661786, 1356.203394391576, 1357.049837016359, 1357.896279536272, 1358.7427219514525, 1359.589164262037, 1360.4356064681626, 1361.2820485699651, 1362.12849056758, 1362.9749324611435, 1363.821374250791, 1364.6678159366588, 1365.5142575188797, 1366.3606989975897, 1367.207140372923, 1368.053581645014, 1368.9000228139976, 1369.7464638800054, 1370.592904843172, 1371.4393457036306, 1372.2857864615146, 1373.1322271169563, 1373.9786676700876, 1374.8251081210412, 1375.6715484699494, 1376.517988716944, 1377.3644288621563, 1378.2108689057168, 1379.0573088477572, 1379.9037486884083, 1380.750188427801, 1381.5966280660653, 1382.4430676033305, 1383.2895070397271, 1384.1359463753852, 1384.9823856104329, 1385.8288247450005, 1386.6752637792158, 1387.5217027132078, 1388.3681415471056, 1389.2145802810362, 1390.0610189151287, 1390.9074574495094, 1391.753895884307, 1392.6003342196477, 1393.4467724556591, 1394.2932105924683, 1395.1396486302008, 1395.986086568984, 1396.8325244089428, 1397.6789621502037, 1398.5253997928928, 1399.3718373371344, 1400.2182747830545, 1401.0647121307773, 1401.9111493804276, 1402.7575865321307, 1403.6040235860103, 1404.4504605421898, 1405.2968974007936, 1406.1433341619452, 1406.9897708257683, 1407.8362073923856, 1408.6826438619196, 1409.5290802344934, 1410.3755165102295, 1411.2219526892507, 1412.0683887716782, 1412.9148247576334, 1413.7612606472385, 1414.6076964406147, 1415.4541321378845, 1416.3005677391661, 1417.1470032445818, 1417.9934386542518, 1418.839873968297, 1419.686309186837, 1420.532744309991, 1421.379179337879, 1422.2256142706206, 1423.0720491083355, 1423.918483851142, 1424.7649184991583, 1425.6113530525038, 1426.4577875112966, 1427.3042218756555, 1428.1506561456977, 1428.9970903215403, 1429.8435244033017, 1430.6899583910995, 1431.5363922850497, 1432.3828260852697, 1433.2292597918768, 1434.0756934049862, 1434.9221269247153, 1435.768560351179, 1436.6149936844938, 1437.4614269247759, 1438.3078600721394, 1439.1542931267008, 1440.000726088574, 1440.8471589578744, 1441.693591734717, 1442.540024419216, 1443.3864570114847, 1444.2328895116377, 1445.079321919789, 1445.9257542360526, 1446.7721864605414, 1447.6186185933682, 1448.4650506346463, 1449.3114825844887, 1450.1579144430082, 1451.004346210317, 1451.8507778865267, 1452.6972094717498, 1453.5436409660983, 1454.3900723696847, 1455.2365036826184, 1456.0829349050116, 1456.9293660369754, 1457.775797078621, 1458.622228030059, 1459.4686588913987, 1460.3150896627515, 1461.161520344227, 1462.007950935936, 1462.8543814379873, 1463.70081185049, 1464.547242173554, 1465.3936724072882, 1466.2401025518025, 1467.0865326072046, 1467.9329625736027, 1468.7793924511059, 1469.6258222398226, 1470.47225193986, 1471.3186815513268, 1472.1651110743296, 1473.0115405089764, 1473.857969855375, 1474.704399113631, 1475.550828283853, 1476.397257366146, 1477.2436863606174, 1478.090115267374, 1478.9365440865206, 1479.7829728181646, 1480.6294014624104, 1481.475830019365, 1482.3222584891325, 1483.1686868718186, 1484.015115167529, 1484.8615433763675, 1485.7079714984397, 1486.5543995338494, 1487.400827482701, 1488.2472553450996, 1489.0936831211482, 1489.9401108109503, 1490.78653841461, 1491.632965932231, 1492.4793933639166, 1493.32582070977, 1494.1722479698929, 1495.018675144389, 1495.8651022333606, 1496.7115292369108, 1497.5579561551413, 1498.4043829881534, 1499.2508097360496, 1500.0972363989317, 1500.9436629769023, 1501.7900894700601, 1502.636515878508, 1503.4829422023465, 1504.3293684416774, 1505.1757945966006, 1506.022220667216, 1506.8686466536246, 1507.7150725559263, 1508.5614983742219, 1509.4079241086106, 1510.2543497591914, 1511.1007753260644, 1511.947200809329, 1512.7936262090846, 1513.6400515254297, 1514.4864767584627, 1515.3329019082828, 1516.179326974989, 1517.0257519586785, 1517.8721768594505, 1518.718601677402, 1519.565026412631, 1520.411451065236, 1521.2578756353132, 1522.1043001229614, 1522.9507245282764, 1523.7971488513558, 1524.643573092297, 1525.4899972511953, 1526.3364213281486, 1527.1828453232522, 1528.029269236603, 1528.8756930682964, 1529.7221168184287, 1530.568540487096, 1531.4149640743929, 1532.2613875804157, 1533.1078110052588, 1533.9542343490177, 1534.8006576117878, 1535.6470807936628, 1536.4935038947383, 1537.339926915108, 1538.1863498548664, 1539.0327727141078, 1539.879195492927, 1540.725618191416, 1541.5720408096695, 1542.4184633477807, 1543.264885805844, 1544.1113081839515, 1544.957730482196, 1545.804152700671, 1546.650574839469, 1547.4969968986834, 1548.343418878406, 1549.1898407787287, 1550.0362625997438, 1550.8826843415436, 1551.7291060042203, 1552.575527587865, 1553.4219490925689, 1554.2683705184238, 1555.1147918655215, 1555.961213133952, 1556.8076343238072, 1557.6540554351768, 1558.500476468152, 1559.3468974228242, 1560.1933182992823, 1561.0397390976175, 1561.886159817919, 1562.732580460277, 1563.579001024782, 1564.4254215115222, 1565.2718419205885, 1566.118262252069, 1566.9646825060538, 1567.8111026826311, 1568.6575227818903, 1569.5039428039206, 1570.3503627488094, 1571.1967826166463, 1572.0432024075185, 1572.8896221215148, 1573.7360417587236, 1574.5824613192317, 1575.428880803128, 1576.2753002104992, 1577.1217195414329, 1577.9681387960172, 1578.8145579743386, 1579.6609770764837, 1580.5073961025398, 1581.3538150525937, 1582.2002339267326, 1583.0466527250423, 1583.8930714476087, 1584.7394900945183, 1585.5859086658575, 1586.432327161713, 1587.2787455821685, 1588.1251639273107, 1588.9715821972252, 1589.8180003919972, 1590.6644185117132, 1591.5108365564558, 1592.3572545263114, 1593.2036724213647, 1594.050090241701, 1594.896507987404, 1595.7429256585576, 1596.5893432552468, 1597.4357607775557, 1598.282178225569, 1599.1285955993696, 1599.975012899041, 1600.8214301246676, 1601.6678472763329, 1602.5142643541194, 1603.3606813581114, 1604.2070982883906, 1605.0535151450408, 1605.8999319281454, 1606.7463486377858, 1607.5927652740456, 1608.4391818370063, 1609.2855983267507, 1610.1320147433614, 1610.9784310869193, 1611.8248473575075, 1612.6712635552067, 1613.5176796800995, 1614.3640957322664, 1615.2105117117892, 1616.0569276187498, 1616.9033434532282, 1617.7497592153065, 1618.5961749050646, 1619.4425905225835, 1620.2890060679447, 1621.1354215412273, 1621.981836942513, 1622.8282522718805, 1623.674667529411, 1624.5210827151845, 1625.3674978292809, 1626.2139128717788, 1627.0603278427586, 1627.9067427423, 1628.7531575704825, 1629.599572327385, 1630.445987013086, 1631.2924016276652, 1632.1388161712011, 1632.9852306437733, 1633.8316450454597, 1634.6780593763383, 1635.524473636488, 1636.3708878259872, 1637.2173019449149, 1638.063715993347, 1638.9101299713625, 1639.756543879039, 1640.602957716455, 1641.4493714836867, 1642.2957851808126, 1643.142198807909, 1643.9886123650533, 1644.8350258523235, 1645.6814392697952, 1646.5278526175462, 1647.3742658956521, 1648.2206791041904, 1649.0670922432375, 1649.9135053128691, 1650.7599183131624, 1651.6063312441922, 1652.4527441060357, 1653.2991568987677, 1654.1455696224643, 1654.991982277202, 1655.8383948630549, 1656.6848073800995, 1657.5312198284103, 1658.3776322080626, 1659.2240445191321, 1660.0704567616929, 1660.9168689358205, 1661.7632810415887, 1662.6096930790727, 1663.4561050483474, 1664.3025169494867, 1665.1489287825643, 1665.9953405476547, 1666.841752244832, 1667.6881638741706, 1668.534575435744, 1669.3809869296251, 1670.227398355888, 1671.073809714606, 1671.9202210058534, 1672.7666322297025, 1673.613043386226, 1674.4594544754975, 1675.3058654975898, 1676.1522764525769, 1676.998687340529, 1677.84509816152, 1678.691508915622, 1679.5379196029085, 1680.3843302234507, 1681.2307407773203, 1682.07715126459, 1682.9235616853314, 1683.769972039617, 1684.616382327518, 1685.4627925491054, 1686.309202704451, 1687.1556127936265, 1688.0020228167034, 1688.8484327737524, 1689.6948426648441, 1690.5412524900498, 1691.387662249441, 1692.2340719430872, 1693.08048157106, 1693.926891133429, 1694.7733006302653, 1695.6197100616394, 1696.4661194276205, 1697.3125287282799, 1698.1589379636862, 1699.0053471339106, 1699.8517562390216, 1700.6981652790896, 1701.5445742541845, 1702.3909831643748, 1703.237392009731, 1704.0838007903208, 1704.9302095062142, 1705.776618157481, 1706.6230267441886, 1707.4694352664071, 1708.3158437242043, 1709.1622521176491, 1710.0086604468108, 1710.8550687117572, 1711.701476912556, 1712.547885049276, 1713.3942931219851, 1714.240701130752, 1715.0871090756443, 1715.933516956729, 1716.7799247740743, 1717.626332527748, 1718.472740217818, 1719.3191478443514, 1720.1655554074146, 1721.0119629070755, 1721.8583703434012, 1722.70477771646, 1723.5511850263165, 1724.3975922730385, 1725.2439994566928, 1726.0904065773464, 1726.9368136350654, 1727.7832206299156, 1728.629627561964, 1729.4760344312767, 1730.3224412379204, 1731.1688479819607, 1732.0152546634627, 1732.8616612824928, 1733.708067839117, 1734.5544743334012, 1735.4008807654106, 1736.24728713521, 1737.0936934428653, 1737.9400996884422, 1738.786505872005, 1739.6329119936195, 1740.4793180533497, 1741.3257240512612, 1742.1721299874193, 1743.0185358618874, 1743.8649416747312, 1744.7113474260143, 1745.5577531158015, 1746.4041587441575, 1747.2505643111456, 1748.0969698168303, 1748.9433752612763, 1749.789780644547, 1750.6361859667056, 1751.4825912278166, 1752.3289964279431, 1753.1754015671497, 1754.0218066454993, 1754.8682116630544, 1755.714616619879, 1756.5610215160364, 1757.40742635159, 1758.2538311266023, 1759.1002358411358, 1759.9466404952539, 1760.7930450890192, 1761.6394496224955, 1762.485854095743, 1763.3322585088254, 1764.1786628618052, 1765.0250671547449, 1765.8714713877064, 1766.717875560751, 1767.5642796739414, 1768.4106837273396, 1769.2570877210078, 1770.1034916550072, 1770.9498955293989, 1771.796299344245, 1772.6427030996072, 1773.489106795547, 1774.3355104321256, 1775.1819140094033, 1776.028317527442, 1776.8747209863031, 1777.7211243860465, 1778.5675277267344, 1779.413931008426, 1780.2603342311832, 1781.1067373950657, 1781.9531405001349, 1782.7995435464509, 1783.6459465340736, 1784.4923494630636, 1785.338752333481, 1786.1851551453858, 1787.0315578988386, 1787.8779605938985, 1788.7243632306252, 1789.5707658090791, 1790.4171683293193, 1791.2635707914062, 1792.1099731953984, 1792.9563755413553, 1793.802777829337, 1794.6491800594017, 1795.49558223161, 1796.341984346019, 1797.1883864026888, 1798.0347884016785, 1798.8811903430462, 1799.7275922268518, 1800.5739940531523, 1801.4203958220073, 1802.2667975334748, 1803.1131991876136, 1803.959600784482, 1804.8060023241376, 1805.6524038066393, 1806.4988052320446, 1807.3452066004118, 1808.191607911799, 1809.0380091662637, 1809.884410363863, 1810.7308115046555, 1811.5772125886983, 1812.4236136160496, 1813.270014586766, 1814.1164155009046, 1814.9628163585235, 1815.809217159679, 1816.6556179044294, 1817.5020185928306, 1818.3484192249396, 1819.1948198008138, 1820.0412203205094, 1820.8876207840842, 1821.734021191593, 1822.5804215430935, 1823.4268218386417, 1824.2732220782943, 1825.119622262108, 1825.9660223901376, 1826.8124224624405, 1827.6588224790721, 1828.505222440089, 1829.351622345547, 1830.198022195501, 1831.0444219900075, 1831.8908217291219, 1832.7372214129, 1833.5836210413977, 1834.4300206146695, 1835.276420132771, 1836.1228195957578, 1836.9692190036849, 1837.815618356608, 1838.6620176545812, 1839.5084168976596, 1840.3548160858986, 1841.2012152193527, 1842.0476142980772, 1842.8940133221258, 1843.7404122915534, 1844.586811206415, 1845.4332100667643, 1846.279608872657, 1847.1260076241456, 1847.9724063212855, 1848.81880496413, 1849.6652035527343, 1850.511602087152, 1851.358000567436, 1852.2043989936412, 1853.0507973658207, 1853.897195684029, 1854.7435939483187, 1855.5899921587445, 1856.4363903153592, 1857.282788418216, 1858.1291864673685, 1858.9755844628696, 1859.8219824047737, 1860.6683802931323, 1861.514778127999, 1862.361175909427, 1863.207573637469, 1864.0539713121784, 1864.9003689336066, 1865.7467665018073, 1866.5931640168326, 1867.4395614787356, 1868.2859588875688, 1869.1323562433836, 1869.978753546233, 1870.825150796169, 1871.671547993244, 1872.5179451375104, 1873.3643422290195, 1874.210739267823, 1875.0571362539738, 1875.903533187523, 1876.7499300685229, 1877.5963268970247, 1878.4427236730799, 1879.2891203967404, 1880.1355170680572, 1880.9819136870826, 1881.8283102538671, 1882.6747067684619, 1883.5211032309185, 1884.3674996412876, 1885.2138959996216, 1886.0602923059696, 1886.9066885603836, 1887.7530847629139, 1888.5994809136118, 1889.445877012528, 1890.2922730597122, 1891.1386690552158, 1891.985064999089, 1892.8314608913824, 1893.6778567321467, 1894.524252521431, 1895.3706482592866, 1896.217043945763, 1897.0634395809109, 1897.9098351647804, 1898.7562306974205, 1899.6026261788816, 1900.4490216092136, 1901.2954169884658, 1902.141812316689, 1902.9882075939317, 1903.8346028202436, 1904.6809979956747, 1905.5273931202737, 1906.3737881940913, 1907.220183217175, 1908.066578189575, 1908.91297311134, 1909.7593679825195, 1910.605762803163, 1911.4521575733181, 1912.2985522930346, 1913.1449469623608, 1913.991341581346, 1914.837736150039, 1915.6841306684876, 1916.530525136741, 1917.3769195548473, 1918.2233139228554, 1919.0697082408137, 1919.91610250877, 1920.7624967267725, 1921.6088908948698, 1922.4552850131097, 1923.301679081541, 1924.1480731002105, 1924.9944670691666, 1925.8408609884575, 1926.6872548581305, 1927.533648678234, 1928.3800424488147, 1929.2264361699206, 1930.0728298415995, 1930.9192234638986, 1931.7656170368662, 1932.612010560548, 1933.4584040349926, 1934.3047974602462, 1935.1511908363568, 1935.9975841633716, 1936.8439774413366, 1937.6903706703, 1938.5367638503076, 1939.3831569814072, 1940.2295500636453, 1941.0759430970681, 1941.922336081723, 1942.768729017656, 1943.6151219049138, 1944.4615147435436, 1945.3079075335909, 1946.154300275102, 1947.000692968124, 1947.8470856127024, 1948.693478208884, 1949.5398707567144, 1950.3862632562395, 1951.232655707506, 1952.0790481105591, 1952.9254404654457, 1953.7718327722107, 1954.6182250308998, 1955.4646172415592, 1956.311009404234, 1957.157401518971, 1958.003793585814, 1958.8501856048094, 1959.6965775760023, 1960.5429694994384, 1961.3893613751625, 1962.2357532032206, 1963.0821449836571, 1963.928536716517, 1964.7749284018462, 1965.6213200396887, 1966.4677116300907, 1967.3141031730959, 1968.1604946687492, 1969.0068861170957, 1969.85327751818, 1970.6996688720474, 1971.546060178741, 1972.3924514383066, 1973.2388426507878, 1974.0852338162297, 1974.9316249346764, 1975.7780160061716, 1976.6244070307605, 1977.4707980084866, 1978.3171889393943, 1979.163579823528, 1980.0099706609305, 1980.856361451647, 1981.7027521957207, 1982.549142893196, 1983.3955335441165, 1984.2419241485254, 1985.0883147064665, 1985.934705217984, 1986.7810956831206, 1987.627486101921, 1988.4738764744277, 1989.3202668006838, 1990.1666570807336, 1991.0130473146194, 1991.8594375023854, 1992.7058276440741, 1993.5522177397283, 1994.398607789392, 1995.2449977931071, 1996.091387750918, 1996.9377776628662, 1997.784167528995, 1998.630557349347, 1999.4769471239656, 2000.3233368528931, 2001.1697265361715, 2002.016116173844, 2002.8625057659528, 2003.7088953125408, 2004.5552848136501, 2005.401674269323, 2006.2480636796013, 2007.0944530445279, 2007.940842364145, 2008.7872316384949, 2009.633620867619, 2010.480010051559, 2011.326399190358, 2012.172788284057, 2013.019177332699, 2013.8655663363245, 2014.7119552949755, 2015.5583442086943, 2016.404733077522, 2017.2511219015012, 2018.0975106806718, 2018.9438994150764, 2019.7902881047557, 2020.6366767497516, 2021.4830653501062, 2022.329453905859, 2023.1758424170525, 2024.0222308837272, 2024.8686193059248, 2025.7150076836863, 2026.561396017052, 2027.4077843060636, 2028.2541725507615, 2029.100560751187, 2029.946948907381, 2030.793337019384, 2031.6397250872362, 2032.486113110979, 2033.3325010906524, 2034.1788890262978, 2035.0252769179551, 2035.8716647656645, 2036.718052569467, 2037.5644403294025, 2038.4108280455118, 2039.2572157178347, 2040.103603346411, 2040.949990931282, 2041.7963784724866, 2042.6427659700662, 2043.4891534240592, 2044.3355408345067, 2045.1819282014478, 2046.028315524923, 2046.8747028049722, 2047.7210900416344, 2048.56747723495, 2049.413864384958, 2050.2602514916985, 2051.1066385552112, 2051.953025575535, 2052.7994125527093, 2053.6457994867737, 2054.492186377768, 2055.3385732257316, 2056.1849600307028, 2057.031346792721, 2057.8777335118257, 2058.724120188056, 2059.5705068214515, 2060.4168934120503, 2061.2632799598914, 2062.109666465014, 2062.9560529274568, 2063.802439347259, 2064.6488257244596, 2065.495212059096, 2066.341598351208, 2067.1879846008337, 2068.034370808012, 2068.880756972782, 2069.727143095181, 2070.5735291752476, 2071.419915213021, 2072.2663012085386, 2073.1126871618394, 2073.9590730729615, 2074.8054589419426, 2075.6518447688213, 2076.4982305536355, 2077.3446162964237, 2078.1910019972233, 2079.0373876560725, 2079.8837732730094, 2080.7301588480714, 2081.576544381297, 2082.422929872723, 2083.269315322388, 2084.115700730329, 2084.9620860965842, 2085.8084714211914, 2086.6548567041873, 2087.5012419456098, 2088.347627145496, 2089.1940123038844, 2090.0403974208116, 2090.8867824963145, 2091.733167530431, 2092.5795525231974, 2093.425937474652, 2094.272322384832, 2095.1187072537737, 2095.9650920815143, 2096.811476868091, 2097.6578616135407, 2098.5042463179007, 2099.350630981207, 2100.1970156034963, 2101.043400184806, 2101.889784725173, 2102.736169224634, 2103.5825536832253, 2104.428938100983, 2105.2753224779444, 2106.1217068141455, 2106.968091109624, 2107.814475364414, 2108.6608595785538, 2109.5072437520785, 2110.353627885025, 2111.20001197743, 2112.046396029329, 2112.8927800407587, 2113.7391640117544, 2114.5855479423526, 2115.4319318325897, 2116.2783156825008, 2117.124699492122, 2117.9710832614896, 2118.81746699064, 2119.663850679607, 2120.510234328429, 2121.356617937139, 2122.203001505775, 2123.0493850343714, 2123.895768522964, 2124.742151971588, 2125.5885353802796, 2126.434918749074, 2127.2813020780063, 2128.1276853671125, 2128.9740686164273, 2129.8204518259863, 2130.666834995824, 2131.5132181259764, 2132.3596012164785, 2133.2059842673657, 2134.0523672786726, 2134.898750250434, 2135.745133182686, 2136.591516075462, 2137.437898928798, 2138.2842817427286, 2139.130664517288, 2139.9770472525124, 2140.823429948435, 2141.669812605092, 2142.516195222516, 2143.3625778007436, 2144.2089603398085, 2145.055342839745, 2145.901725300588, 2146.748107722372, 2147.594490105131, 2148.4408724489, 2149.2872547537127, 2150.133637019603, 2150.9800192466064, 2151.8264014347565, 2152.6727835840875, 2153.519165694633, 2154.3655477664274, 2155.2119297995055, 2156.0583117939, 2156.9046937496455, 2157.751075666776, 2158.597457545325, 2159.4438393853275, 2160.2902211868154, 2161.136602949824, 2161.982984674386, 2162.8293663605355, 2163.675748008306, 2164.5221296177315, 2165.3685111888453, 2166.214892721681, 2167.0612742162716, 2167.9076556726513, 2168.7540370908528, 2169.60041847091, 2170.4467998128557, 2171.2931811167236, 2172.139562382547, 2172.9859436103584, 2173.8323248001916, 2174.67870595208, 2175.525087066055, 2176.371468142152, 2177.2178491804025, 2178.06423018084, 2178.9106111434967, 2179.756992068406, 2180.6033729556016, 2181.4497538051146, 2182.296134616979, 2183.1425153912273, 2183.988896127892, 2184.8352768270065, 2185.681657488602, 2186.5280381127122, 2187.374418699369, 2188.220799248605, 2189.067179760453, 2189.913560234945, 2190.759940672114, 2191.6063210719917, 2192.452701434611, 2193.2990817600034, 2194.145462048202, 2194.991842299238, 2195.8382225131445, 2196.6846026899534, 2197.5309828296968, 2198.3773629324064, 2199.223742998114, 2200.0701230268533, 2200.916503018654, 2201.762882973549, 2202.6092628915703, 2203.4556427727493, 2204.3020226171184, 2205.1484024247084, 2205.994782195552, 2206.84116192968, 2207.6875416271246, 2208.533921287917, 2209.3803009120893, 2210.226680499673, 2211.073060050699, 2211.9194395651994, 2212.7658190432053, 2213.612198484748, 2214.458577889859, 2215.304957258569, 2216.1513365909104, 2216.9977158869137, 2217.8440951466105, 2218.6904743700316, 2219.5368535572084, 2220.3832327081714, 2221.229611822953, 2222.0759909015824, 2222.9223699440918, 2223.768748950512, 2224.6151279208734, 2225.461506855208, 2226.307885753545, 2227.154264615917, 2228.000643442353, 2228.8470222328847, 2229.6934009875436, 2230.539779706359, 2231.386158389362, 2232.232537036583, 2233.0789156480528, 2233.9252942238018, 2234.771672763861, 2235.61805126826, 2236.4644297370296, 2237.3108081702003, 2238.1571865678025, 2239.003564929866, 2239.8499432564217, 2240.6963215475, 2241.54269980313, 2242.389078023343, 2243.2354562081687, 2244.081834357637, 2244.9282124717784, 2245.7745905506226, 2246.6209685942003, 2247.46734660254, 2248.3137245756734, 2249.160102513629, 2250.006480416437, 2250.852858284128, 2251.699236116731, 2252.5456139142757, 2253.3919916767927, 2254.238369404311, 2255.08474709686, 2255.9311247544697, 2256.77750237717, 2257.6238799649905, 2258.47025751796, 2259.3166350361084, 2260.163012519465, 2261.0093899680596, 2261.8557673819214, 2262.7021447610796, 2263.5485221055637, 2264.394899415403, 2265.2412766906264, 2266.087653931264, 2266.934031137344, 2267.780408308896, 2268.626785445949, 2269.473162548532, 2270.319539616675, 2271.1659166504055, 2272.012293649754, 2272.8586706147485, 2273.705047545418, 2274.551424441792, 2275.397801303898, 2276.2441781317666, 2277.0905549254253, 2277.9369316849034, 2278.78330841023, 2279.629685101433, 2280.4760617585416, 2281.322438381584, 2282.168814970589, 2283.0151915255856, 2283.861568046602, 2284.7079445336663, 2285.554320986808, 2286.400697406054, 2287.247073791434, 2288.0934501429765, 2288.939826460709, 2289.78620274466, 2290.632578994858, 2291.4789552113316, 2292.325331394108, 2293.1717075432166, 2294.0180836586846, 2294.8644597405405, 2295.710835788813, 2296.557211803529, 2297.4035877847173, 2298.2499637324054, 2299.096339646622, 2299.9427155273943, 2300.7890913747506, 2301.635467188719, 2302.4818429693264, 2303.3282187166014, 2304.1745944305717, 2305.020970111265, 2305.867345758709, 2306.713721372931, 2307.5600969539596, 2308.4064725018216, 2309.252848016545, 2310.0992234981572, 2310.945598946686, 2311.7919743621583, 2312.6383497446022, 2313.4847250940447, 2314.331100410513, 2315.177475694036, 2316.023850944639, 2316.8702261623507, 2317.7166013471974, 2318.5629764992073, 2319.409351618407, 2320.255726704824, 2321.102101758486, 2321.948476779419, 2322.7948517676505, 2323.641226723208, 2324.4876016461185, 2325.3339765364085, 2326.180351394105, 2327.0267262192356, 2327.8731010118267, 2328.7194757719058, 2329.565850499499, 2330.4122251946337, 2331.2585998573363, 2332.104974487634, 2332.951349085553, 2333.7977236511206, 2334.6440981843634, 2335.4904726853074, 2336.3368471539807, 2337.1832215904083, 2338.029595994618, 2338.875970366635, 2339.722344706487, 2340.5687190142003, 2341.4150932898006, 2342.2614675333157, 2343.1078417447707, 2343.9542159241923, 2344.800590071607, 2345.6469641870412, 2346.4933382705212, 2347.339712322073, 2348.1860863417232, 2349.0324603294976, 2349.8788342854227, 2350.7252082095247, 2351.5715821018293, 2352.4179559623626, 2353.264329791151, 2354.11070358822, 2354.9570773535966, 2355.803451087306, 2356.6498247893737, 2357.496198459827, 2358.3425720986907, 2359.188945705991, 2360.035319281754, 2360.8816928260044, 2361.728066338769, 2362.574439820073, 2363.4208132699428, 2364.267186688404, 2365.1135600754815, 2365.9599334312015, 2366.8063067555895, 2367.6526800486713, 2368.499053310472, 2369.3454265410173, 2370.191799740333, 2371.038172908444, 2371.884546045376, 2372.7309191511545, 2373.5772922258047, 2374.423665269352, 2375.270038281822, 2376.1164112632396, 2376.962784213631, 2377.80915713302, 2378.655530021433, 2379.5019028788947, 2380.34827570543, 2381.1946485010644, 2382.041021265823, 2382.887393999731, 2383.733766702813, 2384.580139375095, 2385.4265120166006, 2386.2728846273553, 2387.119257207385, 2387.9656297567135, 2388.812002275366, 2389.6583747633676, 2390.504747220743, 2391.3511196475165, 2392.1974920437133, 2393.0438644093583, 2393.890236744476, 2394.736609049092, 2395.58298132323, 2396.4293535669144, 2397.2757257801704, 2398.122097963023, 2398.968470115496, 2399.8148422376144, 2400.6612143294024, 2401.507586390884, 2402.3539584220853, 2403.200330423029, 2404.0467023937404, 2404.893074334244, 2405.7394462445636, 2406.5858181247245, 2407.4321899747497, 2408.2785617946643, 2409.1249335844923, 2409.9713053442583, 2410.817677073986, 2411.6640487737, 2412.510420443424, 2413.356792083183, 2414.203163693, 2415.0495352729, 2415.8959068229065, 2416.7422783430434, 2417.5886498333352, 2418.4350212938057, 2419.281392724479, 2420.1277641253782, 2420.974135496528, 2421.8205068379525, 2422.6668781496746, 2423.513249431719, 2424.3596206841094, 2425.205991906869, 2426.052363100022, 2426.8987342635915, 2427.7451053976024, 2428.591476502077, 2429.43784757704, 2430.284218622515, 2431.1305896385247, 2431.976960625094, 2432.823331582245, 2433.669702510002, 2434.5160734083884, 2435.3624442774276, 2436.208815117143, 2437.055185927558, 2437.901556708696, 2438.7479274605807, 2439.5942981832354, 2440.440668876683, 2441.2870395409473, 2442.133410176051, 2442.9797807820178, 2443.8261513588704, 2444.672521906633, 2445.518892425328, 2446.3652629149783, 2447.211633375608, 2448.058003807239, 2448.9043742098956, 2449.7507445836, 2450.5971149283755, 2451.443485244245, 2452.2898555312313, 2453.1362257893584, 2453.9825960186477, 2454.8289662191232, 2455.6753363908074, 2456.5217065337233, 2457.3680766478938, 2458.214446733341, 2459.0608167900887, 2459.907186818159, 2460.7535568175754, 2461.5999267883594, 2462.446296730535, 2463.2926666441235, 2464.1390365291486, 2464.985406385633, 2465.831776213598, 2466.678146013068, 2467.524515784064, 2468.37088552661, 2469.217255240727, 2470.063624926438, 2470.9099945837656, 2471.756364212732, 2472.6027338133604, 2473.449103385672, 2474.2954729296903, 2475.141842445437, 2475.9882119329345, 2476.834581392205, 2477.680950823271, 2478.5273202261546, 2479.373689600878, 2480.2200589474637, 2481.0664282659336, 2481.9127975563097, 2482.759166818614, 2483.6055360528694, 2484.4519052590977, 2485.2982744373203, 2486.14464358756, 2486.9910127098383, 2487.8373818041778, 2488.6837508705994, 2489.5301199091264, 2490.3764889197796, 2491.2228579025814, 2492.069226857554, 2492.9155957847183, 2493.7619646840976, 2494.6083335557123, 2495.4547023995847, 2496.3010712157366, 2497.1474400041893, 2497.9938087649657, 2498.840177498086, 2499.6865462035735, 2500.5329148814485, 2501.379283531733, 2502.225652154449, 2503.0720207496174, 2503.918389317261, 2504.7647578573997, 2505.6111263700564, 2506.4574948552518, 2507.3038633130077, 2508.150231743345, 2508.9966001462863, 2509.842968521852, 2510.689336870064, 2511.535705190943, 2512.382073484511, 2513.228441750789, 2514.0748099897983, 2514.9211782015605, 2515.7675463860965, 2516.6139145434277, 2517.460282673576, 2518.306650776561, 2519.1530188524052, 2519.999386901129, 2520.845754922754, 2521.6921229173013, 2522.5384908847914, 2523.384858825246, 2524.2312267386856, 2525.077594625132, 2525.9239624846055, 2526.7703303171274, 2527.6166981227184, 2528.4630659013997, 2529.309433653192, 2530.1558013781164, 2531.0021690761937, 2531.848536747444, 2532.69490439189, 2533.54127200955, 2534.387639600447, 2535.2340071646004, 2536.0803747020313, 2536.9267422127614, 2537.7731096968096, 2538.619477154198, 2539.4658445849464, 2540.312211989076, 2541.1585793666072, 2542.0049467175604, 2542.851314041957, 2543.697681339816, 2544.5440486111593, 2545.3904158560067, 2546.236783074379, 2547.083150266297, 2547.9295174317804, 2548.7758845708495, 2549.6222516835255, 2550.4686187698285, 2551.314985829779, 2552.161352863397, 2553.0077198707027, 2553.854086851717, 2554.70045380646, 2555.5468207349513, 2556.393187637212, 2557.2395545132617, 2558.0859213631215, 2558.9322881868106, 2559.77865498435, 2560.6250217557586, 2561.471388501058, 2562.317755220267, 2563.1641219134062, 2564.0104885804963, 2564.856855221556, 2565.7032218366066, 2566.5495884256675, 2567.3959549887586, 2568.2423215258996, 2569.0886880371113, 2569.935054522413, 2570.781420981825, 2571.6277874153666, 2572.474153823058, 2573.320520204919, 2574.166886560969, 2575.0132528912286, 2575.859619195717, 2576.7059854744543, 2577.5523517274605, 2578.3987179547544, 2579.2450841563564, 2580.0914503322856, 2580.937816482562, 2581.784182607206, 2582.6305487062355, 2583.476914779672, 2584.3232808275334, 2585.1696468498403, 2586.016012846612, 2586.862378817868, 2587.7087447636277, 2588.5551106839102, 2589.401476578736, 2590.2478424481237, 2591.094208292093, 2591.940574110663, 2592.7869399038536, 2593.6333056716835, 2594.479671414173, 2595.326037131341, 2596.172402823206, 2597.0187684897887, 2597.865134131107, 2598.7114997471813, 2599.55786533803, 2600.4042309036727, 2601.2505964441284, 2602.096961959416, 2602.943327449556, 2603.789692914566, 2604.636058354466, 2605.4824237692746, 2606.328789159011, 2607.175154523695, 2608.021519863344, 2608.8678851779787, 2609.7142504676167, 2610.560615732278, 2611.4069809719813, 2612.2533461867456, 2613.0997113765893, 2613.9460765415315, 2614.7924416815918, 2615.6388067967882, 2616.48517188714, 2617.3315369526663, 2618.177901993385, 2619.0242670093157, 2619.870632000477, 2620.7169969668876, 2621.563361908566, 2622.409726825532, 2623.2560917178025, 2624.1024565853977, 2624.948821428335, 2625.7951862466343, 2626.6415510403135, 2627.487915809391, 2628.3342805538864, 2629.180645273817, 2630.027009969202, 2630.8733746400603, 2631.71973928641, 2632.5661039082697, 2633.4124685056577, 2634.2588330785925, 2635.105197627093, 2635.951562151177, 2636.7979266508632, 2637.64429112617, 2638.4906555771154, 2639.3370200037184, 2640.183384405997, 2641.0297487839694, 2641.876113137654, 2642.722477467069, 2643.5688417722336, 2644.4152060531646, 2645.2615703098813, 2646.107934542401, 2646.954298750742, 2647.800662934924, 2648.647027094963, 2649.493391230879, 2650.3397553426885, 2651.1861194304106, 2652.0324834940634, 2652.8788475336646, 2653.7252115492324, 2654.5715755407846, 2655.4179395083397, 2656.2643034519156, 2657.11066737153, 2657.957031267201, 2658.803395138946, 2659.6497589867836, 2660.496122810732, 2661.342486610808, 2662.1888503870305, 2663.035214139417, 2663.881577867985, 2664.7279415727535, 2665.5743052537387, 2666.420668910959, 2667.267032544433, 2668.1133961541773, 2668.9597597402108, 2669.8061233025496, 2670.652486841213, 2671.4988503562176, 2672.345213847582, 2673.191577315323, 2674.0379407594583, 2674.884304180006, 2675.7306675769837, 2676.5770309504087, 2677.4233943002987, 2678.269757626671, 2679.1161209295433, 2679.9624842089333, 2680.8088474648584, 2681.655210697336, 2682.5015739063833, 2683.347937092018, 2684.194300254258, 2685.0406633931198, 2685.8870265086216, 2686.7333896007804, 2687.5797526696133, 2688.4261157151386, 2689.2724787373722, 2690.118841736333, 2690.9652047120367, 2691.8115676645016, 2692.657930593745, 2693.504293499783, 2694.350656382635, 2695.197019242316, 2696.0433820788444, 2696.889744892237, 2697.7361076825114, 2698.5824704496845, 2699.428833193773, 2700.2751959147945, 2701.121558612766, 2701.9679212877045, 2702.814283939627, 2703.6606465685504, 2704.507009174492, 2705.353371757469, 2706.1997343174985, 2707.0460968545967, 2707.892459368781, 2708.7388218600686, 2709.5851843284768, 2710.431546774021, 2711.2779091967195, 2712.1242715965886, 2712.9706339736454, 2713.816996327907, 2714.6633586593894, 2715.5097209681103, 2716.3560832540857, 2717.2024455173328, 2718.0488077578684, 2718.895169975709, 2719.7415321708722, 2720.5878943433736, 2721.434256493231, 2722.28061862046, 2723.126980725078, 2723.9733428071013, 2724.8197048665465, 2725.6660669034304, 2726.5124289177697, 2727.358790909581, 2728.20515287888, 2729.0515148256854, 2729.8978767500116, 2730.744238651876, 2731.5906005312954, 2732.4369623882853, 2733.2833242228635, 2734.1296860350453, 2734.976047824848, 2735.822409592288, 2736.6687713373813, 2737.5151330601443, 2738.3614947605934, 2739.2078564387457, 2740.0542180946163, 2740.9005797282225, 2741.74694133958, 2742.593302928706, 2743.4396644956164, 2744.2860260403268, 2745.132387562854, 2745.9787490632148, 2746.8251105414247, 2747.6714719975002, 2748.5178334314573, 2749.3641948433124, 2750.210556233082, 2751.0569176007816, 2751.9032789464277, 2752.7496402700363, 2753.596001571623, 2754.4423628512054, 2755.288724108798, 2756.135085344418, 2756.9814465580803, 2757.8278077498017, 2758.6741689195987, 2759.5205300674866, 2760.366891193482, 2761.2132522976, 2762.0596133798567, 2762.9059744402684, 2763.7523354788514, 2764.598696495621, 2765.445057490593, 2766.291418463784, 2767.1377794152095, 2767.9841403448854, 2768.830501252827, 2769.676862139051, 2770.5232230035726, 2771.3695838464077, 2772.2159446675723, 2773.0623054670823, 2773.908666244953, 2774.7550270012002, 2775.6013877358405, 2776.4477484488884, 2777.2941091403604, 2778.140469810272, 2778.986830458638, 2779.8331910854754, 2780.6795516907987, 2781.5259122746243, 2782.3722728369676, 2783.218633377844, 2784.0649938972697, 2784.9113543952594, 2785.757714871829, 2786.6040753269945, 2787.450435760771, 2788.2967961731742, 2789.143156564219, 2789.9895169339215, 2790.835877282297, 2791.682237609361, 2792.5285979151286, 2793.3749581996153, 2794.2213184628367, 2795.0676787048087, 2795.914038925546, 2796.760399125064, 2797.606759303378, 2798.4531194605033, 2799.299479596456, 2800.14583971125, 2800.992199804902, 2801.8385598774266, 2802.684919928839, 2803.5312799591547, 2804.3776399683884, 2805.2239999565563, 2806.0703599236726, 2806.9167198697533, 2807.763079794813, 2808.6094396988674, 2809.4557995819314, 2810.3021594440197, 2811.148519285148, 2811.994879105331, 2812.8412389045843, 2813.6875986829227, 2814.533958440361, 2815.3803181769144, 2816.226677892598, 2817.0730375874273, 2817.9193972614166, 2818.7657569145813, 2819.612116546936, 2820.458476158496, 2821.304835749276, 2822.1511953192917, 2822.997554868557, 2823.8439143970872, 2824.690273904898, 2825.5366333920024, 2826.3829928584173, 2827.2293523041562, 2828.0757117292346, 2828.922071133667, 2829.768430517468, 2830.6147898806535, 2831.461149223237, 2832.307508545234, 2833.1538678466595, 2834.0002271275275, 2834.8465863878528, 2835.6929456276507, 2836.5393048469355, 2837.385664045722, 2838.232023224025, 2839.078382381859, 2839.924741519239, 2840.7711006361787, 2841.617459732694, 2842.463818808799, 2843.3101778645073, 2844.1565368998354, 2845.002895914796, 2845.8492549094053, 2846.6956138836763, 2847.5419728376246, 2848.3883317712643, 2849.23469068461, 2850.081049577677, 2850.9274084504777, 2851.7737673030288, 2852.6201261353435, 2853.4664849474366, 2854.3128437393225, 2855.1592025110153, 2856.00556126253, 2856.851919993881, 2857.698278705082, 2858.5446373961477, 2859.3909960670926, 2860.237354717931, 2861.083713348677, 2861.930071959345, 2862.7764305499495, 2863.622789120505, 2864.4691476710245, 2865.315506201524, 2866.1618647120163, 2867.008223202517, 2867.854581673039, 2868.700940123597, 2869.547298554205, 2870.3936569648777, 2871.2400153556296, 2872.0863737264735, 2872.9327320774246, 2873.779090408497, 2874.6254487197043, 2875.4718070110607, 2876.31816528258, 2877.1645235342776, 2878.010881766166, 2878.8572399782606, 2879.7035981705744, 2880.5499563431217, 2881.3963144959166, 2882.242672628973, 2883.0890307423056, 2883.9353888359274, 2884.7817469098527, 2885.628104964095, 2886.47446299867, 2887.3208210135895, 2888.1671790088685, 2889.0135369845207, 2889.8598949405596, 2890.706252877, 2891.552610793855, 2892.3989686911386, 2893.2453265688646, 2894.091684427047, 2894.9380422656996, 2895.784400084836, 2896.63075788447, 2897.4771156646157, 2898.3234734252865, 2899.1698311664964, 2900.016188888259, 2900.8625465905875, 2901.7089042734965, 2902.5552619369996, 2903.40161958111, 2904.247977205842, 2905.0943348112082, 2905.9406923972238, 2906.7870499639007, 2907.633407511254, 2908.4797650392966, 2909.326122548042, 2910.1724800375046, 2911.018837507697, 2911.8651949586333, 2912.7115523903267, 2913.557909802791, 2914.40426719604, 2915.2506245700865, 2916.096981924945, 2916.943339260628, 2917.7896965771492, 2918.6360538745225, 2919.482411152761, 2920.328768411878, 2921.1751256518874, 2922.0214828728026, 2922.8678400746367, 2923.714197257403, 2924.5605544211157, 2925.406911565787, 2926.253268691431, 2927.099625798061, 2927.9459828856902, 2928.792339954332, 2929.6386970039994, 2930.4850540347056, 2931.331411046465, 2932.1777680392893, 2933.024125013193, 2933.8704819681893, 2934.716838904291, 2935.5631958215113, 2936.4095527198633, 2937.2559095993606, 2938.1022664600164, 2938.948623301844, 2939.7949801248556, 2940.641336929065, 2941.4876937144854, 2942.3340504811304, 2943.1804072290124, 2944.026763958145, 2944.8731206685407, 2945.7194773602127, 2946.5658340331747, 2947.4121906874398, 2948.25854732302, 2949.1049039399295, 2949.9512605381806, 2950.797617117787, 2951.6439736787606, 2952.4903302211155, 2953.336686744864, 2954.183043250019, 2955.0293997365948, 2955.8757562046026, 2956.7221126540567, 2957.5684690849685, 2958.414825497352, 2959.26118189122, 2960.1075382665854, 2960.953894623461, 2961.8002509618595, 2962.6466072817943, 2963.4929635832777, 2964.339319866323, 2965.1856761309423, 2966.032032377149, 2966.8783886049555, 2967.724744814375, 2968.57110100542, 2969.4174571781036, 2970.2638133324385, 2971.110169468437, 2971.9565255861125, 2972.8028816854767, 2973.649237766543, 2974.4955938293247, 2975.341949873833, 2976.1883059000825, 2977.034661908084, 2977.881017897852, 2978.727373869397, 2979.573729822733, 2980.4200857578726, 2981.266441674828, 2982.112797573612, 2982.959153454237, 2983.805509316716, 2984.6518651610613, 2985.4982209872855, 2986.3445767954013, 2987.1909325854203, 2988.0372883573564, 2988.883644111221, 2989.7299998470276, 2990.5763555647877, 2991.422711264515, 2992.2690669462204, 2993.1154226099175, 2993.9617782556184, 2994.8081338833354, 2995.6544894930817, 2996.5008450848686, 2997.3472006587094, 2998.193556214616, 2999.039911752601, 2999.8862672726764, 3000.732622774855, 3001.578978259149, 3002.4253337255705, 3003.271689174132, 3004.118044604846, 3004.964400017725, 3005.810755412781, 3006.657110790026, 3007.5034661494724, 3008.349821491133, 3009.196176815019, 3010.0425321211437, 3010.8888874095187, 3011.7352426801563, 3012.581597933069, 3013.427953168269, 3014.2743083857686, 3015.120663585579, 3015.9670187677134, 3016.813373932184, 3017.659729079002, 3018.5060842081803, 3019.352439319731, 3020.198794413666, 3021.0451494899976, 3021.8915045487374, 3022.737859589898, 3023.5842146134914, 3024.4305696195297, 3025.2769246080247, 3026.123279578989, 3026.9696345324337, 3027.8159894683713, 3028.662344386814, 3029.5086992877737, 3030.355054171262, 3031.201409037292, 3032.0477638858742, 3032.8941187170212, 3033.7404735307455, 3034.586828327058, 3035.4331831059712, 3036.279537867497, 3037.125892611647, 3037.972247338434, 3038.818602047869, 3039.664956739964, 3040.511311414731, 3041.357666072182, 3042.2040207123287, 3043.0503753351827, 3043.8967299407564, 3044.7430845290614, 3045.589439100109, 3046.4357936539113, 3047.2821481904803, 3048.1285027098274, 3048.974857211965, 3049.8212116969044, 3050.6675661646573, 3051.5139206152353, 3052.3602750486507, 3053.206629464915, 3054.052983864039, 3054.8993382460358, 3055.745692610916, 3056.592046958692, 3057.4384012893756, 3058.2847556029774, 3059.13110989951, 3059.9774641789845, 3060.8238184414126, 3061.6701726868064, 3062.516526915177, 3063.3628811265366, 3064.209235320896, 3065.0555894982667, 3065.9019436586614, 3066.7482978020903, 3067.594651928566, 3068.441006038099, 3069.287360130702, 3070.133714206386, 3070.9800682651617, 3071.826422307042, 3072.6727763320373, 3073.5191303401593, 3074.36548433142, 3075.21183830583, 3076.058192263402, 3076.9045462041463, 3077.7509001280746, 3078.5972540351986, 3079.4436079255292, 3080.2899617990784, 3081.1363156558573, 3081.9826694958774, 3082.82902331915, 3083.6753771256863, 3084.5217309154978, 3085.3680846885954, 3086.214438444991, 3087.0607921846963, 3087.9071459077218, 3088.753499614079, 3089.599853303779, 3090.4462069768338, 3091.292560633254, 3092.138914273051, 3092.985267896236, 3093.8316215028203, 3094.6779750928154, 3095.524328666232, 3096.370682223082, 3097.217035763376, 3098.0633892871256, 3098.9097427943416, 3099.7560962850353, 3100.602449759218, 3101.4488032169006, 3102.295156658095, 3103.1415100828112, 3103.9878634910615, 3104.834216882856, 3105.680570258206, 3106.5269236171234, 3107.3732769596186, 3108.219630285703, 3109.065983595387, 3109.912336888682, 3110.7586901656, 3111.6050434261506, 3112.4513966703457, 3113.297749898196, 3114.1441031097124, 3114.9904563049067, 3115.836809483789, 3116.6831626463704, 3117.529515792662, 3118.375868922675, 3119.2222220364206, 3120.068575133909, 3120.9149282151516, 3121.761281280159, 3122.6076343289424, 3123.453987361513, 3124.300340377881, 3125.146693378058, 3125.9930463620544, 3126.839399329881, 3127.6857522815494, 3128.5321052170693, 3129.3784581364525, 3130.2248110397095, 3131.071163926851, 3131.9175167978883, 3132.7638696528315, 3133.610222491692, 3134.4565753144807, 3135.302928121208, 3136.149280911885, 3136.9956336865216, 3137.84198644513, 3138.6883391877195, 3139.5346919143017, 3140.381044624887, 3141.2273973194865, 3142.0737499981105, 3142.9201026607698, 3143.766455307475, 3144.6128079382374, 3145.459160553067, 3146.3055131519745, 3147.1518657349707, 3147.998218302066, 3148.844570853272, 3149.6909233885985, 3150.537275908056, 3151.3836284116555, 3152.2299808994076, 3153.0763333713226, 3153.9226858274114, 3154.7690382676847, 3155.6153906921527, 3156.461743100826, 3157.3080954937154, 3158.154447870831, 3159.000800232184, 3159.847152577784, 3160.6935049076424, 3161.5398572217696, 3162.3862095201757, 3163.2325618028713, 3164.078914069867, 3164.9252663211732, 3165.7716185568006, 3166.617970776759, 3167.46432298106, 3168.3106751697132, 3169.157027342729, 3170.003379500118, 3170.8497316418907, 3171.696083768057, 3172.542435878628, 3173.388787973614, 3174.235140053025, 3175.0814921168717, 3175.927844165164, 3176.7741961979127, 3177.620548215128, 3178.4669002168202, 3179.3132522029996, 3180.1596041736766, 3181.0059561288617, 3181.852308068565, 3182.698659992797, 3183.5450119015672, 3184.3913637948867, 3185.2377156727653, 3186.0840675352133, 3186.9304193822413, 3187.77677121386, 3188.623123030078, 3189.469474830907, 3190.315826616357, 3191.1621783864375, 3192.008530141159, 3192.8548818805325, 3193.7012336045673, 3194.547585313274, 3195.3939370066623, 3196.2402886847426, 3197.086640347525, 3197.9329919950196, 3198.779343627237, 3199.6256952441863, 3200.4720468458786, 3201.3183984323236, 3202.1647500035315, 3203.0111015595126, 3203.8574531002764, 3204.7038046258335, 3205.550156136194, 3206.396507631367, 3207.242859111364, 3208.089210576194, 3208.9355620258675, 3209.781913460394, 3210.628264879784, 3211.474616284048, 3212.320967673195, 3213.167319047235, 3214.0136704061792, 3214.860021750036, 3215.706373078817, 3216.5527243925308, 3217.3990756911876, 3218.2454269747977, 3219.091778243371, 3219.9381294969176, 3220.784480735447, 3221.630831958969, 3222.4771831674934, 3223.323534361031, 3224.1698855395907, 3225.016236703183, 3225.862587851818, 3226.7089389855046, 3227.5552901042533, 3228.401641208074, 3229.2479922969765, 3230.0943433709704, 3230.9406944300654, 3231.7870454742715, 3232.6333965035988, 3233.4797475180567, 3234.326098517655, 3235.172449502403, 3236.018800472312, 3236.86515142739, 3237.7115023676483, 3238.557853293096, 3239.404204203742, 3240.2505550995975, 3241.096905980671, 3241.9432568469733, 3242.789607698513, 3243.6359585353007, 3244.4823093573455, 3245.3286601646573, 3246.175010957246, 3247.021361735121, 3247.867712498292, 3248.7140632467685, 3249.5604139805605, 3250.4067646996773, 3251.253115404129, 3252.099466093925, 3252.9458167690746, 3253.7921674295876, 3254.6385180754737, 3255.4848687067424, 3256.3312193234033, 3257.177569925466, 3258.02392051294, 3258.870271085835, 3259.7166216441606, 3260.562972187926, 3261.409322717141, 3262.2556732318153, 3263.1020237319576, 3263.948374217578, 3264.7947246886865, 3265.641075145292, 3266.487425587404, 3267.333776015032, 3268.1801264281858, 3269.0264768268744, 3269.8728272111075, 3270.7191775808947, 3271.565527936245, 3272.411878277168, 3273.258228603673, 3274.10457891577, 3274.950929213468, 3275.7972794967764, 3276.6436297657046, 3277.4899800202625, 3278.336330260459, 3279.182680486303, 3280.0290306978045, 3280.8753808949727, 3281.7217310778174, 3282.5680812463474, 3283.414431400572, 3284.260781540501, 3285.107131666143, 3285.9534817775075, 3286.7998318746045, 3287.646181957443, 3288.4925320260318, 3289.3388820803807, 3290.1852321204988, 3291.031582146395, 3291.877932158079, 3292.72428215556, 3293.5706321388475, 3294.4169821079504, 3295.263332062878, 3296.109682003639, 3296.9560319302436, 3297.8023818427005, 3298.6487317410188, 3299.495081625208, 3300.341431495277, 3301.187781351235, 3302.0341311930915, 3302.8804810208553, 3303.726830834536, 3304.5731806341423, 3305.4195304196833, 3306.2658801911684, 3307.112229948607, 3307.9585796920073, 3308.8049294213793, 3309.651279136732, 3310.497628838074, 3311.343978525415, 3312.1903281987634, 3313.0366778581288, 3313.88302750352, 3314.7293771349464, 3315.575726752417, 3316.42207635594, 3317.2684259455255, 3318.1147755211823, 3318.9611250829194, 3319.8074746307457, 3320.65382416467, 3321.5001736847016, 3322.3465231908494, 3323.1928726831225, 3324.03922216153, 3324.88557162608, 3325.731921076783, 3326.5782705136467, 3327.4246199366803, 3328.270969345893, 3329.1173187412933, 3329.963668122891, 3330.8100174906945, 3331.6563668447125, 3332.5027161849544, 3333.3490655114288, 3334.195414824145, 3335.041764123111, 3335.8881134083363, 3336.73446267983, 3337.5808119376, 3338.4271611816566, 3339.2735104120075, 3340.119859628662, 3340.966208831629, 3341.812558020917, 3342.658907196535, 3343.505256358492, 3344.3516055067967, 3345.1979546414577, 3346.044303762484, 3346.8906528698844, 3347.737001963668, 3348.5833510438424, 3349.429700110418, 3350.276049163402, 3351.1223982028046, 3351.9687472286337, 3352.815096240898, 3353.6614452396066, 3354.507794224768, 3355.3541431963913, 3356.200492154485, 3357.0468410990575, 3357.8931900301177, 3358.739538947674, 3359.585887851736, 3360.4322367423115, 3361.2785856194096, 3362.1249344830385, 3362.9712833332073, 3363.8176321699248, 3364.6639809931994, 3365.5103298030394, 3366.356678599454, 3367.2030273824516, 3368.0493761520406, 3368.89572490823, 3369.7420736510285, 3370.588422380444, 3371.4347710964857, 3372.2811197991623, 3373.127468488482, 3373.973817164453, 3374.8201658270846, 3375.666514476385, 3376.512863112363, 3377.359211735027, 3378.2055603443855, 3379.051908940447, 3379.8982575232203, 3380.7446060927136, 3381.5909546489356, 3382.4373031918944, 3383.283651721599, 3384.130000238058, 3384.9763487412793, 3385.8226972312714, 3386.669045708043, 3387.515394171603, 3388.3617426219594, 3389.2080910591208, 3390.0544394830954, 3390.9007878938914, 3391.747136291518, 3392.5934846759833, 3393.4398330472955, 3394.286181405463, 3395.1325297504945, 3395.9788780823988, 3396.8252264011835, 3397.6715747068574, 3398.5179229994287, 3399.3642712789056, 3400.2106195452966, 3401.05696779861, 3401.9033160388544, 3402.749664266038, 3403.5960124801695, 3404.442360681257, 3405.2887088693087, 3406.135057044333, 3406.9814052063375, 3407.8277533553314, 3408.674101491323, 3409.5204496143206, 3410.366797724332, 3411.2131458213657, 3412.05949390543, 3412.9058419765333, 3413.7521900346837, 3414.5985380798893, 3415.4448861121587, 3416.2912341315, 3417.1375821379215, 3417.9839301314314, 3418.830278112038, 3419.676626079749, 3420.522974034573, 3421.369321976518, 3422.215669905593, 3423.0620178218055, 3423.9083657251635, 3424.7547136156754, 3425.6010614933493, 3426.4474093581935, 3427.293757210216, 3428.1401050494255, 3428.98645287583, 3429.832800689437, 3430.679148490255, 3431.5254962782924, 3432.3718440535567, 3433.2181918160563, 3434.0645395658, 3434.910887302795, 3435.7572350270493, 3436.6035827385717, 3437.44993043737, 3438.296278123452, 3439.142625796826, 3439.9889734575, 3440.8353211054823, 3441.681668740781, 3442.528016363404, 3443.374363973359, 3444.220711570654, 3445.067059155298, 3445.913406727298, 3446.7597542866624, 3447.6061018333994, 3448.4524493675162, 3449.2987968890216, 3450.1451443979236, 3450.99149189423, 3451.8378393779485, 3452.6841868490874, 3453.5305343076543, 3454.3768817536575, 3455.223229187105, 3456.0695766080044, 3456.915924016364, 3457.7622714121917, 3458.608618795495, 3459.4549661662822, 3460.301313524561, 3461.1476608703397, 3461.994008203626, 3462.8403555244277, 3463.686702832753, 3464.533050128609, 3465.3793974120044, 3466.2257446829467, 3467.072091941444, 3467.9184391875037, 3468.764786421134, 3469.611133642343, 3470.457480851138, 3471.303828047527, 3472.150175231518, 3472.996522403119, 3473.8428695623375, 3474.6892167091814, 3475.5355638436586, 3476.3819109657766, 3477.228258075543, 3478.0746051729666, 3478.9209522580545, 3479.7672993308147, 3480.6136463912544, 3481.459993439382, 3482.3063404752047, 3483.1526874987308, 3483.9990345099677, 3484.8453815089233, 3485.6917284956053, 3486.5380754700213, 3487.3844224321792, 3488.2307693820867, 3489.0771163197514, 3489.923463245181, 3490.7698101583837, 3491.6161570593667, 3492.4625039481375, 3493.308850824704, 3494.1551976890746, 3495.001544541256, 3495.847891381256, 3496.6942382090824, 3497.540585024743, 3498.3869318282455, 3499.233278619597, 3500.0796253988055, 3500.9259721658786, 3501.772318920824, 3502.6186656636496, 3503.4650123943625, 3504.3113591129704, 3505.1577058194807, 3506.0040525139016, 3506.8503991962402, 3507.6967458665044, 3508.5430925247015, 3509.389439170839, 3510.235785804925, 3511.0821324269664, 3511.928479036971, 3512.774825634947, 3513.6211722209005, 3514.46751879484, 3515.3138653567735, 3516.1602119067074, 3517.00655844465, 3517.8529049706085, 3518.6992514845906, 3519.5455979866038, 3520.391944476655, 3521.2382909547528, 3522.0846374209036, 3522.9309838751155, 3523.777330317396, 3524.6236767477517, 3525.4700231661914, 3526.3163695727217, 3527.1627159673503, 3528.0090623500846, 3528.855408720932, 3529.7017550798996, 3530.5481014269953, 3531.3944477622267, 3532.240794085601, 3533.0871403971255, 3533.9334866968074, 3534.7798329846546, 3535.6261792606742, 3536.4725255248736, 3537.31887177726, 3538.1652180178407, 3539.011564246624, 3539.8579104636165, 3540.7042566688256, 3541.5506028622585, 3542.3969490439226, 3543.2432952138256, 3544.0896413719747, 3544.9359875183773, 3545.7823336530405, 3546.6286797759717, 3547.4750258871786, 3548.321371986668, 3549.1677180744473, 3550.014064150524, 3550.860410214905, 3551.7067562675984, 3552.5531023086105, 3553.3994483379493, 3554.2457943556215, 3555.092140361635, 3555.9384863559962, 3556.7848323387134, 3557.631178309793, 3558.4775242692426, 3559.3238702170693, 3560.1702161532808, 3561.016562077884, 3561.862907990886, 3562.709253892294, 3563.555599782115, 3564.401945660357, 3565.2482915270266, 3566.094637382131, 3566.940983225678, 3567.787329057674, 3568.6336748781264, 3569.4800206870423, 3570.326366484429, 3571.1727122702937, 3572.0190580446438, 3572.865403807486, 3573.7117495588277, 3574.558095298676, 3575.404441027038, 3576.250786743921, 3577.0971324493316, 3577.9434781432774, 3578.7898238257653, 3579.6361694968027, 3580.4825151563964, 3581.3288608045536, 3582.1752064412817, 3583.0215520665874, 3583.8678976804777, 3584.71424328296, 3585.5605888740415, 3586.406934453729, 3587.253280022029, 3588.099625578949, 3588.9459711244967, 3589.7923166586784, 3590.6386621815013, 3591.4850076929724, 3592.331353193099, 3593.177698681888, 3594.0240441593464, 3594.870389625481, 3595.716735080299, 3596.563080523807, 3597.409425956013, 3598.2557713769233, 3599.102116786545, 3599.948462184885, 3600.7948075719505, 3601.641152947748, 3602.487498312285, 3603.3338436655677, 3604.1801890076035, 3605.0265343384, 3605.8728796579635, 3606.7192249663008, 3607.565570263419, 3608.411915549325, 3609.258260824026, 3610.1046060875287, 3610.9509513398402, 3611.797296580967, 3612.6436418109165, 3613.4899870296954, 3614.3363322373107, 3615.1826774337687, 3616.029022619077, 3616.875367793242, 3617.721712956271, 3618.5680581081706, 3619.4144032489476, 3620.2607483786087, 3621.1070934971613, 3621.953438604612, 3622.7997837009675, 3623.646128786235, 3624.4924738604204, 3625.338818923532, 3626.1851639755755, 3627.031509016558, 3627.877854046487, 3628.724199065368, 3629.5705440732086, 3630.4168890700157, 3631.2632340557957, 3632.109579030556, 3632.9559239943023, 3633.802268947042, 3634.648613888782, 3635.494958819529, 3636.3413037392897, 3637.187648648071, 3638.0339935458796, 3638.880338432722, 3639.726683308605, 3640.5730281735355, 3641.41937302752, 3642.265717870566, 3643.1120627026794, 3643.958407523867, 3644.804752334136, 3645.651097133493, 3646.497441921944, 3647.343786699496, 3648.190131466156, 3649.0364762219306, 3649.8828209668263, 3650.72916570085, 3651.5755104240084, 3652.421855136308, 3653.268199837755, 3654.1145445283573, 3654.9608892081205, 3655.8072338770517, 3656.6535785351575, 3657.499923182444, 3658.3462678189185, 3659.1926124445877, 3660.0389570594575, 3660.8853016635353, 3661.7316462568274, 3662.57799083934, 3663.4243354110804, 3664.270679972055, 3665.11702452227, 3665.9633690617325, 3666.8097135904486, 3667.6560581084254, 3668.5024026156693, 3669.3487471121866, 3670.195091597984, 3671.0414360730683, 3671.887780537446, 3672.7341249911233, 3673.580469434107, 3674.4268138664033, 3675.273158288019, 3676.1195026989612, 3676.9658470992354, 3677.812191488849, 3678.6585358678076, 3679.5048802361184, 3680.3512245937877, 3681.1975689408223, 3682.0439132772285, 3682.8902576030127, 3683.7366019181813, 3684.582946222741, 3685.4292905166985, 3686.27563480006, 3687.1219790728314, 3687.96832333502, 3688.814667586632, 3689.6610118276735, 3690.5073560581513, 3691.353700278072, 3692.200044487442, 3693.0463886862676, 3693.892732874555, 3694.7390770523107, 3695.5854212195413, 3696.431765376253, 3697.278109522453, 3698.124453658147, 3698.970797783342, 3699.8171418980432, 3700.663486002258, 3701.5098300959926, 3702.356174179253, 3703.2025182520456, 3704.0488623143774, 3704.8952063662546, 3705.741550407683, 3706.5878944386695, 3707.4342384592205, 3708.280582469342, 3709.1269264690404, 3709.973270458322, 3710.8196144371936, 3711.665958405661, 3712.5123023637307, 3713.3586463114093, 3714.204990248703, 3715.0513341756177, 3715.89767809216, 3716.744021998336, 3717.5903658941525, 3718.4367097796153, 3719.2830536547312, 3720.1293975195063, 3720.9757413739467, 3721.8220852180584, 3722.6684290518483, 3723.5147728753223, 3724.3611166884866, 3725.207460491348, 3726.053804283912, 3726.9001480661855, 3727.7464918381743, 3728.592835599885, 3729.4391793513237, 3730.2855230924965, 3731.13186682341, 3731.9782105440695, 3732.8245542544823, 3733.6708979546543, 3734.517241644591, 3735.3635853243, 3736.209928993786, 3737.056272653056, 3737.9026163021163, 3738.748959940973, 3739.5953035696316, 3740.441647188099, 3741.2879907963816, 3742.134334394485, 3742.9806779824157, 3743.8270215601797, 3744.6733651277827, 3745.5197086852318, 3746.366052232532, 3747.212395769691, 3748.0587392967136, 3748.9050828136064, 3749.7514263203757, 3750.5977698170273, 3751.444113303567, 3752.290456780002, 3753.1368002463373, 3753.9831437025796, 3754.8294871487346, 3755.6758305848093, 3756.522174010809, 3757.3685174267403, 3758.2148608326083, 3759.06120422842, 3759.9075476141816, 3760.7538909898985, 3761.600234355577, 3762.446577711223, 3763.292921056843, 3764.1392643924432, 3764.985607718029, 3765.8319510336064, 3766.678294339182, 3767.5246376347613, 3768.370980920351, 3769.2173241959567, 3770.063667461585, 3770.9100107172408, 3771.756353962931, 3772.602697198661, 3773.4490404244375, 3774.295383640266, 3775.141726846153, 3775.9880700421036, 3776.8344132281245, 3777.6807564042215, 3778.527099570401, 3779.373442726668, 3780.2197858730297, 3781.066129009491, 3781.912472136058, 3782.7588152527373, 3783.6051583595345, 3784.4515014564554, 3785.2978445435065, 3786.144187620693, 3786.9905306880214, 3787.836873745497, 3788.6832167931266, 3789.5295598309153, 3790.3759028588697, 3791.222245876995, 3792.068588885298, 3792.914931883784, 3793.761274872459, 3794.607617851329, 3795.4539608204, 3796.3003037796775, 3797.1466467291675, 3797.9929896688764, 3798.8393325988095, 3799.685675518973, 3800.5320184293723, 3801.378361330014, 3802.224704220903, 3803.0710471020457, 3803.9173899734483, 3804.763732835116, 3805.610075687055, 3806.4564185292716, 3807.3027613617705, 3808.1491041845584, 3808.995446997641, 3809.841789801024, 3810.688132594713, 3811.534475378714, 3812.380818153033, 3813.227160917676, 3814.0735036726483, 3814.919846417956, 3815.7661891536045, 3816.6125318796, 3817.458874595948, 3818.305217302655, 3819.1515599997256, 3819.9979026871665, 3820.844245364983, 3821.6905880331815, 3822.536930691767, 3823.3832733407453, 3824.2296159801226, 3825.0759586099043, 3825.9223012300963, 3826.7686438407045, 3827.6149864417343, 3828.4613290331918, 3829.3076716150827, 3830.154014187412, 3831.0003567501863, 3831.8466993034112, 3832.693041847092, 3833.539384381235, 3834.385726905845, 3835.2320694209284, 3836.078411926491, 3836.9247544225377, 3837.771096909075, 3838.6174393861083, 3839.4637818536435, 3840.3101243116857, 3841.156466760241, 3842.0028091993154, 3842.8491516289137, 3843.6954940490423, 3844.5418364597062, 3845.388178860912, 3846.2345212526643, 3847.0808636349693, 3847.927206007833, 3848.7735483712604, 3849.6198907252574, 3850.4662330698293, 3851.312575404982, 3852.1589177307214, 3853.0052600470526, 3853.8516023539814, 3854.697944651514, 3855.5442869396547, 3856.39062921841, 3857.236971487786, 3858.083313747787, 3858.9296559984195, 3859.7759982396888, 3860.6223404716006, 3861.46868269416, 3862.3150249073733, 3863.1613671112455, 3864.0077093057826, 3864.85405149099, 3865.7003936668734, 3866.546735833438, 3867.3930779906896, 3868.2394201386337, 3869.0857622772755, 3869.932104406621, 3870.7784465266755, 3871.6247886374445, 3872.471130738934, 3873.3174728311487, 3874.163814914095, 3875.010156987778, 3875.8564990522027, 3876.7028411073757, 3877.549183153302, 3878.3955251899865, 3879.2418672174354, 3880.0882092356537, 3880.9345512446475, 3881.780893244422, 3882.6272352349824, 3883.4735772163344, 3884.3199191884837, 3885.1662611514357, 3886.0126031051955, 3886.858945049769, 3887.705286985161, 3888.5516289113775, 3889.397970828424, 3890.244312736306, 3891.0906546350284, 3891.936996524597, 3892.7833384050173, 3893.6296802762945, 3894.4760221384345, 3895.322363991442, 3896.168705835323, 3897.015047670083, 3897.8613894957266, 3898.70773131226, 3899.5540731196884, 3900.400414918017, 3901.2467567072513, 3902.0930984873967, 3902.939440258459, 3903.785782020443, 3904.632123773354, 3905.478465517198, 3906.3248072519796, 3907.171148977705, 3908.017490694379, 3908.863832402007, 3909.710174100595, 3910.5565157901474, 3911.40285747067, 3912.249199142168, 3913.095540804647, 3913.9418824581126, 3914.788224102569, 3915.634565738023, 3916.4809073644788, 3917.3272489819424, 3918.173590590419, 3919.019932189913, 3919.866273780431, 3920.712615361978, 3921.558956934559, 3922.405298498179, 3923.251640052844, 3924.097981598559, 3924.944323135329, 3925.7906646631595, 3926.637006182056, 3927.483347692024, 3928.329689193068, 3929.176030685194, 3930.0223721684065, 3930.8687136427116, 3931.715055108114, 3932.5613965646194, 3933.4077380122326, 3934.254079450959, 3935.100420880804, 3935.9467623017727, 3936.79310371387, 3937.639445117102, 3938.485786511473, 3939.3321278969893, 3940.178469273655, 3941.024810641476, 3941.871152000457, 3942.717493350604, 3943.5638346919213, 3944.410176024415, 3945.2565173480893, 3946.1028586629504, 3946.949199969003, 3947.7955412662523, 3948.6418825547034, 3949.4882238343616, 3950.3345651052323, 3951.1809063673204, 3952.0272476206314, 3952.87358886517, 3953.719930100942, 3954.5662713279517, 3955.412612546205, 3956.2589537557064, 3957.105294956462, 3957.9516361484757, 3958.797977331754, 3959.644318506301, 3960.490659672122, 3961.3370008292227, 3962.1833419776076, 3963.0296831172823, 3963.8760242482517, 3964.722365370521, 3965.568706484095, 3966.415047588979, 3967.2613886851786, 3968.1077297726983, 3968.9540708515433, 3969.800411921719, 3970.6467529832303, 3971.4930940360823, 3972.33943508028, 3973.1857761158285, 3974.032117142733, 3974.8784581609984, 3975.72479917063, 3976.5711401716326, 3977.4174811640114, 3978.2638221477714, 3979.110163122918, 3979.956504089456, 3980.8028450473903, 3981.6491859967264, 3982.4955269374686, 3983.341867869623, 3984.1882087931936, 3985.034549708186, 3985.8808906146055, 3986.7272315124565, 3987.573572401744, 3988.4199132824738, 3989.26625415465, 3990.112595018278, 3990.958935873363, 3991.8052767199097, 3992.651617557923, 3993.4979583874087, 3994.344299208371, 3995.190640020815, 3996.036980824746, 3996.8833216201688, 3997.729662407088, 3998.5760031855098, 3999.422343955438, 4000.268684716878, 4001.1150254698346, 4001.961366214313, 4002.807706950318, 4003.6540476778546, 4004.5003883969275, 4005.346729107542, 4006.193069809703, 4007.0394105034156, 4007.8857511886845, 4008.7320918655146, 4009.578432533911, 4010.4247731938785, 4011.2711138454224, 4012.117454488547, 4012.9637951232576, 4013.810135749559, 4014.656476367456, 4015.502816976954, 4016.3491575780577, 4017.1954981707718, 4018.0418387551013, 4018.888179331051, 4019.734519898626, 4020.580860457831, 4021.427201008671, 4022.273541551151, 4023.119882085276, 4023.9662226110504, 4024.8125631284793, 4025.6589036375676, 4026.50524413832, 4027.3515846307414, 4028.197925114837, 4029.0442655906113, 4029.8906060580694, 4030.736946517216, 4031.583286968056, 4032.4296274105945, 4033.275967844836, 4034.122308270785, 4034.9686486884475, 4035.814989097827, 4036.6613294989293, 4037.5076698917587, 4038.35401027632, 4039.2003506526185, 4040.0466910206587, 4040.8930313804453, 4041.7393717319833, 4042.5857120752776, 4043.4320524103327, 4044.2783927371534, 4045.124733055745, 4045.9710733661113, 4046.8174136682583, 4047.66375396219, 4048.5100942479116, 4049.3564345254276, 4050.2027747947427, 4051.049115055862, 4051.8954553087897, 4052.741795553531, 4053.588135790091, 4054.434476018474, 4055.280816238685, 4056.1271564507283, 4056.973496654609, 4057.8198368503317, 4058.6661770379014, 4059.5125172173225, 4060.3588573886, 4061.205197551739, 4062.051537706743, 4062.897877853618, 4063.7442179923682, 4064.5905581229986, 4065.4368982455135, 4066.283238359918, 4067.1295784662166, 4067.975918564414, 4068.822258654515, 4069.668598736524, 4070.514938810446, 4071.3612788762857, 4072.207618934048, 4073.0539589837367, 4073.900299025357, 4074.746639058914, 4075.592979084412, 4076.4393191018557, 4077.28565911125, 4078.1319991125993, 4078.978339105908, 4079.8246790911817, 4080.6710190684244, 4081.5173590376407, 4082.3636989988354, 4083.2100389520133, 4084.0563788971785, 4084.902718834336, 4085.7490587634907, 4086.595398684647, 4087.4417385978095, 4088.288078502983, 4089.134418400172, 4089.980758289381, 4090.8270981706146, 4091.673438043878, 4092.5197779091754, 4093.3661177665113, 4094.2124576158903, 4095.0587974573173, 4095.9051372907966, 4096.751477116333, 4097.597816933931, 4098.444156743596, 4099.290496545332, 4100.136836339142, 4100.9831761250325, 4101.829515903008, 4102.675855673072, 4103.52219543523, 4104.3685351894865, 4105.214874935846, 4106.061214674312, 4106.907554404891, 4107.753894127586, 4108.600233842403, 4109.446573549346, 4110.292913248419, 4111.139252939626, 4111.985592622973, 4112.8319322984635, 4113.678271966102, 4114.524611625894, 4115.370951277843, 4116.217290921954, 4117.063630558233, 4117.909970186683, 4118.756309807308, 4119.602649420113, 4120.4489890251025, 4121.295328622281, 4122.141668211653, 4122.988007793224, 4123.8343473669975, 4124.680686932978, 4125.52702649117, 4126.373366041578, 4127.219705584207, 4128.0660451190615, 4128.912384646145, 4129.758724165463, 4130.60506367702, 4131.451403180819, 4132.297742676867, 4133.144082165166, 4133.990421645722, 4134.836761118539, 4135.68310058362, 4136.529440040972, 4137.375779490599, 4138.222118932504, 4139.068458366692, 4139.914797793168, 4140.761137211936, 4141.607476623001, 4142.453816026366, 4143.300155422037, 4144.146494810018, 4144.992834190313, 4145.8391735629275, 4146.685512927865, 4147.531852285129, 4148.378191634726, 4149.224530976659, 4150.070870310932, 4150.917209637551, 4151.76354895652, 4152.6098882678425, 4153.456227571523, 4154.302566867567, 4155.148906155978, 4155.99524543676, 4156.841584709919, 4157.687923975457, 4158.53426323338, 4159.380602483693, 4160.2269417264, 4161.073280961504, 4161.91962018901, 4162.765959408923, 4163.612298621247, 4164.4586378259855, 4165.304977023145, 4166.151316212728, 4166.997655394739, 4167.843994569183, 4168.6903337360645, 4169.536672895387, 4170.383012047155, 4171.229351191373, 4172.075690328046, 4172.922029457178, 4173.768368578772, 4174.614707692835, 4175.461046799369, 4176.307385898379, 4177.153724989869, 4178.000064073844, 4178.846403150309, 4179.692742219267, 4180.539081280722, 4181.385420334679, 4182.231759381142, 4183.0780984201165, 4183.924437451606, 4184.770776475613, 4185.617115492145, 4186.463454501204, 4187.309793502795, 4188.1561324969225, 4189.00247148359, 4189.848810462802, 4190.695149434564, 4191.541488398879, 4192.387827355751, 4193.234166305186, 4194.080505247186, 4194.926844181757, 4195.773183108902, 4196.619522028627, 4197.465860940935, 4198.31219984583, 4199.158538743317, 4200.0048776334, 4200.851216516083, 4201.69755539137, 4202.5438942592655, 4203.390233119774, 4204.2365719729, 4205.082910818646, 4205.929249657019, 4206.775588488021, 4207.621927311657, 4208.468266127931, 4209.314604936848, 4210.160943738411, 4211.007282532625, 4211.853621319494, 4212.699960099022, 4213.546298871213, 4214.392637636072, 4215.238976393603, 4216.085315143809, 4216.931653886695, 4217.777992622266, 4218.624331350525, 4219.470670071477, 4220.317008785126, 4221.163347491475, 4222.00968619053, 4222.856024882294, 4223.702363566772, 4224.548702243967, 4225.395040913884, 4226.241379576527, 4227.0877182319, 4227.934056880007, 4228.780395520853, 4229.626734154441, 4230.4730727807755, 4231.319411399862, 4232.165750011703, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302, 4233.012088616302])
-plt.ticklabel_format(style='sci', axis='y', scilimits=(-3, 3))
-plt.ylabel(r"$D_{\rm HP}$/Mpc", fontsize=14)
-plt.xlabel("$t$/Giga años", fontsize=14)
-plt.savefig("plots/horizonte particulas.svg")
-    
\ No newline at end of file
@@ -0,0 +1,149 @@
+use core::f64;
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{derivada_log_e2, e2};
+use crate::plotting::linspace;
+
+
+/// Intervalo de factor de escala [a_min, a_max] en el que E² ≥ 0, es decir en el que puede existir el universo.
+/// a_min = 0 significa que el intervalo llega hasta el Big Bang y a_max = ∞ que el universo se expande para siempre.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntervaloPermitido{
+    pub a_min: f64,
+    pub a_max: f64,
+}
+
+impl IntervaloPermitido{
+    pub fn contiene(&self, a: f64) -> bool{
+        self.a_min <= a && a <= self.a_max
+    }
+
+    /// Redshift máximo desde el que puede llegar luz hasta un observador con factor de escala a0.
+    /// Es infinito si el intervalo llega hasta el Big Bang.
+    pub fn z_max(&self, a0: f64) -> f64{
+        if self.a_min == 0. {f64::INFINITY} else {a0/self.a_min - 1.}
+    }
+
+    pub fn tiene_big_bang(&self) -> bool{
+        self.a_min == 0.
+    }
+
+    pub fn se_expande_para_siempre(&self) -> bool{
+        self.a_max.is_infinite()
+    }
+}
+
+// Rango de ln(x) (x = a0/a) en el que se buscan las raíces de E². Fuera de él domina
+// un único término del polinomio y el signo ya no cambia para densidades razonables.
+const LN_X_MIN: f64 = -30.;
+const LN_X_MAX: f64 = 30.;
+pub(crate) const MUESTRAS: i64 = 3000;
+
+/// Busca los intervalos de factor de escala en los que E²(a) ≥ 0 para unos parámetros.
+///
+/// Con w = -1 E² es un polinomio de grado ≤ 4 en x = a0/a, así que tiene como mucho cuatro raíces positivas. Se muestrea
+/// en ln(x), se refinan los mínimos locales (para no perder dos raíces muy próximas, como en los universos
+/// que "remolonean" cerca del estático de Einstein) y se localizan las raíces por bisección.
+/// Los intervalos se devuelven ordenados de menor a mayor factor de escala.
+pub fn intervalos_permitidos(parametros: ParametrosCosmologicos) -> Vec<IntervaloPermitido>{
+    let p = |ln_x: f64| e2(ln_x.exp(), parametros);
+    let mut puntos: Vec<(f64, f64)> = linspace(LN_X_MIN, LN_X_MAX, MUESTRAS).into_iter().chain([LN_X_MAX]).map(|u| (u, p(u))).collect();
+
+    // Un mínimo local positivo entre dos muestras puede esconder dos raíces
+    let mut minimos = Vec::new();
+    for sl in puntos.windows(3){
+        if sl[1].1 < sl[0].1 && sl[1].1 < sl[2].1 && sl[1].1 > 0.{
+            let u = minimo_seccion_aurea(&p, sl[0].0, sl[2].0);
+            let valor = p(u);
+            if valor <= 0.{
+                minimos.push((u, valor));
+            }
+        }
+    }
+    puntos.extend(minimos);
+    puntos.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // Intervalos en ln(x) donde E² ≥ 0, con ±∞ si llegan al borde del muestreo
+    let mut intervalos_x = Vec::new();
+    let mut inicio = if puntos[0].1 >= 0. {Some(f64::NEG_INFINITY)} else {None};
+    for sl in puntos.windows(2){
+        let ((u0, p0), (u1, p1)) = (sl[0], sl[1]);
+        if p0 >= 0. && p1 < 0.{
+            intervalos_x.push((inicio.take().unwrap(), biseccion(&p, u0, u1)));
+        }
+        else if p0 < 0. && p1 >= 0.{
+            inicio = Some(biseccion(&p, u0, u1));
+        }
+    }
+    if let Some(inicio) = inicio{
+        intervalos_x.push((inicio, f64::INFINITY));
+    }
+
+    // x = a0/a así que los extremos se intercambian
+    let mut intervalos: Vec<_> = intervalos_x.into_iter().map(|(u_min, u_max)| IntervaloPermitido{
+        a_min: parametros.factor_escala*(-u_max).exp(),
+        a_max: parametros.factor_escala*(-u_min).exp(),
+    }).collect();
+    intervalos.reverse();
+    intervalos
+}
+
+/// Intervalo permitido en el que está el universo hoy (a = factor_escala). Como las densidades suman 1
+/// E²(a0) = 1 > 0 y este intervalo siempre existe.
+pub fn intervalo_actual(parametros: ParametrosCosmologicos) -> IntervaloPermitido{
+    intervalos_permitidos(parametros).into_iter().find(|i| i.contiene(parametros.factor_escala))
+        .unwrap_or(IntervaloPermitido{a_min: parametros.factor_escala, a_max: parametros.factor_escala})
+}
+
+/// Redshift máximo observable hoy, infinito si hay Big Bang
+pub fn z_maximo(parametros: ParametrosCosmologicos) -> f64{
+    // Si ningún término de E² es negativo y alguno es positivo, E² > 0 para todo a y no hace falta buscar raíces.
+    // Es el caso habitual y los muestreadores lo recalculan en cada punto.
+    let terminos = [parametros.densidad_energia_oscura, parametros.densidad_curvatura, parametros.densidad_materia, parametros.densidad_radiacion];
+    if terminos.iter().all(|&t| t >= 0.) && terminos.iter().any(|&t| t > 0.){
+        return f64::INFINITY;
+    }
+    intervalo_actual(parametros).z_max(parametros.factor_escala)
+}
+
+/// Parámetro de deceleración q = -ä a/ȧ² en z, q = ½ d ln E²/d ln(1+z) - 1
+pub fn parametro_deceleracion(z: f64, parametros: ParametrosCosmologicos) -> f64{
+    derivada_log_e2(1. + z, parametros)/2. - 1.
+}
+
+/// Redshift al que la expansión empezó a acelerarse, el primer z > 0 en el que q pasa a ser positivo.
+/// None si hoy no se acelera o si se ha acelerado siempre (en todo el pasado observable).
+pub fn z_aceleracion(parametros: ParametrosCosmologicos) -> Option<f64>{
+    let q = |ln_x: f64| parametro_deceleracion(ln_x.exp() - 1., parametros);
+    if q(0.) >= 0.{
+        return None;
+    }
+    let ln_x_max = z_maximo(parametros).ln_1p().min(LN_X_MAX);
+    let puntos: Vec<f64> = linspace(0., ln_x_max, MUESTRAS).into_iter().chain([ln_x_max]).collect();
+    puntos.windows(2).find(|sl| q(sl[1]) >= 0.).map(|sl| biseccion(&q, sl[0], sl[1]).exp() - 1.)
+}
+
+/// Raíz de f en [a, b] sabiendo que f(a) y f(b) tienen distinto signo. Se devuelve el extremo del lado en el que f ≥ 0
+/// para que el intervalo que se construye con ella no incluya puntos con E² < 0.
+pub(crate) fn biseccion(f: &impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64{
+    let positivo_en_a = f(a) >= 0.;
+    for _ in 0..100{
+        let m = (a+b)/2.;
+        if m == a || m == b {break}
+        if (f(m) >= 0.) == positivo_en_a {a = m} else {b = m}
+    }
+    if positivo_en_a {a} else {b}
+}
+
+fn minimo_seccion_aurea(f: &impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64{
+    let r = (5f64.sqrt()-1.)/2.;
+    let mut c = b - r*(b-a);
+    let mut d = a + r*(b-a);
+    for _ in 0..100{
+        if f(c) < f(d) {b = d} else {a = c}
+        c = b - r*(b-a);
+        d = a + r*(b-a);
+        if (b-a).abs() < 1e-14 {break}
+    }
+    (a+b)/2.
+}
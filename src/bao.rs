@@ -0,0 +1,196 @@
+use core::f64;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{luminosity_distance_mpc, hubble_parameter, con_radiacion_estandar, e, comprobar_alcanzable, error_integral};
+use crate::integral_methods::integrate;
+use crate::consts::{C_MPC_GY, KM_S_MPC_TO_GY, OMEGA_FOTONES_H2};
+use crate::error::{ErrorCosmologico, Magnitud};
+use crate::fitting::Verosimilitud;
+use crate::dual::{Dual, Flotante};
+use crate::linalg::{cholesky, invertir};
+use crate::supernovae::separar;
+use crate::texto;
+
+
+pub(crate) const TOLERANCIA_HORIZONTE_SONIDO: f64 = 1e-10;
+
+/// Horizonte de sonido comóvil en z, r_s = ∫_z^∞ c_s/H dz' con c_s = c/√(3(1 + 3ρ_b/4ρ_γ)), en leguas cósmicas.
+/// Se integra con la radiación estándar si el modelo no tiene (ver `con_radiacion_estandar`).
+pub fn sound_horizon(z: f64, parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    let parametros = con_radiacion_estandar(parametros);
+    comprobar_alcanzable(Magnitud::HorizonteSonido, z, f64::INFINITY, TOLERANCIA_HORIZONTE_SONIDO, parametros)?;
+    // R = 3ρ_b/4ρ_γ hoy, escala como a = 1/(1+z)
+    let r_0 = 3.*parametros.densidad_bariones/(4.*OMEGA_FOTONES_H2)*parametros.h().powi(2);
+    let error = |err| error_integral(Magnitud::HorizonteSonido, z, TOLERANCIA_HORIZONTE_SONIDO, err);
+    let integral = integrate(parametros.metodo_distancia, |zp: f64| 1./(e(1.+zp, parametros)*(3.*(1. + r_0/(1.+zp))).sqrt()),
+                             z, f64::INFINITY, 1e-20, TOLERANCIA_HORIZONTE_SONIDO).map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    Ok(parametros.c/parametros.constante_hubble*integral.value)
+}
+
+/// Horizonte de sonido en la época de arrastre r_d, en Mpc.
+///
+/// Se usa el ajuste de Aubourg et al. 2015 (ec. 16) a los resultados de CAMB, con una precisión del 0.02%:
+/// r_d = 55.154 exp(-72.3 (ω_ν + 0.0006)²) / (ω_cb^0.25351 ω_b^0.12807) Mpc, sin neutrinos masivos (ω_ν = 0).
+/// Integrar c_s/H hasta el z_d de Eisenstein & Hu da un r_d un 2% mayor, demasiado para los BAO actuales.
+pub fn drag_sound_horizon<T: Flotante>(parametros: ParametrosCosmologicos<T>) -> T{
+    let h2 = parametros.h().powi(2);
+    let omega_cb = parametros.densidad_materia*h2;
+    let omega_b = parametros.densidad_bariones*h2;
+    T::from(55.154*(-72.3*0.0006f64.powi(2)).exp())/(omega_cb.powf(0.25351)*omega_b.powf(0.12807))
+}
+
+
+/// Distancia que mide cada fila de un fichero de BAO, siempre dividida por r_d
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservableBao{
+    /// D_M/r_d, en el fichero `DM_rd`
+    DistanciaComovil,
+    /// D_H/r_d = c/(H r_d), en el fichero `DH_rd`
+    DistanciaHubble,
+    /// D_V/r_d, en el fichero `DV_rd`
+    DistanciaVolumen,
+}
+
+impl ObservableBao{
+    /// Distancia del modelo en Mpc. Se calcula directamente en Mpc y no en leguas cósmicas para que se pueda
+    /// derivar con números duales (ver `luminosity_distance_mpc`).
+    pub fn distancia<T: Flotante>(self, z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+        let d_m = || luminosity_distance_mpc(z, parametros).map(|d| d/(1.0+z)).map_err(|err| err.con_magnitud(Magnitud::DistanciaComovil));
+        let d_h = || hubble_parameter(z, parametros).map(|h| T::from(C_MPC_GY)/(h*KM_S_MPC_TO_GY)).map_err(|err| err.con_magnitud(Magnitud::DistanciaHubble));
+        match self{
+            ObservableBao::DistanciaComovil => d_m(),
+            ObservableBao::DistanciaHubble => d_h(),
+            ObservableBao::DistanciaVolumen => {
+                let volumen = |err: ErrorCosmologico| err.con_magnitud(Magnitud::DistanciaVolumen);
+                let (d_m, d_h) = (d_m().map_err(volumen)?, d_h().map_err(volumen)?);
+                Ok((d_m*z*d_m*d_h).cbrt())
+            }
+        }
+    }
+}
+
+/// Medidas de BAO con su covarianza. Las medidas de redshifts distintos se suponen independientes y las
+/// dos de un par anisótropo (D_M, D_H) están correlacionadas, así que la covarianza es diagonal por bloques.
+///
+/// Fichero en texto o CSV, ignorando las líneas vacías y las que empiezan por #. Cada línea es
+/// ```text
+/// z_eff  DM_rd|DH_rd|DV_rd  valor  error
+/// z_eff  DM_DH_rd  D_M/r_d  D_H/r_d  error_DM  error_DH  correlacion
+/// ```
+#[derive(Clone, Debug)]
+pub struct MedidasBao{
+    pub z: Vec<f64>,
+    pub observable: Vec<ObservableBao>,
+    pub valor: Vec<f64>,
+    pub sigma: Vec<f64>,
+    inversa_covarianza: Vec<f64>,
+}
+
+impl MedidasBao{
+    pub fn leer(datos: &Path) -> anyhow::Result<Self>{
+        let texto = fs::read_to_string(datos).with_context(|| texto!(NoSePudoLeer, datos.display()))?;
+        let mut medidas = MedidasBao{z: Vec::new(), observable: Vec::new(), valor: Vec::new(), sigma: Vec::new(), inversa_covarianza: Vec::new()};
+        // (índice de la primera medida del par, correlación)
+        let mut correlaciones: Vec<(usize, f64)> = Vec::new();
+        for (n, linea) in texto.lines().enumerate(){
+            let linea = linea.trim();
+            if linea.is_empty() || linea.starts_with('#'){
+                continue;
+            }
+            let campos = separar(linea);
+            let tipo = campos.get(1).map(|t| t.to_lowercase().replace('/', "_")).unwrap_or_default();
+            let numeros = campos.iter().enumerate().filter(|&(i, _)| i != 1).map(|(_, c)| c.parse::<f64>())
+                .collect::<Result<Vec<_>, _>>().with_context(|| texto!(NoEsNumero, datos.display(), n+1))?;
+            let observable = match tipo.as_str(){
+                "dm_rd" => Some(ObservableBao::DistanciaComovil),
+                "dh_rd" => Some(ObservableBao::DistanciaHubble),
+                "dv_rd" => Some(ObservableBao::DistanciaVolumen),
+                "dm_dh_rd" => None,
+                _ => bail!(texto!(TipoMedidaBao, datos.display(), n+1, campos.get(1).unwrap_or(&""))),
+            };
+            match (observable, &numeros[..]){
+                (Some(observable), &[z, valor, sigma]) => medidas.añadir(z, observable, valor, sigma),
+                (None, &[z, d_m, d_h, sigma_m, sigma_h, correlacion]) => {
+                    if correlacion.abs() >= 1.{
+                        bail!(texto!(CorrelacionBao, datos.display(), n+1));
+                    }
+                    correlaciones.push((medidas.z.len(), correlacion));
+                    medidas.añadir(z, ObservableBao::DistanciaComovil, d_m, sigma_m);
+                    medidas.añadir(z, ObservableBao::DistanciaHubble, d_h, sigma_h);
+                }
+                (Some(_), _) => bail!(texto!(ColumnasBao, datos.display(), n+1)),
+                (None, _) => bail!(texto!(ColumnasBaoAnisotropo, datos.display(), n+1)),
+            }
+        }
+        if medidas.z.is_empty(){
+            bail!(texto!(SinMedidas, datos.display()));
+        }
+        if let Some(i) = medidas.sigma.iter().position(|&s| s <= 0.){
+            bail!(texto!(ErrorNoPositivoEnZ, datos.display(), medidas.z[i]));
+        }
+
+        let n = medidas.z.len();
+        let mut matriz = vec![0.; n*n];
+        for i in 0..n{
+            matriz[i*n+i] = medidas.sigma[i].powi(2);
+        }
+        for (i, correlacion) in correlaciones{
+            let covarianza = correlacion*medidas.sigma[i]*medidas.sigma[i+1];
+            matriz[i*n+i+1] = covarianza;
+            matriz[(i+1)*n+i] = covarianza;
+        }
+        if cholesky(&matriz, n).is_none(){
+            bail!(texto!(CovarianzaNoDefinida, datos.display()));
+        }
+        medidas.inversa_covarianza = invertir(&matriz, n).ok_or(anyhow!(texto!(CovarianzaNoInvertible, datos.display())))?;
+        Ok(medidas)
+    }
+
+    fn añadir(&mut self, z: f64, observable: ObservableBao, valor: f64, sigma: f64){
+        self.z.push(z);
+        self.observable.push(observable);
+        self.valor.push(valor);
+        self.sigma.push(sigma);
+    }
+
+    /// Valores de D/r_d del modelo para cada medida. NaN si el modelo no llega a ese redshift.
+    pub fn predicciones<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> Vec<T>{
+        let parametros = con_radiacion_estandar(parametros);
+        let r_d = drag_sound_horizon(parametros);
+        self.z.iter().zip(&self.observable).map(|(&z, o)| o.distancia(z, parametros).unwrap_or(T::from(f64::NAN))/r_d).collect()
+    }
+
+    fn chi2_gaussiano<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> T{
+        let n = self.z.len();
+        let diferencias: Vec<T> = self.predicciones(parametros).iter().zip(&self.valor).map(|(&p, &v)| T::from(v) - p).collect();
+        if diferencias.iter().any(|d| !d.is_finite()){
+            return T::from(f64::INFINITY);
+        }
+        (0..n).map(|i| diferencias[i]*(0..n).map(|j| diferencias[j]*self.inversa_covarianza[i*n+j]).sum::<T>()).sum()
+    }
+}
+
+impl Verosimilitud for MedidasBao{
+    fn nombre(&self) -> &str{
+        "BAO"
+    }
+
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+        self.chi2_gaussiano(parametros)
+    }
+
+    fn chi2_dual(&self, parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+        Some(self.chi2_gaussiano(parametros))
+    }
+
+    fn numero_datos(&self) -> usize{
+        self.z.len()
+    }
+}
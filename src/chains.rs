@@ -0,0 +1,208 @@
+use core::f64;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::fitting::Parametro;
+use crate::texto;
+
+
+/// Muestras de una cadena de Markov. Cada fila es [paso, caminante, valores de los parámetros..., log posterior].
+#[derive(Clone, Debug)]
+pub struct Cadena{
+    pub parametros: Vec<Parametro>,
+    pub filas: Vec<Vec<f64>>,
+}
+
+impl Cadena{
+    pub fn new(parametros: &[Parametro]) -> Self{
+        Cadena{parametros: parametros.to_vec(), filas: Vec::new()}
+    }
+
+    pub fn columnas(&self) -> Vec<&'static str>{
+        ["paso", "caminante"].into_iter().chain(self.parametros.iter().map(|p| p.nombre())).chain(["log_posterior"]).collect()
+    }
+
+    pub fn añadir(&mut self, paso: usize, caminante: usize, valores: &[f64], log_posterior: f64){
+        let mut fila = vec![paso as f64, caminante as f64];
+        fila.extend_from_slice(valores);
+        fila.push(log_posterior);
+        self.filas.push(fila);
+    }
+
+    /// Valores de los parámetros de las filas con paso ≥ quemado
+    pub fn muestras(&self, quemado: usize) -> impl Iterator<Item = &[f64]>{
+        let n = self.parametros.len();
+        self.filas.iter().filter(move |f| f[0] >= quemado as f64).map(move |f| &f[2..2+n])
+    }
+
+    /// Escribe la cadena en CSV o, si la extensión es .npy, en el formato binario de numpy
+    /// (float64, una fila por muestra y las mismas columnas que el CSV).
+    pub fn escribir(&self, fichero: &Path) -> anyhow::Result<()>{
+        if let Some(directorio) = fichero.parent(){
+            fs::create_dir_all(directorio)?;
+        }
+        let contenido = if es_npy(fichero){
+            escribir_npy(&self.filas, self.columnas().len())
+        }
+        else{
+            (self.columnas().join(",") + "\n" + &filas_csv(&self.filas)).into_bytes()
+        };
+        fs::write(fichero, contenido).with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+    }
+
+    /// Añade al fichero las filas a partir de `desde`; las anteriores tienen que estar ya escritas con `escribir` o con
+    /// esta misma función. En los .npy sólo se reescribe la cabecera, que ocupa siempre lo mismo para el mismo número de columnas.
+    pub fn escribir_desde(&self, fichero: &Path, desde: usize) -> anyhow::Result<()>{
+        if desde == 0 || !fichero.exists(){
+            return self.escribir(fichero);
+        }
+        let nuevas = &self.filas[desde..];
+        let resultado = if es_npy(fichero){
+            let columnas = self.columnas().len();
+            let cabecera = cabecera_npy(self.filas.len(), columnas);
+            let mut f = OpenOptions::new().read(true).write(true).open(fichero)?;
+            let mut inicio = [0u8; 10];
+            f.read_exact(&mut inicio)?;
+            // Ficheros con otra longitud de cabecera (de versiones anteriores) se reescriben enteros
+            if 10 + u16::from_le_bytes([inicio[8], inicio[9]]) as usize != cabecera.len(){
+                return self.escribir(fichero);
+            }
+            f.seek(SeekFrom::Start(0))?;
+            f.write_all(&cabecera)?;
+            let final_anterior = (cabecera.len() + 8*desde*columnas) as u64;
+            f.set_len(final_anterior)?;
+            f.seek(SeekFrom::Start(final_anterior))?;
+            f.write_all(&nuevas.iter().flatten().flat_map(|x| x.to_le_bytes()).collect::<Vec<u8>>())
+        }
+        else{
+            OpenOptions::new().append(true).open(fichero).and_then(|mut f| f.write_all(filas_csv(nuevas).as_bytes()))
+        };
+        resultado.with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+    }
+
+    /// Lee una cadena escrita con `escribir`
+    pub fn leer(fichero: &Path, parametros: &[Parametro]) -> anyhow::Result<Self>{
+        let mut cadena = Cadena::new(parametros);
+        let columnas = cadena.columnas().len();
+        if es_npy(fichero){
+            let bytes = fs::read(fichero).with_context(|| texto!(NoSePudoLeer, fichero.display()))?;
+            let (forma, datos) = leer_npy(&bytes).with_context(|| texto!(NpyNoValido, fichero.display()))?;
+            if forma.1 != columnas{
+                bail!(texto!(ColumnasCadena, fichero.display(), forma.1, columnas));
+            }
+            cadena.filas = datos.chunks(columnas).map(|f| f.to_vec()).collect();
+        }
+        else{
+            let texto = fs::read_to_string(fichero).with_context(|| texto!(NoSePudoLeer, fichero.display()))?;
+            let mut lineas = texto.lines();
+            if lineas.next() != Some(cadena.columnas().join(",").as_str()){
+                bail!(texto!(CabeceraCadena, fichero.display(), format!("{:?}", parametros)));
+            }
+            for (n, linea) in lineas.enumerate(){
+                let fila = linea.split(',').map(|x| x.parse::<f64>()).collect::<Result<Vec<_>, _>>()
+                    .with_context(|| texto!(NoEsNumero, fichero.display(), n+2))?;
+                if fila.len() != columnas{
+                    bail!(texto!(ColumnasEsperadas, fichero.display(), n+2, columnas));
+                }
+                cadena.filas.push(fila);
+            }
+        }
+        Ok(cadena)
+    }
+}
+
+fn es_npy(fichero: &Path) -> bool{
+    fichero.extension().is_some_and(|e| e == "npy")
+}
+
+fn filas_csv(filas: &[Vec<f64>]) -> String{
+    let mut texto = String::new();
+    for fila in filas{
+        texto += &fila.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+        texto.push('\n');
+    }
+    texto
+}
+
+/// Cabecera mágica, longitud de la cabecera y diccionario de python del formato .npy versión 1.0
+fn cabecera_npy(filas: usize, columnas: usize) -> Vec<u8>{
+    let mut cabecera = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}", filas, columnas);
+    // Se reserva sitio para el número de filas más largo, así la cabecera no cambia de tamaño al añadir filas,
+    // y se rellena con espacios para que los datos empiecen en un múltiplo de 64 bytes
+    cabecera += &" ".repeat(usize::MAX.to_string().len() - filas.to_string().len());
+    while (10 + cabecera.len() + 1) % 64 != 0{
+        cabecera.push(' ');
+    }
+    cabecera.push('\n');
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(cabecera.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(cabecera.as_bytes());
+    bytes
+}
+
+/// Formato .npy versión 1.0: la cabecera y los datos
+fn escribir_npy(filas: &[Vec<f64>], columnas: usize) -> Vec<u8>{
+    let mut bytes = cabecera_npy(filas.len(), columnas);
+    for x in filas.iter().flatten(){
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+fn leer_npy(bytes: &[u8]) -> anyhow::Result<((usize, usize), Vec<f64>)>{
+    if bytes.len() < 10 || &bytes[..6] != b"\x93NUMPY" || bytes[6] != 1{
+        bail!(texto!(NpySinCabecera));
+    }
+    let longitud = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    let cabecera = std::str::from_utf8(bytes.get(10..10+longitud).ok_or(anyhow!(texto!(NpyCabeceraIncompleta)))?)?;
+    if !cabecera.contains("'<f8'") || !cabecera.contains("'fortran_order': False"){
+        bail!(texto!(NpySoloFloat64));
+    }
+    let forma = cabecera.split("'shape': (").nth(1).and_then(|s| s.split(')').next()).ok_or(anyhow!(texto!(NpySinForma)))?;
+    let dimensiones = forma.split(',').map(str::trim).filter(|s| !s.is_empty()).map(|s| s.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let forma = match dimensiones[..]{
+        [filas, columnas] => (filas, columnas),
+        _ => bail!(texto!(NpyDosDimensiones)),
+    };
+    let datos: Vec<f64> = bytes[10+longitud..].chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect();
+    if datos.len() != forma.0*forma.1{
+        bail!(texto!(NpyForma));
+    }
+    Ok((forma, datos))
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn escribir_por_partes_igual_que_entera(){
+        let directorio = std::env::temp_dir().join(format!("cadenas_{}", std::process::id()));
+        let parametros = [Parametro::DensidadMateria, Parametro::ConstanteHubble];
+        let mut cadena = Cadena::new(&parametros);
+        for extension in ["csv", "npy"]{
+            let por_partes = directorio.join(format!("por_partes.{extension}"));
+            let entera = directorio.join(format!("entera.{extension}"));
+            cadena.filas.clear();
+            let mut escritas = 0;
+            // Pasa de 9 a 10 y de 99 a 100 filas, que cambia el número de cifras de la cabecera del .npy
+            for paso in 0..60{
+                cadena.añadir(paso, 0, &[0.3 + paso as f64*1e-3, 70.], -1.5);
+                cadena.añadir(paso, 1, &[0.31, 69.5 - paso as f64], -2.);
+                if paso % 7 == 0{
+                    cadena.escribir_desde(&por_partes, escritas).unwrap();
+                    escritas = cadena.filas.len();
+                }
+            }
+            cadena.escribir_desde(&por_partes, escritas).unwrap();
+            cadena.escribir(&entera).unwrap();
+            assert_eq!(fs::read(&por_partes).unwrap(), fs::read(&entera).unwrap(), "{extension}");
+            assert_eq!(Cadena::leer(&por_partes, &parametros).unwrap().filas, cadena.filas);
+        }
+        fs::remove_dir_all(directorio).unwrap();
+    }
+}
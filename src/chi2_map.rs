@@ -0,0 +1,105 @@
+use core::f64;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use anyhow::{bail, Context};
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::{con_valores, Parametro, Verosimilitud};
+use crate::plotting::linspace;
+use crate::texto;
+
+
+/// Opciones de la orden `mapa_chi2`, tabla [mapa_chi2] del fichero de entrada
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionMapa{
+    /// Parámetros de los ejes x e y
+    pub parametros: (Parametro, Parametro),
+    pub rango_x: (f64, f64),
+    pub rango_y: (f64, f64),
+    /// Puntos en cada eje
+    pub puntos: usize,
+    /// Hilos para evaluar la rejilla, 0 para usar todos los procesadores
+    pub hilos: usize,
+    /// Fichero CSV con la rejilla
+    pub salida: PathBuf,
+}
+
+impl Default for ConfiguracionMapa{
+    fn default() -> Self{
+        ConfiguracionMapa{parametros: (Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura), rango_x: (0., 1.),
+                          rango_y: (0., 1.5), puntos: 50, hilos: 0, salida: PathBuf::from("mapas/chi2.csv")}
+    }
+}
+
+/// χ² en una rejilla de dos parámetros, con el resto fijos. `chi2` está ordenado por filas:
+/// el índice i*x.len() + j corresponde a (x[j], y[i]). Los modelos no físicos tienen χ² infinito.
+#[derive(Clone, Debug)]
+pub struct MapaChi2{
+    pub parametros: (Parametro, Parametro),
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub chi2: Vec<f64>,
+}
+
+/// Evalúa el χ² en la rejilla repartiendo las filas entre varios hilos
+pub fn mapa_chi2(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, configuracion: &ConfiguracionMapa) -> anyhow::Result<MapaChi2>{
+    let (px, py) = configuracion.parametros;
+    if px == py{
+        bail!(texto!(ParametrosMapaIguales));
+    }
+    if configuracion.puntos < 2{
+        bail!(texto!(PuntosPorEje));
+    }
+    // linspace no incluye el extremo superior
+    let eje = |(min, max): (f64, f64)| -> Vec<f64> {linspace(min, max, configuracion.puntos as i64 - 1).into_iter().chain([max]).collect()};
+    let (x, y) = (eje(configuracion.rango_x), eje(configuracion.rango_y));
+    let hilos = match configuracion.hilos{
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        n => n,
+    };
+
+    let chi2_punto = |k: usize| -> f64 {
+        let modelo = con_valores(&[px, py], &[x[k % x.len()], y[k / x.len()]], parametros);
+        let chi2 = verosimilitud.chi2(modelo);
+        if chi2.is_nan() {f64::INFINITY} else {chi2}
+    };
+    let total = x.len()*y.len();
+    let por_hilo = total.div_ceil(hilos);
+    let chi2 = thread::scope(|s| {
+        let trozos: Vec<_> = (0..total).step_by(por_hilo)
+            .map(|inicio| s.spawn(move || (inicio..total.min(inicio + por_hilo)).map(chi2_punto).collect::<Vec<f64>>()))
+            .collect();
+        trozos.into_iter().flat_map(|t| t.join().expect("un hilo del mapa de χ² ha fallado")).collect()
+    });
+    Ok(MapaChi2{parametros: configuracion.parametros, x, y, chi2})
+}
+
+impl MapaChi2{
+    /// Punto de la rejilla con el menor χ²: (x, y, χ²)
+    pub fn minimo(&self) -> (f64, f64, f64){
+        let (k, chi2) = self.chi2.iter().copied().enumerate().fold((0, f64::INFINITY), |(i, m), (j, v)| if v < m {(j, v)} else {(i, m)});
+        (self.x[k % self.x.len()], self.y[k / self.x.len()], chi2)
+    }
+
+    /// Δχ² respecto al mínimo de la rejilla
+    pub fn delta_chi2(&self) -> Vec<f64>{
+        let minimo = self.minimo().2;
+        self.chi2.iter().map(|c| c - minimo).collect()
+    }
+
+    /// Escribe la rejilla en CSV con las columnas x, y, χ² y Δχ²
+    pub fn escribir(&self, fichero: &Path) -> anyhow::Result<()>{
+        if let Some(directorio) = fichero.parent(){
+            fs::create_dir_all(directorio)?;
+        }
+        let mut texto = format!("{},{},chi2,delta_chi2\n", self.parametros.0.nombre(), self.parametros.1.nombre());
+        for (k, (chi2, delta)) in self.chi2.iter().zip(self.delta_chi2()).enumerate(){
+            texto += &format!("{},{},{},{}\n", self.x[k % self.x.len()], self.y[k / self.x.len()], chi2, delta);
+        }
+        fs::write(fichero, texto).with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+    }
+}
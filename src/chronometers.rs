@@ -0,0 +1,103 @@
+use core::f64;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{hubble_parameter, con_radiacion_estandar};
+use crate::fitting::Verosimilitud;
+use crate::dual::{Dual, Flotante};
+use crate::linalg::{cholesky, invertir};
+use crate::supernovae::{leer_covarianza, separar};
+use crate::texto;
+
+
+/// Medidas de H(z) con cronómetros cósmicos (edades diferenciales de galaxias pasivas, H = -1/(1+z) dz/dt).
+/// No dependen de ninguna escala de distancia calibrada, así que miden H0 independientemente de las supernovas y del CMB.
+#[derive(Clone, Debug)]
+pub struct MedidasHubble{
+    pub z: Vec<f64>,
+    /// H(z) en km/s/Mpc
+    pub valor: Vec<f64>,
+    pub sigma: Vec<f64>,
+    inversa_covarianza: Vec<f64>,
+}
+
+impl MedidasHubble{
+    /// Lee un fichero en texto o CSV con las columnas z, H y σ, ignorando las líneas vacías y las que empiezan por #.
+    ///
+    /// La covarianza opcional tiene el mismo formato que la de las supernovas (N seguido de los N² elementos)
+    /// y se suma a diag(σ²), p.ej. la sistemática de los modelos de población estelar.
+    pub fn leer(datos: &Path, covarianza: Option<&Path>) -> anyhow::Result<Self>{
+        let texto = fs::read_to_string(datos).with_context(|| texto!(NoSePudoLeer, datos.display()))?;
+        let mut z = Vec::new();
+        let mut valor = Vec::new();
+        let mut sigma = Vec::new();
+        for (n, linea) in texto.lines().enumerate(){
+            let linea = linea.trim();
+            if linea.is_empty() || linea.starts_with('#'){
+                continue;
+            }
+            let numeros = separar(linea).iter().map(|c| c.parse::<f64>())
+                .collect::<Result<Vec<_>, _>>().with_context(|| texto!(NoEsNumero, datos.display(), n+1))?;
+            match numeros[..]{
+                [zi, hi, si, ..] if si > 0. => {
+                    z.push(zi);
+                    valor.push(hi);
+                    sigma.push(si);
+                }
+                [_, _, _, ..] => bail!(texto!(ErrorNoPositivo, datos.display(), n+1)),
+                _ => bail!(texto!(ColumnasCronometros, datos.display(), n+1)),
+            }
+        }
+        if z.is_empty(){
+            bail!(texto!(SinMedidas, datos.display()));
+        }
+
+        let n = z.len();
+        let mut matriz = match covarianza{
+            Some(covarianza) => leer_covarianza(covarianza, n)?,
+            None => vec![0.; n*n],
+        };
+        for i in 0..n{
+            matriz[i*n+i] += sigma[i].powi(2);
+        }
+        if cholesky(&matriz, n).is_none(){
+            bail!(texto!(CovarianzaNoDefinida, datos.display()));
+        }
+        let inversa_covarianza = invertir(&matriz, n).ok_or(anyhow!(texto!(CovarianzaNoInvertible, datos.display())))?;
+        Ok(MedidasHubble{z, valor, sigma, inversa_covarianza})
+    }
+}
+
+impl MedidasHubble{
+    fn chi2_gaussiano<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> T{
+        let parametros = con_radiacion_estandar(parametros);
+        let n = self.z.len();
+        let diferencias: Vec<T> = self.z.iter().zip(&self.valor)
+            .map(|(&z, &v)| T::from(v) - hubble_parameter(z, parametros).unwrap_or(T::from(f64::NAN))).collect();
+        if diferencias.iter().any(|d| !d.is_finite()){
+            return T::from(f64::INFINITY);
+        }
+        (0..n).map(|i| diferencias[i]*(0..n).map(|j| diferencias[j]*self.inversa_covarianza[i*n+j]).sum::<T>()).sum()
+    }
+}
+
+impl Verosimilitud for MedidasHubble{
+    fn nombre(&self) -> &str{
+        "cronómetros cósmicos"
+    }
+
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+        self.chi2_gaussiano(parametros)
+    }
+
+    fn chi2_dual(&self, parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+        Some(self.chi2_gaussiano(parametros))
+    }
+
+    fn numero_datos(&self) -> usize{
+        self.z.len()
+    }
+}
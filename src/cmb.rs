@@ -0,0 +1,119 @@
+use core::f64;
+
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{comoving_distance, con_radiacion_estandar};
+use crate::bao::sound_horizon;
+use crate::error::{CausaError, ErrorCosmologico, Magnitud};
+use crate::fitting::Verosimilitud;
+use crate::linalg::invertir;
+
+
+/// Redshift del desacoplo z_*, ajuste de Hu & Sugiyama 1996 en función de ω_b y ω_m.
+/// Sin bariones o sin materia (p.ej. de Sitter o Milne) el ajuste no tiene sentido y se devuelve un error.
+pub fn decoupling_redshift(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    let h2 = parametros.h().powi(2);
+    let omega_b = parametros.densidad_bariones*h2;
+    let omega_m = parametros.densidad_materia*h2;
+    if !(omega_b > 0. && omega_m > 0.){
+        return Err(ErrorCosmologico{magnitud: Magnitud::RedshiftDesacoplo, z: 0., tolerancia: 0., causa: CausaError::SinDesacoplo});
+    }
+    let g1 = 0.0783*omega_b.powf(-0.238)/(1. + 39.5*omega_b.powf(0.763));
+    let g2 = 0.560/(1. + 21.1*omega_b.powf(1.81));
+    Ok(1048.*(1. + 0.00124*omega_b.powf(-0.738))*(1. + g1*omega_m.powf(g2)))
+}
+
+/// Parámetro de desplazamiento R = √Ω_m H0 D_M(z_*)/c
+pub fn shift_parameter(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    let parametros = con_radiacion_estandar(parametros);
+    let d_m = comoving_distance(decoupling_redshift(parametros)?, parametros)?;
+    Ok(parametros.densidad_materia.sqrt()*parametros.constante_hubble*d_m/parametros.c)
+}
+
+/// Escala acústica l_A = π D_M(z_*)/r_s(z_*)
+pub fn acoustic_scale(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    let parametros = con_radiacion_estandar(parametros);
+    let z = decoupling_redshift(parametros)?;
+    Ok(f64::consts::PI*comoving_distance(z, parametros)?/sound_horizon(z, parametros)?)
+}
+
+/// 100θ_*, con θ_* = r_s(z_*)/D_M(z_*) = π/l_A el tamaño angular del horizonte de sonido en el desacoplo.
+/// Con el z_* de Hu & Sugiyama se parece al 100θ_MC de Planck, pero no es exactamente igual: con sus parámetros de 2018 da 1.0391
+/// frente a 1.04092. Es el que se usa con `cien_theta_estrella` en el fichero de entrada.
+pub fn cien_theta(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    Ok(100.*f64::consts::PI/acoustic_scale(parametros)?)
+}
+
+
+/// Priors de distancia comprimidos publicados
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompresionCmb{
+    /// Planck 2018 TT,TE,EE+lowE para ΛCDM (Chen, Huang & Wang 2019, arXiv:1808.05724)
+    #[default]
+    Planck2018,
+}
+
+impl CompresionCmb{
+    /// Valores medios de (R, l_A, ω_b), sus errores y la matriz de correlación
+    fn datos(self) -> ([f64; 3], [f64; 3], [f64; 9]){
+        match self{
+            CompresionCmb::Planck2018 => ([1.7502, 301.471, 0.02236], [0.0046, 0.09, 0.00015],
+                                          [1., 0.46, -0.66,
+                                           0.46, 1., -0.33,
+                                           -0.66, -0.33, 1.]),
+        }
+    }
+}
+
+/// Tabla [cmb] del fichero de entrada
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionCmb{
+    pub compresion: CompresionCmb,
+}
+
+/// Verosimilitud gaussiana de los priors de distancia del CMB (R, l_A, ω_b). Resume la información del fondo
+/// que da el CMB sin necesidad de calcular el espectro de potencias, así que se puede combinar con los demás datos.
+#[derive(Clone, Debug)]
+pub struct PriorsDistanciaCmb{
+    pub compresion: CompresionCmb,
+    pub media: [f64; 3],
+    pub sigma: [f64; 3],
+    inversa_covarianza: Vec<f64>,
+}
+
+impl PriorsDistanciaCmb{
+    pub fn new(compresion: CompresionCmb) -> Self{
+        let (media, sigma, correlacion) = compresion.datos();
+        let covarianza: Vec<f64> = (0..9).map(|k| correlacion[k]*sigma[k/3]*sigma[k%3]).collect();
+        let inversa_covarianza = invertir(&covarianza, 3).expect("la covarianza publicada es definida positiva");
+        PriorsDistanciaCmb{compresion, media, sigma, inversa_covarianza}
+    }
+
+    /// (R, l_A, ω_b) del modelo
+    pub fn predicciones(&self, parametros: ParametrosCosmologicos) -> Result<[f64; 3], ErrorCosmologico>{
+        let omega_b = parametros.densidad_bariones*parametros.h().powi(2);
+        Ok([shift_parameter(parametros)?, acoustic_scale(parametros)?, omega_b])
+    }
+}
+
+impl Verosimilitud for PriorsDistanciaCmb{
+    fn nombre(&self) -> &str{
+        "CMB (priors de distancia)"
+    }
+
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+        let Ok(predicciones) = self.predicciones(parametros) else {return f64::INFINITY};
+        let diferencias: Vec<f64> = self.media.iter().zip(predicciones).map(|(m, p)| m - p).collect();
+        if diferencias.iter().any(|d| !d.is_finite()){
+            return f64::INFINITY;
+        }
+        (0..3).map(|i| diferencias[i]*(0..3).map(|j| self.inversa_covarianza[i*3+j]*diferencias[j]).sum::<f64>()).sum()
+    }
+
+    fn numero_datos(&self) -> usize{
+        3
+    }
+}
@@ -1,6 +1,18 @@
 pub const C: f64 = 299792458.;// m/s
-pub const M_TO_MPC: f64 = 1./3.0857e22;
+pub const MPC_EN_M: f64 = 3.085677581491367e22; // 1 Mpc en metros (UAI 2015)
+pub const ANYO_EN_S: f64 = 3600.*24.*365.25; // año juliano
+pub const M_TO_MPC: f64 = 1./MPC_EN_M;
 pub const C_MPC: f64 = C*M_TO_MPC;
 pub const C_GY: f64 = C/S_TO_GY;
 pub const C_MPC_GY: f64 = C_MPC/S_TO_GY;
-pub const S_TO_GY: f64 = 1./(3600.*24.*365.25*1e9);
\ No newline at end of file
+pub const S_TO_GY: f64 = 1./(ANYO_EN_S*1e9);
+pub const KM_S_MPC_TO_GY: f64 = 1e3*M_TO_MPC/S_TO_GY; // km/s/Mpc a 1/GY
+pub const G: f64 = 6.67430e-11; // m³/kg/s²
+pub const MASA_SOLAR_KG: f64 = 1.98841e30;
+
+pub const OMEGA_FOTONES_H2: f64 = 2.4730e-5; // Ω_γ h² con T_CMB = 2.7255 K
+pub const N_EFF: f64 = 3.046; // número efectivo de especies de neutrinos
+pub const OMEGA_RADIACION_H2: f64 = OMEGA_FOTONES_H2*(1. + 0.22710731766*N_EFF); // fotones y neutrinos sin masa, 7/8 (4/11)^(4/3) = 0.2271
+
+pub const SUBPASOS_RUNGE_KUTTA: u64 = 10; // pasos de Runge-Kutta entre cada par de puntos de a(t) y f(z)
+pub const PUNTOS_CURVAS: i64 = 1000; // puntos de cada curva de la ejecución normal
@@ -0,0 +1,48 @@
+use core::f64;
+
+
+/// Segmento de una curva de nivel
+pub type Segmento = ((f64, f64), (f64, f64));
+
+/// Curva de nivel `nivel` de una función muestreada en una rejilla con marching squares.
+/// `valores` está ordenado por filas: el índice i*x.len() + j corresponde a (x[j], y[i]).
+/// Las celdas con algún valor NaN se saltan; los infinitos cuentan como valores por encima del nivel.
+pub fn contorno(x: &[f64], y: &[f64], valores: &[f64], nivel: f64) -> Vec<Segmento>{
+    let nx = x.len();
+    let mut segmentos = Vec::new();
+    for i in 0..y.len().saturating_sub(1){
+        for j in 0..nx.saturating_sub(1){
+            // Esquinas en sentido antihorario empezando por (x[j], y[i])
+            let esquinas = [(j, i), (j+1, i), (j+1, i+1), (j, i+1)];
+            let v = esquinas.map(|(a, b)| valores[b*nx + a]);
+            if v.iter().any(|v| v.is_nan()){
+                continue;
+            }
+            // Punto del lado entre las esquinas k y k+1 donde la interpolación lineal vale `nivel`
+            let corte = |k: usize| -> (f64, f64){
+                let (a, b) = (esquinas[k], esquinas[(k+1)%4]);
+                let (va, vb) = (v[k], v[(k+1)%4]);
+                let t = if va.is_infinite() {1.} else if vb.is_infinite() {0.} else {(nivel - va)/(vb - va)};
+                (x[a.0] + t*(x[b.0] - x[a.0]), y[a.1] + t*(y[b.1] - y[a.1]))
+            };
+            let lados: Vec<usize> = (0..4).filter(|&k| (v[k] > nivel) != (v[(k+1)%4] > nivel)).collect();
+            match lados[..]{
+                [a, b] => segmentos.push((corte(a), corte(b))),
+                [a, b, c, d] => {
+                    // Punto de silla: se decide con el valor medio de la celda
+                    let centro_encima = v.iter().sum::<f64>()/4. > nivel;
+                    if centro_encima == (v[0] > nivel){
+                        segmentos.push((corte(a), corte(b)));
+                        segmentos.push((corte(c), corte(d)));
+                    }
+                    else{
+                        segmentos.push((corte(d), corte(a)));
+                        segmentos.push((corte(b), corte(c)));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    segmentos
+}
@@ -0,0 +1,46 @@
+use crate::input::ParametrosCosmologicos;
+use crate::cmb::cien_theta;
+use crate::units::{Densidad, Tasa, Unidades};
+use crate::locale::Texto;
+use crate::texto;
+
+
+/// Una componente del universo hoy, en las tres formas habituales de dar su densidad
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Componente{
+    pub nombre: &'static str,
+    /// Parámetro de densidad Ω = ρ/ρ_c
+    pub densidad: f64,
+    /// Densidad física ω = Ω h²
+    pub densidad_fisica: f64,
+    /// Densidad de masa (o de energía entre c²) Ω ρ_c. La de la curvatura es la equivalente, no una densidad real.
+    pub masa: Densidad,
+}
+
+/// Todas las densidades derivadas del modelo, sea cual sea la parametrización del fichero de entrada:
+/// materia, bariones, materia oscura fría, radiación, energía oscura, curvatura y el total sin curvatura
+pub fn componentes(parametros: &ParametrosCosmologicos) -> Vec<Componente>{
+    let h2 = parametros.h().powi(2);
+    let critica = Densidad::critica(Tasa::por_giga_anyo(parametros.constante_hubble));
+    let p = parametros;
+    [(Texto::Materia, p.densidad_materia), (Texto::Bariones, p.densidad_bariones), (Texto::MateriaOscuraFria, p.densidad_materia - p.densidad_bariones),
+     (Texto::Radiacion, p.densidad_radiacion), (Texto::EnergiaOscura, p.densidad_energia_oscura), (Texto::Curvatura, p.densidad_curvatura),
+     (Texto::Total, p.densidad_materia + p.densidad_radiacion + p.densidad_energia_oscura)]
+        .into_iter().map(|(nombre, densidad)| Componente{nombre: nombre.texto(), densidad, densidad_fisica: densidad*h2, masa: critica*densidad}).collect()
+}
+
+/// Resumen en texto de `componentes`, con h, la densidad crítica y 100θ_*, para la salida por pantalla
+pub fn resumen_densidades(parametros: &ParametrosCosmologicos, unidades: &Unidades) -> String{
+    let critica = Densidad::critica(Tasa::por_giga_anyo(parametros.constante_hubble));
+    let mut texto = texto!(DensidadCritica, parametros.h(), format!("{:e}", unidades.densidad(critica)), unidades.densidad) + "\n";
+    texto += &format!("{:<20} {:>13} {:>13} {:>13}\n", Texto::Componente, "Ω", "ω = Ω h²", format!("ρ [{}]", unidades.densidad));
+    for componente in componentes(parametros){
+        texto += &format!("{:<20} {:>13.6e} {:>13.6e} {:>13.6e}\n", componente.nombre, componente.densidad, componente.densidad_fisica,
+                          unidades.densidad(componente.masa));
+    }
+    match cien_theta(*parametros){
+        Ok(theta) => texto += &format!("100θ_* = {}", theta),
+        Err(err) => texto += &texto!(ThetaNoCalculable, err),
+    }
+    texto
+}
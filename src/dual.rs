@@ -0,0 +1,281 @@
+use core::f64;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::Parametro;
+use crate::consts::KM_S_MPC_TO_GY;
+
+
+/// Número real sobre el que se pueden calcular las magnitudes cosmológicas: `f64` o un número dual.
+/// Las comparaciones (signos, tolerancias, ramas) se hacen siempre con la parte real, así que un cálculo
+/// con números duales sigue exactamente el mismo camino que con `f64`.
+pub trait Flotante: Copy + Default + fmt::Debug + PartialEq + From<f64> + Sum
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + Add<f64, Output = Self> + Sub<f64, Output = Self> + Mul<f64, Output = Self> + Div<f64, Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign{
+    /// Parte real
+    fn real(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: f64) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn sin(self) -> Self;
+    fn sinh(self) -> Self;
+    fn abs(self) -> Self;
+    /// base^self con la base real
+    fn potencia_de(self, base: f64) -> Self;
+
+    fn is_nan(self) -> bool{
+        self.real().is_nan()
+    }
+
+    fn is_finite(self) -> bool{
+        self.real().is_finite()
+    }
+}
+
+impl Flotante for f64{
+    fn real(self) -> f64 {self}
+    fn sqrt(self) -> Self {f64::sqrt(self)}
+    fn cbrt(self) -> Self {f64::cbrt(self)}
+    fn powi(self, n: i32) -> Self {f64::powi(self, n)}
+    fn powf(self, n: f64) -> Self {f64::powf(self, n)}
+    fn exp(self) -> Self {f64::exp(self)}
+    fn ln(self) -> Self {f64::ln(self)}
+    fn log10(self) -> Self {f64::log10(self)}
+    fn sin(self) -> Self {f64::sin(self)}
+    fn sinh(self) -> Self {f64::sinh(self)}
+    fn abs(self) -> Self {f64::abs(self)}
+    fn potencia_de(self, base: f64) -> Self {base.powf(self)}
+}
+
+
+/// Número dual a + b ε con ε² = 0, para derivación automática en modo directo: si se evalúa f en x + ε
+/// se obtiene f(x) + f'(x) ε. Con `sembrar` la parte ε se pone en uno de los parámetros cosmológicos y
+/// al final `derivada` es la derivada del resultado respecto a ese parámetro, exacta salvo el error de
+/// la cuadratura (la regla de integración se deriva junto con el integrando).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual{
+    pub valor: f64,
+    pub derivada: f64,
+}
+
+impl Dual{
+    pub fn new(valor: f64, derivada: f64) -> Self{
+        Dual{valor, derivada}
+    }
+
+    /// Aplica una función con derivada `df` en el valor
+    fn cadena(self, valor: f64, df: f64) -> Self{
+        Dual{valor, derivada: df*self.derivada}
+    }
+}
+
+impl From<f64> for Dual{
+    fn from(valor: f64) -> Self{
+        Dual{valor, derivada: 0.}
+    }
+}
+
+impl Flotante for Dual{
+    fn real(self) -> f64 {self.valor}
+    fn sqrt(self) -> Self {let r = self.valor.sqrt(); self.cadena(r, 0.5/r)}
+    fn cbrt(self) -> Self {let r = self.valor.cbrt(); self.cadena(r, 1./(3.*r*r))}
+    fn powi(self, n: i32) -> Self {self.cadena(self.valor.powi(n), n as f64*self.valor.powi(n-1))}
+    fn powf(self, n: f64) -> Self {self.cadena(self.valor.powf(n), n*self.valor.powf(n-1.))}
+    fn exp(self) -> Self {let e = self.valor.exp(); self.cadena(e, e)}
+    fn ln(self) -> Self {self.cadena(self.valor.ln(), 1./self.valor)}
+    fn log10(self) -> Self {self.cadena(self.valor.log10(), 1./(self.valor*f64::consts::LN_10))}
+    fn sin(self) -> Self {self.cadena(self.valor.sin(), self.valor.cos())}
+    fn sinh(self) -> Self {self.cadena(self.valor.sinh(), self.valor.cosh())}
+    fn abs(self) -> Self {if self.valor < 0. {-self} else {self}}
+    fn potencia_de(self, base: f64) -> Self {let p = base.powf(self.valor); self.cadena(p, p*base.ln())}
+}
+
+impl Add for Dual{
+    type Output = Self;
+    fn add(self, otro: Self) -> Self {Dual{valor: self.valor + otro.valor, derivada: self.derivada + otro.derivada}}
+}
+
+impl Sub for Dual{
+    type Output = Self;
+    fn sub(self, otro: Self) -> Self {Dual{valor: self.valor - otro.valor, derivada: self.derivada - otro.derivada}}
+}
+
+impl Mul for Dual{
+    type Output = Self;
+    fn mul(self, otro: Self) -> Self {Dual{valor: self.valor*otro.valor, derivada: self.derivada*otro.valor + self.valor*otro.derivada}}
+}
+
+impl Div for Dual{
+    type Output = Self;
+    fn div(self, otro: Self) -> Self{
+        Dual{valor: self.valor/otro.valor, derivada: (self.derivada*otro.valor - self.valor*otro.derivada)/(otro.valor*otro.valor)}
+    }
+}
+
+impl Neg for Dual{
+    type Output = Self;
+    fn neg(self) -> Self {Dual{valor: -self.valor, derivada: -self.derivada}}
+}
+
+impl Add<f64> for Dual{
+    type Output = Self;
+    fn add(self, otro: f64) -> Self {Dual{valor: self.valor + otro, derivada: self.derivada}}
+}
+
+impl Sub<f64> for Dual{
+    type Output = Self;
+    fn sub(self, otro: f64) -> Self {Dual{valor: self.valor - otro, derivada: self.derivada}}
+}
+
+impl Mul<f64> for Dual{
+    type Output = Self;
+    fn mul(self, otro: f64) -> Self {Dual{valor: self.valor*otro, derivada: self.derivada*otro}}
+}
+
+impl Div<f64> for Dual{
+    type Output = Self;
+    fn div(self, otro: f64) -> Self {Dual{valor: self.valor/otro, derivada: self.derivada/otro}}
+}
+
+impl AddAssign for Dual{
+    fn add_assign(&mut self, otro: Self) {*self = *self + otro}
+}
+
+impl SubAssign for Dual{
+    fn sub_assign(&mut self, otro: Self) {*self = *self - otro}
+}
+
+impl MulAssign for Dual{
+    fn mul_assign(&mut self, otro: Self) {*self = *self*otro}
+}
+
+impl DivAssign for Dual{
+    fn div_assign(&mut self, otro: Self) {*self = *self/otro}
+}
+
+impl Sum for Dual{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self{
+        iter.fold(Dual::default(), |a, b| a + b)
+    }
+}
+
+
+/// Los parámetros con números duales, con la parte ε en `parametro` (y en las magnitudes derivadas de él,
+/// como la curvatura) y cero en los demás
+pub fn sembrar(parametros: ParametrosCosmologicos, parametro: Parametro) -> ParametrosCosmologicos<Dual>{
+    let mut dual = parametros.convertir(Dual::from);
+    // La constante de Hubble está en 1/GY pero la derivada es respecto al valor en km/s/Mpc, como en el fichero de entrada
+    parametro.campo(&mut dual).derivada = if parametro == Parametro::ConstanteHubble {KM_S_MPC_TO_GY} else {1.};
+    dual.recalcular_derivados();
+    dual
+}
+
+/// Valor de f y su derivada respecto a `parametro`
+pub fn derivada<E>(parametros: ParametrosCosmologicos, parametro: Parametro,
+                   f: impl Fn(ParametrosCosmologicos<Dual>) -> Result<Dual, E>) -> Result<(f64, f64), E>{
+    f(sembrar(parametros, parametro)).map(|d| (d.valor, d.derivada))
+}
+
+/// Valor de f y su gradiente respecto a los parámetros `libres`, con una evaluación por parámetro
+pub fn gradiente<E>(parametros: ParametrosCosmologicos, libres: &[Parametro],
+                    f: impl Fn(ParametrosCosmologicos<Dual>) -> Result<Dual, E>) -> Result<(f64, Vec<f64>), E>{
+    let mut valor = None;
+    let mut gradiente = Vec::with_capacity(libres.len());
+    for &parametro in libres{
+        let (v, d) = derivada(parametros, parametro, &f)?;
+        valor = Some(v);
+        gradiente.push(d);
+    }
+    let valor = match valor{
+        Some(valor) => valor,
+        None => f(parametros.convertir(Dual::from))?.valor,
+    };
+    Ok((valor, gradiente))
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::consts::C_MPC_GY;
+    use crate::fitting::con_valores;
+    use crate::functions::luminosity_distance_mpc;
+    use crate::presets::Preset;
+
+    /// Derivada centrada con paso relativo `h`
+    fn diferencia_finita(x: f64, h: f64, f: impl Fn(f64) -> f64) -> f64{
+        let paso = h*x.abs().max(1.);
+        (f(x + paso) - f(x - paso))/(2.*paso)
+    }
+
+    #[test]
+    fn funciones_elementales(){
+        type Funcion = fn(Dual) -> Dual;
+        let funciones: [(&str, Funcion); 11] = [
+            ("sqrt", |x| x.sqrt()), ("cbrt", |x| x.cbrt()), ("powi", |x| x.powi(3)), ("powf", |x| x.powf(-1.7)),
+            ("exp", |x| x.exp()), ("ln", |x| x.ln()), ("log10", |x| x.log10()), ("sin", |x| x.sin()), ("sinh", |x| x.sinh()),
+            ("potencia_de", |x| x.potencia_de(2.5)), ("cociente", |x| (x*x + 1.)/(x - 3.)*x.abs()),
+        ];
+        for (nombre, f) in funciones{
+            for x in [0.3, 1.2, 2.7]{
+                let dual = f(Dual::new(x, 1.));
+                let numerica = diferencia_finita(x, 1e-6, |x| f(Dual::from(x)).valor);
+                assert!((dual.derivada - numerica).abs() < 1e-7*numerica.abs().max(1.), "{nombre}({x}): {} != {numerica}", dual.derivada);
+            }
+        }
+    }
+
+    #[test]
+    fn distancia_luminosidad_frente_a_diferencias_finitas(){
+        let libres = [Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura, Parametro::ConstanteHubble, Parametro::W, Parametro::Wa];
+        let planck = Preset::Planck18.parametros();
+        // Plano (la serie de sinn), abierto y cerrado
+        for (densidad_materia, densidad_energia_oscura) in [(0.3153, 0.6847 - planck.densidad_radiacion), (0.3, 0.6), (0.3, 0.8)]{
+            let centro = [densidad_materia, densidad_energia_oscura, 67.36, -0.9, 0.1];
+            let parametros = con_valores(&libres, &centro, planck);
+            for z in [0.5, 2., 10.]{
+                let (valor, gradiente) = gradiente(parametros, &libres, |p| luminosity_distance_mpc(z, p)).unwrap();
+                assert!((valor - luminosity_distance_mpc(z, parametros).unwrap()).abs() < 1e-12*valor);
+                for (i, (&parametro, derivada)) in libres.iter().zip(gradiente).enumerate(){
+                    let numerica = diferencia_finita(centro[i], 1e-4, |x| {
+                        let mut xx = centro;
+                        xx[i] = x;
+                        luminosity_distance_mpc(z, con_valores(&libres, &xx, planck)).unwrap()
+                    });
+                    assert!((derivada - numerica).abs() < 1e-6*numerica.abs(),
+                            "{:?} z={z} Ωm={densidad_materia} ΩΛ={densidad_energia_oscura}: {derivada} != {numerica}", parametro);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distancia_luminosidad_analitica(){
+        for z in [0.1, 1., 5., 50.]{
+            // Einstein-de Sitter: D_L = 2c/H0 (1+z)(1 - 1/√(1+z))
+            let eds = Preset::EinsteinDeSitter.parametros();
+            let c_h0 = C_MPC_GY/eds.constante_hubble;
+            let exacta = 2.*c_h0*(1. + z)*(1. - 1./(1. + z).sqrt());
+            let (valor, derivada_h0) = derivada(eds, Parametro::ConstanteHubble, |p| luminosity_distance_mpc(z, p)).unwrap();
+            assert!((valor - exacta).abs() < 1e-6*exacta, "EdS z={z}: {valor} != {exacta}");
+            // D_L ∝ 1/H0
+            let h0 = Parametro::ConstanteHubble.valor(&eds);
+            assert!((derivada_h0 + exacta/h0).abs() < 1e-6*exacta/h0, "EdS z={z}: ∂D_L/∂H0 = {derivada_h0}");
+
+            // Milne: D_L = c/H0 z (1 + z/2)
+            let milne = Preset::Milne.parametros();
+            let c_h0 = C_MPC_GY/milne.constante_hubble;
+            let exacta = c_h0*z*(1. + z/2.);
+            let (valor, derivada_h0) = derivada(milne, Parametro::ConstanteHubble, |p| luminosity_distance_mpc(z, p)).unwrap();
+            assert!((valor - exacta).abs() < 1e-6*exacta, "Milne z={z}: {valor} != {exacta}");
+            let h0 = Parametro::ConstanteHubble.valor(&milne);
+            assert!((derivada_h0 + exacta/h0).abs() < 1e-6*exacta/h0, "Milne z={z}: ∂D_L/∂H0 = {derivada_h0}");
+        }
+    }
+}
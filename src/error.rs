@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::integral_methods::QuadratureError;
+use crate::locale::Texto;
+use crate::texto;
+
+
+/// Magnitud que se estaba calculando cuando se produjo el error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Magnitud{
+    DistanciaLuminosidad,
+    DistanciaAngular,
+    DistanciaComovil,
+    DistanciaHubble,
+    DistanciaVolumen,
+    ParametroHubble,
+    Edad,
+    HorizonteParticulas,
+    HorizonteSonido,
+    TiempoRetorno,
+    TiempoConforme,
+    RedshiftDesacoplo,
+}
+
+impl fmt::Display for Magnitud{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nombre = match self{
+            Magnitud::DistanciaLuminosidad => Texto::MagnitudDistanciaLuminosidad,
+            Magnitud::DistanciaAngular => Texto::MagnitudDistanciaAngular,
+            Magnitud::DistanciaComovil => Texto::MagnitudDistanciaComovil,
+            Magnitud::DistanciaHubble => Texto::MagnitudDistanciaHubble,
+            Magnitud::DistanciaVolumen => Texto::MagnitudDistanciaVolumen,
+            Magnitud::ParametroHubble => Texto::MagnitudParametroHubble,
+            Magnitud::Edad => Texto::MagnitudEdad,
+            Magnitud::HorizonteParticulas => Texto::MagnitudHorizonteParticulas,
+            Magnitud::HorizonteSonido => Texto::MagnitudHorizonteSonido,
+            Magnitud::TiempoRetorno => Texto::MagnitudTiempoRetorno,
+            Magnitud::TiempoConforme => Texto::MagnitudTiempoConforme,
+            Magnitud::RedshiftDesacoplo => Texto::MagnitudRedshiftDesacoplo,
+        };
+        write!(f, "{}", nombre.texto())
+    }
+}
+
+/// Motivo por el que no se ha podido calcular una magnitud
+#[derive(Debug, Clone, Copy)]
+pub enum CausaError{
+    /// E²(z) < 0 a partir de z_limite, dentro del rango de integración: la luz de esos redshifts nunca llega hasta
+    /// nosotros (universos con rebote, sin Big Bang).
+    RedshiftInalcanzable{z_limite: f64},
+    /// La cuadratura no ha alcanzado la tolerancia pedida
+    Integracion(QuadratureError),
+    /// El universo se expande para siempre y no tiene tamaño máximo
+    SinMaximo,
+    /// La integral hasta el Big Bang diverge: E(z) crece como (1+z)^exponente en el pasado y hace falta más que `minimo`
+    /// (p.ej. de Sitter no tiene Big Bang y su edad y su horizonte son infinitos)
+    Divergente{exponente: f64, minimo: f64},
+    /// El ajuste de z_* necesita ω_b > 0 y ω_m > 0. No depende de z, así que el error se muestra sin él.
+    SinDesacoplo,
+    /// Una tabla interpolada (`TablaCosmologica`) se ha quedado con menos de los cuatro puntos que necesita el spline
+    TablaInsuficiente{puntos: usize},
+}
+
+/// Error al calcular una magnitud cosmológica: qué se calculaba, en qué z, con qué tolerancia y por qué ha fallado.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorCosmologico{
+    pub magnitud: Magnitud,
+    pub z: f64,
+    pub tolerancia: f64,
+    pub causa: CausaError,
+}
+
+impl ErrorCosmologico{
+    /// El mismo error pero atribuido a otra magnitud (p.ej. la distancia angular se calcula con la de luminosidad)
+    pub fn con_magnitud(self, magnitud: Magnitud) -> Self{
+        ErrorCosmologico{magnitud, ..self}
+    }
+}
+
+impl fmt::Display for ErrorCosmologico{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let CausaError::SinDesacoplo = self.causa{
+            return write!(f, "{}", texto!(SinDesacoplo, self.magnitud));
+        }
+        write!(f, "{}", texto!(NoSePudoCalcular, self.magnitud, self.z, format!("{:e}", self.tolerancia)))?;
+        match self.causa{
+            CausaError::RedshiftInalcanzable{z_limite} => write!(f, "{}", texto!(RedshiftInalcanzable, z_limite)),
+            CausaError::Integracion(err) => write!(f, "{}", err),
+            CausaError::SinMaximo => write!(f, "{}", Texto::SinMaximo),
+            CausaError::Divergente{exponente, minimo} => write!(f, "{}", texto!(IntegralDivergente, exponente, minimo)),
+            CausaError::SinDesacoplo => Ok(()),
+            CausaError::TablaInsuficiente{puntos} => write!(f, "{}", texto!(TablaInsuficiente, puntos)),
+        }
+    }
+}
+
+impl std::error::Error for ErrorCosmologico{}
@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::{Serialize, Deserialize};
+
+use crate::pgfplots::{ConfiguracionLatex, DatosLatex, FiguraPgf};
+use crate::texto;
+
+
+/// Gráficas de la ejecución normal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TipoGrafica{
+    /// Distancias de luminosidad y angular
+    Distancias,
+    FactorEscala,
+    /// H a lo largo de la evolución de a(t)
+    ConstanteHubble,
+    /// H(z), con los cronómetros cósmicos si los hay
+    HubbleZ,
+    RadioHubble,
+    HorizonteParticulas,
+    /// Edad del universo en cada z
+    EdadUniverso,
+}
+
+impl TipoGrafica{
+    pub const TODAS: [TipoGrafica; 7] = [TipoGrafica::Distancias, TipoGrafica::FactorEscala, TipoGrafica::ConstanteHubble, TipoGrafica::HubbleZ,
+                                         TipoGrafica::RadioHubble, TipoGrafica::HorizonteParticulas, TipoGrafica::EdadUniverso];
+
+    /// Nombre en el fichero de entrada, que es también el de los ficheros de la gráfica
+    pub fn nombre(self) -> &'static str{
+        match self{
+            TipoGrafica::Distancias => "distancias",
+            TipoGrafica::FactorEscala => "factor_escala",
+            TipoGrafica::ConstanteHubble => "constante_hubble",
+            TipoGrafica::HubbleZ => "hubble_z",
+            TipoGrafica::RadioHubble => "radio_hubble",
+            TipoGrafica::HorizonteParticulas => "horizonte_particulas",
+            TipoGrafica::EdadUniverso => "edad_universo",
+        }
+    }
+
+    pub fn x_por_defecto(self) -> VariableX{
+        match self{
+            TipoGrafica::Distancias | TipoGrafica::HubbleZ | TipoGrafica::EdadUniverso => VariableX::Redshift,
+            _ => VariableX::Tiempo,
+        }
+    }
+
+    pub fn escala_y_por_defecto(self) -> Escala{
+        match self{
+            TipoGrafica::Distancias | TipoGrafica::ConstanteHubble => Escala::Log,
+            _ => Escala::Lineal,
+        }
+    }
+}
+
+/// Variable del eje x de las gráficas de la ejecución normal. Los tiempos se cuentan desde hoy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VariableX{
+    #[serde(rename = "t")]
+    Tiempo,
+    #[serde(rename = "z")]
+    Redshift,
+    #[serde(rename = "a")]
+    FactorEscala,
+    /// Tiempo conforme η = ∫ dt/a
+    #[serde(rename = "eta", alias = "tiempo_conforme")]
+    TiempoConforme,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Escala{
+    #[default]
+    Lineal,
+    Log,
+}
+
+impl Escala{
+    /// Nombre en matplotlib
+    pub fn matplotlib(self) -> &'static str{
+        match self{
+            Escala::Lineal => "linear",
+            Escala::Log => "log",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Formato{
+    #[default]
+    Svg,
+    Png,
+    Pdf,
+    /// Documento de LaTeX standalone con la figura en PGFPlots
+    Tex,
+    /// Sólo el tikzpicture de PGFPlots, para incluirlo con \input
+    Pgf,
+}
+
+impl Formato{
+    pub fn extension(self) -> &'static str{
+        match self{
+            Formato::Svg => "svg",
+            Formato::Png => "png",
+            Formato::Pdf => "pdf",
+            Formato::Tex => "tex",
+            Formato::Pgf => "pgf",
+        }
+    }
+
+    /// Si lo escribe `FiguraPgf` en lugar de matplotlib
+    pub fn es_latex(self) -> bool{
+        matches!(self, Formato::Tex | Formato::Pgf)
+    }
+}
+
+/// Opciones de una gráfica de la ejecución normal, tabla [graficas.<nombre>]. Lo que no se da es lo de [graficas]
+/// (estilo y grosor) o lo propio de cada gráfica (variable x y escalas).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpcionesGrafica{
+    pub x: Option<VariableX>,
+    pub escala_x: Option<Escala>,
+    pub escala_y: Option<Escala>,
+    pub rango_x: Option<(f64, f64)>,
+    pub rango_y: Option<(f64, f64)>,
+    /// Estilo de línea de matplotlib, p.ej. "-", "--", ":" o "-."
+    pub estilo: Option<String>,
+    pub grosor: Option<f64>,
+}
+
+/// Qué gráficas se dibujan y cómo, tabla [graficas] del fichero de entrada. El directorio, los formatos, la resolución
+/// y el tamaño valen para las gráficas de todas las órdenes; el resto, para las de la ejecución normal.
+/// ```toml
+/// [graficas]
+/// dibujar = ["distancias", "hubble_z"]
+/// formatos = ["svg", "png"]
+///
+/// [graficas.distancias]
+/// x = "a"
+/// escala_x = "log"
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionGraficas{
+    pub dibujar: Vec<TipoGrafica>,
+    pub directorio: PathBuf,
+    pub formatos: Vec<Formato>,
+    /// Sólo afecta a los formatos de mapa de bits (PNG)
+    pub dpi: u32,
+    /// Ancho y alto en pulgadas, salvo en las gráficas de varios paneles que tienen su propio tamaño
+    pub tamanyo: (f64, f64),
+    pub estilo: String,
+    pub grosor: f64,
+    pub distancias: OpcionesGrafica,
+    pub factor_escala: OpcionesGrafica,
+    pub constante_hubble: OpcionesGrafica,
+    pub hubble_z: OpcionesGrafica,
+    pub radio_hubble: OpcionesGrafica,
+    pub horizonte_particulas: OpcionesGrafica,
+    pub edad_universo: OpcionesGrafica,
+    pub latex: ConfiguracionLatex,
+}
+
+impl Default for ConfiguracionGraficas{
+    fn default() -> Self{
+        ConfiguracionGraficas{dibujar: TipoGrafica::TODAS.to_vec(), directorio: PathBuf::from("plots"), formatos: vec![Formato::Svg], dpi: 100,
+                              tamanyo: (6.4, 4.8), estilo: "-".to_string(), grosor: 1.5, distancias: OpcionesGrafica::default(),
+                              factor_escala: OpcionesGrafica::default(), constante_hubble: OpcionesGrafica::default(),
+                              hubble_z: OpcionesGrafica::default(), radio_hubble: OpcionesGrafica::default(),
+                              horizonte_particulas: OpcionesGrafica::default(), edad_universo: OpcionesGrafica::default(),
+                              latex: ConfiguracionLatex::default()}
+    }
+}
+
+impl ConfiguracionGraficas{
+    pub fn opciones(&self, tipo: TipoGrafica) -> &OpcionesGrafica{
+        match tipo{
+            TipoGrafica::Distancias => &self.distancias,
+            TipoGrafica::FactorEscala => &self.factor_escala,
+            TipoGrafica::ConstanteHubble => &self.constante_hubble,
+            TipoGrafica::HubbleZ => &self.hubble_z,
+            TipoGrafica::RadioHubble => &self.radio_hubble,
+            TipoGrafica::HorizonteParticulas => &self.horizonte_particulas,
+            TipoGrafica::EdadUniverso => &self.edad_universo,
+        }
+    }
+
+    /// Fichero `<directorio>/<nombre>.<extension>`, con el nombre pasado por `nombre_seguro`
+    pub fn fichero(&self, nombre: &str, extension: &str) -> PathBuf{
+        self.directorio.join(format!("{}.{}", nombre_seguro(nombre), extension))
+    }
+
+    /// Imágenes de una gráfica que guarda matplotlib, una por formato que no sea de LaTeX
+    pub fn imagenes(&self, nombre: &str) -> Vec<PathBuf>{
+        self.formatos.iter().filter(|formato| !formato.es_latex()).map(|formato| self.fichero(nombre, formato.extension())).collect()
+    }
+
+    /// Si se ha pedido algún formato de LaTeX, para no preparar las figuras de PGFPlots si no
+    pub fn latex(&self) -> bool{
+        self.formatos.iter().any(|formato| formato.es_latex())
+    }
+
+    /// Escribe la figura en cada formato de LaTeX pedido y, si los datos van aparte, sus tablas .dat. Devuelve los ficheros escritos.
+    pub fn escribir_latex(&self, nombre: &str, figura: &FiguraPgf) -> anyhow::Result<Vec<PathBuf>>{
+        fs::create_dir_all(&self.directorio).with_context(|| texto!(NoSePudoCrear, self.directorio.display()))?;
+        let raiz = nombre_seguro(nombre);
+        let dat = (self.latex.datos == DatosLatex::Dat).then_some(raiz.as_str());
+        let mut escritos = Vec::new();
+        for formato in self.formatos.iter().filter(|formato| formato.es_latex()){
+            let (texto, tablas) = match formato{
+                Formato::Tex => figura.documento(&self.latex.preambulo, dat),
+                _ => figura.pgf(dat),
+            };
+            // Las tablas son las mismas en los dos formatos
+            for (fichero, tabla) in tablas{
+                let fichero = self.directorio.join(fichero);
+                if !escritos.contains(&fichero){
+                    fs::write(&fichero, tabla).with_context(|| texto!(NoSePudoEscribir, fichero.display()))?;
+                    escritos.push(fichero);
+                }
+            }
+            let fichero = self.fichero(nombre, formato.extension());
+            fs::write(&fichero, texto).with_context(|| texto!(NoSePudoEscribir, fichero.display()))?;
+            escritos.push(fichero);
+        }
+        Ok(escritos)
+    }
+
+    /// Escribe el script de python de una gráfica: importa numpy y matplotlib, fija el tamaño, la resolución y el grosor
+    /// de línea, ejecuta `cuerpo` y guarda la figura actual en cada formato. Devuelve la ruta del script.
+    ///
+    /// Para que `reproducir` pueda comparar las imágenes bit a bit se quita la fecha de los svg y los pdf y se fija la
+    /// semilla de los identificadores de los svg, que si no son aleatorios.
+    pub fn escribir_script(&self, nombre: &str, cuerpo: &str) -> anyhow::Result<PathBuf>{
+        fs::create_dir_all(&self.directorio).with_context(|| texto!(NoSePudoCrear, self.directorio.display()))?;
+        let guardar: String = self.imagenes(nombre).iter().map(|imagen| {
+            let metadatos = match imagen.extension().and_then(|e| e.to_str()){
+                Some("svg") => ", metadata={\"Date\": None}",
+                Some("pdf") => ", metadata={\"CreationDate\": None}",
+                _ => "",
+            };
+            format!("plt.savefig({:?}{})\n", imagen.display().to_string().replace('\\', "/"), metadatos)
+        }).collect();
+        let script = format!("import numpy as np\nimport matplotlib.pyplot as plt\n\
+                              plt.rcParams.update({{\"figure.figsize\": ({}, {}), \"savefig.dpi\": {}, \"lines.linewidth\": {}, \
+                              \"svg.hashsalt\": \"calculadora-cosmologica\"}})\n{}\n{}",
+                             self.tamanyo.0, self.tamanyo.1, self.dpi, self.grosor, cuerpo.trim_matches('\n'), guardar);
+        let fichero = self.fichero(nombre, "py");
+        fs::write(&fichero, script).with_context(|| texto!(NoSePudoEscribir, fichero.display()))?;
+        Ok(fichero)
+    }
+}
+
+/// Nombre de fichero que se puede usar en la shell sin comillas: sin espacios, acentos ni caracteres especiales
+pub fn nombre_seguro(nombre: &str) -> String{
+    let nombre: String = nombre.chars().map(|c| match c{
+        'á' | 'à' | 'ä' | 'â' => 'a',
+        'é' | 'è' | 'ë' | 'ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        c if c.is_ascii_alphanumeric() || c == '-' || c == '.' => c.to_ascii_lowercase(),
+        _ => '_',
+    }).collect();
+    match nombre.trim_start_matches(['-', '.']){
+        "" => "grafica".to_string(),
+        nombre => nombre.to_string(),
+    }
+}
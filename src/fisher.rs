@@ -0,0 +1,216 @@
+use core::f64;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{luminosity_distance_mpc, distance_modulus, hubble_parameter, con_radiacion_estandar};
+use crate::growth::fsigma8;
+use crate::error::{ErrorCosmologico, Magnitud};
+use crate::fitting::Parametro;
+use crate::linalg::invertir;
+use crate::dual::{derivada, Flotante};
+use crate::texto;
+
+
+/// Δχ² de los contornos de 1σ, 2σ y 3σ (68.3%, 95.4% y 99.7%) con dos parámetros
+pub const DELTA_CHI2_68: f64 = 2.30;
+pub const DELTA_CHI2_95: f64 = 6.18;
+pub const DELTA_CHI2_997: f64 = 11.83;
+
+/// Un bin de redshift de la encuesta. Los errores de D_A, H y fσ8 son relativos (0.01 = 1%) y el de μ
+/// está en magnitudes, como se suele dar para las supernovas. Los que faltan no se miden en ese bin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BinEncuesta{
+    pub z: f64,
+    pub da: Option<f64>,
+    pub h: Option<f64>,
+    pub fsigma8: Option<f64>,
+    pub mu: Option<f64>,
+}
+
+/// Encuesta para la previsión con la matriz de Fisher, en un fichero TOML aparte:
+/// ```toml
+/// parametros = ["densidad_materia", "w", "wa"]
+/// area = 14000               # grados cuadrados
+/// area_referencia = 14000    # área para la que se dan los errores, por defecto `area`
+/// priors = {constante_hubble = 1.0}  # priors gaussianos opcionales, σ de cada parámetro
+///
+/// [[bin]]
+/// z = 0.5
+/// da = 0.01
+/// h = 0.02
+/// fsigma8 = 0.03
+/// ```
+/// Los errores escalan con el volumen, σ ∝ √(area_referencia/area).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Encuesta{
+    pub parametros: Vec<Parametro>,
+    pub area: f64,
+    pub area_referencia: Option<f64>,
+    #[serde(default)]
+    pub priors: BTreeMap<Parametro, f64>,
+    pub bin: Vec<BinEncuesta>,
+}
+
+impl Encuesta{
+    pub fn leer(fichero: &Path) -> anyhow::Result<Self>{
+        let texto = fs::read_to_string(fichero).with_context(|| texto!(NoSePudoLeer, fichero.display()))?;
+        let encuesta: Encuesta = toml::from_str(&texto).with_context(|| texto!(EncuestaNoValida, fichero.display()))?;
+        if encuesta.parametros.is_empty(){
+            bail!(texto!(EncuestaSinParametros, fichero.display()));
+        }
+        if encuesta.area <= 0. || encuesta.area_referencia.is_some_and(|a| a <= 0.){
+            bail!(texto!(EncuestaArea, fichero.display()));
+        }
+        Ok(encuesta)
+    }
+
+    /// Factor por el que se multiplican los errores del fichero
+    pub fn escala_errores(&self) -> f64{
+        (self.area_referencia.unwrap_or(self.area)/self.area).sqrt()
+    }
+}
+
+/// Magnitud que se mide en un bin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservableEncuesta{
+    /// D_A en Mpc
+    DistanciaAngular,
+    /// H en km/s/Mpc
+    Hubble,
+    FSigma8,
+    ModuloDistancia,
+}
+
+impl ObservableEncuesta{
+    pub fn valor<T: Flotante>(self, z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+        let parametros = con_radiacion_estandar(parametros);
+        match self{
+            ObservableEncuesta::DistanciaAngular => luminosity_distance_mpc(z, parametros).map(|d| d*(1.0+z).powi(-2))
+                .map_err(|err| err.con_magnitud(Magnitud::DistanciaAngular)),
+            ObservableEncuesta::Hubble => hubble_parameter(z, parametros),
+            ObservableEncuesta::FSigma8 => Ok(fsigma8(z, parametros)),
+            ObservableEncuesta::ModuloDistancia => distance_modulus(z, parametros),
+        }
+    }
+}
+
+/// Elipse de error de dos parámetros, con los semiejes para Δχ² = 1. Para el contorno del 68% o del 95%
+/// se multiplican por √DELTA_CHI2_68 o √DELTA_CHI2_95. El ángulo (en radianes) es el del semieje mayor con el eje x.
+#[derive(Clone, Copy, Debug)]
+pub struct Elipse{
+    pub semieje_mayor: f64,
+    pub semieje_menor: f64,
+    pub angulo: f64,
+}
+
+/// Matriz de Fisher de una encuesta alrededor de unos parámetros fiduciales
+#[derive(Clone, Debug)]
+pub struct Fisher{
+    pub parametros: Vec<Parametro>,
+    pub fiduciales: Vec<f64>,
+    /// Matriz de Fisher, con la magnitud absoluta de las supernovas ya marginalizada si hay μ
+    pub matriz: Vec<f64>,
+    /// Covarianza marginalizada, la inversa de `matriz`
+    pub covarianza: Vec<f64>,
+}
+
+/// Calcula F_ij = Σ ∂O/∂p_i ∂O/∂p_j / σ_O² derivando cada observable con números duales.
+/// Si hay μ se añade su constante aditiva como parámetro de molestia y se marginaliza. Los priors se suman a la diagonal.
+pub fn fisher(encuesta: &Encuesta, parametros: ParametrosCosmologicos) -> anyhow::Result<Fisher>{
+    let libres = &encuesta.parametros;
+    let n = libres.len();
+    let fiduciales: Vec<f64> = libres.iter().map(|p| p.valor(&parametros)).collect();
+    let escala = encuesta.escala_errores();
+
+    // (z, observable, σ) de cada medida
+    let mut medidas = Vec::new();
+    for bin in &encuesta.bin{
+        for (error, observable) in [(bin.da, ObservableEncuesta::DistanciaAngular), (bin.h, ObservableEncuesta::Hubble),
+                                    (bin.fsigma8, ObservableEncuesta::FSigma8), (bin.mu, ObservableEncuesta::ModuloDistancia)]{
+            let Some(error) = error else {continue};
+            let sigma = if observable == ObservableEncuesta::ModuloDistancia {error}
+                        else {error*observable.valor(bin.z, parametros)?.abs()};
+            medidas.push((bin.z, observable, sigma*escala));
+        }
+    }
+    if medidas.is_empty(){
+        bail!(texto!(EncuestaVacia));
+    }
+    let hay_mu = medidas.iter().any(|m| m.1 == ObservableEncuesta::ModuloDistancia);
+    let m = n + hay_mu as usize;
+
+    // Derivadas de cada medida respecto a cada parámetro (y a la constante de μ al final)
+    let mut derivadas = vec![vec![0.; m]; medidas.len()];
+    for (i, &parametro) in libres.iter().enumerate(){
+        for (k, &(z, observable, _)) in medidas.iter().enumerate(){
+            derivadas[k][i] = derivada(parametros, parametro, |p| observable.valor(z, p))?.1;
+        }
+    }
+    if hay_mu{
+        for (k, medida) in medidas.iter().enumerate(){
+            derivadas[k][n] = (medida.1 == ObservableEncuesta::ModuloDistancia) as u8 as f64;
+        }
+    }
+
+    let mut completa = vec![0.; m*m];
+    for (d, &(_, _, sigma)) in derivadas.iter().zip(&medidas){
+        for i in 0..m{
+            for j in 0..m{
+                completa[i*m+j] += d[i]*d[j]/(sigma*sigma);
+            }
+        }
+    }
+    for (parametro, sigma) in &encuesta.priors{
+        match libres.iter().position(|p| p == parametro){
+            Some(i) => completa[i*m+i] += 1./(sigma*sigma),
+            None => bail!(texto!(PriorSinParametro, parametro.nombre())),
+        }
+    }
+
+    let Some(covarianza_completa) = invertir(&completa, m) else{
+        bail!(texto!(FisherSingular))
+    };
+    let covarianza: Vec<f64> = (0..n*n).map(|k| covarianza_completa[(k/n)*m + k%n]).collect();
+    let matriz = invertir(&covarianza, n).unwrap_or(vec![f64::NAN; n*n]);
+    Ok(Fisher{parametros: libres.clone(), fiduciales, matriz, covarianza})
+}
+
+impl Fisher{
+    fn indice(&self, parametro: Parametro) -> Option<usize>{
+        self.parametros.iter().position(|&p| p == parametro)
+    }
+
+    /// Errores de cada parámetro marginalizando sobre los demás
+    pub fn errores_marginalizados(&self) -> Vec<f64>{
+        let n = self.parametros.len();
+        (0..n).map(|i| self.covarianza[i*n+i].sqrt()).collect()
+    }
+
+    /// Errores de cada parámetro fijando los demás a sus valores fiduciales
+    pub fn errores_condicionados(&self) -> Vec<f64>{
+        let n = self.parametros.len();
+        (0..n).map(|i| 1./self.matriz[i*n+i].sqrt()).collect()
+    }
+
+    /// Elipse de error de los parámetros i y j, marginalizando sobre los demás
+    pub fn elipse(&self, i: usize, j: usize) -> Elipse{
+        let n = self.parametros.len();
+        let (sxx, syy, sxy) = (self.covarianza[i*n+i], self.covarianza[j*n+j], self.covarianza[i*n+j]);
+        let media = (sxx + syy)/2.;
+        let radio = ((sxx - syy).powi(2)/4. + sxy*sxy).sqrt();
+        Elipse{semieje_mayor: (media + radio).sqrt(), semieje_menor: (media - radio).max(0.).sqrt(), angulo: 0.5*(2.*sxy).atan2(sxx - syy)}
+    }
+
+    /// Figura de mérito de la DETF, 1/√det Cov(w0, wa). None si w o wa no son parámetros de la encuesta.
+    pub fn figura_merito(&self) -> Option<f64>{
+        let n = self.parametros.len();
+        let (i, j) = (self.indice(Parametro::W)?, self.indice(Parametro::Wa)?);
+        let determinante = self.covarianza[i*n+i]*self.covarianza[j*n+j] - self.covarianza[i*n+j].powi(2);
+        Some(1./determinante.sqrt())
+    }
+}
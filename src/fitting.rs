@@ -0,0 +1,415 @@
+use core::f64;
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::consts::KM_S_MPC_TO_GY;
+use crate::linalg::invertir;
+use crate::supernovae::CatalogoSupernovas;
+use crate::bao::MedidasBao;
+use crate::chronometers::MedidasHubble;
+use crate::cmb::{ConfiguracionCmb, PriorsDistanciaCmb};
+use crate::priors::Priors;
+use crate::mcmc::ConfiguracionMcmc;
+use crate::chi2_map::ConfiguracionMapa;
+use crate::hmc::ConfiguracionHmc;
+use crate::nested::ConfiguracionAnidado;
+use crate::sweep::ConfiguracionBarrido;
+use crate::phase_diagram::ConfiguracionDiagramaFases;
+use crate::units::Unidades;
+use crate::figures::ConfiguracionGraficas;
+use crate::locale::Idioma;
+use crate::dual::{gradiente, Dual};
+use crate::texto;
+
+
+/// Parámetros cosmológicos que se pueden ajustar o muestrear. Los nombres son los mismos que en el fichero de entrada.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Parametro{
+    DensidadMateria,
+    DensidadRadiacion,
+    DensidadEnergiaOscura,
+    /// En km/s/Mpc, como en el fichero de entrada
+    ConstanteHubble,
+    W,
+    DensidadBariones,
+    Wa,
+    Sigma8,
+}
+
+impl Parametro{
+    pub fn nombre(self) -> &'static str{
+        match self{
+            Parametro::DensidadMateria => "densidad_materia",
+            Parametro::DensidadRadiacion => "densidad_radiacion",
+            Parametro::DensidadEnergiaOscura => "densidad_energia_oscura",
+            Parametro::ConstanteHubble => "constante_hubble",
+            Parametro::W => "w",
+            Parametro::DensidadBariones => "densidad_bariones",
+            Parametro::Wa => "wa",
+            Parametro::Sigma8 => "sigma8",
+        }
+    }
+
+    pub fn valor(self, parametros: &ParametrosCosmologicos) -> f64{
+        match self{
+            Parametro::DensidadMateria => parametros.densidad_materia,
+            Parametro::DensidadRadiacion => parametros.densidad_radiacion,
+            Parametro::DensidadEnergiaOscura => parametros.densidad_energia_oscura,
+            Parametro::ConstanteHubble => parametros.constante_hubble/KM_S_MPC_TO_GY,
+            Parametro::W => parametros.w,
+            Parametro::DensidadBariones => parametros.densidad_bariones,
+            Parametro::Wa => parametros.wa,
+            Parametro::Sigma8 => parametros.sigma8,
+        }
+    }
+
+    /// Campo de los parámetros que corresponde a este. La constante de Hubble está en 1/GY, no en km/s/Mpc.
+    pub fn campo<T>(self, parametros: &mut ParametrosCosmologicos<T>) -> &mut T{
+        match self{
+            Parametro::DensidadMateria => &mut parametros.densidad_materia,
+            Parametro::DensidadRadiacion => &mut parametros.densidad_radiacion,
+            Parametro::DensidadEnergiaOscura => &mut parametros.densidad_energia_oscura,
+            Parametro::ConstanteHubble => &mut parametros.constante_hubble,
+            Parametro::W => &mut parametros.w,
+            Parametro::DensidadBariones => &mut parametros.densidad_bariones,
+            Parametro::Wa => &mut parametros.wa,
+            Parametro::Sigma8 => &mut parametros.sigma8,
+        }
+    }
+
+    /// Los mismos parámetros con este cambiado a `valor`, recalculando la curvatura y las unidades
+    pub fn con_valor(self, parametros: ParametrosCosmologicos, valor: f64) -> ParametrosCosmologicos{
+        con_valores(&[self], &[valor], parametros)
+    }
+}
+
+/// Aplica los valores `xx` a los parámetros libres. Las magnitudes derivadas se recalculan una sola vez al final,
+/// que buscar el redshift máximo es caro.
+pub fn con_valores(libres: &[Parametro], xx: &[f64], mut parametros: ParametrosCosmologicos) -> ParametrosCosmologicos{
+    for (&p, &x) in libres.iter().zip(xx){
+        *p.campo(&mut parametros) = if p == Parametro::ConstanteHubble {x*KM_S_MPC_TO_GY} else {x};
+    }
+    parametros.recalcular_derivados();
+    parametros
+}
+
+
+/// Un conjunto de datos que sabe calcular su χ² = -2 ln L (salvo una constante) para unos parámetros.
+/// Si los parámetros llevan a una región no física (p.ej. un redshift inalcanzable) el χ² es infinito.
+/// Es `Sync` para poder evaluarla desde varios hilos (ver `mapa_chi2`). Todas evalúan el modelo con la radiación
+/// estándar si no tiene (ver `con_radiacion_estandar`), para que los datos a z bajo y los del CMB vean el mismo modelo.
+pub trait Verosimilitud: Sync{
+    fn nombre(&self) -> &str;
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64;
+    /// El χ² con números duales, para derivarlo respecto a los parámetros (ver `gradiente_chi2`).
+    /// None si la verosimilitud no se puede evaluar con duales; entonces se deriva con diferencias finitas.
+    fn chi2_dual(&self, _parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+        None
+    }
+    /// Número de datos menos los parámetros de molestia que se marginalizan dentro de la verosimilitud
+    /// (p.ej. la magnitud absoluta de las supernovas), para contar los grados de libertad
+    fn numero_datos(&self) -> usize;
+}
+
+/// Varios conjuntos de datos independientes: el χ² total es la suma
+impl Verosimilitud for Vec<Box<dyn Verosimilitud>>{
+    fn nombre(&self) -> &str{
+        "combinada"
+    }
+
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+        self.iter().map(|v| v.chi2(parametros)).sum()
+    }
+
+    fn chi2_dual(&self, parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+        self.iter().map(|v| v.chi2_dual(parametros)).sum()
+    }
+
+    fn numero_datos(&self) -> usize{
+        self.iter().map(|v| v.numero_datos()).sum()
+    }
+}
+
+
+/// Ficheros de un catálogo de supernovas
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FuenteSupernovas{
+    pub datos: PathBuf,
+    pub covarianza: Option<PathBuf>,
+}
+
+/// Ficheros de medidas de H(z) con cronómetros cósmicos, ver `MedidasHubble`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FuenteCronometros{
+    pub datos: PathBuf,
+    pub covarianza: Option<PathBuf>,
+}
+
+/// Fichero de medidas de BAO, ver `MedidasBao`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FuenteBao{
+    pub datos: PathBuf,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionAjuste{
+    pub parametros: Vec<Parametro>,
+}
+
+impl Default for ConfiguracionAjuste{
+    fn default() -> Self{
+        ConfiguracionAjuste{parametros: vec![Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura]}
+    }
+}
+
+/// Conjuntos de datos y parámetros libres, se lee del mismo fichero que `InputFile`:
+/// ```toml
+/// [supernovas]
+/// datos = "datos/supernovas_ejemplo.txt"
+///
+/// [bao]
+/// datos = "datos/bao_desi_dr1.txt"
+///
+/// [cronometros]
+/// datos = "datos/cronometros_cosmicos.txt"
+///
+/// [cmb]
+/// compresion = "planck2018"
+///
+/// [ajuste]
+/// parametros = ["densidad_materia", "densidad_energia_oscura", "w"]
+///
+/// [priors]
+/// densidad_materia = {uniforme = [0, 1]}
+///
+/// [mcmc]
+/// pasos = 2000
+///
+/// [hmc]
+/// algoritmo = "nuts"
+///
+/// [anidado]
+/// puntos_vivos = 400
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConfiguracionDatos{
+    pub supernovas: Option<FuenteSupernovas>,
+    pub bao: Option<FuenteBao>,
+    pub cronometros: Option<FuenteCronometros>,
+    /// Priors de distancia del CMB, se usan si está la tabla [cmb] aunque esté vacía
+    pub cmb: Option<ConfiguracionCmb>,
+    #[serde(default)]
+    pub ajuste: ConfiguracionAjuste,
+    /// Priors de los parámetros que se muestrean
+    #[serde(default)]
+    pub priors: Priors,
+    #[serde(default)]
+    pub mcmc: ConfiguracionMcmc,
+    #[serde(default)]
+    pub mapa_chi2: ConfiguracionMapa,
+    #[serde(default)]
+    pub hmc: ConfiguracionHmc,
+    #[serde(default)]
+    pub anidado: ConfiguracionAnidado,
+    #[serde(default)]
+    pub barrido: ConfiguracionBarrido,
+    /// Rango y resolución de la orden `diagrama_fases`
+    #[serde(default)]
+    pub diagrama_fases: ConfiguracionDiagramaFases,
+    /// Unidades de los resultados de la ejecución normal y del barrido
+    #[serde(default)]
+    pub unidades: Unidades,
+    /// Gráficas que se dibujan, su aspecto y dónde se guardan
+    #[serde(default)]
+    pub graficas: ConfiguracionGraficas,
+    /// Idioma de los mensajes, los informes y las gráficas; sin él se usa el del entorno (LANG)
+    pub idioma: Option<Idioma>,
+}
+
+impl ConfiguracionDatos{
+    /// Tablas con datos de observaciones
+    pub const TABLAS_DATOS: [&'static str; 4] = ["supernovas", "bao", "cronometros", "cmb"];
+
+    /// Lee la configuración sin las tablas de datos, para seguir aunque alguna esté mal
+    pub fn sin_datos(texto: &str) -> anyhow::Result<Self>{
+        let mut tabla = texto.parse::<toml::Table>()?;
+        tabla.retain(|clave, _| !Self::TABLAS_DATOS.contains(&clave));
+        Ok(tabla.try_into()?)
+    }
+
+    /// Carga todos los conjuntos de datos configurados
+    pub fn verosimilitudes(&self) -> anyhow::Result<Vec<Box<dyn Verosimilitud>>>{
+        let mut verosimilitudes: Vec<Box<dyn Verosimilitud>> = Vec::new();
+        if let Some(sn) = &self.supernovas{
+            verosimilitudes.push(Box::new(CatalogoSupernovas::leer(&sn.datos, sn.covarianza.as_deref())?));
+        }
+        if let Some(bao) = &self.bao{
+            verosimilitudes.push(Box::new(MedidasBao::leer(&bao.datos)?));
+        }
+        if let Some(cc) = &self.cronometros{
+            verosimilitudes.push(Box::new(MedidasHubble::leer(&cc.datos, cc.covarianza.as_deref())?));
+        }
+        if let Some(cmb) = &self.cmb{
+            verosimilitudes.push(Box::new(PriorsDistanciaCmb::new(cmb.compresion)));
+        }
+        if verosimilitudes.is_empty(){
+            anyhow::bail!(texto!(SinDatos));
+        }
+        Ok(verosimilitudes)
+    }
+}
+
+
+/// Resultado de un ajuste por mínimos cuadrados
+#[derive(Clone, Debug)]
+pub struct Ajuste{
+    pub parametros: ParametrosCosmologicos,
+    pub libres: Vec<Parametro>,
+    pub valores: Vec<f64>,
+    /// Desviaciones típicas a partir de la matriz de covarianza (NaN si el hessiano no es definido positivo)
+    pub errores: Vec<f64>,
+    pub covarianza: Vec<f64>,
+    pub chi2: f64,
+    pub grados_libertad: usize,
+}
+
+/// χ² y su gradiente respecto a los parámetros `libres`, en las unidades de `Parametro::valor`. Se deriva con
+/// números duales si la verosimilitud lo permite y si no con diferencias centradas.
+pub fn gradiente_chi2(verosimilitud: &dyn Verosimilitud, libres: &[Parametro], parametros: ParametrosCosmologicos) -> (f64, Vec<f64>){
+    if let Ok(resultado) = gradiente(parametros, libres, |p| verosimilitud.chi2_dual(p).ok_or(())){
+        return resultado;
+    }
+    let chi2 = verosimilitud.chi2(parametros);
+    let derivadas = libres.iter().map(|&p| {
+        let x = p.valor(&parametros);
+        let paso = 1e-5*x.abs().max(0.1);
+        (verosimilitud.chi2(p.con_valor(parametros, x + paso)) - verosimilitud.chi2(p.con_valor(parametros, x - paso)))/(2.*paso)
+    }).collect();
+    (chi2, derivadas)
+}
+
+/// Minimiza el χ² sobre los parámetros libres empezando en los valores de `parametros`.
+/// Las incertidumbres salen de invertir el hessiano numérico: cov = 2 H⁻¹ con H = ∂²χ².
+pub fn ajustar(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, libres: &[Parametro]) -> Ajuste{
+    let chi2 = |xx: &[f64]| {
+        let valor = verosimilitud.chi2(con_valores(libres, xx, parametros));
+        if valor.is_nan() {f64::INFINITY} else {valor}
+    };
+    let inicial: Vec<f64> = libres.iter().map(|p| p.valor(&parametros)).collect();
+    let (valores, chi2_min) = nelder_mead(&chi2, &inicial, 1e-8, 5000);
+
+    let n = libres.len();
+    let hessiano = hessiano(&chi2, &valores);
+    let covarianza = invertir(&hessiano, n).map(|inv| inv.into_iter().map(|x| 2.*x).collect()).unwrap_or(vec![f64::NAN; n*n]);
+    let errores = (0..n).map(|i| covarianza[i*n+i].sqrt()).collect();
+    Ajuste{parametros: con_valores(libres, &valores, parametros), libres: libres.to_vec(), valores, errores, covarianza,
+           chi2: chi2_min, grados_libertad: verosimilitud.numero_datos().saturating_sub(n)}
+}
+
+/// Método símplex de Nelder–Mead. Devuelve el mínimo y el valor de f en él.
+/// Se para cuando la diferencia entre el mejor y el peor vértice es menor que `tolerancia`.
+pub fn nelder_mead(f: &impl Fn(&[f64]) -> f64, inicial: &[f64], tolerancia: f64, max_iteraciones: usize) -> (Vec<f64>, f64){
+    let n = inicial.len();
+    let mut simplex: Vec<(Vec<f64>, f64)> = vec![(inicial.to_vec(), f(inicial))];
+    for i in 0..n{
+        let mut x = inicial.to_vec();
+        x[i] += 0.1*x[i].abs() + 0.05;
+        let fx = f(&x);
+        simplex.push((x, fx));
+    }
+    let combinar = |a: &[f64], b: &[f64], t: f64| -> Vec<f64> {a.iter().zip(b).map(|(a, b)| a + t*(b - a)).collect()};
+
+    for _ in 0..max_iteraciones{
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if (simplex[n].1 - simplex[0].1).abs() < tolerancia{
+            break;
+        }
+        let centroide: Vec<f64> = (0..n).map(|j| simplex[..n].iter().map(|v| v.0[j]).sum::<f64>()/n as f64).collect();
+        let peor = simplex[n].clone();
+        let reflejado = combinar(&centroide, &peor.0, -1.);
+        let f_reflejado = f(&reflejado);
+        if f_reflejado < simplex[0].1{
+            let expandido = combinar(&centroide, &peor.0, -2.);
+            let f_expandido = f(&expandido);
+            simplex[n] = if f_expandido < f_reflejado {(expandido, f_expandido)} else {(reflejado, f_reflejado)};
+        }
+        else if f_reflejado < simplex[n-1].1{
+            simplex[n] = (reflejado, f_reflejado);
+        }
+        else{
+            let contraido = combinar(&centroide, &peor.0, 0.5);
+            let f_contraido = f(&contraido);
+            if f_contraido < peor.1{
+                simplex[n] = (contraido, f_contraido);
+            }
+            else{
+                // Se encoge todo el símplex hacia el mejor vértice
+                let mejor = simplex[0].0.clone();
+                for v in simplex.iter_mut().skip(1){
+                    v.0 = combinar(&mejor, &v.0, 0.5);
+                    v.1 = f(&v.0);
+                }
+            }
+        }
+    }
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    simplex.swap_remove(0)
+}
+
+/// Hessiano de f en x por diferencias centradas, por filas
+pub fn hessiano(f: &impl Fn(&[f64]) -> f64, x: &[f64]) -> Vec<f64>{
+    let n = x.len();
+    // Paso grande comparado con el ruido de las integrales (tolerancia 1e-7) pero pequeño frente a los errores típicos
+    let pasos: Vec<f64> = x.iter().map(|xi| 1e-2*(xi.abs() + 1.)).collect();
+    let evaluar = |desplazamientos: &[(usize, f64)]| {
+        let mut y = x.to_vec();
+        for &(i, d) in desplazamientos{
+            y[i] += d*pasos[i];
+        }
+        f(&y)
+    };
+    let f0 = f(x);
+    let mut h = vec![0.; n*n];
+    for i in 0..n{
+        h[i*n+i] = (evaluar(&[(i, 1.)]) - 2.*f0 + evaluar(&[(i, -1.)]))/pasos[i].powi(2);
+        for j in 0..i{
+            let valor = (evaluar(&[(i, 1.), (j, 1.)]) - evaluar(&[(i, 1.), (j, -1.)])
+                        - evaluar(&[(i, -1.), (j, 1.)]) + evaluar(&[(i, -1.), (j, -1.)]))/(4.*pasos[i]*pasos[j]);
+            h[i*n+j] = valor;
+            h[j*n+i] = valor;
+        }
+    }
+    h
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::analysis::z_maximo;
+    use crate::error::CausaError;
+    use crate::functions::luminosity_distance;
+    use crate::presets::Preset;
+
+    #[test]
+    fn redshift_maximo_al_cambiar_parametros(){
+        let libres = [Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura];
+        let planck = Preset::Planck18.parametros();
+        assert_eq!(planck.z_maximo, f64::INFINITY);
+        // Con Ω_Λ = 2 el universo rebota: hay un redshift máximo y más allá las distancias son un error
+        let rebote = con_valores(&libres, &[0.3, 2.], planck);
+        assert!(rebote.z_maximo.is_finite());
+        assert_eq!(rebote.z_maximo, z_maximo(rebote));
+        assert!(luminosity_distance(0.9*rebote.z_maximo, rebote).is_ok());
+        let err = luminosity_distance(1.1*rebote.z_maximo, rebote).unwrap_err();
+        assert!(matches!(err.causa, CausaError::RedshiftInalcanzable{..}));
+        // Y al volver a un modelo con Big Bang se recupera
+        let vuelta = Parametro::DensidadEnergiaOscura.con_valor(rebote, 0.7);
+        assert_eq!(vuelta.z_maximo, f64::INFINITY);
+        assert!(luminosity_distance(1.1*rebote.z_maximo, vuelta).is_ok());
+    }
+}
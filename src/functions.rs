@@ -1,9 +1,13 @@
 use core::f64;
 
 use crate::input::ParametrosCosmologicos;
-use crate::integral_methods::adaptive_simpson_method;
+use crate::integral_methods::{integrate, MetodoIntegracion, QuadratureError, QuadratureResult};
+use crate::error::{CausaError, ErrorCosmologico, Magnitud};
 use crate::{runge_kutta_at_points, trapezoid_method};
 use crate::plotting::linspace;
+use crate::analysis::{intervalo_actual, z_maximo};
+use crate::consts::{C_MPC_GY, KM_S_MPC_TO_GY, OMEGA_RADIACION_H2, SUBPASOS_RUNGE_KUTTA};
+use crate::dual::Flotante;
 
 
 pub fn s_k(xi: f64, k: f64) -> f64{
@@ -14,31 +18,214 @@ pub fn s_k(xi: f64, k: f64) -> f64{
         return xi.sinh()
     }
     
-    return xi
+    xi
+}
+
+/// Argumento de la ecuación de Friedmann, E²(x) con x = 1+z = a0/a. Puede ser negativo en
+/// las regiones a las que el universo no llega (ver `analysis::intervalos_permitidos`).
+pub fn e2<T: Flotante>(x: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    parametros.densidad_energia_oscura*escala_energia_oscura(x, parametros) + parametros.densidad_curvatura*x.powi(2)
+   + parametros.densidad_materia*x.powi(3) + parametros.densidad_radiacion*x.powi(4)
+}
+
+/// ρ_DE(x)/ρ_DE hoy con la ecuación de estado w(a) = w + wa (1-a) (Chevallier–Polarski–Linder).
+/// Con wa = 0 escala como x^(3(1+w)) y con w = -1 es constante.
+pub fn escala_energia_oscura<T: Flotante>(x: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    let potencia = ((T::from(1.)+parametros.w+parametros.wa)*3.).potencia_de(x);
+    // Con números duales wa sólo es igual a 0 si tampoco tiene derivada
+    if parametros.wa == T::from(0.) {potencia} else {potencia*(parametros.wa*-3.*(1. - 1./x)).exp()}
+}
+
+/// Derivada logarítmica d ln E²/d ln x, para la ecuación del crecimiento
+pub fn derivada_log_e2<T: Flotante>(x: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    let energia_oscura = parametros.densidad_energia_oscura*escala_energia_oscura(x, parametros)
+        *((T::from(1.)+parametros.w+parametros.wa)*3. - parametros.wa*3./x);
+    (energia_oscura + parametros.densidad_curvatura*2.*x.powi(2) + parametros.densidad_materia*3.*x.powi(3)
+        + parametros.densidad_radiacion*4.*x.powi(4))/e2(x, parametros)
 }
 
 /// Calcula el valor de E(x) donde x puede ser 1+z o a'/a al pasarse como input
 /// si el valor resultaría en un e^2 <0 se devuleve un número negativo
-pub fn e(x: f64, parametros: ParametrosCosmologicos) -> f64{
-    let arg = parametros.densidad_energia_oscura + parametros.densidad_curvatura*x.powi(2)
-   + parametros.densidad_materia*x.powi(3) + parametros.densidad_radiacion*x.powi(4);
-    let ret = if arg <0. {-(-arg).sqrt()} else {arg.sqrt()};
+pub fn e<T: Flotante>(x: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    let arg = e2(x, parametros);
+    let ret = if arg.real() <0. {-(-arg).sqrt()} else {arg.sqrt()};
     // if ret <=0. {println!("ret. {ret}")}
-    return ret
+    ret
+}
+
+/// Si `densidad_radiacion` es 0 añade la de los fotones del CMB y los neutrinos sin masa, restándola de la energía oscura
+/// para no cambiar la curvatura. A z ~ 1000 la radiación ya no es despreciable: sin ella el horizonte de sonido y la
+/// distancia a la última dispersión salen varios por ciento mal. Lo aplican todas las verosimilitudes y la previsión de Fisher.
+pub fn con_radiacion_estandar<T: Flotante>(mut parametros: ParametrosCosmologicos<T>) -> ParametrosCosmologicos<T>{
+    if parametros.densidad_radiacion == T::from(0.){
+        let h = parametros.h();
+        parametros.densidad_radiacion = T::from(OMEGA_RADIACION_H2)/(h*h);
+        // No se llama a recalcular_derivados: por redondeo la curvatura dejaría de ser exactamente 0 y cambiarían las unidades
+        parametros.densidad_energia_oscura -= parametros.densidad_radiacion;
+        parametros.z_maximo = z_maximo(parametros.real());
+    }
+    parametros
 }
 
 
-pub fn luminosity_distance(z: f64, parametros: ParametrosCosmologicos) -> f64{
-    parametros.factor_escala*(1.0+z) * s_k(parametros.c/(parametros.factor_escala * parametros.constante_hubble) * adaptive_simpson_method(|zp: f64| 1./e(1.0+zp, parametros), 0.0, z, 1e-6, 1e-7).unwrap_or(0.), parametros.signo_curvatura)
+pub(crate) const TOLERANCIA_DISTANCIA: f64 = 1e-7;
+pub(crate) const TOLERANCIA_EDAD: f64 = 1e-7;
+
+/// Comprueba que la integral entre z y z_fin no atraviesa una región con E² < 0. En lugar de
+/// integrar a través de ella (y obtener un resultado sin sentido) se devuelve un error en el borde.
+pub(crate) fn comprobar_alcanzable<T: Flotante>(magnitud: Magnitud, z: f64, z_fin: f64, tolerancia: f64, parametros: ParametrosCosmologicos<T>) -> Result<(), ErrorCosmologico>{
+    let z_limite = parametros.z_maximo;
+    if z_fin > z_limite{
+        return Err(ErrorCosmologico{magnitud, z, tolerancia, causa: CausaError::RedshiftInalcanzable{z_limite}});
+    }
+    Ok(())
 }
 
-pub fn angular_distance(z: f64, parametros: ParametrosCosmologicos) -> f64{
-    (1.0+z).powi(-2) * luminosity_distance(z, parametros)
+/// Exponente p con el que crece E(z) ∝ (1+z)^p a z grande, el mayor entre las componentes que no son nulas.
+/// Las integrales hasta el Big Bang sólo convergen si es suficientemente grande: la edad con p > 0 y el horizonte con p > 1.
+pub(crate) fn exponente_pasado<T: Flotante>(parametros: ParametrosCosmologicos<T>) -> f64{
+    let energia_oscura = 3.*(1. + parametros.w.real() + parametros.wa.real());
+    [(parametros.densidad_energia_oscura, energia_oscura), (parametros.densidad_curvatura, 2.), (parametros.densidad_materia, 3.),
+     (parametros.densidad_radiacion, 4.)].into_iter().filter(|(densidad, _)| densidad.real() != 0.)
+        .map(|(_, exponente)| exponente/2.).fold(f64::NEG_INFINITY, f64::max)
 }
 
-pub fn lookback_time(z:f64, parametros: ParametrosCosmologicos) -> f64{
-    adaptive_simpson_method(|zp: f64| 1./((1.+zp)*e(1.+zp, parametros)), z, f64::INFINITY, 1e-20, 1e-7).unwrap_or(f64::INFINITY)/parametros.constante_hubble
-    
+/// Comprueba que la integral hasta z infinito converge (ver `exponente_pasado`) en lugar de dejar que la cuadratura
+/// se quede sin llegar a la tolerancia
+pub(crate) fn comprobar_convergente<T: Flotante>(magnitud: Magnitud, z: f64, tolerancia: f64, minimo: f64, parametros: ParametrosCosmologicos<T>) -> Result<(), ErrorCosmologico>{
+    let exponente = exponente_pasado(parametros);
+    if exponente <= minimo{
+        return Err(ErrorCosmologico{magnitud, z, tolerancia, causa: CausaError::Divergente{exponente, minimo}});
+    }
+    Ok(())
+}
+
+pub(crate) fn error_integral(magnitud: Magnitud, z: f64, tolerancia: f64, err: Option<QuadratureError>) -> ErrorCosmologico{
+    let err = err.unwrap_or(QuadratureError{partial: QuadratureResult{value: f64::NAN, error: f64::NAN, evaluations: 0}, tolerance: tolerancia});
+    ErrorCosmologico{magnitud, z, tolerancia, causa: CausaError::Integracion(err)}
+}
+
+/// Distancia de luminosidad en Mpc, sin pasar por la legua cósmica. Con números duales es la que hay que usar:
+/// cerca de Ωk = 0 la legua es enorme y su derivada también, y al pasar de leguas a Mpc se pierde la precisión.
+pub fn luminosity_distance_mpc<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::DistanciaLuminosidad, z, z, TOLERANCIA_DISTANCIA, parametros)?;
+    let error = |err| error_integral(Magnitud::DistanciaLuminosidad, z, TOLERANCIA_DISTANCIA, err);
+    let integral = integrate(parametros.metodo_distancia, |zp: f64| T::from(1.)/e(1.0+zp, parametros), 0.0, z, 1e-6, TOLERANCIA_DISTANCIA).map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    // D_M = c/H0 sinn(√|Ωk| I)/√|Ωk|. Cerca de Ωk = 0 se usa la serie, que sigue siendo derivable respecto a Ωk
+    let curvatura = parametros.densidad_curvatura/parametros.factor_escala.powi(2);
+    let i = integral.value;
+    let x2 = curvatura*i*i;
+    let s = if x2.real().abs() < 1e-4 {i*(T::from(1.) + x2/6. + x2*x2/120.)}
+            else{
+                let raiz = curvatura.abs().sqrt();
+                if curvatura.real() > 0. {(raiz*i).sinh()/raiz} else {(raiz*i).sin()/raiz}
+            };
+    Ok(s/parametros.constante_hubble*(C_MPC_GY*(1.0+z)))
+}
+
+/// Distancia de luminosidad en leguas cósmicas
+pub fn luminosity_distance<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    luminosity_distance_mpc(z, parametros).map(|d| d/parametros.legua_cosmica_a_mpc)
+}
+
+pub fn angular_distance<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    luminosity_distance(z, parametros).map(|d| d*(1.0+z).powi(-2)).map_err(|err| err.con_magnitud(Magnitud::DistanciaAngular))
+}
+
+/// Distancia comóvil transversal D_M = D_L/(1+z)
+pub fn comoving_distance<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    luminosity_distance(z, parametros).map(|d| d/(1.0+z)).map_err(|err| err.con_magnitud(Magnitud::DistanciaComovil))
+}
+
+/// Distancia de Hubble D_H = c/H(z)
+pub fn hubble_distance<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::DistanciaHubble, z, z, 0., parametros)?;
+    Ok(parametros.c/(parametros.constante_hubble*e(1.0+z, parametros)))
+}
+
+/// Parámetro de Hubble H(z) = H0 E(z) en km/s/Mpc
+pub fn hubble_parameter<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::ParametroHubble, z, z, 0., parametros)?;
+    Ok(parametros.constante_hubble*e(1.0+z, parametros)/KM_S_MPC_TO_GY)
+}
+
+/// Distancia promediada en volumen D_V = (z D_M² D_H)^(1/3), la que miden los BAO isótropos
+pub fn volume_distance<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    let d_m = comoving_distance(z, parametros).map_err(|err| err.con_magnitud(Magnitud::DistanciaVolumen))?;
+    let d_h = hubble_distance(z, parametros).map_err(|err| err.con_magnitud(Magnitud::DistanciaVolumen))?;
+    Ok((d_m*z*d_m*d_h).cbrt())
+}
+
+/// Módulo de distancia μ = 5 log10(D_L/10 pc)
+pub fn distance_modulus<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    luminosity_distance_mpc(z, parametros).map(|d| d.log10()*5. + 25.)
+}
+
+/// Edad del universo en z, es decir el tiempo transcurrido desde el Big Bang hasta z.
+/// Si el universo no tiene Big Bang (la integral hasta z infinito no converge) se devuelve un error.
+pub fn lookback_time<T: Flotante>(z:f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::Edad, z, f64::INFINITY, TOLERANCIA_EDAD, parametros)?;
+    comprobar_convergente(Magnitud::Edad, z, TOLERANCIA_EDAD, 0., parametros)?;
+    let error = |err| error_integral(Magnitud::Edad, z, TOLERANCIA_EDAD, err);
+    // Hasta infinito se integra en u = atan(z), donde cerca de π/2 los flotantes están separados ~2e-16: min_h tiene que ser mayor
+    let integral = integrate(parametros.metodo_edad, |zp: f64| T::from(1.)/(e(1.+zp, parametros)*(1.+zp)), z, f64::INFINITY, 1e-12, TOLERANCIA_EDAD).map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    Ok(integral.value/parametros.constante_hubble)
+}
+
+/// Tiempo conforme en z contado desde hoy, η = ∫ dt/a = -1/(a0 H0) ∫ dz/E, en giga años (negativo en el pasado).
+/// Es la distancia comóvil a lo largo de la visual entre c.
+pub fn tiempo_conforme<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> Result<T, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::TiempoConforme, z, z, TOLERANCIA_DISTANCIA, parametros)?;
+    let error = |err| error_integral(Magnitud::TiempoConforme, z, TOLERANCIA_DISTANCIA, err);
+    let integral = integrate(parametros.metodo_distancia, |zp: f64| T::from(1.)/e(1.0+zp, parametros), 0.0, z, 1e-6, TOLERANCIA_DISTANCIA).map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    Ok(-integral.value/(parametros.constante_hubble*parametros.factor_escala))
+}
+
+/// Horizonte de partículas hoy en Mpc, c/H0 ∫ da/(a² E) desde el Big Bang. Es la distancia propia, así que no lleva
+/// el sinn de la curvatura. Sólo es finito si hay Big Bang y el universo temprano no está dominado por la curvatura.
+/// Con a = u² el integrando es finito en a = 0 si domina la materia o la radiación (en z la cola va como z^(-3/2)).
+pub fn horizonte_particulas_actual(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::HorizonteParticulas, 0., f64::INFINITY, TOLERANCIA_DISTANCIA, parametros)?;
+    comprobar_convergente(Magnitud::HorizonteParticulas, 0., TOLERANCIA_DISTANCIA, 1., parametros)?;
+    let error = |err| error_integral(Magnitud::HorizonteParticulas, 0., TOLERANCIA_DISTANCIA, err);
+    let a0 = parametros.factor_escala;
+    let integrando = |u: f64| 2.*a0/(u.powi(3)*e(a0/(u*u), parametros));
+    let integral = integrate(MetodoIntegracion::GaussKronrod, integrando, 0., a0.sqrt(), 1e-20, TOLERANCIA_DISTANCIA)
+        .map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    Ok(integral.value*C_MPC_GY/parametros.constante_hubble)
+}
+
+/// Tiempo desde el Big Bang hasta el tamaño máximo del universo, en giga años, si vuelve a colapsar.
+/// t = ∫ da/(a H) hasta a_max; con a = a_max (1-u²) el integrando es finito en a_max, donde E = 0.
+/// Se integra con Gauss-Kronrod porque no evalúa los extremos (a = 0 y a = a_max).
+pub fn tiempo_retorno(parametros: ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::TiempoRetorno, 0., f64::INFINITY, TOLERANCIA_EDAD, parametros)?;
+    let a_max = intervalo_actual(parametros).a_max;
+    if a_max.is_infinite(){
+        return Err(ErrorCosmologico{magnitud: Magnitud::TiempoRetorno, z: 0., tolerancia: TOLERANCIA_EDAD, causa: CausaError::SinMaximo});
+    }
+    let integrando = |u: f64| {
+        let a = a_max*(1. - u*u);
+        2.*u*a_max/(a*e(parametros.factor_escala/a, parametros))
+    };
+    let error = |err| error_integral(Magnitud::TiempoRetorno, parametros.factor_escala/a_max - 1., TOLERANCIA_EDAD, err);
+    let integral = integrate(MetodoIntegracion::GaussKronrod, integrando, 0., 1., 1e-20, TOLERANCIA_EDAD).map_err(|err| error(Some(err)))?;
+    if !integral.value.is_finite(){
+        return Err(error(None));
+    }
+    Ok(integral.value/parametros.constante_hubble)
 }
 
 /// Edo que representa el factor de escala. s representa si el universo está en expansión o en contracción o si ya ha acabado
@@ -70,48 +257,80 @@ pub fn edo_para_a(_: f64, a: f64, sig_and_params: &mut (i8, bool, ParametrosCosm
 //     adaptive_simpson_method(|zp| 1./((1.+zp) * e(1.+zp, parametros)), z, 0., 1e-10, 1e-7).unwrap_or(0.)/parametros.constante_hubble
 // }
 
-pub fn horizonte_de_partículas(t: f64, parametros: ParametrosCosmologicos) -> (f64, Vec<f64>, Vec<f64>){
-    
-    let universe_lifetime = lookback_time(0., parametros);
-    if universe_lifetime.is_finite(){
-        if t <=0.{
-            let elems = 5000;
-            let mut tt_at = linspace(0., t, elems);
-            let mut aa_at = runge_kutta_at_points(edo_para_a, &tt_at, parametros.factor_escala, 10, (1, true, parametros));
-            let mut tt_bt = linspace(t, -universe_lifetime, elems);
-            let mut aa_bt = runge_kutta_at_points(edo_para_a, &tt_bt, aa_at[elems as usize-1], 10, (1, true, parametros));
-            tt_at.reverse();
-            aa_at.reverse(); 
-            tt_bt.reverse();
-            aa_bt.reverse();
-            aa_bt.extend_from_slice(&aa_at[1..]);
-            tt_bt.extend_from_slice(&tt_at[1..]);
-            let func_bt: Vec<_> = aa_bt.iter().map(|a| 1./a).collect();
-            let mut distance = vec![0.; 2*elems as usize-1];
-            for i in 1..tt_bt.len(){
-                distance[i] = aa_bt[i]*parametros.c*trapezoid_method(&tt_bt[i-1..=i], &func_bt[i-1..=i]) + distance[i-1];
-            }
-            return (distance[elems as usize-1], tt_bt, distance);
+/// Horizonte de partículas en el instante t (medido desde hoy) y su evolución.
+/// Sólo está definido si el universo tiene una edad finita.
+pub fn horizonte_de_partículas(t: f64, parametros: ParametrosCosmologicos) -> Result<(f64, Vec<f64>, Vec<f64>), ErrorCosmologico>{
+    comprobar_alcanzable(Magnitud::HorizonteParticulas, 0., f64::INFINITY, TOLERANCIA_DISTANCIA, parametros)?;
+    comprobar_convergente(Magnitud::HorizonteParticulas, 0., TOLERANCIA_DISTANCIA, 1., parametros)?;
+    let universe_lifetime = lookback_time(0., parametros).map_err(|err| err.con_magnitud(Magnitud::HorizonteParticulas))?;
+    if t <=0.{
+        let elems = 5000;
+        let mut tt_at = linspace(0., t, elems);
+        let mut aa_at = runge_kutta_at_points(edo_para_a, &tt_at, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, true, parametros));
+        let mut tt_bt = linspace(t, -universe_lifetime, elems);
+        let mut aa_bt = runge_kutta_at_points(edo_para_a, &tt_bt, aa_at[elems as usize-1], SUBPASOS_RUNGE_KUTTA, (1, true, parametros));
+        tt_at.reverse();
+        aa_at.reverse(); 
+        tt_bt.reverse();
+        aa_bt.reverse();
+        aa_bt.extend_from_slice(&aa_at[1..]);
+        tt_bt.extend_from_slice(&tt_at[1..]);
+        let func_bt: Vec<_> = aa_bt.iter().map(|a| 1./a).collect();
+        let mut distance = vec![0.; 2*elems as usize-1];
+        for i in 1..tt_bt.len(){
+            distance[i] = aa_bt[i]*parametros.c*trapezoid_method(&tt_bt[i-1..=i], &func_bt[i-1..=i]) + distance[i-1];
         }
-        else {
-            let elems = 5000;
-            let tt_at = linspace(0., t, elems);
-            let aa_at = runge_kutta_at_points(edo_para_a, &tt_at, parametros.factor_escala, 10, (1, true, parametros));
-            let mut tt_bt = linspace(0., -universe_lifetime, elems);
-            let mut aa_bt = runge_kutta_at_points(edo_para_a, &tt_bt, parametros.factor_escala, 10, (1, true, parametros));
-            tt_bt.reverse();
-            aa_bt.reverse();
-            aa_bt.extend_from_slice(&aa_at[1..]);
-            tt_bt.extend_from_slice(&tt_at[1..]);
-            let func_bt: Vec<_> = aa_bt.iter().map(|a| 1./a).collect();
-            let mut distance = vec![0.; 2*elems as usize-1];
-            for i in 1..tt_bt.len(){
-                distance[i] = aa_bt[i]*parametros.c*trapezoid_method(&tt_bt[0..=i], &func_bt[0..=i]);
-            }
-            return (distance[2*elems as usize-2], tt_bt, distance);
-       }
+        Ok((distance[elems as usize-1], tt_bt, distance))
+    }
+    else {
+        let elems = 5000;
+        let tt_at = linspace(0., t, elems);
+        let aa_at = runge_kutta_at_points(edo_para_a, &tt_at, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, true, parametros));
+        let mut tt_bt = linspace(0., -universe_lifetime, elems);
+        let mut aa_bt = runge_kutta_at_points(edo_para_a, &tt_bt, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, true, parametros));
+        tt_bt.reverse();
+        aa_bt.reverse();
+        aa_bt.extend_from_slice(&aa_at[1..]);
+        tt_bt.extend_from_slice(&tt_at[1..]);
+        let func_bt: Vec<_> = aa_bt.iter().map(|a| 1./a).collect();
+        let mut distance = vec![0.; 2*elems as usize-1];
+        for i in 1..tt_bt.len(){
+            distance[i] = aa_bt[i]*parametros.c*trapezoid_method(&tt_bt[0..=i], &func_bt[0..=i]);
+        }
+        Ok((distance[2*elems as usize-2], tt_bt, distance))
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::presets::Preset;
 
+    const METODOS: [MetodoIntegracion; 3] = [MetodoIntegracion::Simpson, MetodoIntegracion::GaussKronrod, MetodoIntegracion::TanhSinh];
 
+    #[test]
+    fn edad_de_einstein_de_sitter(){
+        // Sólo materia: t(z) = 2/(3 H0 (1+z)^(3/2))
+        for metodo in METODOS{
+            let parametros = ParametrosCosmologicos{metodo_edad: metodo, ..Preset::EinsteinDeSitter.parametros()};
+            for z in [0., 2., 1000.]{
+                let exacta = 2./(3.*parametros.constante_hubble*(1.+z).powf(1.5));
+                let edad = lookback_time(z, parametros).unwrap();
+                // La tolerancia es absoluta y en unidades de 1/H0
+                assert!(((edad - exacta)*parametros.constante_hubble).abs() < TOLERANCIA_EDAD, "{:?} en z = {}: {} en lugar de {}", metodo, z, edad, exacta);
+            }
+        }
     }
-    return (f64::INFINITY, Vec::new(), Vec::new());
-}
\ No newline at end of file
+
+    #[test]
+    fn edad_de_sitter_sin_agotar_memoria(){
+        // Sin Big Bang la integral diverge: tiene que terminar con un error, antes Simpson dividía sin fin cerca de u = π/2
+        for metodo in METODOS{
+            let parametros = ParametrosCosmologicos{metodo_edad: metodo, ..Preset::DeSitter.parametros()};
+            for z in [0., 2., 3., 10.]{
+                assert!(lookback_time(z, parametros).is_err(), "{:?} en z = {}", metodo, z);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use core::f64;
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{e2, derivada_log_e2};
+use crate::integral_methods::{runge_kutta_at_points, trapezoid_method};
+use crate::plotting::linspace;
+use crate::dual::Flotante;
+use crate::consts::SUBPASOS_RUNGE_KUTTA;
+
+
+/// Factor de escala en el que empieza la integración, en plena época de materia, donde f = 1
+const A_INICIAL: f64 = 1e-2;
+const PUNTOS: usize = 200;
+
+/// Ecuación para la tasa de crecimiento f = d ln D/d ln a en función de ln a, en la aproximación lineal y sin presión:
+/// df/d ln a = -f² - (2 + d ln H/d ln a) f + 3/2 Ω_m(a)
+fn edo_tasa_crecimiento<T: Flotante>(ln_a: f64, f: T, parametros: &mut ParametrosCosmologicos<T>) -> T{
+    let x = (-ln_a).exp();
+    let omega_m = parametros.densidad_materia*x.powi(3)/e2(x, *parametros);
+    // d ln H/d ln a = -1/2 d ln E²/d ln x
+    -f*f - (T::from(2.) - derivada_log_e2(x, *parametros)*0.5)*f + omega_m*1.5
+}
+
+/// Puntos equiespaciados entre a y b, ambos incluidos (`linspace` no incluye el final)
+fn rejilla(a: f64, b: f64) -> Vec<f64>{
+    let mut puntos = linspace(a, b, PUNTOS as i64);
+    puntos.push(b);
+    puntos
+}
+
+/// Factor de crecimiento lineal D(z)/D(0) y tasa de crecimiento f(z)
+pub fn growth<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> (T, T){
+    let ln_a = -(1.+z).ln();
+    let ln_a_inicial = A_INICIAL.ln().min(ln_a - 1.);
+    let tramo_1 = rejilla(ln_a_inicial, ln_a);
+    let f_z = *runge_kutta_at_points(edo_tasa_crecimiento, &tramo_1, T::from(1.), SUBPASOS_RUNGE_KUTTA, parametros).last().unwrap();
+    let tramo_2 = rejilla(ln_a, 0.);
+    let ff = runge_kutta_at_points(edo_tasa_crecimiento, &tramo_2, f_z, SUBPASOS_RUNGE_KUTTA, parametros);
+    // ln D(0) - ln D(z) = ∫ f d ln a
+    ((-trapezoid_method(&tramo_2, &ff)).exp(), f_z)
+}
+
+/// Factor de crecimiento lineal normalizado a hoy, D(z)/D(0)
+pub fn growth_factor<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    growth(z, parametros).0
+}
+
+/// Tasa de crecimiento f = d ln D/d ln a
+pub fn growth_rate<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    growth(z, parametros).1
+}
+
+/// fσ8(z) = f(z) σ8 D(z)/D(0), lo que miden las distorsiones en el espacio de redshift
+pub fn fsigma8<T: Flotante>(z: f64, parametros: ParametrosCosmologicos<T>) -> T{
+    let (d, f) = growth(z, parametros);
+    f*parametros.sigma8*d
+}
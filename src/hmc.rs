@@ -0,0 +1,553 @@
+use core::f64;
+use std::path::PathBuf;
+use std::thread;
+
+use anyhow::bail;
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::{con_valores, gradiente_chi2, Parametro, Verosimilitud};
+use crate::priors::{log_prior, Prior, Priors};
+use crate::chains::Cadena;
+use crate::random::Rng;
+use crate::texto;
+
+
+/// Variante del muestreador hamiltoniano
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgoritmoHmc{
+    /// No-U-Turn Sampler (Hoffman & Gelman 2014): la longitud de cada trayectoria se elige sola
+    #[default]
+    Nuts,
+    /// HMC clásico con un número fijo de pasos de salto
+    Hmc,
+}
+
+/// Opciones de la orden `hmc`, tabla [hmc] del fichero de entrada
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionHmc{
+    pub algoritmo: AlgoritmoHmc,
+    /// Cadenas independientes, cada una en su hilo. En el fichero de salida son los caminantes.
+    pub cadenas: usize,
+    /// Pasos en los que se adaptan el paso de integración y la matriz de masas. Se guardan en la cadena
+    /// (con paso < adaptacion) pero no se usan en los resúmenes.
+    pub adaptacion: usize,
+    /// Pasos después de la adaptación
+    pub pasos: usize,
+    pub semilla: u64,
+    /// Fichero de la cadena, .csv o .npy
+    pub salida: PathBuf,
+    /// Probabilidad de aceptación media que se busca al adaptar el paso de integración
+    pub aceptacion_objetivo: f64,
+    /// Profundidad máxima del árbol de NUTS, como mucho 2^profundidad pasos de salto por trayectoria
+    pub profundidad_maxima: usize,
+    /// Pasos de salto de cada trayectoria con `algoritmo = "hmc"`
+    pub pasos_salto: usize,
+}
+
+impl Default for ConfiguracionHmc{
+    fn default() -> Self{
+        ConfiguracionHmc{algoritmo: AlgoritmoHmc::Nuts, cadenas: 4, adaptacion: 500, pasos: 1000, semilla: 1,
+                         salida: PathBuf::from("cadenas/hmc.csv"), aceptacion_objetivo: 0.8, profundidad_maxima: 10, pasos_salto: 20}
+    }
+}
+
+/// Estadísticas de una cadena después de la adaptación
+#[derive(Clone, Debug)]
+pub struct EstadisticasCadena{
+    /// Paso de integración adaptado
+    pub paso_salto: f64,
+    /// Diagonal de la inversa de la matriz de masas, en las variables transformadas (ver `Transformacion`)
+    pub masa_inversa: Vec<f64>,
+    /// Probabilidad de aceptación media
+    pub aceptacion: f64,
+    /// Trayectorias en las que el error en la energía se ha disparado, suelen indicar una geometría difícil
+    pub divergencias: usize,
+    /// Pasos de salto por trayectoria, de media
+    pub saltos: f64,
+}
+
+/// Resultado de un muestreo
+pub struct ResultadoHmc{
+    pub cadena: Cadena,
+    pub estadisticas: Vec<EstadisticasCadena>,
+}
+
+/// Error en la energía a partir del cual una trayectoria se considera divergente
+const DELTA_MAX: f64 = 1000.;
+
+// Parámetros del promedio dual de Hoffman & Gelman 2014 para adaptar el paso
+const GAMMA: f64 = 0.05;
+const T0: f64 = 10.;
+const KAPPA: f64 = 0.75;
+
+
+/// Cambio de variable de un parámetro a toda la recta real, para que las trayectorias no choquen con los bordes de
+/// los priors uniformes: x = min + (max - min)/(1 + e^(-u)). Los parámetros con prior gaussiano no se transforman.
+#[derive(Clone, Copy, Debug)]
+struct Transformacion(Prior);
+
+/// ln(1 + e^u) sin desbordamientos
+fn softplus(u: f64) -> f64{
+    if u > 0. {u + (-u).exp().ln_1p()} else {u.exp().ln_1p()}
+}
+
+impl Transformacion{
+    /// (x, ln |dx/du|, dx/du, d ln |dx/du|/du)
+    fn restringido(self, u: f64) -> (f64, f64, f64, f64){
+        match self.0{
+            Prior::Uniforme(min, max) => {
+                let s = 1./(1. + (-u).exp());
+                (min + (max - min)*s, (max - min).ln() - softplus(-u) - softplus(u), (max - min)*s*(1. - s), 1. - 2.*s)
+            }
+            Prior::Gaussiano{..} => (u, 0., 1., 0.),
+        }
+    }
+
+    fn libre(self, x: f64) -> f64{
+        match self.0{
+            Prior::Uniforme(min, max) => ((x - min)/(max - x)).ln(),
+            Prior::Gaussiano{..} => x,
+        }
+    }
+}
+
+/// Log posterior en las variables transformadas, con el jacobiano del cambio de variable
+struct Posterior<'a>{
+    verosimilitud: &'a dyn Verosimilitud,
+    parametros: ParametrosCosmologicos,
+    priors: &'a Priors,
+    libres: Vec<Parametro>,
+    transformaciones: Vec<Transformacion>,
+}
+
+/// Punto del espacio de fases: posición (en las variables transformadas), momento, log posterior y su gradiente
+#[derive(Clone, Debug)]
+struct Estado{
+    q: Vec<f64>,
+    p: Vec<f64>,
+    log_p: f64,
+    /// Parte de log_p que viene del jacobiano, para guardar en la cadena el log posterior de los parámetros originales
+    log_jacobiano: f64,
+    gradiente: Vec<f64>,
+}
+
+impl Posterior<'_>{
+    fn restringido(&self, q: &[f64]) -> Vec<f64>{
+        self.transformaciones.iter().zip(q).map(|(t, &u)| t.restringido(u).0).collect()
+    }
+
+    fn estado(&self, q: Vec<f64>, p: Vec<f64>) -> Estado{
+        let n = q.len();
+        let divergente = |q, p| Estado{q, p, log_p: f64::NEG_INFINITY, log_jacobiano: 0., gradiente: vec![0.; n]};
+        let transformadas: Vec<_> = self.transformaciones.iter().zip(&q).map(|(t, &u)| t.restringido(u)).collect();
+        let x: Vec<f64> = transformadas.iter().map(|t| t.0).collect();
+        let prior = log_prior(self.priors, &x);
+        if !prior.is_finite(){
+            return divergente(q, p);
+        }
+        let (chi2, gradiente_chi2) = gradiente_chi2(self.verosimilitud, &self.libres, con_valores(&self.libres, &x, self.parametros));
+        if !chi2.is_finite() || gradiente_chi2.iter().any(|g| !g.is_finite()){
+            return divergente(q, p);
+        }
+        let log_jacobiano: f64 = transformadas.iter().map(|t| t.1).sum();
+        let gradiente = self.priors.values().zip(&transformadas).zip(&gradiente_chi2)
+            .map(|((prior, &(x, _, dx, d_log_jacobiano)), g)| (prior.derivada_log_densidad(x) - g/2.)*dx + d_log_jacobiano).collect();
+        Estado{q, p, log_p: prior + log_jacobiano - chi2/2., log_jacobiano, gradiente}
+    }
+
+    /// Un paso de salto (leapfrog) de tamaño eps
+    fn salto(&self, estado: &Estado, eps: f64, masa_inversa: &[f64]) -> Estado{
+        let p: Vec<f64> = estado.p.iter().zip(&estado.gradiente).map(|(p, g)| p + eps/2.*g).collect();
+        let q = estado.q.iter().zip(&p).zip(masa_inversa).map(|((q, p), m)| q + eps*m*p).collect();
+        let mut nuevo = self.estado(q, p);
+        for (p, g) in nuevo.p.iter_mut().zip(&nuevo.gradiente){
+            *p += eps/2.*g;
+        }
+        nuevo
+    }
+}
+
+impl Estado{
+    fn hamiltoniano(&self, masa_inversa: &[f64]) -> f64{
+        -self.log_p + 0.5*self.p.iter().zip(masa_inversa).map(|(p, m)| p*p*m).sum::<f64>()
+    }
+
+    fn con_momento_aleatorio(&self, masa_inversa: &[f64], rng: &mut Rng) -> Estado{
+        let mut estado = self.clone();
+        estado.p = masa_inversa.iter().map(|m| rng.normal()/m.sqrt()).collect();
+        estado
+    }
+}
+
+/// Criterio de NUTS: la trayectoria entre `menos` y `mas` todavía no ha empezado a dar la vuelta
+fn sin_vuelta(menos: &Estado, mas: &Estado, masa_inversa: &[f64]) -> bool{
+    let proyeccion = |p: &[f64]| -> f64 {
+        mas.q.iter().zip(&menos.q).zip(p).zip(masa_inversa).map(|(((a, b), p), m)| (a - b)*m*p).sum()
+    };
+    proyeccion(&menos.p) >= 0. && proyeccion(&mas.p) >= 0.
+}
+
+/// Subárbol de NUTS (algoritmo 6 de Hoffman & Gelman 2014)
+struct Arbol{
+    menos: Estado,
+    mas: Estado,
+    propuesta: Estado,
+    /// Estados del subárbol dentro de la rebanada
+    n: f64,
+    continuar: bool,
+    /// Suma de las probabilidades de aceptación y número de estados, para adaptar el paso
+    alfa: f64,
+    n_alfa: f64,
+    divergente: bool,
+}
+
+/// Adaptación del paso de integración con el promedio dual de Nesterov
+struct PromedioDual{
+    mu: f64,
+    objetivo: f64,
+    t: f64,
+    h_media: f64,
+    log_eps_medio: f64,
+}
+
+impl PromedioDual{
+    fn new(eps: f64, objetivo: f64) -> Self{
+        PromedioDual{mu: (10.*eps).ln(), objetivo, t: 0., h_media: 0., log_eps_medio: 0.}
+    }
+
+    /// Nuevo paso después de una trayectoria con probabilidad de aceptación `alfa`
+    fn actualizar(&mut self, alfa: f64) -> f64{
+        self.t += 1.;
+        let eta = 1./(self.t + T0);
+        self.h_media = (1. - eta)*self.h_media + eta*(self.objetivo - alfa);
+        let log_eps = self.mu - self.t.sqrt()/GAMMA*self.h_media;
+        let w = self.t.powf(-KAPPA);
+        self.log_eps_medio = w*log_eps + (1. - w)*self.log_eps_medio;
+        log_eps.exp()
+    }
+
+    /// Paso final, el promedio de los de la adaptación
+    fn final_(&self) -> f64{
+        self.log_eps_medio.exp()
+    }
+}
+
+/// Ventanas [inicio, fin) en las que se estima la matriz de masas, como en Stan: un 15% inicial y un 10% final
+/// en los que sólo se adapta el paso y en medio ventanas que doblan su longitud.
+fn ventanas_masa(adaptacion: usize) -> Vec<(usize, usize)>{
+    let inicio_lento = (0.15*adaptacion as f64) as usize;
+    let fin_lento = adaptacion - (0.1*adaptacion as f64) as usize;
+    let mut ventanas = Vec::new();
+    let mut inicio = inicio_lento;
+    let mut ancho = (adaptacion/40).max(5);
+    while inicio < fin_lento{
+        // Si la siguiente ventana no cabe entera esta se alarga hasta el final
+        let fin = if inicio + 3*ancho > fin_lento {fin_lento} else {inicio + ancho};
+        ventanas.push((inicio, fin));
+        inicio = fin;
+        ancho *= 2;
+    }
+    ventanas
+}
+
+struct Muestreador<'a>{
+    posterior: &'a Posterior<'a>,
+    configuracion: &'a ConfiguracionHmc,
+    masa_inversa: Vec<f64>,
+    rng: Rng,
+    saltos: usize,
+}
+
+impl Muestreador<'_>{
+    fn salto(&mut self, estado: &Estado, eps: f64) -> Estado{
+        self.saltos += 1;
+        self.posterior.salto(estado, eps, &self.masa_inversa)
+    }
+
+    /// Paso inicial razonable (algoritmo 4 de Hoffman & Gelman 2014): se dobla o se divide por dos
+    /// hasta que la probabilidad de aceptación de un salto cruza 1/2
+    fn buscar_paso(&mut self, estado: &Estado) -> f64{
+        let inicial = estado.con_momento_aleatorio(&self.masa_inversa, &mut self.rng);
+        let h0 = inicial.hamiltoniano(&self.masa_inversa);
+        let mut eps = 0.1;
+        let log_razon = |m: &mut Self, eps: f64| -> f64 {
+            let h = m.salto(&inicial, eps).hamiltoniano(&m.masa_inversa);
+            if h.is_finite() {h0 - h} else {f64::NEG_INFINITY}
+        };
+        let direccion = if log_razon(self, eps) > 0.5f64.ln() {1.} else {-1.};
+        for _ in 0..50{
+            let log_razon = log_razon(self, eps);
+            if direccion*log_razon <= -direccion*2f64.ln(){
+                break;
+            }
+            eps *= 2f64.powf(direccion);
+        }
+        eps
+    }
+
+    fn construir_arbol(&mut self, estado: &Estado, log_u: f64, direccion: f64, profundidad: usize, eps: f64, h0: f64) -> Arbol{
+        if profundidad == 0{
+            let nuevo = self.salto(estado, direccion*eps);
+            let h = nuevo.hamiltoniano(&self.masa_inversa);
+            let continuar = log_u < -h + DELTA_MAX;
+            let alfa = if h.is_finite() {(h0 - h).exp().min(1.)} else {0.};
+            return Arbol{menos: nuevo.clone(), mas: nuevo.clone(), n: (log_u <= -h) as u8 as f64, continuar, alfa, n_alfa: 1.,
+                         divergente: !continuar, propuesta: nuevo};
+        }
+        let mut arbol = self.construir_arbol(estado, log_u, direccion, profundidad - 1, eps, h0);
+        if arbol.continuar{
+            let extremo = if direccion < 0. {arbol.menos.clone()} else {arbol.mas.clone()};
+            let otro = self.construir_arbol(&extremo, log_u, direccion, profundidad - 1, eps, h0);
+            if otro.n > 0. && self.rng.uniforme() < otro.n/(arbol.n + otro.n){
+                arbol.propuesta = otro.propuesta;
+            }
+            if direccion < 0. {arbol.menos = otro.menos} else {arbol.mas = otro.mas}
+            arbol.alfa += otro.alfa;
+            arbol.n_alfa += otro.n_alfa;
+            arbol.divergente |= otro.divergente;
+            arbol.continuar = otro.continuar && sin_vuelta(&arbol.menos, &arbol.mas, &self.masa_inversa);
+            arbol.n += otro.n;
+        }
+        arbol
+    }
+
+    /// Una trayectoria de NUTS. Devuelve el nuevo estado, la probabilidad de aceptación media y si ha divergido.
+    fn paso_nuts(&mut self, actual: &Estado, eps: f64) -> (Estado, f64, bool){
+        let inicial = actual.con_momento_aleatorio(&self.masa_inversa, &mut self.rng);
+        let h0 = inicial.hamiltoniano(&self.masa_inversa);
+        // Rebanada u ~ U(0, e^(-H0)), en logaritmos
+        let log_u = -h0 + (1. - self.rng.uniforme()).ln();
+        let (mut menos, mut mas) = (inicial.clone(), inicial.clone());
+        let mut propuesta = actual.clone();
+        let (mut n, mut alfa, mut n_alfa, mut divergente) = (1., 0., 0., false);
+        for profundidad in 0..self.configuracion.profundidad_maxima{
+            let direccion = if self.rng.uniforme() < 0.5 {-1.} else {1.};
+            let arbol = self.construir_arbol(if direccion < 0. {&menos} else {&mas}, log_u, direccion, profundidad, eps, h0);
+            if direccion < 0. {menos = arbol.menos} else {mas = arbol.mas}
+            if arbol.continuar && self.rng.uniforme() < arbol.n/n{
+                propuesta = arbol.propuesta;
+            }
+            n += arbol.n;
+            alfa += arbol.alfa;
+            n_alfa += arbol.n_alfa;
+            divergente |= arbol.divergente;
+            if !arbol.continuar || !sin_vuelta(&menos, &mas, &self.masa_inversa){
+                break;
+            }
+        }
+        (propuesta, alfa/n_alfa, divergente)
+    }
+
+    /// Una trayectoria de HMC con `pasos_salto` pasos y aceptación de Metropolis
+    fn paso_hmc(&mut self, actual: &Estado, eps: f64) -> (Estado, f64, bool){
+        let inicial = actual.con_momento_aleatorio(&self.masa_inversa, &mut self.rng);
+        let h0 = inicial.hamiltoniano(&self.masa_inversa);
+        let mut estado = inicial;
+        for _ in 0..self.configuracion.pasos_salto{
+            estado = self.salto(&estado, eps);
+            if !estado.log_p.is_finite(){
+                break;
+            }
+        }
+        let h = estado.hamiltoniano(&self.masa_inversa);
+        let alfa = if h.is_finite() {(h0 - h).exp().min(1.)} else {0.};
+        let divergente = h - h0 >= DELTA_MAX || h.is_nan();
+        if self.rng.uniforme() < alfa {(estado, alfa, divergente)} else {(actual.clone(), alfa, divergente)}
+    }
+
+    /// Ejecuta una cadena entera: (valores de los parámetros y log posterior de cada paso, estadísticas)
+    fn cadena(mut self, inicial: Estado) -> (Vec<(Vec<f64>, f64)>, EstadisticasCadena){
+        let configuracion = self.configuracion;
+        let n = inicial.q.len();
+        let ventanas = ventanas_masa(configuracion.adaptacion);
+        let mut ventana_muestras: Vec<Vec<f64>> = Vec::new();
+        let mut actual = inicial;
+        let mut eps = self.buscar_paso(&actual);
+        let mut promedio = PromedioDual::new(eps, configuracion.aceptacion_objetivo);
+        let (mut aceptacion, mut divergencias) = (0., 0);
+        let mut filas = Vec::with_capacity(configuracion.adaptacion + configuracion.pasos);
+        self.saltos = 0;
+
+        for paso in 0..configuracion.adaptacion + configuracion.pasos{
+            if paso == configuracion.adaptacion{
+                eps = promedio.final_();
+                self.saltos = 0;
+            }
+            let (nuevo, alfa, divergente) = match configuracion.algoritmo{
+                AlgoritmoHmc::Nuts => self.paso_nuts(&actual, eps),
+                AlgoritmoHmc::Hmc => self.paso_hmc(&actual, eps),
+            };
+            actual = nuevo;
+            if paso < configuracion.adaptacion{
+                eps = promedio.actualizar(alfa);
+                if ventanas.iter().any(|&(inicio, fin)| (inicio..fin).contains(&paso)){
+                    ventana_muestras.push(actual.q.clone());
+                }
+                if ventanas.iter().any(|&(_, fin)| fin == paso + 1) && ventana_muestras.len() > 1{
+                    // Varianza de la ventana regularizada hacia 1e-3, como en Stan
+                    let m = ventana_muestras.len() as f64;
+                    for i in 0..n{
+                        let media = ventana_muestras.iter().map(|q| q[i]).sum::<f64>()/m;
+                        let varianza = ventana_muestras.iter().map(|q| (q[i] - media).powi(2)).sum::<f64>()/(m - 1.);
+                        self.masa_inversa[i] = m/(m + 5.)*varianza + 1e-3*5./(m + 5.);
+                    }
+                    ventana_muestras.clear();
+                    eps = self.buscar_paso(&actual);
+                    promedio = PromedioDual::new(eps, configuracion.aceptacion_objetivo);
+                }
+            }
+            else{
+                aceptacion += alfa;
+                divergencias += divergente as usize;
+            }
+            filas.push((self.posterior.restringido(&actual.q), actual.log_p - actual.log_jacobiano));
+        }
+        let pasos = configuracion.pasos.max(1) as f64;
+        let estadisticas = EstadisticasCadena{paso_salto: eps, masa_inversa: self.masa_inversa, aceptacion: aceptacion/pasos,
+                                              divergencias, saltos: self.saltos as f64/pasos};
+        (filas, estadisticas)
+    }
+}
+
+/// Muestreador hamiltoniano (NUTS o HMC con trayectorias fijas) con varias cadenas en paralelo.
+///
+/// Los parámetros libres son los que tienen prior y se muestrean en variables sin bordes (ver `Transformacion`).
+/// El gradiente del log posterior sale de `gradiente_chi2`, exacto con números duales si las verosimilitudes lo
+/// permiten. Durante los primeros `adaptacion` pasos se adaptan el paso de integración (promedio dual) y una matriz
+/// de masas diagonal (varianza de las muestras en ventanas crecientes). La cadena tiene el mismo formato que la de
+/// `muestrear`, con cada cadena como un caminante, y se escribe en `salida` al terminar.
+pub fn muestrear_hmc(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, priors: &Priors,
+                     configuracion: &ConfiguracionHmc) -> anyhow::Result<ResultadoHmc>{
+    let libres: Vec<Parametro> = priors.keys().copied().collect();
+    let n = libres.len();
+    if n == 0{
+        bail!(texto!(SinPriors));
+    }
+    if configuracion.cadenas == 0{
+        bail!(texto!(AlMenosUnaCadena));
+    }
+    let centro: Vec<f64> = libres.iter().map(|p| p.valor(&parametros)).collect();
+    if !log_prior(priors, &centro).is_finite(){
+        bail!(texto!(FueraDePriors, format!("{:?}", centro)));
+    }
+    let transformaciones: Vec<Transformacion> = priors.values().map(|&p| Transformacion(p)).collect();
+    let posterior = Posterior{verosimilitud, parametros, priors, libres: libres.clone(), transformaciones};
+
+    // Cada cadena empieza en una bola pequeña alrededor de los valores de `parametros`, como en `muestrear`
+    let mut iniciales = Vec::with_capacity(configuracion.cadenas);
+    let mut rngs = Vec::with_capacity(configuracion.cadenas);
+    for i in 0..configuracion.cadenas{
+        let mut rng = Rng::new(configuracion.semilla.wrapping_add(i as u64));
+        let mut intentos = 0;
+        let inicial = loop{
+            let x: Vec<f64> = centro.iter().map(|c| c + 1e-3*(c.abs() + 0.1)*rng.normal()).collect();
+            if log_prior(priors, &x).is_finite(){
+                let q = posterior.transformaciones.iter().zip(&x).map(|(t, &x)| t.libre(x)).collect();
+                let estado = posterior.estado(q, vec![0.; n]);
+                if estado.log_p.is_finite(){
+                    break estado;
+                }
+            }
+            intentos += 1;
+            if intentos == 1000{
+                bail!(texto!(SinPuntoInicial, format!("{:?}", centro)));
+            }
+        };
+        iniciales.push(inicial);
+        rngs.push(rng);
+    }
+
+    let resultados: Vec<_> = thread::scope(|s| {
+        let hilos: Vec<_> = iniciales.into_iter().zip(rngs).map(|(inicial, rng)| {
+            let muestreador = Muestreador{posterior: &posterior, configuracion, masa_inversa: vec![1.; n], rng, saltos: 0};
+            s.spawn(move || muestreador.cadena(inicial))
+        }).collect();
+        hilos.into_iter().map(|h| h.join().expect("una cadena de HMC ha fallado")).collect()
+    });
+
+    let mut cadena = Cadena::new(&libres);
+    for paso in 0..configuracion.adaptacion + configuracion.pasos{
+        for (caminante, (filas, _)) in resultados.iter().enumerate(){
+            cadena.añadir(paso, caminante, &filas[paso].0, filas[paso].1);
+        }
+    }
+    cadena.escribir(&configuracion.salida)?;
+    let estadisticas = resultados.into_iter().map(|r| r.1).collect();
+    Ok(ResultadoHmc{cadena, estadisticas})
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::dual::{Dual, Flotante};
+    use crate::presets::Preset;
+
+    /// Gaussiana en (w, wa) con media y covarianza conocidas, sin cosmología
+    struct Gaussiana{
+        media: [f64; 2],
+        inversa_covarianza: [[f64; 2]; 2],
+    }
+
+    impl Gaussiana{
+        fn chi2_gaussiano<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> T{
+            let d = [parametros.w - self.media[0], parametros.wa - self.media[1]];
+            (0..2).map(|i| (0..2).map(|j| d[i]*d[j]*self.inversa_covarianza[i][j]).sum::<T>()).sum()
+        }
+    }
+
+    impl Verosimilitud for Gaussiana{
+        fn nombre(&self) -> &str{
+            "gaussiana"
+        }
+
+        fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+            self.chi2_gaussiano(parametros)
+        }
+
+        fn chi2_dual(&self, parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+            Some(self.chi2_gaussiano(parametros))
+        }
+
+        fn numero_datos(&self) -> usize{
+            2
+        }
+    }
+
+    #[test]
+    fn nuts_recupera_gaussiana(){
+        let media = [-0.9, 0.3];
+        let (sigma_w, sigma_wa, rho) = (0.2, 0.15, 0.6);
+        let covarianza = [[sigma_w*sigma_w, rho*sigma_w*sigma_wa], [rho*sigma_w*sigma_wa, sigma_wa*sigma_wa]];
+        let determinante = covarianza[0][0]*covarianza[1][1] - covarianza[0][1]*covarianza[1][0];
+        let inversa_covarianza = [[covarianza[1][1]/determinante, -covarianza[0][1]/determinante],
+                                  [-covarianza[1][0]/determinante, covarianza[0][0]/determinante]];
+        let verosimilitud = Gaussiana{media, inversa_covarianza};
+        // Priors mucho más anchos que la gaussiana, para que no la corten
+        let priors: Priors = [(Parametro::W, Prior::Uniforme(-10., 10.)), (Parametro::Wa, Prior::Uniforme(-10., 10.))].into();
+        let parametros = con_valores(&[Parametro::W, Parametro::Wa], &[-1., 0.], Preset::Planck18.parametros());
+        let salida = std::env::temp_dir().join(format!("nuts_{}.csv", std::process::id()));
+        let configuracion = ConfiguracionHmc{salida: salida.clone(), ..Default::default()};
+        let resultado = muestrear_hmc(&verosimilitud, parametros, &priors, &configuracion).unwrap();
+        std::fs::remove_file(salida).unwrap();
+
+        let muestras: Vec<&[f64]> = resultado.cadena.muestras(configuracion.adaptacion).collect();
+        assert_eq!(muestras.len(), configuracion.cadenas*configuracion.pasos);
+        let n = muestras.len() as f64;
+        let medias: Vec<f64> = (0..2).map(|i| muestras.iter().map(|m| m[i]).sum::<f64>()/n).collect();
+        for i in 0..2{
+            // Con ~4000 muestras casi independientes el error de la media es ~σ/60
+            assert!((medias[i] - media[i]).abs() < 0.1*covarianza[i][i].sqrt(), "media {i}: {} != {}", medias[i], media[i]);
+            for j in 0..2{
+                let estimada = muestras.iter().map(|m| (m[i] - medias[i])*(m[j] - medias[j])).sum::<f64>()/(n - 1.);
+                assert!((estimada - covarianza[i][j]).abs() < 0.1*(covarianza[i][i]*covarianza[j][j]).sqrt(),
+                        "covarianza {i}{j}: {estimada} != {}", covarianza[i][j]);
+            }
+        }
+        for estadisticas in &resultado.estadisticas{
+            assert_eq!(estadisticas.divergencias, 0);
+        }
+    }
+}
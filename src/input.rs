@@ -1,54 +1,333 @@
 
 use serde::{Serialize, Deserialize};
-use crate::{C_MPC_GY, S_TO_GY};
+use crate::{C_MPC_GY, KM_S_MPC_TO_GY, MetodoIntegracion};
+use crate::consts::OMEGA_RADIACION_H2;
+use crate::dual::Flotante;
+use crate::presets::Preset;
+use crate::models::EntradaModelo;
+use crate::cmb::cien_theta;
+use crate::analysis::{biseccion, z_maximo};
+use crate::texto;
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default)]
+/// Valores por defecto de los parámetros que no hace falta dar (ni en el fichero ni en un preset)
+const W_CONSTANTE_COSMOLOGICA: f64 = -1.;
+const DENSIDAD_BARIONES_PLANCK: f64 = 0.0493;
+const SIGMA8_PLANCK: f64 = 0.8111;
+
+/// Rango de h en el que se busca el que da el 100θ_* pedido
+const RANGO_H_THETA: (f64, f64) = (0.2, 1.5);
+
+/// Fichero de entrada. Las densidades y H0 se pueden dar a mano o partir de un `Preset` (`preset = "planck18"`);
+/// lo que esté en el fichero manda sobre el preset. Sin preset las densidades y H0 son obligatorias.
+///
+/// Además de {Ω_m, Ω_r, Ω_Λ, H0} se aceptan otras parametrizaciones, que se convierten a esa:
+/// - Ω_k (`densidad_curvatura`) en lugar de Ω_Λ, que cierra 1 = Ω_m + Ω_r + Ω_Λ + Ω_k
+/// - las densidades físicas ω_b = Ω_b h² y ω_c = Ω_c h² con `h`; Ω_r es la estándar para ese h y Ω_Λ cierra con Ω_k (0 si no se da)
+/// - 100θ_* (`cien_theta_estrella`, el tamaño angular del horizonte de sonido en el desacoplo) en lugar de H0, que se busca para reproducirlo.
+///   Es el θ_* de `cien_theta`, con el z_* de Hu & Sugiyama: no es el θ_MC de CosmoMC ni exactamente el θ_* de Planck. Con los
+///   parámetros de Planck 2018 da 1.0391 (Planck publica 100θ_* = 1.04110 y 100θ_MC = 1.04092), y con 1.0391 se recupera su H0.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[serde(default)]
 pub struct InputFile{
-    densidad_materia: f64,
-    densidad_radiacion: f64,
-    densidad_energia_oscura: f64,
-    constante_hubble: f64,
+    preset: Option<Preset>,
+
+    densidad_materia: Option<f64>,
+    densidad_radiacion: Option<f64>,
+    densidad_energia_oscura: Option<f64>,
+    // En km/s/Mpc
+    constante_hubble: Option<f64>,
+    // Ecuación de estado de la energía oscura p = wρ, por defecto constante cosmológica
+    w: Option<f64>,
+    // Evolución de w con el factor de escala, w(a) = w + wa (1-a)
+    wa: Option<f64>,
+    // Amplitud de las fluctuaciones hoy, sólo para fσ8. Por defecto la de Planck 2018
+    sigma8: Option<f64>,
+    // Parte de la materia que es bariónica, sólo se usa para el horizonte de sonido. Por defecto la de Planck 2018
+    densidad_bariones: Option<f64>,
+    // Ω_k, en lugar de densidad_energia_oscura
+    densidad_curvatura: Option<f64>,
+    // ω_b = Ω_b h², en lugar de densidad_bariones
+    densidad_fisica_bariones: Option<f64>,
+    // ω_c = Ω_c h² de la materia oscura fría, en lugar de densidad_materia (Ω_m = Ω_b + Ω_c)
+    densidad_fisica_cdm: Option<f64>,
+    // H0/(100 km/s/Mpc), en lugar de constante_hubble
+    h: Option<f64>,
+    // 100θ_* (ver `cien_theta`), en lugar de constante_hubble
+    cien_theta_estrella: Option<f64>,
 
     rango_factor_escala: (f64, f64),
     t_horizonte_particulas: f64,
     z_max_dist: f64,
-    z_max_edad: f64
+    z_max_edad: f64,
+
+    // Método de integración para las distancias y para la edad, por defecto Simpson adaptativo
+    metodo_distancia: MetodoIntegracion,
+    metodo_edad: MetodoIntegracion,
+}
+
+impl Default for InputFile{
+    fn default() -> Self{
+        InputFile{preset: None, densidad_materia: None, densidad_radiacion: None, densidad_energia_oscura: None, constante_hubble: None,
+                  w: None, wa: None, sigma8: None, densidad_bariones: None, densidad_curvatura: None, densidad_fisica_bariones: None,
+                  densidad_fisica_cdm: None, h: None, cien_theta_estrella: None, rango_factor_escala: (-13.8, 10.), t_horizonte_particulas: 0.,
+                  z_max_dist: 15., z_max_edad: 100., metodo_distancia: MetodoIntegracion::default(), metodo_edad: MetodoIntegracion::default()}
+    }
+}
+
+impl InputFile{
+    /// Sólo el preset, con el resto de opciones por defecto
+    pub fn desde_preset(preset: Preset) -> Self{
+        InputFile{preset: Some(preset), ..Default::default()}
+    }
+
+    /// Cambia el preset y descarta los parámetros cosmológicos del fichero, para que manden los del preset
+    /// (como con `--preset` en la línea de órdenes)
+    pub fn con_preset(self, preset: Preset) -> Self{
+        InputFile{preset: Some(preset), densidad_materia: None, densidad_radiacion: None, densidad_energia_oscura: None, constante_hubble: None,
+                  w: None, wa: None, sigma8: None, densidad_bariones: None, densidad_curvatura: None, densidad_fisica_bariones: None,
+                  densidad_fisica_cdm: None, h: None, cien_theta_estrella: None, ..self}
+    }
+
+    /// Los parámetros cosmológicos de un `[[modelo]]` sobre los del fichero. Si el modelo tiene preset se parte de él.
+    /// Si el modelo da un parámetro en otra parametrización (p.ej. `h` cuando el fichero tiene `constante_hubble`),
+    /// se descartan las demás formas de darlo del fichero.
+    pub fn con_modelo(self, modelo: &EntradaModelo) -> Self{
+        let base = match modelo.preset{
+            Some(preset) => self.con_preset(preset),
+            None => self,
+        };
+        let grupo = |del_modelo: &[Option<f64>], del_fichero: &[Option<f64>]| -> Vec<Option<f64>> {
+            if del_modelo.iter().any(Option::is_some) {del_modelo.to_vec()} else {del_fichero.to_vec()}
+        };
+        let hubble = grupo(&[modelo.constante_hubble, modelo.h, modelo.cien_theta_estrella], &[base.constante_hubble, base.h, base.cien_theta_estrella]);
+        let materia = grupo(&[modelo.densidad_materia, modelo.densidad_fisica_cdm], &[base.densidad_materia, base.densidad_fisica_cdm]);
+        let bariones = grupo(&[modelo.densidad_bariones, modelo.densidad_fisica_bariones], &[base.densidad_bariones, base.densidad_fisica_bariones]);
+        let cierre = grupo(&[modelo.densidad_energia_oscura, modelo.densidad_curvatura], &[base.densidad_energia_oscura, base.densidad_curvatura]);
+        InputFile{densidad_materia: materia[0], densidad_fisica_cdm: materia[1],
+                  densidad_radiacion: modelo.densidad_radiacion.or(base.densidad_radiacion),
+                  densidad_energia_oscura: cierre[0], densidad_curvatura: cierre[1],
+                  constante_hubble: hubble[0], h: hubble[1], cien_theta_estrella: hubble[2], w: modelo.w.or(base.w), wa: modelo.wa.or(base.wa),
+                  sigma8: modelo.sigma8.or(base.sigma8), densidad_bariones: bariones[0], densidad_fisica_bariones: bariones[1], ..base}
+    }
+
+    pub fn preset(&self) -> Option<Preset>{
+        self.preset
+    }
+}
+
+/// Los parámetros cosmológicos del fichero de entrada no determinan un modelo
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorEntrada{
+    /// Falta un parámetro obligatorio y no hay preset
+    Falta(&'static str),
+    /// Se ha dado el mismo parámetro de dos formas, p.ej. `constante_hubble` y `h`
+    Incompatibles(&'static str, &'static str),
+    /// Ningún h en `RANGO_H_THETA` da el 100θ_* pedido
+    ThetaInalcanzable(f64),
+}
+
+impl ErrorEntrada{
+    /// Campo del fichero de entrada al que se refiere el error
+    pub fn campo(&self) -> &'static str{
+        match self{
+            ErrorEntrada::Falta(campo) => campo,
+            ErrorEntrada::Incompatibles(_, campo) => campo,
+            ErrorEntrada::ThetaInalcanzable(_) => "cien_theta_estrella",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorEntrada{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result{
+        match self{
+            ErrorEntrada::Falta(campo) => write!(f, "{}", texto!(FaltaCampo, campo)),
+            ErrorEntrada::Incompatibles(uno, otro) => write!(f, "{}", texto!(CamposIncompatibles, uno, otro)),
+            ErrorEntrada::ThetaInalcanzable(theta) => write!(f, "{}", texto!(ThetaInalcanzable, RANGO_H_THETA.0, RANGO_H_THETA.1, theta)),
+        }
+    }
+}
+
+impl std::error::Error for ErrorEntrada{}
+
+/// (Ω_r, Ω_Λ) que cierran 1 = Ω_m + Ω_r + Ω_Λ + Ω_k. Si Ω_k = 0, Ω_r se redondea de forma que 1 - Ω_Λ - Ω_m - Ω_r sea
+/// exactamente 0 y `recalcular_derivados` trate el modelo como plano.
+pub(crate) fn cierre(densidad_materia: f64, densidad_radiacion: f64, densidad_curvatura: f64) -> (f64, f64){
+    let densidad_energia_oscura = 1. - densidad_materia - densidad_radiacion - densidad_curvatura;
+    if densidad_curvatura == 0. && densidad_radiacion != 0.{
+        (1. - densidad_energia_oscura - densidad_materia, densidad_energia_oscura)
+    }
+    else{
+        (densidad_radiacion, densidad_energia_oscura)
+    }
 }
 
-#[derive(Clone, Copy, Debug, Default)]
-pub struct ParametrosCosmologicos{
-    pub densidad_materia: f64,
-    pub densidad_radiacion: f64,
-    pub densidad_energia_oscura: f64,
-    pub densidad_curvatura: f64,
-    pub constante_hubble: f64,
+/// Parámetros del modelo. Las magnitudes que dependen de las densidades o de H0 son de tipo `T` para poder
+/// calcular con números duales (ver `dual::sembrar`); lo demás es configuración y siempre es `f64`.
+/// Se serializa para los manifiestos de las ejecuciones (ver `manifest`), con H0 y c en las unidades internas.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct ParametrosCosmologicos<T = f64>{
+    pub densidad_materia: T,
+    pub densidad_radiacion: T,
+    pub densidad_energia_oscura: T,
+    pub densidad_curvatura: T,
+    pub constante_hubble: T,
+    pub w: T,
+    pub wa: T,
+    pub sigma8: T,
+    pub densidad_bariones: T,
     pub factor_escala: f64,
     pub signo_curvatura: f64,
-    pub c: f64,
-    pub legua_cosmica_a_mpc: f64,
+    pub c: T,
+    pub legua_cosmica_a_mpc: T,
+    /// Redshift máximo observable, infinito si hay Big Bang (ver `analysis::z_maximo`). Buscarlo cuesta miles de
+    /// evaluaciones de E², así que se guarda aquí en lugar de repetirlo en cada distancia. No se guarda en los
+    /// manifiestos: al leerlos se recalcula.
+    #[serde(skip)]
+    pub z_maximo: f64,
 
     pub rango_factor_escala: (f64, f64),
     pub t_horizonte_particulas: f64,
     pub z_max_dist: f64,
-    pub z_max_edad: f64
+    pub z_max_edad: f64,
+
+    pub metodo_distancia: MetodoIntegracion,
+    pub metodo_edad: MetodoIntegracion,
 }
 
-impl std::convert::From<InputFile> for ParametrosCosmologicos{
-    fn from(value: InputFile) -> Self{
-        let densidad_materia = value.densidad_materia;
-        let densidad_radiacion = value.densidad_radiacion;
-        let densidad_energia_oscura = value.densidad_energia_oscura;
-        let constante_hubble = value.constante_hubble/3.08567758e19/S_TO_GY; // km/s/Mpc a 1/GY
-        let densidad_curvatura = 1. - densidad_energia_oscura - densidad_materia - densidad_radiacion;
-        let signo_curvatura = -if densidad_curvatura == 0. {0.} else {densidad_curvatura.signum()};
+impl<T: Flotante> ParametrosCosmologicos<T>{
+    /// Recalcula la densidad de curvatura, las unidades (c y la legua cósmica) y el redshift máximo a partir de las
+    /// demás densidades, de w y de la constante de Hubble. Hay que llamarla cada vez que se cambia alguna de ellas.
+    pub fn recalcular_derivados(&mut self){
+        let densidad_curvatura = T::from(1.) - self.densidad_energia_oscura - self.densidad_materia - self.densidad_radiacion;
+        let signo_curvatura = -if densidad_curvatura.real() == 0. {0.} else {densidad_curvatura.real().signum()};
 
-        let c =  if densidad_curvatura != 0. {densidad_curvatura.abs().sqrt() * 1. * constante_hubble} //  leguas cósmica / GY
-                     else{C_MPC_GY}; // legua cósmica = Mpc
+        let c =  if densidad_curvatura.real() != 0. {densidad_curvatura.abs().sqrt() * self.constante_hubble} //  leguas cósmica / GY
+                     else{T::from(C_MPC_GY)}; // legua cósmica = Mpc
         
-        let legua_cosmica_a_mpc = C_MPC_GY/c;
-        ParametrosCosmologicos{densidad_materia, densidad_radiacion, densidad_energia_oscura, 
-                                densidad_curvatura, constante_hubble, signo_curvatura, c, factor_escala: 1., legua_cosmica_a_mpc, 
-                                rango_factor_escala: value.rango_factor_escala, t_horizonte_particulas: value.t_horizonte_particulas,
-                            z_max_dist: value.z_max_dist, z_max_edad: value.z_max_edad}
-    } 
-}
\ No newline at end of file
+        self.densidad_curvatura = densidad_curvatura;
+        self.signo_curvatura = signo_curvatura;
+        self.c = c;
+        self.legua_cosmica_a_mpc = T::from(C_MPC_GY)/c;
+        self.z_maximo = z_maximo(self.real());
+    }
+
+    /// Los mismos parámetros con cada magnitud pasada por `f`
+    pub fn convertir<U>(self, f: impl Fn(T) -> U) -> ParametrosCosmologicos<U>{
+        ParametrosCosmologicos{densidad_materia: f(self.densidad_materia), densidad_radiacion: f(self.densidad_radiacion),
+            densidad_energia_oscura: f(self.densidad_energia_oscura), densidad_curvatura: f(self.densidad_curvatura),
+            constante_hubble: f(self.constante_hubble), w: f(self.w), wa: f(self.wa), sigma8: f(self.sigma8),
+            densidad_bariones: f(self.densidad_bariones), factor_escala: self.factor_escala, signo_curvatura: self.signo_curvatura,
+            c: f(self.c), legua_cosmica_a_mpc: f(self.legua_cosmica_a_mpc), z_maximo: self.z_maximo,
+            rango_factor_escala: self.rango_factor_escala, t_horizonte_particulas: self.t_horizonte_particulas,
+            z_max_dist: self.z_max_dist, z_max_edad: self.z_max_edad,
+            metodo_distancia: self.metodo_distancia, metodo_edad: self.metodo_edad}
+    }
+
+    /// h = H0/(100 km/s/Mpc)
+    pub fn h(&self) -> T{
+        self.constante_hubble/KM_S_MPC_TO_GY/100.
+    }
+
+    /// Los parámetros con la parte real de cada magnitud
+    pub fn real(self) -> ParametrosCosmologicos{
+        self.convertir(Flotante::real)
+    }
+}
+
+impl ParametrosCosmologicos{
+    /// Los mismos parámetros con otras densidades de materia y de energía oscura
+    pub fn con_densidades(mut self, densidad_materia: f64, densidad_energia_oscura: f64) -> Self{
+        self.densidad_materia = densidad_materia;
+        self.densidad_energia_oscura = densidad_energia_oscura;
+        self.recalcular_derivados();
+        self
+    }
+}
+
+impl InputFile{
+    /// Comprueba que cada parámetro se da de una sola forma
+    fn comprobar_incompatibles(&self) -> Result<(), ErrorEntrada>{
+        let pares = [(self.constante_hubble, "constante_hubble", self.h, "h"),
+                     (self.constante_hubble, "constante_hubble", self.cien_theta_estrella, "cien_theta_estrella"),
+                     (self.h, "h", self.cien_theta_estrella, "cien_theta_estrella"),
+                     (self.densidad_materia, "densidad_materia", self.densidad_fisica_cdm, "densidad_fisica_cdm"),
+                     (self.densidad_bariones, "densidad_bariones", self.densidad_fisica_bariones, "densidad_fisica_bariones"),
+                     (self.densidad_energia_oscura, "densidad_energia_oscura", self.densidad_curvatura, "densidad_curvatura")];
+        match pares.into_iter().find(|(uno, _, otro, _)| uno.is_some() && otro.is_some()){
+            Some((_, uno, _, otro)) => Err(ErrorEntrada::Incompatibles(uno, otro)),
+            None => Ok(()),
+        }
+    }
+
+    /// Los parámetros del fichero con H0 (en km/s/Mpc) ya conocido
+    fn parametros_con_h0(&self, constante_hubble: f64) -> Result<ParametrosCosmologicos, ErrorEntrada>{
+        let preset = self.preset.map(Preset::valores);
+        let obligatorio = |valor: Option<f64>, del_preset: Option<f64>, nombre| valor.or(del_preset).ok_or(ErrorEntrada::Falta(nombre));
+        let opcional = |valor: Option<f64>, del_preset: Option<f64>, defecto: f64| valor.or(del_preset).unwrap_or(defecto);
+        // Con densidades físicas o θ_* las densidades dependen de h, así que la radiación es la estándar y Ω_Λ cierra
+        let fisica = self.densidad_fisica_bariones.is_some() || self.densidad_fisica_cdm.is_some() || self.cien_theta_estrella.is_some();
+        let h2 = (constante_hubble/100.).powi(2);
+
+        let densidad_bariones = opcional(self.densidad_fisica_bariones.map(|omega| omega/h2).or(self.densidad_bariones),
+                                         preset.map(|p| p.densidad_bariones), DENSIDAD_BARIONES_PLANCK);
+        let densidad_materia = obligatorio(self.densidad_fisica_cdm.map(|omega| densidad_bariones + omega/h2).or(self.densidad_materia),
+                                           preset.map(|p| p.densidad_materia), "densidad_materia")?;
+        let densidad_radiacion = match self.densidad_radiacion{
+            Some(densidad_radiacion) => densidad_radiacion,
+            None if fisica => OMEGA_RADIACION_H2/h2,
+            None => obligatorio(None, preset.map(|p| p.densidad_radiacion), "densidad_radiacion")?,
+        };
+        let (densidad_radiacion, densidad_energia_oscura) = match (self.densidad_energia_oscura, self.densidad_curvatura){
+            (Some(densidad_energia_oscura), _) => (densidad_radiacion, densidad_energia_oscura),
+            (None, Some(densidad_curvatura)) => cierre(densidad_materia, densidad_radiacion, densidad_curvatura),
+            (None, None) if fisica => cierre(densidad_materia, densidad_radiacion, 0.),
+            (None, None) => (densidad_radiacion, obligatorio(None, preset.map(|p| p.densidad_energia_oscura), "densidad_energia_oscura")?),
+        };
+
+        let mut parametros = ParametrosCosmologicos{
+            densidad_materia, densidad_radiacion, densidad_energia_oscura,
+            constante_hubble: constante_hubble*KM_S_MPC_TO_GY,
+            w: opcional(self.w, preset.map(|p| p.w), W_CONSTANTE_COSMOLOGICA),
+            wa: opcional(self.wa, preset.map(|p| p.wa), 0.),
+            sigma8: opcional(self.sigma8, preset.map(|p| p.sigma8), SIGMA8_PLANCK),
+            densidad_bariones,
+            factor_escala: 1.,
+            rango_factor_escala: self.rango_factor_escala, t_horizonte_particulas: self.t_horizonte_particulas,
+            z_max_dist: self.z_max_dist, z_max_edad: self.z_max_edad,
+            metodo_distancia: self.metodo_distancia, metodo_edad: self.metodo_edad, ..Default::default()};
+        parametros.recalcular_derivados();
+        Ok(parametros)
+    }
+
+    /// Busca por bisección el h con el que el modelo da el 100θ_* pedido (θ_* crece con h con las densidades físicas fijas)
+    fn parametros_con_theta(&self, theta: f64) -> Result<ParametrosCosmologicos, ErrorEntrada>{
+        let diferencia = |h: f64| -> Result<f64, ErrorEntrada> {
+            let parametros = self.parametros_con_h0(100.*h)?;
+            Ok(cien_theta(parametros).unwrap_or(f64::NAN) - theta)
+        };
+        let (min, max) = RANGO_H_THETA;
+        let (en_min, en_max) = (diferencia(min)?, diferencia(max)?);
+        // También si alguno es NaN
+        if en_min*en_max > 0. || en_min.is_nan() || en_max.is_nan(){
+            return Err(ErrorEntrada::ThetaInalcanzable(theta));
+        }
+        let h = biseccion(&|h| diferencia(h).unwrap_or(f64::NAN), min, max);
+        self.parametros_con_h0(100.*h)
+    }
+}
+
+impl std::convert::TryFrom<InputFile> for ParametrosCosmologicos{
+    type Error = ErrorEntrada;
+
+    fn try_from(value: InputFile) -> Result<Self, ErrorEntrada>{
+        value.comprobar_incompatibles()?;
+        match (value.constante_hubble.or(value.h.map(|h| 100.*h)), value.cien_theta_estrella){
+            (Some(constante_hubble), _) => value.parametros_con_h0(constante_hubble),
+            (None, Some(theta)) => value.parametros_con_theta(theta),
+            (None, None) => {
+                let constante_hubble = value.preset.map(|p| p.valores().constante_hubble).ok_or(ErrorEntrada::Falta("constante_hubble"))?;
+                value.parametros_con_h0(constante_hubble)
+            }
+        }
+    }
+}
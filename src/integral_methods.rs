@@ -1,60 +1,75 @@
 use core::f64;
 use std::fmt;
 
+use crate::dual::Flotante;
+use crate::texto;
+
 /// Versión modificada del crate integrate (https://docs.rs/integrate/0.1.4/integrate/index.html) para permitir aceptar closures (lambda functions en python). 
 /// también permite calcular integrales indefinidas con límites infinitos con un cambio de variable.
+/// Los integrandos pueden devolver cualquier `Flotante` (p.ej. números duales); la variable de integración
+/// y las decisiones sobre el error usan siempre f64, así que la regla se deriva junto con el integrando.
 //---------------------------------------------------------
 #[derive(Clone, Debug)]
 struct SubInterval<F> {
-    upper_limit: F,
-    lower_limit: F,
+    upper_limit: f64,
+    lower_limit: f64,
     function: [F; 5],
     interval: Option<Box<SubInterval<F>>>,
 }
 
 type Result<T> = std::result::Result<T, AdaptiveSimpsonError>;
 
+/// Simpson no ha llegado a la tolerancia. `partial` es la integral de la parte del intervalo que sí se ha aceptado.
 #[derive(Debug, Clone)]
-pub struct AdaptiveSimpsonError;
+pub struct AdaptiveSimpsonError{
+    pub partial: f64,
+}
 
 impl fmt::Display for AdaptiveSimpsonError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let msg = "No subinterval of length > min_h was found for which the estimated error was less that the pro-rated tolerance";
-        write!(f, "{}", msg)
+        write!(f, "{}", texto!(SimpsonSinSubintervalo, self.partial))
     }
 }
 
+/// Máximo de evaluaciones de la función en `adaptive_simpson_method`. Un integrando que no converge (p.ej. con una
+/// singularidad no integrable) seguiría dividiendo subintervalos indefinidamente.
+const MAX_EVALUACIONES_SIMPSON: usize = 1_000_000;
+
 
-pub fn adaptive_simpson_method(
-    f: impl Fn(f64) -> f64,
+pub fn adaptive_simpson_method<T: Flotante>(
+    f: impl Fn(f64) -> T,
     a: f64,
     b: f64,
     min_h: f64,
     tolerance: f64,
-) -> Result<f64> {
+) -> Result<T> {
+    // Los subintervalos se recorren de izquierda a derecha: con los límites al revés no se llegaría nunca a la tolerancia
+    if b < a{
+        return adaptive_simpson_method(f, b, a, min_h, tolerance).map(|integral| -integral)
+    }
     // Si alguno de los límites es infinito arctan(inf) = pi/2
     // y la integral ya es definida
     if a.is_infinite() || b.is_infinite(){
         return helper_func(|x| f(x.tan())/x.cos().powi(2), a.atan(), b.atan(), min_h, tolerance)
     }
     return helper_func(f, a, b, min_h, tolerance);
-    fn helper_func(f: impl Fn(f64) -> f64,
+    fn helper_func<T: Flotante>(f: impl Fn(f64) -> T,
     a: f64,
     b: f64,
     min_h: f64,
-    tolerance: f64,) -> Result<f64>{
+    tolerance: f64,) -> Result<T>{
     let two = 2.;
 
-    let mut integral: f64 = 0.0;
+    let mut integral = T::default();
     let epsilon_density = two * tolerance / (b - a);
 
     // Create the initial level, with lower_limit = a, upper_limit = b,
     // and f(x) evaluated at a, b, and (a + b) / 2.
 
-    let interval: SubInterval<f64> = SubInterval {
+    let interval: SubInterval<T> = SubInterval {
         upper_limit: b,
         lower_limit: a,
-        function: [f(a), f64::NAN, f((a + b) / two), f64::NAN, f(b)],
+        function: [f(a), T::from(f64::NAN), f((a + b) / two), T::from(f64::NAN), f(b)],
         interval: None,
     };
 
@@ -66,11 +81,12 @@ pub fn adaptive_simpson_method(
 
     let mut epsilon = epsilon_density * (b - a);
     let (mut s1, mut s2) = simpson_rule_update(&f, &mut pinterval);
+    let mut evaluaciones = 5;
 
-    let mut qinterval: SubInterval<f64>;
+    let mut qinterval: SubInterval<T>;
 
     while pinterval.upper_limit - pinterval.lower_limit > min_h {
-        if (s1 - s2).abs() < epsilon {
+        if (s1 - s2).real().abs() < epsilon {
             // If the two estimates are close, then increment the
             // integral and if we are not at the right end, set the
             // left end of the new interval to the right end of the
@@ -98,6 +114,11 @@ pub fn adaptive_simpson_method(
 
             let limit1 = pinterval.lower_limit;
             let limit2 = (pinterval.upper_limit + pinterval.lower_limit) / two;
+            // Si min_h es menor que la separación entre flotantes (p.ej. cerca de u = π/2 tras el cambio x = tan(u)) el punto
+            // medio se redondea a un extremo: dividir ya no avanza y la lista de subintervalos crecería sin fin
+            if limit2 == pinterval.lower_limit || limit2 == pinterval.upper_limit || evaluaciones >= MAX_EVALUACIONES_SIMPSON {
+                return Err(AdaptiveSimpsonError{partial: integral.real()});
+            }
 
             let upper_limit = if limit1 > limit2 { limit1 } else { limit2 };
             let lower_limit = if limit1 > limit2 { limit2 } else { limit1 };
@@ -105,7 +126,7 @@ pub fn adaptive_simpson_method(
             qinterval = SubInterval {
                 lower_limit,
                 upper_limit,
-                function: [f64::NAN; 5],
+                function: [T::from(f64::NAN); 5],
                 interval: None,
             };
 
@@ -120,16 +141,17 @@ pub fn adaptive_simpson_method(
 
         // Update Simpson's rule for the new interval
         (s1, s2) = simpson_rule_update(&f, &mut pinterval);
+        evaluaciones += 2;
         epsilon = epsilon_density * (pinterval.upper_limit - pinterval.lower_limit);
     }
-    Err(AdaptiveSimpsonError)
+    Err(AdaptiveSimpsonError{partial: integral.real()})
 }
 }
 
-fn simpson_rule_update(
-    f: &dyn Fn(f64) -> f64,
-    pinterval: &mut SubInterval<f64>,
-) -> (f64, f64) {
+fn simpson_rule_update<T: Flotante>(
+    f: &dyn Fn(f64) -> T,
+    pinterval: &mut SubInterval<T>,
+) -> (T, T) {
     let two = 2.;
     let four = two + two;
     let six = four + two;
@@ -140,24 +162,275 @@ fn simpson_rule_update(
     pinterval.function[1] = f(pinterval.lower_limit + h4);
     pinterval.function[3] = f(pinterval.upper_limit - h4);
 
-    let mut s1 = pinterval.function[0] + four * pinterval.function[2] + pinterval.function[4];
-    s1 *= h / six;
+    let mut s1 = pinterval.function[0] + pinterval.function[2] * four + pinterval.function[4];
+    s1 = s1 * (h / six);
 
     let mut s2 = pinterval.function[0]
-        + four * pinterval.function[1]
-        + two * pinterval.function[2]
-        + four * pinterval.function[3]
+        + pinterval.function[1] * four
+        + pinterval.function[2] * two
+        + pinterval.function[3] * four
         + pinterval.function[4];
-    s2 *= h / (six * two);
+    s2 = s2 * (h / (six * two));
 
     (s1, s2)
 }
 
+//---------------------------------------
+// Cuadraturas con estimación del error: Gauss-Kronrod (G7K15) adaptativa y tanh-sinh (doble exponencial).
+
+/// Resultado de una cuadratura: valor, estimación del error absoluto y número de evaluaciones de la función.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QuadratureResult<T = f64> {
+    pub value: T,
+    pub error: f64,
+    pub evaluations: usize,
+}
+
+impl<T: Flotante> QuadratureResult<T> {
+    /// El mismo resultado con la parte real del valor
+    pub fn real(self) -> QuadratureResult {
+        QuadratureResult{value: self.value.real(), error: self.error, evaluations: self.evaluations}
+    }
+}
+
+/// Error de una cuadratura que no ha alcanzado la tolerancia pedida. Guarda el resultado parcial
+/// (con el error que sí se ha alcanzado) para que quien llama pueda decidir si le sirve.
+#[derive(Debug, Clone, Copy)]
+pub struct QuadratureError {
+    pub partial: QuadratureResult,
+    pub tolerance: f64,
+}
+
+impl fmt::Display for QuadratureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", texto!(CuadraturaSinTolerancia, format!("{:e}", self.tolerance), self.partial.evaluations, self.partial.value,
+                                      format!("{:e}", self.partial.error)))
+    }
+}
+
+impl std::error::Error for QuadratureError {}
+
+/// Método de integración numérica, se puede escoger uno distinto para cada integral
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetodoIntegracion {
+    #[default]
+    Simpson,
+    GaussKronrod,
+    TanhSinh,
+}
+
+/// Integra f entre a y b con el método pedido. min_h es el tamaño mínimo de los subintervalos
+/// en los métodos adaptativos (Simpson y Gauss-Kronrod), tanh-sinh no lo usa.
+pub fn integrate<T: Flotante>(
+    metodo: MetodoIntegracion,
+    f: impl Fn(f64) -> T,
+    a: f64,
+    b: f64,
+    min_h: f64,
+    tolerance: f64,
+) -> std::result::Result<QuadratureResult<T>, QuadratureError> {
+    // Los métodos adaptativos no saben tratar un intervalo de longitud nula
+    if a == b {
+        return Ok(QuadratureResult::default());
+    }
+    match metodo {
+        MetodoIntegracion::Simpson => {
+            let evaluations = std::cell::Cell::new(0);
+            let value = adaptive_simpson_method(|x| {evaluations.set(evaluations.get()+1); f(x)}, a, b, min_h, tolerance);
+            let evaluations = evaluations.get();
+            // Simpson sólo garantiza que el error está por debajo de la tolerancia
+            value.map(|value| QuadratureResult{value, error: tolerance, evaluations})
+                 .map_err(|err| QuadratureError{partial: QuadratureResult{value: err.partial, error: f64::INFINITY, evaluations}, tolerance})
+        }
+        MetodoIntegracion::GaussKronrod => gauss_kronrod_method(f, a, b, min_h, tolerance),
+        MetodoIntegracion::TanhSinh => tanh_sinh_method(f, a, b, tolerance),
+    }
+}
+
+// Nodos y pesos de Kronrod (15 puntos) y de Gauss (7 puntos), en [-1, 1] y sólo la mitad positiva.
+#[allow(clippy::excessive_precision)]
+const XGK: [f64; 8] = [
+    0.991455371120812639206854697526329, 0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926, 0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013, 0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245, 0.000000000000000000000000000000000,
+];
+#[allow(clippy::excessive_precision)]
+const WGK: [f64; 8] = [
+    0.022935322010529224963732008058970, 0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518, 0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550, 0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649, 0.209482141084727828012999174891714,
+];
+// Los nodos de Gauss son XGK[1], XGK[3], XGK[5] y XGK[7]
+#[allow(clippy::excessive_precision)]
+const WG: [f64; 4] = [
+    0.129484966168869693270611432679082, 0.279705391489276667901467771423780,
+    0.381830050505118944950369775488975, 0.417959183673469387755102040816327,
+];
+
+/// Regla G7K15 en [a, b], devuelve (integral de Kronrod, |Kronrod - Gauss|)
+fn gauss_kronrod_15<T: Flotante>(f: &dyn Fn(f64) -> T, a: f64, b: f64) -> (T, f64) {
+    let centro = (a + b) / 2.;
+    let radio = (b - a) / 2.;
+    let f_centro = f(centro);
+    let mut kronrod = f_centro * WGK[7];
+    let mut gauss = f_centro * WG[3];
+    for i in 0..7 {
+        let suma = f(centro - radio * XGK[i]) + f(centro + radio * XGK[i]);
+        kronrod += suma * WGK[i];
+        if i % 2 == 1 {
+            gauss += suma * WG[i / 2];
+        }
+    }
+    (kronrod * radio, ((kronrod - gauss) * radio).real().abs())
+}
+
+/// Cuadratura adaptativa de Gauss-Kronrod (G7K15). En cada paso se divide por la mitad el subintervalo
+/// con mayor error estimado hasta que la suma de los errores es menor que la tolerancia.
+/// Los límites infinitos se tratan con el mismo cambio de variable x = tan(u) que `adaptive_simpson_method`.
+/// Si algún subintervalo tendría que dividirse por debajo de min_h se devuelve el resultado parcial como error.
+pub fn gauss_kronrod_method<T: Flotante>(
+    f: impl Fn(f64) -> T,
+    a: f64,
+    b: f64,
+    min_h: f64,
+    tolerance: f64,
+) -> std::result::Result<QuadratureResult<T>, QuadratureError> {
+    if a.is_infinite() || b.is_infinite(){
+        return helper_func(&|x: f64| f(x.tan())/x.cos().powi(2), a.atan(), b.atan(), min_h, tolerance)
+    }
+    return helper_func(&f, a, b, min_h, tolerance);
+    fn helper_func<T: Flotante>(f: &dyn Fn(f64) -> T,
+    a: f64,
+    b: f64,
+    min_h: f64,
+    tolerance: f64,) -> std::result::Result<QuadratureResult<T>, QuadratureError>{
+        // (a, b, integral, error)
+        let (value, error) = gauss_kronrod_15(f, a, b);
+        let mut intervals = vec![(a, b, value, error)];
+        let mut result = QuadratureResult{value, error, evaluations: 15};
+        while result.error > tolerance || result.error.is_nan() {
+            let (i, &(a, b, value, error)) = intervals.iter().enumerate()
+                .max_by(|x, y| x.1.3.total_cmp(&y.1.3)).unwrap();
+            if (b - a).abs() / 2. < min_h || !error.is_finite() {
+                return Err(QuadratureError{partial: result.real(), tolerance});
+            }
+            let medio = (a + b) / 2.;
+            let (value_1, error_1) = gauss_kronrod_15(f, a, medio);
+            let (value_2, error_2) = gauss_kronrod_15(f, medio, b);
+            intervals[i] = (a, medio, value_1, error_1);
+            intervals.push((medio, b, value_2, error_2));
+            result.value += value_1 + value_2 - value;
+            result.error += error_1 + error_2 - error;
+            result.evaluations += 30;
+            // Se recalcula de vez en cuando para que no se acumule el error de redondeo en las sumas
+            if intervals.len() % 64 == 0 {
+                result.value = intervals.iter().map(|x| x.2).sum();
+                result.error = intervals.iter().map(|x| x.3).sum();
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Cuadratura tanh-sinh (doble exponencial) en [a, b]. El cambio x = tanh(π/2 sinh t) concentra los puntos
+/// cerca de los extremos así que funciona bien con singularidades integrables en los límites (p.ej. 1/√x).
+/// La función nunca se evalúa en los extremos. Se divide el paso por la mitad hasta que dos niveles
+/// consecutivos difieren menos que la tolerancia, que es la estimación del error que se devuelve.
+/// Los límites infinitos se tratan con el mismo cambio de variable x = tan(u) que `adaptive_simpson_method`.
+pub fn tanh_sinh_method<T: Flotante>(
+    f: impl Fn(f64) -> T,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+) -> std::result::Result<QuadratureResult<T>, QuadratureError> {
+    // Los nodos se filtran con a < x < b, así que con los límites al revés se integra al derecho y se cambia el signo
+    if b < a{
+        return tanh_sinh_method(f, b, a, tolerance)
+            .map(|resultado| QuadratureResult{value: -resultado.value, ..resultado})
+            .map_err(|err| QuadratureError{partial: QuadratureResult{value: -err.partial.value, ..err.partial}, ..err})
+    }
+    if a.is_infinite() || b.is_infinite(){
+        return helper_func(&|x: f64| f(x.tan())/x.cos().powi(2), a.atan(), b.atan(), tolerance)
+    }
+    return helper_func(&f, a, b, tolerance);
+    fn helper_func<T: Flotante>(f: &dyn Fn(f64) -> T,
+    a: f64,
+    b: f64,
+    tolerance: f64,) -> std::result::Result<QuadratureResult<T>, QuadratureError>{
+        const MAX_LEVEL: u32 = 12;
+        // En |t| = 4 los nodos están a ~1e-37 del extremo, así que incluso con singularidades
+        // como 1/√x lo que queda fuera es despreciable
+        const T_MAX: f64 = 4.;
+        let half_pi = f64::consts::FRAC_PI_2;
+        let centro = (a + b) / 2.;
+        let radio = (b - a) / 2.;
+        // Contribución de los nodos ±t. La distancia al extremo se calcula como 1/(e^u cosh u)
+        // en lugar de 1 - tanh(u) para no perder precisión cerca de los límites.
+        // Suma también las evaluaciones y devuelve false si alguna no es finita.
+        let par = |t: f64, suma: &mut T, evaluaciones: &mut usize| -> bool {
+            let u = half_pi * t.sinh();
+            let complemento = 1. / (u.exp() * u.cosh());
+            let peso = half_pi * t.cosh() / u.cosh().powi(2);
+            for x in [b - radio * complemento, a + radio * complemento] {
+                if x > a && x < b {
+                    let y = f(x);
+                    *evaluaciones += 1;
+                    if !y.is_finite() {
+                        return false;
+                    }
+                    *suma += y * peso;
+                }
+            }
+            true
+        };
+        // Un nodo con valor no finito estropea la suma: se devuelve lo acumulado hasta entonces como resultado parcial
+        let no_finito = |suma: T, h: f64, evaluations: usize|
+            QuadratureError{partial: QuadratureResult{value: (suma * h * radio).real(), error: f64::INFINITY, evaluations}, tolerance};
+
+        let mut h = 1.;
+        let mut suma = f(centro) * half_pi;
+        let mut evaluations = 1;
+        if !suma.is_finite() {
+            return Err(no_finito(T::default(), h, evaluations));
+        }
+        let mut k = 1;
+        while k as f64 * h <= T_MAX {
+            if !par(k as f64 * h, &mut suma, &mut evaluations) {
+                return Err(no_finito(suma, h, evaluations));
+            }
+            k += 1;
+        }
+        let mut value = suma * h * radio;
+        let mut error = f64::INFINITY;
+        for _ in 0..MAX_LEVEL {
+            h /= 2.;
+            // En cada nivel sólo hace falta evaluar los nodos impares
+            let mut k = 1;
+            while k as f64 * h <= T_MAX {
+                if !par(k as f64 * h, &mut suma, &mut evaluations) {
+                    return Err(no_finito(suma, 2. * h, evaluations));
+                }
+                k += 2;
+            }
+            let nuevo = suma * h * radio;
+            error = (nuevo - value).real().abs();
+            value = nuevo;
+            if error <= tolerance {
+                return Ok(QuadratureResult{value, error, evaluations});
+            }
+        }
+        Err(QuadratureError{partial: QuadratureResult{value: value.real(), error, evaluations}, tolerance})
+    }
+}
+
 //---------------------------------------
 
-pub fn runge_kutta<T>(f: impl Fn(f64, f64, &mut T) -> f64, delta_x: f64, initial_x: f64, initial_condition: f64, steps: u64, params: &mut T) -> f64{
+pub fn runge_kutta<T: Flotante, P>(f: impl Fn(f64, T, &mut P) -> T, delta_x: f64, initial_x: f64, initial_condition: T, steps: u64, params: &mut P) -> T{
     if initial_condition.is_nan(){
-        return f64::NAN;
+        return T::from(f64::NAN);
     }
     let h = delta_x/steps as f64;
     let half_h = h/2.0;
@@ -169,18 +442,18 @@ pub fn runge_kutta<T>(f: impl Fn(f64, f64, &mut T) -> f64, delta_x: f64, initial
     let mut x = initial_x;
     for i in 0..steps{
         k1 = f(x, y, params);
-        k2 = f(x+half_h, y+half_h*k1, params);
-        k3 = f(x+half_h, y+half_h*k2, params);
+        k2 = f(x+half_h, y+k1*half_h, params);
+        k3 = f(x+half_h, y+k2*half_h, params);
         k4 = f(x+h, y+k3*h, params);
         
-        y = y + h*(k1 +2.*k2+2.*k3+k4)/6.;
-        x = initial_x + i as f64*h;
+        y += (k1 + k2*2. + k3*2. + k4)*h/6.;
+        x = initial_x + (i+1) as f64*h;
     }
     y
 }
 
 
-pub fn runge_kutta_at_points<T>(f: impl Fn(f64, f64, &mut T) -> f64, xx: &Vec<f64>, initial_condition: f64, sub_steps: u64, mut params: T) -> Vec<f64>{
+pub fn runge_kutta_at_points<T: Flotante, P>(f: impl Fn(f64, T, &mut P) -> T, xx: &[f64], initial_condition: T, sub_steps: u64, mut params: P) -> Vec<T>{
     let mut yy = Vec::with_capacity(xx.len());
     yy.push(initial_condition);
     for i in 1..xx.len(){
@@ -189,8 +462,103 @@ pub fn runge_kutta_at_points<T>(f: impl Fn(f64, f64, &mut T) -> f64, xx: &Vec<f6
     yy
 }
 
-pub fn trapezoid_method(xx: &[f64], yy: &[f64]) -> f64{
+pub fn trapezoid_method<T: Flotante>(xx: &[f64], yy: &[T]) -> T{
     let dx: Vec<_> = xx.windows(2).map(|sl| sl[1]-sl[0]).collect();
     let parl: Vec<_> = yy.windows(2).map(|sl| (sl[0]+sl[1])/2.).collect();
-    dx.into_iter().zip(parl.into_iter()).map(|(a, b)| a*b).sum()
+    dx.into_iter().zip(parl).map(|(a, b)| b*a).sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METODOS: [MetodoIntegracion; 3] = [MetodoIntegracion::Simpson, MetodoIntegracion::GaussKronrod, MetodoIntegracion::TanhSinh];
+
+    /// Integra con el método pedido y comprueba el resultado contra el valor exacto
+    fn comprobar(metodo: MetodoIntegracion, f: impl Fn(f64) -> f64, a: f64, b: f64, exacto: f64, tolerancia: f64) {
+        let resultado = integrate(metodo, f, a, b, 1e-12, tolerancia)
+            .unwrap_or_else(|err| panic!("{:?} en [{}, {}]: {}", metodo, a, b, err));
+        let error = (resultado.value - exacto).abs();
+        assert!(error <= 10. * tolerancia, "{:?} en [{}, {}]: {} en lugar de {} (error {:e})", metodo, a, b, resultado.value, exacto, error);
+        assert!(resultado.evaluations > 0);
+    }
+
+    #[test]
+    fn funciones_suaves() {
+        for metodo in METODOS {
+            comprobar(metodo, f64::sin, 0., f64::consts::PI, 2., 1e-9);
+            comprobar(metodo, f64::exp, 0., 1., f64::consts::E - 1., 1e-9);
+            comprobar(metodo, |x| x.powi(5) - 3. * x, -1., 2., 21. / 2. - 4.5, 1e-9);
+            // Con los límites al revés cambia el signo
+            comprobar(metodo, f64::exp, 1., 0., 1. - f64::consts::E, 1e-9);
+        }
+    }
+
+    #[test]
+    fn limites_infinitos() {
+        for metodo in METODOS {
+            comprobar(metodo, |x| (-x).exp(), 0., f64::INFINITY, 1., 1e-8);
+            comprobar(metodo, |x| 1. / (x * x), 1., f64::INFINITY, 1., 1e-8);
+            comprobar(metodo, |x| 1. / (1. + x * x), f64::NEG_INFINITY, f64::INFINITY, f64::consts::PI, 1e-8);
+        }
+    }
+
+    #[test]
+    fn singularidades_en_los_extremos() {
+        // Simpson evalúa los extremos, así que sólo sirven los que no lo hacen
+        for metodo in [MetodoIntegracion::GaussKronrod, MetodoIntegracion::TanhSinh] {
+            comprobar(metodo, |x| 1. / x.sqrt(), 0., 1., 2., 1e-7);
+            comprobar(metodo, f64::ln, 0., 1., -1., 1e-8);
+            comprobar(metodo, |x| 1. / (1. - x * x).sqrt(), -1., 1., f64::consts::PI, 1e-6);
+        }
+    }
+
+    #[test]
+    fn intervalo_de_longitud_nula() {
+        for metodo in METODOS {
+            for a in [0., 1.5, -3.] {
+                let resultado = integrate(metodo, |x| 1. / x, a, a, 1e-12, 1e-9).unwrap();
+                assert_eq!(resultado.value, 0., "{:?} en [{}, {}]", metodo, a, a);
+            }
+        }
+    }
+
+    #[test]
+    fn estimacion_del_error() {
+        // El error devuelto tiene que acotar el error real
+        for (f, exacto) in [(f64::sin as fn(f64) -> f64, 2.), (|x: f64| x.sqrt(), 2. / 3. * f64::consts::PI.powf(1.5))] {
+            for resultado in [gauss_kronrod_method(f, 0., f64::consts::PI, 1e-12, 1e-6).unwrap(),
+                              tanh_sinh_method(f, 0., f64::consts::PI, 1e-6).unwrap()] {
+                assert!(resultado.error <= 1e-6);
+                assert!((resultado.value - exacto).abs() <= resultado.error.max(1e-14), "{:?}", resultado);
+            }
+        }
+    }
+
+    #[test]
+    fn sin_alcanzar_la_tolerancia() {
+        // 1/x no es integrable en 0: tiene que devolver un error con el resultado parcial, no un valor
+        let err = gauss_kronrod_method(|x| 1. / x, 0., 1., 1e-6, 1e-8).unwrap_err();
+        assert_eq!(err.tolerance, 1e-8);
+        assert!(err.partial.error > 1e-8);
+        assert!(integrate(MetodoIntegracion::Simpson, |x| 1. / x, 0., 1., 1e-6, 1e-8).is_err());
+    }
+
+    #[test]
+    fn integrando_que_no_converge() {
+        // ∫ dx/x hasta infinito diverge. Con x = tan(u) y un min_h que no se puede representar cerca de u = π/2
+        // Simpson tiene que parar cuando el punto medio coincide con un extremo en lugar de seguir dividiendo
+        for a in [0.5, 1., 2., 3.] {
+            let err = integrate(MetodoIntegracion::Simpson, |x| 1. / x, a, f64::INFINITY, 1e-20, 1e-7).unwrap_err();
+            assert!(err.partial.value.is_finite() && err.partial.value > 0., "a = {}: {}", a, err);
+            assert!(err.partial.evaluations <= MAX_EVALUACIONES_SIMPSON + 5);
+        }
+        // Con ~1e11 oscilaciones se agotan las evaluaciones mucho antes de llegar a min_h
+        let err = integrate(MetodoIntegracion::Simpson, |x| (1e12 * x).sin(), 0., 1., 1e-300, 1e-14).unwrap_err();
+        assert!(err.partial.evaluations <= MAX_EVALUACIONES_SIMPSON + 5);
+        // tanh-sinh no puede ignorar un nodo en el que el integrando no es finito
+        let err = tanh_sinh_method(|x| if x > 0.9 { f64::NAN } else { 1. }, 0., 1., 1e-8).unwrap_err();
+        assert!(err.partial.error.is_infinite());
+    }
 }
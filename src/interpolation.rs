@@ -0,0 +1,317 @@
+use core::f64;
+
+use crate::input::ParametrosCosmologicos;
+use crate::integral_methods::{integrate, MetodoIntegracion};
+use crate::functions::{e, error_integral, lookback_time, s_k};
+use crate::error::{CausaError, ErrorCosmologico, Magnitud};
+use crate::plotting::linspace;
+use crate::analysis::z_maximo;
+
+
+/// Spline cúbico sobre una malla estrictamente creciente. En los extremos se fija la primera derivada a la del
+/// polinomio cúbico que pasa por los cuatro puntos más cercanos, así el error es O(h⁴) también cerca de los bordes
+/// (con la condición natural, segunda derivada nula, el error en los bordes sólo sería O(h²)).
+/// La evaluación busca el intervalo por bisección así que cada consulta es O(log n).
+#[derive(Clone, Debug)]
+pub struct CubicSpline{
+    xx: Vec<f64>,
+    yy: Vec<f64>,
+    // segundas derivadas en los nodos
+    m: Vec<f64>,
+}
+
+/// Derivada en xx[0] del polinomio que interpola los puntos dados
+fn derivada_lagrange(xx: &[f64], yy: &[f64]) -> f64{
+    let x0 = xx[0];
+    let mut derivada = yy[0]*xx[1..].iter().map(|xk| 1./(x0-xk)).sum::<f64>();
+    for j in 1..xx.len(){
+        let num: f64 = (1..xx.len()).filter(|&k| k != j).map(|k| x0-xx[k]).product();
+        let den: f64 = (0..xx.len()).filter(|&k| k != j).map(|k| xx[j]-xx[k]).product();
+        derivada += yy[j]*num/den;
+    }
+    derivada
+}
+
+impl CubicSpline{
+    /// None si hay menos de cuatro puntos o no hay tantas x como y
+    pub fn new(xx: Vec<f64>, yy: Vec<f64>) -> Option<Self>{
+        let n = xx.len();
+        if n < 4 || n != yy.len(){
+            return None;
+        }
+        let d_ini = derivada_lagrange(&xx[..4], &yy[..4]);
+        let x_fin: Vec<f64> = xx[n-4..].iter().rev().copied().collect();
+        let y_fin: Vec<f64> = yy[n-4..].iter().rev().copied().collect();
+        let d_fin = derivada_lagrange(&x_fin, &y_fin);
+
+        // Sistema tridiagonal para las segundas derivadas (algoritmo de Thomas)
+        let mut sub = vec![0.; n];
+        let mut diag = vec![0.; n];
+        let mut sup = vec![0.; n];
+        let mut rhs = vec![0.; n];
+        let h = |i: usize| xx[i+1]-xx[i];
+        let pendiente = |i: usize| (yy[i+1]-yy[i])/h(i);
+        diag[0] = h(0)/3.;
+        sup[0] = h(0)/6.;
+        rhs[0] = pendiente(0) - d_ini;
+        for i in 1..n-1{
+            sub[i] = h(i-1)/6.;
+            diag[i] = (h(i-1)+h(i))/3.;
+            sup[i] = h(i)/6.;
+            rhs[i] = pendiente(i) - pendiente(i-1);
+        }
+        sub[n-1] = h(n-2)/6.;
+        diag[n-1] = h(n-2)/3.;
+        rhs[n-1] = d_fin - pendiente(n-2);
+        for i in 1..n{
+            let w = sub[i]/diag[i-1];
+            diag[i] -= w*sup[i-1];
+            rhs[i] -= w*rhs[i-1];
+        }
+        let mut m = vec![0.; n];
+        m[n-1] = rhs[n-1]/diag[n-1];
+        for i in (0..n-1).rev(){
+            m[i] = (rhs[i] - sup[i]*m[i+1])/diag[i];
+        }
+        Some(CubicSpline{xx, yy, m})
+    }
+
+    /// Intervalo en el que está definido el spline
+    pub fn domain(&self) -> (f64, f64){
+        (self.xx[0], self.xx[self.xx.len()-1])
+    }
+
+    /// Evalúa el spline en x. Fuera del dominio se devuelve NaN en lugar de extrapolar.
+    pub fn eval(&self, x: f64) -> f64{
+        let (min, max) = self.domain();
+        if !(min..=max).contains(&x){
+            return f64::NAN;
+        }
+        let i = self.xx.partition_point(|&xi| xi <= x).clamp(1, self.xx.len()-1);
+        let h = self.xx[i]-self.xx[i-1];
+        let a = (self.xx[i]-x)/h;
+        let b = 1.-a;
+        a*self.yy[i-1] + b*self.yy[i] + ((a.powi(3)-a)*self.m[i-1] + (b.powi(3)-b)*self.m[i])*h*h/6.
+    }
+}
+
+/// Se queda con la parte inicial de (xx, yy) en la que xx es estrictamente creciente.
+/// Sirve para invertir funciones que dejan de ser monótonas (p.ej. la distancia angular tiene un máximo).
+fn rama_creciente(xx: &[f64], yy: &[f64]) -> (Vec<f64>, Vec<f64>){
+    let fin = xx.windows(2).position(|sl| sl[1] <= sl[0] || !sl[1].is_finite()).map_or(xx.len(), |i| i+1);
+    (xx[..fin].to_vec(), yy[..fin].to_vec())
+}
+
+
+/// Tabla precalculada de distancias y edades para unos `ParametrosCosmologicos` dados.
+///
+/// Las cantidades se tabulan en una malla uniforme en ln(1+z) entre 0 y `z_max` y se interpolan con splines cúbicos,
+/// de modo que cada consulta cuesta O(log n) en lugar de una integral adaptativa.
+/// El error de interpolación de un spline cúbico escala como h⁴ con el paso de la malla: con la malla por defecto
+/// (`TablaCosmologica::new`, 2000 puntos) el error relativo es del orden de 1e-9 para modelos razonables,
+/// muy por debajo de la tolerancia de las integrales (1e-7). El error efectivamente alcanzado se estima al construir
+/// la tabla comparando con el cálculo directo en los puntos medios y se guarda en `error_relativo_estimado`.
+///
+/// Las distancias se dan en leguas cósmicas y los tiempos en giga años, como en `functions`.
+/// Fuera del rango tabulado todas las consultas devuelven NaN. Si el universo no llega hasta `z_max` (E² < 0 antes)
+/// la tabla se corta en el redshift máximo observable, ver `analysis::z_maximo`. Si no se puede calcular la edad en
+/// `z_max` (p.ej. de Sitter, sin Big Bang) la tabla no tiene edades y esas consultas también devuelven NaN.
+#[derive(Clone, Debug)]
+pub struct TablaCosmologica{
+    pub z_max: f64,
+    pub error_relativo_estimado: f64,
+    factor_escala: f64,
+    // Todas las tablas directas usan x = ln(1+z) como variable
+    luminosidad: CubicSpline,
+    angular: CubicSpline,
+    edad: Option<CubicSpline>,
+    // Tablas inversas, devuelven x = ln(1+z)
+    z_de_luminosidad: CubicSpline,
+    z_de_angular: CubicSpline,
+    z_de_edad: Option<CubicSpline>,
+}
+
+/// Tolerancia de las integrales de cada tramo de la tabla, por debajo de la de `functions` para que no domine el error
+const TOLERANCIA_TABLA: f64 = 1e-10;
+
+impl TablaCosmologica{
+    pub fn new(z_max: f64, parametros: ParametrosCosmologicos) -> Result<Self, ErrorCosmologico>{
+        Self::with_points(z_max, 2000, parametros)
+    }
+
+    /// Construye la tabla con `puntos` nodos. Falla si alguna integral no llega a la tolerancia o si la malla (o la rama
+    /// invertible de alguna magnitud) tiene menos de cuatro puntos.
+    pub fn with_points(z_max: f64, puntos: i64, parametros: ParametrosCosmologicos) -> Result<Self, ErrorCosmologico>{
+        let z_max = z_max.min(z_maximo(parametros));
+        let insuficiente = |magnitud, puntos| ErrorCosmologico{magnitud, z: z_max, tolerancia: TOLERANCIA_TABLA, causa: CausaError::TablaInsuficiente{puntos}};
+        let spline = |magnitud, xx: Vec<f64>, yy: Vec<f64>| {
+            let puntos = xx.len();
+            CubicSpline::new(xx, yy).ok_or(insuficiente(magnitud, puntos))
+        };
+        // Con z_max <= 0 (o NaN) todos los nodos serían z = 0
+        if z_max.is_nan() || z_max <= 0.{
+            return Err(insuficiente(Magnitud::DistanciaLuminosidad, 1));
+        }
+        let x_max = (1.+z_max).ln();
+        // linspace no incluye el extremo superior
+        let mut xx = linspace(0., x_max, (puntos-1).max(0));
+        xx.push(x_max);
+        let zz: Vec<f64> = xx.iter().map(|x| x.exp_m1()).collect();
+
+        let integral = |magnitud, f: &dyn Fn(f64) -> f64, a: f64, b: f64| -> Result<f64, ErrorCosmologico>{
+            let resultado = integrate(MetodoIntegracion::Simpson, f, a, b, 1e-12, TOLERANCIA_TABLA)
+                .map_err(|err| error_integral(magnitud, b, TOLERANCIA_TABLA, Some(err)))?;
+            if !resultado.value.is_finite(){
+                return Err(error_integral(magnitud, b, TOLERANCIA_TABLA, None));
+            }
+            Ok(resultado.value)
+        };
+        let inversa_e = |zp: f64| 1./e(1.0+zp, parametros);
+
+        // integral de 1/E acumulada tramo a tramo
+        let mut integral_comovil = vec![0.; zz.len()];
+        for i in 1..zz.len(){
+            integral_comovil[i] = integral_comovil[i-1] + integral(Magnitud::DistanciaLuminosidad, &inversa_e, zz[i-1], zz[i])?;
+        }
+        let distancia = |z: f64, i: f64| parametros.factor_escala*(1.0+z) * s_k(parametros.c/(parametros.factor_escala * parametros.constante_hubble) * i, parametros.signo_curvatura);
+        let dl: Vec<f64> = zz.iter().zip(&integral_comovil).map(|(&z, &i)| distancia(z, i)).collect();
+        let da: Vec<f64> = zz.iter().zip(&dl).map(|(z, d)| (1.0+z).powi(-2)*d).collect();
+
+        // t(z) se integra desde z_max hacia z = 0
+        let edad = match lookback_time(z_max, parametros){
+            Ok(edad_z_max) => {
+                let mut tt = vec![0.; zz.len()];
+                tt[zz.len()-1] = edad_z_max;
+                for i in (0..zz.len()-1).rev(){
+                    tt[i] = tt[i+1] + integral(Magnitud::Edad, &|zp: f64| 1./((1.+zp)*e(1.+zp, parametros)), zz[i], zz[i+1])?/parametros.constante_hubble;
+                }
+                Some(tt)
+            }
+            Err(_) => None,
+        };
+
+        let (dl_inv, x_dl) = rama_creciente(&dl, &xx);
+        let (da_inv, x_da) = rama_creciente(&da, &xx);
+        let (edad, z_de_edad) = match edad{
+            Some(tt) => {
+                let mut tt_rev = tt.clone();
+                let mut xx_rev = xx.clone();
+                tt_rev.reverse();
+                xx_rev.reverse();
+                let (t_inv, x_t) = rama_creciente(&tt_rev, &xx_rev);
+                (Some(spline(Magnitud::Edad, xx.clone(), tt)?), Some(spline(Magnitud::Edad, t_inv, x_t)?))
+            }
+            None => (None, None),
+        };
+
+        let mut tabla = TablaCosmologica{
+            z_max, error_relativo_estimado: 0., factor_escala: parametros.factor_escala,
+            luminosidad: spline(Magnitud::DistanciaLuminosidad, xx.clone(), dl)?, angular: spline(Magnitud::DistanciaAngular, xx.clone(), da)?,
+            edad, z_de_luminosidad: spline(Magnitud::DistanciaLuminosidad, dl_inv, x_dl)?,
+            z_de_angular: spline(Magnitud::DistanciaAngular, da_inv, x_da)?, z_de_edad,
+        };
+
+        // Estimación del error en los puntos medios de (como mucho) 50 intervalos repartidos por la malla
+        let salto = (xx.len()/50).max(1);
+        for i in (0..xx.len()-1).step_by(salto){
+            let z = ((xx[i]+xx[i+1])/2.).exp_m1();
+            // referencia con la misma tolerancia que la tabla, no la de `luminosity_distance`
+            let directo = distancia(z, integral(Magnitud::DistanciaLuminosidad, &inversa_e, 0., z)?);
+            let error = ((tabla.luminosity_distance(z)-directo)/directo).abs();
+            if error.is_finite(){
+                tabla.error_relativo_estimado = tabla.error_relativo_estimado.max(error);
+            }
+        }
+        Ok(tabla)
+    }
+
+    pub fn luminosity_distance(&self, z: f64) -> f64{
+        self.luminosidad.eval(z.ln_1p())
+    }
+
+    pub fn angular_distance(&self, z: f64) -> f64{
+        self.angular.eval(z.ln_1p())
+    }
+
+    /// Edad del universo en z (igual que `functions::lookback_time`). NaN si el universo no tiene edad finita.
+    pub fn lookback_time(&self, z: f64) -> f64{
+        self.edad.as_ref().map_or(f64::NAN, |s| s.eval(z.ln_1p()))
+    }
+
+    /// Factor de escala en el instante t (giga años desde el origen), sólo para el pasado tabulado.
+    pub fn factor_escala(&self, t: f64) -> f64{
+        self.factor_escala/(1.+self.z_from_lookback_time(t))
+    }
+
+    /// Redshift al que corresponde una distancia luminosidad
+    pub fn z_from_luminosity_distance(&self, d: f64) -> f64{
+        self.z_de_luminosidad.eval(d).exp_m1()
+    }
+
+    /// Redshift al que corresponde una distancia angular. Como D_A tiene un máximo
+    /// sólo se invierte la rama de z menor que la del máximo.
+    pub fn z_from_angular_distance(&self, d: f64) -> f64{
+        self.z_de_angular.eval(d).exp_m1()
+    }
+
+    pub fn z_from_lookback_time(&self, t: f64) -> f64{
+        self.z_de_edad.as_ref().map_or(f64::NAN, |s| s.eval(t).exp_m1())
+    }
+
+    /// Instante en el que el factor de escala vale a (inversa de `factor_escala`)
+    pub fn t_from_factor_escala(&self, a: f64) -> f64{
+        self.lookback_time(self.factor_escala/a - 1.)
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::functions::{luminosity_distance, TOLERANCIA_DISTANCIA};
+    use crate::presets::Preset;
+
+    #[test]
+    fn spline_reproduce_polinomios_cubicos(){
+        let xx = linspace(0., 2., 20);
+        let cubica = |x: f64| x.powi(3) - 2.*x + 1.;
+        let spline = CubicSpline::new(xx.clone(), xx.iter().map(|&x| cubica(x)).collect()).unwrap();
+        for x in linspace(0., xx[xx.len()-1], 57){
+            assert!((spline.eval(x) - cubica(x)).abs() < 1e-12, "x = {}", x);
+        }
+        assert!(spline.eval(-0.1).is_nan());
+        assert!(CubicSpline::new(vec![0., 1., 2.], vec![0., 1., 2.]).is_none());
+        assert!(CubicSpline::new(xx, vec![0.; 5]).is_none());
+    }
+
+    #[test]
+    fn tabla_de_distancias_dentro_de_la_tolerancia(){
+        for preset in [Preset::Planck18, Preset::EinsteinDeSitter, Preset::Milne]{
+            let parametros = preset.parametros();
+            let tabla = TablaCosmologica::new(10., parametros).unwrap();
+            // El error del spline frente a la integral con tolerancia 1e-10, del orden de 1e-9 según la documentación
+            assert!(tabla.error_relativo_estimado < 1e-8, "{}: {}", preset, tabla.error_relativo_estimado);
+            for z in [0.013, 0.1, 0.5, 1., 2.7, 6., 9.9]{
+                // `luminosity_distance` sólo es exacta hasta su propia tolerancia, así que la diferencia es como mucho la suma
+                let directo = luminosity_distance(z, parametros).unwrap();
+                let error = ((tabla.luminosity_distance(z) - directo)/directo).abs();
+                assert!(error < 2.*TOLERANCIA_DISTANCIA, "{} en z = {}: error relativo {}", preset, z, error);
+                assert!((tabla.z_from_luminosity_distance(directo) - z).abs() < 1e-6*(1. + z));
+            }
+        }
+    }
+
+    #[test]
+    fn tabla_sin_edad_o_sin_puntos(){
+        // de Sitter no tiene Big Bang: hay distancias pero no edades, y construir la tabla termina
+        let tabla = TablaCosmologica::new(3., Preset::DeSitter.parametros()).unwrap();
+        assert!(tabla.luminosity_distance(2.).is_finite());
+        assert!(tabla.lookback_time(2.).is_nan());
+        assert!(tabla.z_from_lookback_time(1.).is_nan());
+        // Con menos de cuatro puntos no hay spline
+        let parametros = Preset::Planck18.parametros();
+        let err = TablaCosmologica::with_points(3., 3, parametros).unwrap_err();
+        assert!(matches!(err.causa, CausaError::TablaInsuficiente{puntos: 3}));
+        assert!(TablaCosmologica::new(0., parametros).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+pub mod input;
+pub mod functions;
+pub mod integral_methods;
+pub mod consts;
+pub mod plotting;
+pub mod interpolation;
+pub mod error;
+pub mod analysis;
+pub mod phase_diagram;
+pub mod linalg;
+pub mod fitting;
+pub mod supernovae;
+pub mod random;
+pub mod priors;
+pub mod chains;
+pub mod mcmc;
+pub mod bao;
+pub mod chronometers;
+pub mod cmb;
+pub mod growth;
+pub mod fisher;
+pub mod contours;
+pub mod chi2_map;
+pub mod dual;
+pub mod hmc;
+pub mod nested;
+pub mod validation;
+pub mod presets;
+pub mod models;
+pub mod sweep;
+pub mod units;
+pub mod densities;
+pub mod manifest;
+pub mod figures;
+pub mod locale;
+pub mod pgfplots;
+
+
+pub use functions::*;
+pub use input::*;
+pub use integral_methods::*;
+pub use consts::*;
+pub use plotting::*;
+pub use interpolation::*;
+pub use error::*;
+pub use analysis::*;
+pub use phase_diagram::*;
+pub use fitting::*;
+pub use supernovae::*;
+pub use random::*;
+pub use priors::*;
+pub use chains::*;
+pub use mcmc::*;
+pub use bao::*;
+pub use chronometers::*;
+pub use cmb::*;
+pub use growth::*;
+pub use fisher::*;
+pub use contours::*;
+pub use chi2_map::*;
+pub use dual::*;
+pub use hmc::*;
+pub use nested::*;
+pub use validation::*;
+pub use presets::*;
+pub use models::*;
+pub use sweep::*;
+pub use units::*;
+pub use densities::*;
+pub use manifest::*;
+pub use figures::*;
+pub use locale::*;
+pub use pgfplots::*;
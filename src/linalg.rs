@@ -0,0 +1,56 @@
+// Álgebra lineal mínima para matrices de covarianza (simétricas y definidas positivas).
+// Las matrices se guardan por filas en un `Vec<f64>` de n*n elementos.
+
+
+/// Descomposición de Cholesky A = L Lᵀ. Devuelve L (triangular inferior) o None si A no es definida positiva.
+pub fn cholesky(a: &[f64], n: usize) -> Option<Vec<f64>>{
+    let mut l = vec![0.; n*n];
+    for i in 0..n{
+        for j in 0..=i{
+            let suma: f64 = (0..j).map(|k| l[i*n+k]*l[j*n+k]).sum();
+            if i == j{
+                let d = a[i*n+i] - suma;
+                if d <= 0. || !d.is_finite(){
+                    return None;
+                }
+                l[i*n+i] = d.sqrt();
+            }
+            else{
+                l[i*n+j] = (a[i*n+j] - suma)/l[j*n+j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Resuelve L Lᵀ x = b a partir del factor de Cholesky L
+pub fn resolver_cholesky(l: &[f64], n: usize, b: &[f64]) -> Vec<f64>{
+    let mut y = vec![0.; n];
+    for i in 0..n{
+        y[i] = (b[i] - (0..i).map(|k| l[i*n+k]*y[k]).sum::<f64>())/l[i*n+i];
+    }
+    let mut x = vec![0.; n];
+    for i in (0..n).rev(){
+        x[i] = (y[i] - (i+1..n).map(|k| l[k*n+i]*x[k]).sum::<f64>())/l[i*n+i];
+    }
+    x
+}
+
+/// Inversa de una matriz simétrica definida positiva
+pub fn invertir(a: &[f64], n: usize) -> Option<Vec<f64>>{
+    let l = cholesky(a, n)?;
+    let mut inversa = vec![0.; n*n];
+    for j in 0..n{
+        let mut e = vec![0.; n];
+        e[j] = 1.;
+        for (i, x) in resolver_cholesky(&l, n, &e).into_iter().enumerate(){
+            inversa[i*n+j] = x;
+        }
+    }
+    Some(inversa)
+}
+
+/// Producto matriz-vector
+pub fn producto(a: &[f64], n: usize, x: &[f64]) -> Vec<f64>{
+    (0..n).map(|i| (0..n).map(|j| a[i*n+j]*x[j]).sum()).collect()
+}
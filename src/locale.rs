@@ -0,0 +1,406 @@
+use std::env;
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Serialize, Deserialize};
+
+
+/// Idioma de los mensajes por pantalla, los errores, los informes y las etiquetas de las gráficas
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Idioma{
+    #[default]
+    #[serde(rename = "es")]
+    Espanol,
+    #[serde(rename = "en")]
+    Ingles,
+}
+
+/// Idioma de todo el programa, lo fija `main` al empezar. Es global porque lo necesitan los `Display` de los errores.
+static IDIOMA: AtomicU8 = AtomicU8::new(0);
+
+impl Idioma{
+    pub fn actual() -> Idioma{
+        match IDIOMA.load(Ordering::Relaxed){
+            1 => Idioma::Ingles,
+            _ => Idioma::Espanol,
+        }
+    }
+
+    pub fn fijar(self){
+        IDIOMA.store(self as u8, Ordering::Relaxed);
+    }
+
+    /// El de la clave `idioma` del fichero de entrada si la tiene y es válida; si no, el del entorno y si tampoco, español
+    pub fn elegir(texto: &str) -> Idioma{
+        let del_fichero = texto.parse::<toml::Table>().ok()
+            .and_then(|tabla| tabla.get("idioma").cloned())
+            .and_then(|valor| valor.try_into::<Idioma>().ok());
+        del_fichero.or_else(Idioma::del_entorno).unwrap_or_default()
+    }
+
+    /// Idioma de LC_ALL, LC_MESSAGES o LANG (la primera que no esté vacía), p.ej. "en_GB.UTF-8" o "es_ES.UTF-8"
+    pub fn del_entorno() -> Option<Idioma>{
+        let valor = ["LC_ALL", "LC_MESSAGES", "LANG"].into_iter().filter_map(|variable| env::var(variable).ok()).find(|v| !v.is_empty())?;
+        match valor.get(..2){
+            Some("es") => Some(Idioma::Espanol),
+            Some("en") => Some(Idioma::Ingles),
+            _ => None,
+        }
+    }
+}
+
+/// Cambia cada `{}` de la plantilla por el siguiente argumento. Los formatos especiales (`{:e}`, `{:?}`...) se aplican
+/// antes, al pasar el argumento.
+pub fn rellenar(plantilla: &str, argumentos: &[&dyn fmt::Display]) -> String{
+    let mut texto = String::with_capacity(plantilla.len());
+    let mut argumentos = argumentos.iter();
+    let mut resto = plantilla;
+    while let Some(i) = resto.find("{}"){
+        texto += &resto[..i];
+        if let Some(argumento) = argumentos.next(){
+            let _ = write!(texto, "{}", argumento);
+        }
+        resto = &resto[i+2..];
+    }
+    texto + resto
+}
+
+/// Texto del catálogo en el idioma actual, con los `{}` rellenados con los argumentos:
+/// `texto!(EdadActual, edad, unidades.tiempo)`
+#[macro_export]
+macro_rules! texto{
+    ($clave:ident) => {
+        $crate::locale::Texto::$clave.texto().to_string()
+    };
+    ($clave:ident, $($argumento:expr),+ $(,)?) => {
+        $crate::locale::rellenar($crate::locale::Texto::$clave.texto(), &[$(&$argumento as &dyn ::std::fmt::Display),+])
+    };
+}
+
+macro_rules! catalogo{
+    ($($clave:ident: $espanol:literal, $ingles:literal;)*) => {
+        /// Claves de los catálogos de textos
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Texto{
+            $($clave,)*
+        }
+
+        impl Texto{
+            pub fn espanol(self) -> &'static str{
+                match self{
+                    $(Texto::$clave => $espanol,)*
+                }
+            }
+
+            pub fn ingles(self) -> &'static str{
+                match self{
+                    $(Texto::$clave => $ingles,)*
+                }
+            }
+        }
+    };
+}
+
+impl Texto{
+    /// Plantilla en el idioma actual
+    pub fn texto(self) -> &'static str{
+        match Idioma::actual(){
+            Idioma::Espanol => self.espanol(),
+            Idioma::Ingles => self.ingles(),
+        }
+    }
+}
+
+impl fmt::Display for Texto{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        f.pad(self.texto())
+    }
+}
+
+catalogo!{
+    // Línea de órdenes y manifiestos
+    FaltaNombrePreset: "falta el nombre del preset después de --preset", "missing preset name after --preset";
+    FaltaManifiesto: "falta el manifiesto, p.ej. `reproducir plots/manifiesto_normal.toml`", "missing manifest, e.g. `reproducir plots/manifiesto_normal.toml`";
+    SinManifiesto: "{} no tiene manifiesto", "{} has no manifest";
+    FicheroConErrores: "input_data.toml tiene errores (la orden `validar` los muestra junto con los avisos)",
+        "input_data.toml has errors (the `validar` command shows them along with the warnings)";
+    ParametrosManifiesto: "Parámetros del manifiesto del {} (versión {}, git {})", "Parameters from the manifest of {} (version {}, git {})";
+    ManifiestoSinModelos: "el manifiesto no tiene modelos", "the manifest has no models";
+    FaltaEncuesta: "falta el fichero de la encuesta, p.ej. `fisher datos/encuesta_ejemplo.toml`", "missing survey file, e.g. `fisher datos/encuesta_ejemplo.toml`";
+    OrdenDesconocida: "orden desconocida: {} (las disponibles son: validar, presets, reproducir <manifiesto>, diagrama_fases, ajuste, mcmc [--continuar], \
+                       hmc, anidado, mapa_chi2, barrido, fisher <encuesta.toml>; todas admiten --preset <nombre>)",
+        "unknown command: {} (available: validar, presets, reproducir <manifest>, diagrama_fases, ajuste, mcmc [--continuar], hmc, anidado, \
+         mapa_chi2, barrido, fisher <survey.toml>; all accept --preset <name>)";
+    ManifiestoGuardado: "Manifiesto guardado en {} ({} ficheros de salida)", "Manifest saved to {} ({} output files)";
+    SalidasIguales: "Todas las salidas coinciden bit a bit con las del manifiesto", "All outputs match the manifest bit for bit";
+    DiferenciaManifiesto: "Diferencia con el manifiesto: {}", "Difference from the manifest: {}";
+    PulseEnter: "Pulse enter para salir...", "Press enter to exit...";
+    UsoPresets: "Se eligen con preset = \"nombre\" en input_data.toml (los campos del fichero mandan) o con --preset <nombre>",
+        "Choose one with preset = \"name\" in input_data.toml (the fields in the file take precedence) or with --preset <name>";
+    FicheroValido: "input_data.toml es válido ({})", "input_data.toml is valid ({})";
+    ErroresYAvisos: "input_data.toml tiene {} y {}", "input_data.toml has {} and {}";
+    UnError: "1 error", "1 error";
+    VariosErrores: "{} errores", "{} errors";
+    UnAviso: "1 aviso", "1 warning";
+    VariosAvisos: "{} avisos", "{} warnings";
+    VersionManifiesto: "versión {} ({}), el manifiesto es de la {} ({})", "version {} ({}), the manifest is from {} ({})";
+    AjustesManifiesto: "ajustes numéricos {}, el manifiesto tiene {}", "numerical settings {}, the manifest has {}";
+    SalidaDistinta: "{} es distinto", "{} differs";
+    SalidaNoEscrita: "{} no se ha vuelto a escribir", "{} was not written again";
+
+    // Ficheros
+    NoSePudoLeer: "no se pudo leer {}", "could not read {}";
+    NoSePudoEscribir: "no se pudo escribir {}", "could not write {}";
+    NoSePudoCrear: "no se pudo crear {}", "could not create {}";
+    NoEsNumero: "{}:{}: no es un número", "{}:{}: not a number";
+    SinMedidas: "{} no contiene ninguna medida", "{} contains no measurements";
+    CovarianzaNoDefinida: "la matriz de covarianza de {} no es definida positiva", "the covariance matrix of {} is not positive definite";
+    CovarianzaNoInvertible: "no se pudo invertir la covarianza de {}", "could not invert the covariance of {}";
+    TipoMedidaBao: "{}:{}: tipo de medida desconocido \"{}\", debe ser DM_rd, DH_rd, DV_rd o DM_DH_rd",
+        "{}:{}: unknown measurement type \"{}\", it must be DM_rd, DH_rd, DV_rd or DM_DH_rd";
+    CorrelacionBao: "{}:{}: la correlación debe estar entre -1 y 1", "{}:{}: the correlation must be between -1 and 1";
+    ColumnasBao: "{}:{}: se esperaban 4 columnas: z, tipo, valor y error", "{}:{}: expected 4 columns: z, type, value and error";
+    ColumnasBaoAnisotropo: "{}:{}: se esperaban 7 columnas: z, DM_DH_rd, D_M/r_d, D_H/r_d, sus errores y la correlación",
+        "{}:{}: expected 7 columns: z, DM_DH_rd, D_M/r_d, D_H/r_d, their errors and the correlation";
+    ErrorNoPositivoEnZ: "{}: el error de la medida en z={} no es positivo", "{}: the error of the measurement at z={} is not positive";
+    ErrorNoPositivo: "{}:{}: el error debe ser positivo", "{}:{}: the error must be positive";
+    ColumnasCronometros: "{}:{}: se esperaban las columnas z, H y σ", "{}:{}: expected the columns z, H and σ";
+    CovarianzaNoNumerica: "{} contiene algo que no es un número", "{} contains something that is not a number";
+    FormatoCovarianza: "{} debe empezar por el número de datos ({}) seguido de {} elementos",
+        "{} must start with the number of data points ({}) followed by {} elements";
+    SinColumnaRedshift: "no se encuentra la columna de redshift en {}", "no redshift column found in {}";
+    SinColumnaMu: "no se encuentra la columna de μ ni la de m_B en {}", "neither a μ nor an m_B column found in {}";
+    SinColumnaErrores: "no se encuentra la columna de errores en {}", "no error column found in {}";
+    FaltaColumna: "{}:{}: falta la columna {} o no es un número", "{}:{}: column {} is missing or not a number";
+    SinSupernovas: "{} no contiene ninguna supernova", "{} contains no supernovae";
+    NpyNoValido: "{} no es un .npy válido", "{} is not a valid .npy";
+    ColumnasCadena: "{} tiene {} columnas pero se esperaban {}", "{} has {} columns but {} were expected";
+    CabeceraCadena: "la cabecera de {} no corresponde a los parámetros {}", "the header of {} does not match the parameters {}";
+    ColumnasEsperadas: "{}:{}: se esperaban {} columnas", "{}:{}: expected {} columns";
+    NpySinCabecera: "falta la cabecera de numpy versión 1", "missing numpy version 1 header";
+    NpyCabeceraIncompleta: "cabecera incompleta", "incomplete header";
+    NpySoloFloat64: "sólo se leen matrices float64 en orden C", "only C-order float64 arrays can be read";
+    NpySinForma: "falta la forma", "missing shape";
+    NpyDosDimensiones: "se esperaba una matriz de dos dimensiones", "expected a two-dimensional array";
+    NpyForma: "el número de datos no corresponde a la forma", "the number of values does not match the shape";
+    EncuestaNoValida: "{} no es una encuesta válida", "{} is not a valid survey";
+    EncuestaSinParametros: "{}: no hay ningún parámetro", "{}: there are no parameters";
+    EncuestaArea: "{}: el área debe ser positiva", "{}: the area must be positive";
+    PresetDesconocido: "preset desconocido: {} (los disponibles son: {})", "unknown preset: {} (available: {})";
+
+    // Ejecución normal
+    CabeceraModelo: "\n== Modelo {} ==", "\n== Model {} ==";
+    SinDatosObservacion: "se sigue sin esos datos", "continuing without that data";
+    Chi2Cronometros: "χ² de los cronómetros cósmicos: {} con {} medidas", "cosmic chronometer χ²: {} with {} measurements";
+    VelocidadLuz: "c: {} leguas cósmicas/giga año", "c: {} cosmic leagues/Gyr";
+    IntervaloPermitido: "E²>=0 para a en [{}, {}]", "E²>=0 for a in [{}, {}]";
+    ZMaximo: "z máximo observable: {}", "maximum observable z: {}";
+    EdadActual: "edad actual del universo: {} {}", "current age of the universe: {} {}";
+    ResumenHubble: "H0 = {} {}, tiempo de Hubble: {} {}, distancia de Hubble: {} {}", "H0 = {} {}, Hubble time: {} {}, Hubble distance: {} {}";
+    HorizonteEnT: "horizonte de particulas en t={} Giga años es: {} {}", "particle horizon at t={} Gyr: {} {}";
+    EdadEnZ: "Edad del universo en z={} es: {} {}", "Age of the universe at z={}: {} {}";
+    DensidadCritica: "h = {}, densidad crítica ρ_c = {} {}", "h = {}, critical density ρ_c = {} {}";
+    Componente: "componente", "component";
+    ThetaNoCalculable: "100θ_* no se puede calcular: {}", "100θ_* cannot be computed: {}";
+    Materia: "materia", "matter";
+    Bariones: "bariones", "baryons";
+    MateriaOscuraFria: "materia oscura fría", "cold dark matter";
+    Radiacion: "radiación", "radiation";
+    EnergiaOscura: "energía oscura", "dark energy";
+    Curvatura: "curvatura", "curvature";
+    Total: "total", "total";
+
+    // Gráficas
+    GraficaGuardada: "Gráfica guardada en {}", "Plot saved to {}";
+    GraficasGuardadas: "Gráficas guardadas en {}", "Plots saved in {}";
+    ScriptFallido: "python no ha terminado bien con {} (código de salida {}), sus gráficas no se han guardado",
+        "python did not finish {} successfully (exit code {}), its plots were not saved";
+    MapaSinPdf: "Aviso: el mapa de χ² no se puede guardar en pdf, sólo en svg o png", "Warning: the χ² map cannot be saved as pdf, only as svg or png";
+    CronometrosCosmicos: "Cronómetros cósmicos", "Cosmic chronometers";
+    DistanciaLuminosidad: "Distancia luminosidad", "Luminosity distance";
+    DistanciaAngular: "Distancia angular", "Angular diameter distance";
+    Modelo: "Modelo", "Model";
+    EjeEdad: "edad del universo en {}", "age of the universe in {}";
+    ExpansionEterna: "Big Bang y expansión eterna", "Big Bang and eternal expansion";
+    Recolapso: "recolapso", "recollapse";
+    Rebote: "rebote (sin Big Bang)", "bounce (no Big Bang)";
+    Remoloneo: "remoloneo", "loitering";
+    ModelosDeTipo: "{}: {} modelos", "{}: {} models";
+    UniversoPlano: "universo plano", "flat universe";
+    Aceleracion: "aceleración", "acceleration";
+    SinBigBang: "sin Big Bang", "no Big Bang";
+    Minimo: "mínimo", "minimum";
+    GigaAnyosCorto: "Ga", "Gyr";
+    Supernovas: "Supernovas", "Supernovae";
+    MejorAjuste: "Mejor ajuste", "Best fit";
+
+    // Órdenes
+    TablaPuntosGuardada: "Tabla de {} puntos guardada en {}", "Table of {} points saved to {}";
+    Chi2Grados: "χ² = {} con {} grados de libertad (χ²/ν = {})", "χ² = {} with {} degrees of freedom (χ²/ν = {})";
+    Chi2SinGrados: "χ² = {} con 0 grados de libertad", "χ² = {} with 0 degrees of freedom";
+    HorizonteArrastre: "Horizonte de sonido en el arrastre r_d = {} Mpc", "Sound horizon at the drag epoch r_d = {} Mpc";
+    DesplazamientoSupernovas: "Constante aditiva de las supernovas (M o desplazamiento por H0): {}", "Supernova additive constant (M or H0 offset): {}";
+    CadenaGuardada: "Cadena guardada en {}", "Chain saved to {}";
+    FraccionAceptacion: "Fracción de aceptación: {}", "Acceptance fraction: {}";
+    EstadisticasCadena: "Cadena {}: paso {}, aceptación media {}, {} pasos de salto por trayectoria, {} divergencias",
+        "Chain {}: step size {}, mean acceptance {}, {} leapfrog steps per trajectory, {} divergences";
+    AvisoDivergencias: "Aviso: hay trayectorias divergentes, prueba con una aceptacion_objetivo mayor",
+        "Warning: there are divergent trajectories, try a larger aceptacion_objetivo";
+    SinMuestras: "no quedan muestras después de descartar los {} primeros pasos (quemado)", "no samples left after discarding the first {} steps (quemado)";
+    ResumenAnidado: "Modelo {}: ln Z = {} ± {}, información {} nats, {} iteraciones, {} evaluaciones de la verosimilitud",
+        "Model {}: ln Z = {} ± {}, information {} nats, {} iterations, {} likelihood evaluations";
+    MuestrasEfectivas: "    {} muestras efectivas guardadas en {}", "    {} effective samples saved to {}";
+    ComparacionCon: "Comparación con {} (ln B > 0 favorece al modelo frente a la referencia):",
+        "Comparison with {} (ln B > 0 favours the model over the reference):";
+    ColumnaModelo: "modelo", "model";
+    ColumnaEvidencia: "evidencia", "evidence";
+    Referencia: "referencia", "reference";
+    TablaGuardada: "Tabla guardada en {}", "Table saved to {}";
+    NoConcluyente: "no concluyente", "inconclusive";
+    Debil: "débil", "weak";
+    Moderada: "moderada", "moderate";
+    Fuerte: "fuerte", "strong";
+    CabeceraFisher: "Parámetro = fiducial ± error marginalizado (error con los demás fijos)", "Parameter = fiducial ± marginalised error (error with the others fixed)";
+    CabeceraElipses: "Elipses del 68% (semiejes y ángulo del mayor con el primer parámetro):",
+        "68% ellipses (semi-axes and angle of the major axis with the first parameter):";
+    FiguraMerito: "Figura de mérito de la DETF (w0–wa): {}", "DETF figure of merit (w0–wa): {}";
+    SinFiguraMerito: "Para la figura de mérito de la DETF hay que incluir w y wa en los parámetros", "The DETF figure of merit needs w and wa among the parameters";
+    MinimoRejilla: "Mínimo de la rejilla: {} = {}, {} = {}, χ² = {}", "Grid minimum: {} = {}, {} = {}, χ² = {}";
+    RejillaGuardada: "Rejilla guardada en {}", "Grid saved to {}";
+    EdadHoy: "edad hoy [{}]", "age today [{}]";
+    TiempoRetornoColumna: "tiempo de retorno [{}]", "turnaround time [{}]";
+    HorizonteColumna: "horizonte de partículas [{}]", "particle horizon [{}]";
+    ZAceleracion: "z de la aceleración", "z of the onset of acceleration";
+    SinDatos: "no hay ningún conjunto de datos configurado (p.ej. una tabla [supernovas] o [bao])", "no data set is configured (e.g. a [supernovas] or [bao] table)";
+    SinPriors: "no hay ningún parámetro con prior, añade una tabla [priors]", "there is no parameter with a prior, add a [priors] table";
+    AlMenosUnaCadena: "hace falta al menos una cadena", "at least one chain is needed";
+    FueraDePriors: "los valores iniciales {} están fuera de los priors", "the initial values {} are outside the priors";
+    SinPuntoInicial: "no se ha encontrado ningún punto inicial con posterior finito cerca de {}", "no initial point with a finite posterior found near {}";
+    EstadoGenerador: "el estado del generador debe tener 4 elementos", "the generator state must have 4 elements";
+    PuntoControlDistinto: "el punto de control {} es de otros parámetros o de otro número de caminantes",
+        "checkpoint {} is for other parameters or another number of walkers";
+    ModeloSinPriors: "el modelo {} no tiene ningún parámetro con prior", "model {} has no parameter with a prior";
+    ModeloPlanoConPrior: "el modelo {} es plano, así que densidad_energia_oscura no puede tener prior",
+        "model {} is flat, so densidad_energia_oscura cannot have a prior";
+    FijoConPrior: "en el modelo {}, {} es fijo y también tiene prior", "in model {}, {} is fixed and also has a prior";
+    SinPuntoVerosimil: "no se encuentra ningún punto con ln L > {} después de {} intentos", "no point with ln L > {} found after {} attempts";
+    PuntosVivos: "hacen falta más de {} puntos vivos para {} parámetros", "more than {} live points are needed for {} parameters";
+    PuntosInicialesSinVerosimilitud: "ninguno de los {} puntos iniciales tiene verosimilitud (¿priors fuera de la región física?)",
+        "none of the {} initial points has a likelihood (priors outside the physical region?)";
+    EnModelo: "en el modelo {}", "in model {}";
+    EncuestaVacia: "la encuesta no mide nada", "the survey measures nothing";
+    PriorSinParametro: "hay un prior para {} pero no es uno de los parámetros", "there is a prior for {} but it is not one of the parameters";
+    FisherSingular: "la matriz de Fisher es singular: algún parámetro (o combinación) no está constreñido por la encuesta",
+        "the Fisher matrix is singular: some parameter (or combination) is not constrained by the survey";
+
+    // Errores de cálculo
+    NoSePudoCalcular: "no se pudo calcular {} en z={} (tolerancia {}): ", "could not compute {} at z={} (tolerance {}): ";
+    RedshiftInalcanzable: "E²<0 a partir de z={}, la luz de redshifts mayores no llega hasta nosotros (no hay Big Bang, hay un rebote)",
+        "E²<0 from z={} on, light from higher redshifts never reaches us (there is no Big Bang but a bounce)";
+    SinMaximo: "el universo se expande para siempre, no llega a un tamaño máximo", "the universe expands forever and never reaches a maximum size";
+    IntegralDivergente: "la integral diverge cuando z → ∞ y el resultado es infinito (en el pasado E(z) crece como (1+z)^{} y haría falta un exponente mayor que {})",
+        "the integral diverges as z → ∞ and the result is infinite (in the past E(z) grows as (1+z)^{} and an exponent above {} would be needed)";
+    SinDesacoplo: "no se pudo calcular {}: sin bariones o sin materia no hay desacoplo (el ajuste de Hu & Sugiyama necesita ω_b > 0 y ω_m > 0)",
+        "could not compute {}: without baryons or matter there is no decoupling (the Hu & Sugiyama fit needs ω_b > 0 and ω_m > 0)";
+    TablaInsuficiente: "la tabla sólo tiene {} puntos distintos y el spline necesita al menos 4", "the table only has {} distinct points and the spline needs at least 4";
+    CuadraturaSinTolerancia: "la cuadratura no ha llegado a la tolerancia {} después de {} evaluaciones (valor parcial {}, error estimado {})",
+        "the quadrature did not reach the tolerance {} after {} evaluations (partial value {}, estimated error {})";
+    SimpsonSinSubintervalo: "algún subintervalo no ha llegado a un error estimado menor que su parte de la tolerancia antes de no poder dividirse más o agotar las evaluaciones (valor parcial {})",
+        "a subinterval did not reach an estimated error below its share of the tolerance before it could not be split further or the evaluations ran out (partial value {})";
+    MagnitudDistanciaLuminosidad: "la distancia luminosidad", "the luminosity distance";
+    MagnitudDistanciaAngular: "la distancia angular", "the angular diameter distance";
+    MagnitudDistanciaComovil: "la distancia comóvil", "the comoving distance";
+    MagnitudDistanciaHubble: "la distancia de Hubble", "the Hubble distance";
+    MagnitudDistanciaVolumen: "la distancia promediada en volumen", "the volume-averaged distance";
+    MagnitudParametroHubble: "el parámetro de Hubble", "the Hubble parameter";
+    MagnitudEdad: "la edad del universo", "the age of the universe";
+    MagnitudHorizonteParticulas: "el horizonte de partículas", "the particle horizon";
+    MagnitudHorizonteSonido: "el horizonte de sonido", "the sound horizon";
+    MagnitudTiempoRetorno: "el tiempo de retorno", "the turnaround time";
+    MagnitudTiempoConforme: "el tiempo conforme", "the conformal time";
+    MagnitudRedshiftDesacoplo: "el redshift del desacoplo z_*", "the decoupling redshift z_*";
+    FaltaCampo: "falta {} en el fichero de entrada (o un preset, p.ej. preset = \"planck18\")", "{} is missing from the input file (or a preset, e.g. preset = \"planck18\")";
+    CamposIncompatibles: "{} y {} dan el mismo parámetro, hay que elegir uno", "{} and {} give the same parameter, choose one";
+    ThetaInalcanzable: "ningún h entre {} y {} da 100θ_* = {} con el resto de parámetros", "no h between {} and {} gives 100θ_* = {} with the other parameters";
+
+    // Unidades y presets
+    GigaAnyos: "giga años", "Gyr";
+    Anyos: "años", "yr";
+    InversoGigaAnyo: "1/giga año", "1/Gyr";
+    Megaparsecs: "Mpc", "Mpc";
+    Gigaparsecs: "Gpc", "Gpc";
+    GigaAnyosLuz: "Gal", "Gly";
+    Metros: "m", "m";
+    MegaparsecsH: "Mpc/h", "Mpc/h";
+    Segundos: "s", "s";
+    KmSMpc: "km/s/Mpc", "km/s/Mpc";
+    KgM3: "kg/m³", "kg/m³";
+    GCm3: "g/cm³", "g/cm³";
+    MasasSolaresMpc3: "M☉/Mpc³", "M☉/Mpc³";
+    DescripcionPlanck18: "Planck 2018 TT,TE,EE+lowE+lensing, ΛCDM plano", "Planck 2018 TT,TE,EE+lowE+lensing, flat ΛCDM";
+    DescripcionWmap9: "WMAP 9 años, ΛCDM plano", "WMAP 9 years, flat ΛCDM";
+    DescripcionEinsteinDeSitter: "Einstein-de Sitter, plano y sólo materia", "Einstein-de Sitter, flat and matter only";
+    DescripcionDeSitter: "de Sitter, plano y sólo constante cosmológica", "de Sitter, flat and cosmological constant only";
+    DescripcionMilne: "Milne, vacío con curvatura negativa", "Milne, empty with negative curvature";
+
+    // Validación del fichero de entrada
+    Error: "error", "error";
+    Aviso: "aviso", "warning";
+    EnCampo: " en {}", " in {}";
+    ClaveDesconocida: "clave desconocida, no se usa (¿una errata?)", "unknown key, not used (a typo?)";
+    DeModelo: "{} (modelo {})", "{} (model {})";
+    NombreVacio: "el nombre del modelo no puede estar vacío", "the model name cannot be empty";
+    NombreRepetido: "ya hay un modelo que se llama {}", "there is already a model called {}";
+    NoFinito: "debe ser un número finito, no {}", "must be a finite number, not {}";
+    MateriaNegativa: "la densidad de materia no puede ser negativa ({})", "the matter density cannot be negative ({})";
+    RadiacionNegativa: "la densidad de radiación no puede ser negativa ({})", "the radiation density cannot be negative ({})";
+    SinRadiacion: "la densidad de radiación es 0, así que las magnitudes del universo temprano (z ≳ 1000, la edad en z_max_edad, \
+                   el horizonte de partículas) no serán correctas; hoy es Ω_r ≈ 9e-5",
+        "the radiation density is 0, so early-universe quantities (z ≳ 1000, the age at z_max_edad, the particle horizon) \
+         will not be accurate; today Ω_r ≈ 9e-5";
+    HubbleNoPositiva: "debe ser positiva, en km/s/Mpc ({})", "must be positive, in km/s/Mpc ({})";
+    HubbleLejana: "{} km/s/Mpc está muy lejos de los valores medidos (≈ 67-74), ¿está en km/s/Mpc?",
+        "{} km/s/Mpc is far from the measured values (≈ 67-74), is it in km/s/Mpc?";
+    BarionesFueraDeRango: "debe estar entre 0 y la densidad de materia ({}), es {}", "must be between 0 and the matter density ({}), it is {}";
+    BarionesMasQueMateria: "es mayor que la densidad de materia ({} > {}), sólo importa con BAO o CMB", "is larger than the matter density ({} > {}), only matters with BAO or CMB";
+    DebePositiva: "debe ser positiva ({})", "must be positive ({})";
+    DebePositivo: "debe ser positivo ({})", "must be positive ({})";
+    Fantasma: "la energía oscura es fantasma (w < -1) en alguna época, su densidad crece con la expansión",
+        "the dark energy is phantom (w < -1) at some epoch, its density grows with the expansion";
+    CasiPlano: "las densidades suman 1 salvo el redondeo (Ω_k = {}), así que el modelo es curvo y la legua cósmica es enorme ({} Mpc); \
+                las distancias en Mpc siguen siendo correctas",
+        "the densities add up to 1 up to rounding (Ω_k = {}), so the model is curved and the cosmic league is huge ({} Mpc); \
+         distances in Mpc are still correct";
+    ExtremosDesordenados: "el primer extremo debe ser menor que el segundo ({} ≥ {})", "the first end must be smaller than the second ({} ≥ {})";
+    ExtremosDesordenadosDe: "el primer extremo de {} debe ser menor que el segundo ({} ≥ {})", "the first end of {} must be smaller than the second ({} ≥ {})";
+    AlMenosDosPuntos: "hacen falta al menos 2 puntos ({})", "at least 2 points are needed ({})";
+    PuntosPorEje: "hacen falta al menos 2 puntos por eje", "at least 2 points per axis are needed";
+    AlMenosDosPuntosPorEje: "hacen falta al menos 2 puntos por eje ({})", "at least 2 points per axis are needed ({})";
+    SinBigBangValidacion: "el universo no tiene Big Bang (rebota en a = {}), no hay edad ni horizonte de partículas y sólo llega luz de z < {}",
+        "the universe has no Big Bang (it bounces at a = {}), so there is no age or particle horizon and light only arrives from z < {}";
+    DistanciasHasta: "las distancias sólo se pueden calcular hasta z = {}", "distances can only be computed up to z = {}";
+    AntesDelBigBang: "t = {} Gyr es anterior al Big Bang, que fue hace {} Gyr", "t = {} Gyr is before the Big Bang, which was {} Gyr ago";
+    RangoAntesDelBigBang: "empieza antes del Big Bang, que fue hace {} Gyr; la curva se corta ahí", "starts before the Big Bang, which was {} Gyr ago; the curve stops there";
+    EdadInfinita: "no se puede calcular la edad del universo, ¿es infinita? ({})", "the age of the universe cannot be computed, is it infinite? ({})";
+    NoExisteFichero: "no existe el fichero {}", "file {} does not exist";
+    PriorDesordenado: "el mínimo debe ser menor que el máximo ({} ≥ {})", "the minimum must be smaller than the maximum ({} ≥ {})";
+    SigmaNoPositiva: "sigma debe ser positiva ({})", "sigma must be positive ({})";
+    FueraDelPrior: "el valor del fichero ({}) está fuera del prior y `mcmc` y `hmc` empiezan en él",
+        "the value in the file ({}) is outside the prior and `mcmc` and `hmc` start from it";
+    Caminantes: "hacen falta al menos {} caminantes para {} parámetros", "at least {} walkers are needed for {} parameters";
+    QuemadoExcesivo: "se descartarían todos los pasos ({} ≥ {})", "all steps would be discarded ({} ≥ {})";
+    AlMenosUno: "debe ser al menos 1", "must be at least 1";
+    EntreCeroYUno: "debe estar entre 0 y 1 ({})", "must be between 0 and 1 ({})";
+    CadenasYPasos: "hacen falta al menos una cadena y un paso", "at least one chain and one step are needed";
+    ParametrosMapaIguales: "los dos parámetros del mapa deben ser distintos", "the two parameters of the map must be different";
+    ParametrosBarridoIguales: "los dos parámetros del barrido deben ser distintos", "the two parameters of the sweep must be different";
+    NumeroParametrosBarrido: "el barrido es de uno o dos parámetros, no de {}", "the sweep takes one or two parameters, not {}";
+    RedshiftsPositivos: "los redshifts deben ser positivos ({})", "redshifts must be positive ({})";
+    Expansion: "debe ser al menos 1 para que el elipsoide contenga los puntos vivos ({})", "must be at least 1 for the ellipsoid to contain the live points ({})";
+    SinFormatos: "no hay formatos, no se guarda ninguna gráfica", "there are no formats, no plot is saved";
+    LatexSinFormatos: "no se ha pedido ningún formato \"tex\" ni \"pgf\", esta tabla no se usa", "no \"tex\" or \"pgf\" format is requested, this table is unused";
+    TamanyoNoPositivo: "el ancho y el alto deben ser positivos ({}, {})", "width and height must be positive ({}, {})";
+    RangoLogaritmico: "con escala logarítmica el rango debe ser positivo ({})", "with a logarithmic scale the range must be positive ({})";
+    TiempoLogaritmico: "los tiempos se cuentan desde hoy: en el pasado son negativos y no salen con escala logarítmica",
+        "times are counted from today: they are negative in the past and do not show on a logarithmic scale";
+}
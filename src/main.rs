@@ -1,27 +1,18 @@
 
 use core::f64;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use anyhow::Result;
-use toml;
+use anyhow::{Context, Result};
 // use gnuplot::{AxesCommon, Caption, Color, Figure, LabelOption};
-// use plotters::prelude::*;
+use plotters::prelude::*;
 // use full_palette::ORANGE;
 use std::process::Command;
 
 
-mod input;
-mod functions;
-mod integral_methods;
-mod consts;
-mod plotting;
-
-
-use functions::*;
-use input::*;
-use integral_methods::*;
-use consts::*;
-use plotting::*;
+use calculadora_cosmologica::*;
+use crate::texto;
 
 
 
@@ -29,143 +20,1187 @@ use plotting::*;
 // const FONT: &str = "New Computer Modern";
 
 fn main() -> Result<()>{
-    let parametros: ParametrosCosmologicos = toml::from_str::<InputFile>(fs::read_to_string("input_data.toml")?.as_str())?.into(); 
+    // Hasta leer el fichero de entrada, que puede elegir otro, los mensajes van en el idioma del entorno
+    Idioma::del_entorno().unwrap_or_default().fijar();
+    let mut argumentos: Vec<String> = std::env::args().skip(1).collect();
+    // --preset <nombre> en cualquier posición: los parámetros cosmológicos del preset en lugar de los del fichero
+    let preset: Option<Preset> = match argumentos.iter().position(|a| a == "--preset"){
+        Some(i) if i + 1 < argumentos.len() => {
+            let nombre = argumentos.remove(i + 1);
+            argumentos.remove(i);
+            Some(nombre.parse()?)
+        }
+        Some(_) => anyhow::bail!(texto!(FaltaNombrePreset)),
+        None => None,
+    };
+    if argumentos.first().map(String::as_str) == Some("presets"){
+        return listar_presets();
+    }
+    // reproducir <manifiesto>: el manifiesto hace de fichero de entrada y se repite la orden con la que se escribió
+    let (texto, argumentos) = if argumentos.first().map(String::as_str) == Some("reproducir"){
+        let Some(fichero) = argumentos.get(1) else {
+            anyhow::bail!(texto!(FaltaManifiesto));
+        };
+        let texto = fs::read_to_string(fichero).with_context(|| texto!(NoSePudoLeer, fichero))?;
+        let manifiesto = Manifiesto::leer(&texto)?.with_context(|| texto!(SinManifiesto, fichero))?;
+        manifiesto.idioma.fijar();
+        (texto, manifiesto.argumentos)
+    }
+    else{
+        let texto = fs::read_to_string("input_data.toml")?;
+        Idioma::elegir(&texto).fijar();
+        (texto, argumentos)
+    };
+    let orden = argumentos.first().map(String::as_str);
+
+    let diagnosticos = validar_texto(&texto, preset);
+    if orden == Some("validar"){
+        return validar(&diagnosticos);
+    }
+    // La ejecución normal sólo usa los datos para dibujarlos encima de H(z): sus errores se dan como avisos y se sigue sin ellos
+    let (errores, errores_datos): (Vec<&Diagnostico>, Vec<&Diagnostico>) = diagnosticos.iter().filter(|d| d.gravedad == Gravedad::Error)
+        .partition(|d| orden.is_some() || !d.en_tabla_datos());
+    if !errores.is_empty(){
+        for diagnostico in errores{
+            eprintln!("{}", con_fichero(diagnostico));
+        }
+        anyhow::bail!(texto!(FicheroConErrores));
+    }
+    let sin_datos = !errores_datos.is_empty();
+    for diagnostico in errores_datos{
+        let aviso = Diagnostico{gravedad: Gravedad::Aviso, ..diagnostico.clone()};
+        eprintln!("{} ({})", con_fichero(&aviso), Texto::SinDatosObservacion);
+    }
+    let entrada: InputFile = toml::from_str(&texto)?;
+    let entrada = match preset{
+        Some(preset) => entrada.con_preset(preset),
+        None => entrada,
+    };
+    if let Some(preset) = entrada.preset(){
+        println!("Preset {}: {}", preset, preset.descripcion());
+    }
+    let anterior = Manifiesto::leer(&texto)?;
+    // Las órdenes usan los parámetros del fichero; la ejecución normal, los de cada [[modelo]] si los hay.
+    // Con un manifiesto, los ya resueltos que tiene guardados.
+    let modelos = match (&anterior, orden){
+        (Some(anterior), _) => {
+            println!("{}", texto!(ParametrosManifiesto, anterior.fecha, anterior.version, anterior.git));
+            anterior.modelos()
+        }
+        (None, None) => toml::from_str::<ConfiguracionModelos>(&texto)?.resolver(entrada)?,
+        (None, Some(_)) => vec![("fichero".to_string(), ParametrosCosmologicos::try_from(entrada)?)],
+    };
+    let parametros = modelos.first().map(|(_, parametros)| *parametros).context(Texto::ManifiestoSinModelos)?;
+    let configuracion: ConfiguracionDatos = if sin_datos {ConfiguracionDatos::sin_datos(&texto)?} else {toml::from_str(&texto)?};
+
+    let inicio = SystemTime::now();
+    let antes = EstadoSalidas::leer(&directorios_salida(&configuracion))?;
+    match orden{
+        Some("diagrama_fases") => dibujar_diagrama_fases(parametros, configuracion.diagrama_fases, &configuracion.graficas)?,
+        Some("ajuste") => ajuste(parametros, &configuracion)?,
+        Some("mcmc") => mcmc(parametros, &configuracion, argumentos.iter().any(|a| a == "--continuar"))?,
+        Some("hmc") => hmc(parametros, &configuracion)?,
+        Some("anidado") => anidado(parametros, &configuracion)?,
+        Some("mapa_chi2") => dibujar_mapa_chi2(parametros, &configuracion)?,
+        Some("barrido") => dibujar_barrido(parametros, &configuracion)?,
+        Some("fisher") => match argumentos.get(1){
+            Some(encuesta) => previsión_fisher(parametros, &Encuesta::leer(encuesta.as_ref())?, &configuracion.graficas)?,
+            None => anyhow::bail!(texto!(FaltaEncuesta)),
+        },
+        Some(orden) => anyhow::bail!(texto!(OrdenDesconocida, orden)),
+        None => ejecucion_normal(&texto, &modelos, &configuracion)?,
+    }
+
+    let mut manifiesto = Manifiesto::nuevo(&argumentos, &modelos, inicio);
+    manifiesto.registrar_salidas(&antes)?;
+    let fichero = directorio_salida(orden, &configuracion).join(format!("manifiesto_{}.toml", orden.unwrap_or("normal")));
+    manifiesto.escribir(&texto, &fichero)?;
+    println!("{}", texto!(ManifiestoGuardado, fichero.display(), manifiesto.salidas.len()));
+    if let Some(anterior) = &anterior{
+        let diferencias = manifiesto.diferencias(anterior);
+        if diferencias.is_empty(){
+            println!("{}", texto!(SalidasIguales));
+        }
+        for diferencia in diferencias{
+            println!("{}", texto!(DiferenciaManifiesto, diferencia));
+        }
+    }
+
+    if orden.is_none(){
+        println!("{}", texto!(PulseEnter));
+        std::io::stdin().read_line(&mut String::new()).unwrap();
+    }
+    Ok(())
+}
+
+/// Directorios en los que escriben las órdenes, sin repetir
+fn directorios_salida(configuracion: &ConfiguracionDatos) -> Vec<PathBuf>{
+    let mut directorios = vec![configuracion.graficas.directorio.clone()];
+    for salida in [&configuracion.mcmc.salida, &configuracion.hmc.salida, &configuracion.anidado.salida, &configuracion.anidado.tabla,
+                   &configuracion.mapa_chi2.salida, &configuracion.barrido.salida]{
+        let directorio = salida.parent().map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+        if !directorios.contains(&directorio){
+            directorios.push(directorio);
+        }
+    }
+    directorios
+}
+
+/// Directorio en el que se guarda el manifiesto, el de la salida principal de la orden
+fn directorio_salida(orden: Option<&str>, configuracion: &ConfiguracionDatos) -> PathBuf{
+    let salida = match orden{
+        Some("mcmc") => &configuracion.mcmc.salida,
+        Some("hmc") => &configuracion.hmc.salida,
+        Some("anidado") => &configuracion.anidado.salida,
+        Some("mapa_chi2") => &configuracion.mapa_chi2.salida,
+        Some("barrido") => &configuracion.barrido.salida,
+        _ => return configuracion.graficas.directorio.clone(),
+    };
+    salida.parent().map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+}
+
+/// Ejecución normal: curvas de cada modelo, juntas en cada gráfica
+fn ejecucion_normal(texto: &str, modelos: &[(String, ParametrosCosmologicos)], configuracion: &ConfiguracionDatos) -> Result<()>{
+    let comparacion = toml::from_str::<ConfiguracionModelos>(texto)?.comparacion;
+    let unidades = configuracion.unidades;
+    let graficas = &configuracion.graficas;
+    // Los cronómetros sólo se dibujan encima de H(z), si no se pueden leer se dibuja sin ellos
+    let cronometros = configuracion.cronometros.as_ref().and_then(|fuente| {
+        MedidasHubble::leer(&fuente.datos, fuente.covarianza.as_deref())
+            .inspect_err(|err| eprintln!("{}: {:#} ({})", con_mayuscula(Texto::Aviso.texto()), err, Texto::SinDatosObservacion)).ok()
+    });
+    let (z_datos, h_datos, sigma_datos) = match &cronometros{
+        Some(medidas) => {
+            let tasa = |h: &f64| unidades.tasa(Tasa::km_s_mpc(*h));
+            (medidas.z.clone(), medidas.valor.iter().map(tasa).collect(), medidas.sigma.iter().map(tasa).collect())
+        }
+        None => (vec![], vec![], vec![]),
+    };
+
+    let mut curvas = Vec::with_capacity(modelos.len());
+    for (nombre, parametros) in modelos{
+        if modelos.len() > 1{
+            println!("{}", texto!(CabeceraModelo, nombre));
+        }
+        let z_max = z_datos.iter().fold(parametros.z_max_dist, |a, &b| a.max(1.05*b));
+        curvas.push(curvas_modelo(*parametros, z_max, &unidades));
+        if let Some(medidas) = &cronometros{
+            println!("{}", texto!(Chi2Cronometros, medidas.chi2(*parametros), medidas.numero_datos()));
+        }
+    }
+    let nombres: Vec<String> = modelos.iter().map(|(nombre, _)| nombre.clone()).collect();
+
+    let eje_h = format!("H/{}", unidades.tasa);
+    let formato_sci = "style='sci', scilimits=(-3, 3)";
+    // Los cronómetros sólo se pueden poner en el eje x si es z o a, que no dependen del modelo
+    let a0 = modelos.first().map_or(1., |(_, parametros)| parametros.factor_escala);
+    let datos = match graficas.opciones(TipoGrafica::HubbleZ).x.unwrap_or(TipoGrafica::HubbleZ.x_por_defecto()){
+        VariableX::Redshift => Some(z_datos.clone()),
+        VariableX::FactorEscala => Some(z_datos.iter().map(|z| a0/(1. + z)).collect()),
+        VariableX::Tiempo | VariableX::TiempoConforme => None,
+    };
+    let datos = match datos{
+        Some(x_datos) if !x_datos.is_empty() => Some((x_datos, h_datos, sigma_datos)),
+        _ => None,
+    };
+    let mut procesos = Vec::with_capacity(graficas.dibujar.len());
+    for &tipo in &graficas.dibujar{
+        type Acceso = fn(&CurvasModelo) -> &Curva;
+        let (grafica, curva, secundaria): (Grafica, Acceso, Option<Acceso>) = match tipo{
+            TipoGrafica::Distancias => (Grafica{tipo, eje_y: format!("D[{}]", unidades.longitud), series: (Texto::DistanciaLuminosidad.texto(), Texto::DistanciaAngular.texto()),
+                                                formato_y: "", puntos: None},
+                                        |c| &c.distancia_luminosidad, Some(|c| &c.distancia_angular)),
+            TipoGrafica::FactorEscala => (Grafica{tipo, eje_y: "a/lc".to_string(), series: ("", ""), formato_y: "style='plain'", puntos: None},
+                                          |c| &c.factor_escala, None),
+            TipoGrafica::ConstanteHubble => (Grafica{tipo, eje_y: eje_h.clone(), series: ("", ""), formato_y: "", puntos: None},
+                                             |c| &c.hubble, None),
+            TipoGrafica::HubbleZ => (Grafica{tipo, eje_y: eje_h.clone(), series: (Texto::Modelo.texto(), ""), formato_y: "", puntos: datos.clone()},
+                                     |c| &c.hubble_z, None),
+            TipoGrafica::RadioHubble => (Grafica{tipo, eje_y: format!(r"$D_{{\rm H}}$/{}", unidades.longitud), series: ("", ""),
+                                                 formato_y: formato_sci, puntos: None},
+                                         |c| &c.radio_hubble, None),
+            TipoGrafica::HorizonteParticulas => (Grafica{tipo, eje_y: format!(r"$D_{{\rm HP}}$/{}", unidades.longitud), series: ("", ""),
+                                                         formato_y: formato_sci, puntos: None},
+                                                 |c| &c.horizonte, None),
+            TipoGrafica::EdadUniverso => (Grafica{tipo, eje_y: texto!(EjeEdad, unidades.tiempo), series: ("", ""),
+                                                  formato_y: "style='plain'", puntos: None},
+                                          |c| &c.edad, None),
+        };
+        let principales: Vec<&Curva> = curvas.iter().map(curva).collect();
+        let secundarias: Vec<&Curva> = secundaria.map(|f| curvas.iter().map(f).collect()).unwrap_or_default();
+        procesos.push(dibujar_modelos(&grafica, graficas, &unidades, &nombres, &principales, &secundarias, comparacion)?);
+    }
+    // Se esperan todos aunque alguno falle, para no dejar procesos sueltos, y sólo se dice que se han guardado si todos han ido bien
+    let mut correctas = true;
+    for script in procesos.into_iter().flatten(){
+        if let Err(err) = script.esperar(){
+            eprintln!("Error: {}", err);
+            correctas = false;
+        }
+    }
+    if correctas{
+        println!("{}", texto!(GraficasGuardadas, graficas.directorio.display()));
+    }
+    Ok(())
+}
+
+/// Puntos de una curva: sus abscisas y la y de cada una
+struct Curva{
+    x: Abscisas,
+    y: Vec<f64>,
+}
+
+/// Puntos en los que se ha calculado una curva, en tiempo (giga años desde hoy) o en redshift. Las demás variables del
+/// eje x se calculan a partir de ellos sólo si se piden.
+#[derive(Clone)]
+struct Abscisas{
+    puntos: Vec<f64>,
+    en_tiempo: bool,
+    parametros: ParametrosCosmologicos,
+}
+
+impl Abscisas{
+    fn tiempos(tt: &[f64], parametros: ParametrosCosmologicos) -> Self{
+        Abscisas{puntos: tt.to_vec(), en_tiempo: true, parametros}
+    }
+
+    fn redshifts(zz: &[f64], parametros: ParametrosCosmologicos) -> Self{
+        Abscisas{puntos: zz.to_vec(), en_tiempo: false, parametros}
+    }
+
+    /// Valores de la variable en cada punto, con los tiempos en las unidades de salida y contados desde hoy. Donde no se
+    /// pueden calcular (antes del Big Bang, más allá del z máximo) son NaN, que python deja en blanco.
+    fn valores(&self, variable: VariableX, unidades: &Unidades) -> Vec<f64>{
+        let parametros = self.parametros;
+        let tiempo = |giga_anyos: f64| unidades.tiempo(Tiempo::giga_anyos(giga_anyos));
+        let valores: Vec<f64> = match (self.en_tiempo, variable){
+            (true, VariableX::Tiempo) => self.puntos.iter().map(|&t| tiempo(t)).collect(),
+            (true, _) => {
+                let (aa, eta) = self.factor_escala_y_tiempo_conforme();
+                match variable{
+                    VariableX::Redshift => aa.iter().map(|a| parametros.factor_escala/a - 1.).collect(),
+                    VariableX::FactorEscala => aa,
+                    _ => eta.into_iter().map(tiempo).collect(),
+                }
+            }
+            (false, VariableX::Redshift) => self.puntos.clone(),
+            (false, VariableX::FactorEscala) => self.puntos.iter().map(|z| parametros.factor_escala/(1. + z)).collect(),
+            (false, VariableX::Tiempo) => {
+                let edad_actual = lookback_time(0., parametros).unwrap_or(f64::NAN);
+                self.puntos.iter().map(|&z| tiempo(lookback_time(z, parametros).unwrap_or(f64::NAN) - edad_actual)).collect()
+            }
+            (false, VariableX::TiempoConforme) => self.puntos.iter().map(|&z| tiempo(tiempo_conforme(z, parametros).unwrap_or(f64::NAN))).collect(),
+        };
+        valores.into_iter().map(|x| if x.is_finite() {x} else {f64::NAN}).collect()
+    }
+
+    /// a(t) integrado desde hoy hacia el pasado y hacia el futuro, y el tiempo conforme ∫ dt/a con la regla del trapecio
+    fn factor_escala_y_tiempo_conforme(&self) -> (Vec<f64>, Vec<f64>){
+        let tt = &self.puntos;
+        let mut a = vec![f64::NAN; tt.len()];
+        let mut eta = vec![f64::NAN; tt.len()];
+        for pasado in [true, false]{
+            let mut indices: Vec<usize> = (0..tt.len()).filter(|&i| (tt[i] < 0.) == pasado).collect();
+            indices.sort_by(|&i, &j| tt[i].abs().total_cmp(&tt[j].abs()));
+            let puntos: Vec<f64> = [0.].into_iter().chain(indices.iter().map(|&i| tt[i])).collect();
+            let aa = runge_kutta_at_points(edo_para_a, &puntos, self.parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, false, self.parametros));
+            let mut conforme = 0.;
+            for (k, &i) in indices.iter().enumerate(){
+                conforme += trapezoid_method(&puntos[k..=k+1], &[1./aa[k], 1./aa[k+1]]);
+                a[i] = aa[k+1];
+                eta[i] = conforme;
+            }
+        }
+        (a, eta)
+    }
+}
+
+/// Curvas de la ejecución normal para un modelo, en las unidades de salida
+struct CurvasModelo{
+    distancia_luminosidad: Curva,
+    distancia_angular: Curva,
+    factor_escala: Curva,
+    hubble: Curva,
+    hubble_z: Curva,
+    radio_hubble: Curva,
+    horizonte: Curva,
+    edad: Curva,
+}
+
+/// Calcula las curvas de un modelo y muestra sus magnitudes por pantalla. H(z) se calcula hasta z_max_hubble
+/// para que llegue a los datos de los cronómetros.
+fn curvas_modelo(parametros: ParametrosCosmologicos, z_max_hubble: f64, unidades: &Unidades) -> CurvasModelo{
+    let longitud = |leguas: f64| unidades.longitud(Longitud::leguas_cosmicas(leguas, &parametros), &parametros);
+    let tiempo = |giga_anyos: f64| unidades.tiempo(Tiempo::giga_anyos(giga_anyos));
+    let hubble_0 = Tasa::por_giga_anyo(parametros.constante_hubble);
     println!("{:?}", parametros);
-    println!("1lc =  {:e} m", C_GY/parametros.c);
-    println!("c: {:e} leguas cósmicas/giga año", parametros.c);
+    println!("1lc =  {:e} {}", longitud(1.), unidades.longitud);
+    println!("{}", texto!(VelocidadLuz, format!("{:e}", parametros.c)));
     println!("k: {:e}", parametros.signo_curvatura);
-    println!("edad actual del universo: {} giga años", lookback_time(0., parametros));
-
+    for intervalo in intervalos_permitidos(parametros){
+        println!("{}", texto!(IntervaloPermitido, intervalo.a_min, intervalo.a_max));
+    }
+    println!("{}", texto!(ZMaximo, z_maximo(parametros)));
+    match lookback_time(0., parametros){
+        Ok(edad) => println!("{}", texto!(EdadActual, tiempo(edad), unidades.tiempo)),
+        Err(err) => eprintln!("Error: {}", err),
+    }
+    println!("{}", texto!(ResumenHubble, unidades.tasa(hubble_0), unidades.tasa, unidades.tiempo(hubble_0.tiempo_hubble()), unidades.tiempo, unidades.longitud(hubble_0.distancia_hubble(), &parametros), unidades.longitud));
+    println!("{}", resumen_densidades(&parametros, unidades));
 
-    let zz: Vec<f64> = linspace(0., parametros.z_max_dist, 1000);
-    let d1: Vec<_> = zz.iter().map(|&z| luminosity_distance(z, parametros)*parametros.legua_cosmica_a_mpc).collect();
-    let d2: Vec<_> = zz.iter().map(|&z| angular_distance(z ,parametros)*parametros.legua_cosmica_a_mpc).collect();
 
+    // Las distancias y las edades se interpolan en una tabla en lugar de hacer una integral por punto. Donde la tabla no
+    // llega (más allá del z máximo, sin edad finita o si no se ha podido construir) se calcula directamente, que es lo
+    // que da el mensaje de error
+    let tabla = TablaCosmologica::new(parametros.z_max_dist.max(parametros.z_max_edad), parametros).ok();
+    let de_tabla = |z: f64, consulta: fn(&TablaCosmologica, f64) -> f64, directa: fn(f64, ParametrosCosmologicos) -> Result<f64, ErrorCosmologico>|
+        match tabla.as_ref().map(|tabla| consulta(tabla, z)){
+            Some(valor) if valor.is_finite() => Ok(valor),
+            _ => directa(z, parametros),
+        };
 
-    let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {y1}, c="tab:orange", label="Distancia luminosidad")
-plt.plot({xx}, {y2}, c="tab:blue", label="Distancia angular")
-plt.xlabel("z", fontsize=14)
-plt.ylabel("D[Mpc]", fontsize=14)
-plt.legend()
-plt.yscale("log")
-plt.savefig("plots/distancias.svg")
-    "#, xx=format_as_list(&zz), y1 = format_as_list(&d1), y2=format_as_list(&d2));
-    std::fs::write("plots/distancias.py", py_program)?;
-    let mut distancias = Command::new("py").arg("plots/distancias.py").spawn()?;
+    let (zz, d1) = evaluar_hasta_error(&linspace(0., parametros.z_max_dist, PUNTOS_CURVAS),
+                                       |z| de_tabla(z, TablaCosmologica::luminosity_distance, luminosity_distance).map(longitud));
+    let (zz, d2) = evaluar_hasta_error(&zz, |z| de_tabla(z, TablaCosmologica::angular_distance, angular_distance).map(longitud));
+    let d1 = d1[..zz.len()].to_vec();
 
     let mut tt: Vec<f64>;
     let mut aa: Vec<f64>;
     if parametros.rango_factor_escala.0 *parametros.rango_factor_escala.1 <0.{
-        tt = linspace(0., parametros.rango_factor_escala.0, 1000);
-        aa = runge_kutta_at_points(edo_para_a, &tt, parametros.factor_escala, 10, (1, false, parametros));
+        tt = linspace(0., parametros.rango_factor_escala.0, PUNTOS_CURVAS);
+        aa = runge_kutta_at_points(edo_para_a, &tt, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, false, parametros));
         tt.reverse();
         aa.reverse();
-        let tt2: Vec<f64> = linspace(0., parametros.rango_factor_escala.1, 1000);
-        let aa2: Vec<_> = runge_kutta_at_points(edo_para_a, &tt2, parametros.factor_escala, 10, (1, false, parametros));
+        let tt2: Vec<f64> = linspace(0., parametros.rango_factor_escala.1, PUNTOS_CURVAS);
+        let aa2: Vec<_> = runge_kutta_at_points(edo_para_a, &tt2, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, false, parametros));
         tt.extend_from_slice(&tt2[1..]);
         aa.extend_from_slice(&aa2[1..]);
     }
     else{
         let extr = parametros.rango_factor_escala.0.signum() *  parametros.rango_factor_escala.0.abs().max(parametros.rango_factor_escala.1.abs());
-        tt = linspace(0., extr, 1000);
-        aa = runge_kutta_at_points(edo_para_a, &tt, parametros.factor_escala, 10, (1, false, parametros));
+        tt = linspace(0., extr, PUNTOS_CURVAS);
+        aa = runge_kutta_at_points(edo_para_a, &tt, parametros.factor_escala, SUBPASOS_RUNGE_KUTTA, (1, false, parametros));
+    }
+
+    let hh: Vec<_> = aa.as_slice().windows(2).map(|sl| Tasa::por_giga_anyo(if sl[1]>sl[0] {1.} else {-1.}*parametros.constante_hubble *e(parametros.factor_escala/sl[0], parametros))).collect();
+    let (z_hubble, hubble_z) = evaluar_hasta_error(&linspace(0., z_max_hubble, PUNTOS_CURVAS), |z| hubble_parameter(z, parametros).map(|h| unidades.tasa(Tasa::km_s_mpc(h))));
+
+    // let xx: Vec<f64> = linspace(parametros.rango_radio_hubble.0, parametros.rango_radio_hubble.1, 10000);
+    let radio: Vec<_> = hh.iter().map(|&h| unidades.longitud(h.distancia_hubble(), &parametros)).collect();
+    let hh: Vec<_> = hh.into_iter().map(|h| unidades.tasa(h)).collect();
+    let t_hubble = Abscisas::tiempos(&tt[0..tt.len()-1], parametros);
+
+    // println!("root 2: {}", newton_method(|x| x.sqrt(), |x| 2.*x.sqrt(), 2., 1., 1e-6));
+    let horizonte = match horizonte_de_partículas(parametros.t_horizonte_particulas, parametros){
+        Ok((dist, tt, dd)) => {
+            println!("{}", texto!(HorizonteEnT, parametros.t_horizonte_particulas, longitud(dist), unidades.longitud));
+            Curva{x: Abscisas::tiempos(&tt, parametros), y: dd.into_iter().map(longitud).collect()}
+        }
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            Curva{x: Abscisas::tiempos(&[], parametros), y: vec![]}
+        }
+    };
+
+    let (xx, age) = evaluar_hasta_error(&linspace(0., parametros.z_max_edad, PUNTOS_CURVAS),
+                                        |z| de_tabla(z, TablaCosmologica::lookback_time, lookback_time).map(tiempo));
+    if let Some(edad) = age.last(){
+        println!("{}", texto!(EdadEnZ, xx[xx.len()-1], edad, unidades.tiempo))
     }
 
+    let z_distancias = Abscisas::redshifts(&zz, parametros);
+    CurvasModelo{distancia_luminosidad: Curva{x: z_distancias.clone(), y: d1}, distancia_angular: Curva{x: z_distancias, y: d2},
+                 factor_escala: Curva{x: Abscisas::tiempos(&tt, parametros), y: aa}, hubble: Curva{x: t_hubble.clone(), y: hh},
+                 hubble_z: Curva{x: Abscisas::redshifts(&z_hubble, parametros), y: hubble_z}, radio_hubble: Curva{x: t_hubble, y: radio},
+                 horizonte, edad: Curva{x: Abscisas::redshifts(&xx, parametros), y: age}}
+}
+
+/// Una gráfica de la ejecución normal: cuál es, eje y, nombre de la curva principal y de la secundaria (discontinua),
+/// formato de los números del eje y si es lineal (argumentos de `ticklabel_format`) y medidas (x, y, σ) que se dibujan
+/// sobre las curvas, los cronómetros en H(z)
+struct Grafica{
+    tipo: TipoGrafica,
+    eje_y: String,
+    series: (&'static str, &'static str),
+    formato_y: &'static str,
+    puntos: Option<(Vec<f64>, Vec<f64>, Vec<f64>)>,
+}
+
+/// Dibuja una curva por modelo (y otra discontinua si hay secundarias), con leyenda si hay varios modelos. Debajo, si hay más
+/// de uno, un panel con el cociente o la diferencia de cada modelo con el primero, interpolado en los puntos de cada uno.
+/// La variable x, las escalas, los rangos y el estilo de línea son los de [graficas.<nombre>].
+fn dibujar_modelos(grafica: &Grafica, graficas: &ConfiguracionGraficas, unidades: &Unidades, nombres: &[String], curvas: &[&Curva],
+                   secundarias: &[&Curva], comparacion: Comparacion) -> Result<Option<ScriptLanzado>>{
+    let opciones = graficas.opciones(grafica.tipo);
+    let variable = opciones.x.unwrap_or(grafica.tipo.x_por_defecto());
+    let escala_x = opciones.escala_x.unwrap_or_default();
+    let escala_y = opciones.escala_y.unwrap_or(grafica.tipo.escala_y_por_defecto());
+    let valores = |curvas: &[&Curva]| -> (Vec<Vec<f64>>, Vec<Vec<f64>>)
+        {curvas.iter().map(|c| (c.x.valores(variable, unidades), c.y.clone())).unzip()};
+    let (xx, yy) = valores(curvas);
+    let (xx2, yy2) = if secundarias.is_empty() {(vec![Vec::new(); curvas.len()], vec![Vec::new(); curvas.len()])} else {valores(secundarias)};
+    let lista = |listas: &[Vec<f64>]| format!("[{}]", listas.iter().map(format_as_list).collect::<Vec<_>>().join(", "));
+    let (x, y, x2, y2) = (lista(&xx), lista(&yy), lista(&xx2), lista(&yy2));
+    let estilo = opciones.estilo.as_ref().unwrap_or(&graficas.estilo);
+    let grosor = opciones.grosor.unwrap_or(graficas.grosor);
+    let nombre_serie = |serie: &str| if serie.is_empty() {"None".to_string()} else {format!("{:?}", serie)};
+    let eje_x = match variable{
+        VariableX::Tiempo => format!("$t$/{}", unidades.tiempo),
+        VariableX::Redshift => "$z$".to_string(),
+        VariableX::FactorEscala => "$a$".to_string(),
+        VariableX::TiempoConforme => format!(r"$\eta$/{}", unidades.tiempo),
+    };
+    let mut ajustes = format!("ax.set_xscale(\"{}\")\nax.set_yscale(\"{}\")\n", escala_x.matplotlib(), escala_y.matplotlib());
+    if escala_y == Escala::Lineal && !grafica.formato_y.is_empty(){
+        ajustes += &format!("ax.ticklabel_format(axis='y', {})\n", grafica.formato_y);
+    }
+    if let Some((min, max)) = opciones.rango_x{
+        ajustes += &format!("ax.set_xlim({}, {})\n", min, max);
+    }
+    if let Some((min, max)) = opciones.rango_y{
+        ajustes += &format!("ax.set_ylim({}, {})\n", min, max);
+    }
+    if let Some((x_datos, y_datos, sigma_datos)) = &grafica.puntos{
+        ajustes += &format!("ax.errorbar({}, {}, yerr={}, fmt=\".\", c=\"k\", label={:?})\n", format_as_list(x_datos), format_as_list(y_datos),
+                            format_as_list(sigma_datos), Texto::CronometrosCosmicos.texto());
+    }
     let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {yy})
-plt.ticklabel_format(style='plain')
-plt.ylabel("a/lc", fontsize=14)
-plt.xlabel("$t$/Giga años", fontsize=14)
-plt.savefig("plots/factor_escala.svg")
-    "#, xx=format_as_list(&tt), yy = format_as_list(&aa));
-    std::fs::write("plots/factor_escala.py", py_program)?;
-    let mut escala = Command::new("py").arg("plots/factor_escala.py").spawn()?;
-
-    let hh: Vec<_> = aa.as_slice().windows(2).map(|sl|  if sl[1]>sl[0] {1.} else {-1.}*parametros.constante_hubble *e(parametros.factor_escala/sl[0], parametros)*S_TO_GY*3.08567758e19).collect();
+modelos = {nombres:?}
+x = {x}
+y = {y}
+x2 = {x2}
+y2 = {y2}
+comparacion = "{comparacion}"
+estilo = {estilo:?}
+grosor = {grosor}
+if len(modelos) > 1 and comparacion != "ninguna":
+    fig, (ax, panel) = plt.subplots(2, 1, sharex=True, gridspec_kw={{"height_ratios": [3, 1]}})
+else:
+    fig, ax = plt.subplots()
+    panel = None
+def etiqueta(nombre, serie):
+    if len(modelos) == 1:
+        return serie
+    return f"{{nombre}}, {{serie}}" if serie and any(y2) else nombre
+for i, nombre in enumerate(modelos):
+    ax.plot(x[i], y[i], c=f"C{{i}}", ls=estilo, lw=grosor, label=etiqueta(nombre, {serie1}))
+    if y2[i]:
+        ax.plot(x2[i], y2[i], c=f"C{{i}}", ls="--", lw=grosor, label=etiqueta(nombre, {serie2}))
+{ajustes}
+ax.set_ylabel(r"{eje_y}", fontsize=14)
+if panel is None:
+    ax.set_xlabel(r"{eje_x}", fontsize=14)
+else:
+    xr, yr = np.array(x[0]), np.array(y[0])
+    validos = ~np.isnan(xr)
+    orden = np.argsort(xr[validos])
+    xr, yr = xr[validos][orden], yr[validos][orden]
+    for i in range(1, len(modelos)):
+        xi, yi = np.array(x[i]), np.array(y[i])
+        if len(xr) == 0 or len(xi) == 0:
+            continue
+        dentro = (xi >= xr.min()) & (xi <= xr.max())
+        referencia = np.interp(xi[dentro], xr, yr)
+        with np.errstate(divide="ignore", invalid="ignore"):
+            valor = yi[dentro]/referencia if comparacion == "cociente" else yi[dentro] - referencia
+        panel.plot(xi[dentro], valor, c=f"C{{i}}", ls=estilo, lw=grosor)
+    panel.axhline(1 if comparacion == "cociente" else 0, c="C0", lw=0.8)
+    panel.set_ylabel(("÷ " if comparacion == "cociente" else "− ") + modelos[0], fontsize=12)
+    panel.set_xlabel(r"{eje_x}", fontsize=14)
+if ax.get_legend_handles_labels()[1]:
+    ax.legend()
+"#, comparacion=comparacion.nombre(), serie1=nombre_serie(grafica.series.0), serie2=nombre_serie(grafica.series.1),
+        ajustes=ajustes.trim_end(), eje_y=grafica.eje_y);
+    let proceso = lanzar_script(graficas, grafica.tipo.nombre(), &py_program)?;
+
+    if graficas.latex(){
+        let etiqueta = |nombre: &str, serie: &str| match (nombres.len(), serie.is_empty()){
+            (1, true) => None,
+            (1, false) => Some(escapar_latex(serie)),
+            (_, false) if !secundarias.is_empty() => Some(escapar_latex(&format!("{}, {}", nombre, serie))),
+            _ => Some(escapar_latex(nombre)),
+        };
+        let eje_x = etiqueta_latex(&eje_x);
+        let mut ejes = EjesPgf{escala_x, escala_y, rango_x: opciones.rango_x, rango_y: opciones.rango_y, ..EjesPgf::new(eje_x.clone(), etiqueta_latex(&grafica.eje_y))};
+        for (i, nombre) in nombres.iter().enumerate(){
+            let color = format!("C{}", i%10);
+            let serie = SeriePgf::linea(xx[i].clone(), yy[i].clone(), &color, estilo, grosor);
+            ejes.series.push(SeriePgf{leyenda: etiqueta(nombre, grafica.series.0), ..serie});
+            if !yy2[i].is_empty(){
+                let serie = SeriePgf::linea(xx2[i].clone(), yy2[i].clone(), &color, "--", grosor);
+                ejes.series.push(SeriePgf{leyenda: etiqueta(nombre, grafica.series.1), ..serie});
+            }
+        }
+        if let Some((x_datos, y_datos, sigma_datos)) = &grafica.puntos{
+            ejes.series.push(SeriePgf::puntos(x_datos.clone(), y_datos.clone(), sigma_datos.clone(), "black")
+                .con_leyenda(escapar_latex(Texto::CronometrosCosmicos.texto())));
+        }
+        let figura = if nombres.len() > 1 && comparacion != Comparacion::Ninguna{
+            let signo = if comparacion == Comparacion::Cociente {"÷"} else {"−"};
+            let mut panel = EjesPgf{escala_x, rango_x: opciones.rango_x, ..EjesPgf::new(eje_x, escapar_latex(&format!("{} {}", signo, nombres[0])))};
+            for i in 1..nombres.len(){
+                let (x, y) = comparacion.comparar(&xx[0], &yy[0], &xx[i], &yy[i]);
+                panel.series.push(SeriePgf::linea(x, y, &format!("C{}", i%10), estilo, grosor));
+            }
+            let extremos: Vec<f64> = xx.iter().flatten().copied().filter(|x| x.is_finite()).collect();
+            let (min, max) = opciones.rango_x.unwrap_or((extremos.iter().copied().fold(f64::INFINITY, f64::min), extremos.iter().copied().fold(f64::NEG_INFINITY, f64::max)));
+            panel.series.push(SeriePgf::linea(vec![min, max], vec![comparacion.referencia(); 2], "C0", "-", 0.8));
+            ejes.etiqueta_x = String::new();
+            FiguraPgf{altos: vec![3., 1.], compartir_ejes: true, ..FiguraPgf::rejilla(vec![ejes, panel], 1, graficas.tamanyo)}
+        }
+        else {FiguraPgf::new(ejes, graficas.tamanyo)};
+        graficas.escribir_latex(grafica.tipo.nombre(), &figura)?;
+    }
+    Ok(proceso)
+}
+
+/// Script de una gráfica que se está ejecutando con python
+struct ScriptLanzado{
+    script: PathBuf,
+    proceso: std::process::Child,
+}
+
+impl ScriptLanzado{
+    /// Espera a que acabe. Si python no termina bien (p.ej. porque falta matplotlib) las imágenes no se han guardado.
+    fn esperar(mut self) -> Result<()>{
+        let estado = self.proceso.wait()?;
+        if !estado.success(){
+            // Sin código de salida si lo ha terminado una señal
+            anyhow::bail!(texto!(ScriptFallido, self.script.display(), estado.code().map_or(estado.to_string(), |codigo| codigo.to_string())));
+        }
+        Ok(())
+    }
+}
+
+/// Escribe el script de una gráfica en el directorio de las gráficas y lo lanza con python sin esperar a que acabe. Si sólo
+/// se han pedido formatos de LaTeX no hay script.
+fn lanzar_script(graficas: &ConfiguracionGraficas, nombre: &str, cuerpo: &str) -> Result<Option<ScriptLanzado>>{
+    if graficas.imagenes(nombre).is_empty(){
+        return Ok(None);
+    }
+    let script = graficas.escribir_script(nombre, cuerpo)?;
+    let proceso = Command::new("py").arg(&script).spawn()?;
+    Ok(Some(ScriptLanzado{script, proceso}))
+}
+
+/// Escribe el script de una gráfica, lo ejecuta y dice dónde se ha guardado. Si se han pedido formatos de LaTeX, escribe
+/// también la `figura`, que sólo se prepara entonces.
+fn dibujar_script(graficas: &ConfiguracionGraficas, nombre: &str, cuerpo: &str, figura: impl FnOnce() -> Result<FiguraPgf>) -> Result<()>{
+    if let Some(script) = lanzar_script(graficas, nombre, cuerpo)?{
+        script.esperar()?;
+    }
+    let mut ficheros = graficas.imagenes(nombre);
+    if graficas.latex(){
+        ficheros.extend(graficas.escribir_latex(nombre, &figura()?)?);
+    }
+    let ficheros: Vec<String> = ficheros.iter().map(|fichero| fichero.display().to_string()).collect();
+    println!("{}", texto!(GraficaGuardada, ficheros.join(", ")));
+    Ok(())
+}
+
+/// Orden `barrido`: tabla de magnitudes derivadas variando uno o dos parámetros, con una curva por magnitud
+/// o, con dos parámetros, un mapa de color
+fn dibujar_barrido(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Result<()>{
+    let opciones = &configuracion.barrido;
+    let resultado = barrido(parametros, opciones)?;
+    let unidades = &configuracion.unidades;
+    resultado.escribir(&opciones.salida, unidades)?;
+    println!("{}", texto!(TablaPuntosGuardada, resultado.derivadas.len(), opciones.salida.display()));
+
+    let columnas = columnas(&resultado.z_distancia, unidades);
+    let filas: Vec<Vec<f64>> = resultado.derivadas.iter().map(|d| d.valores(unidades)).collect();
+    // a_max es infinito si el universo se expande para siempre: en la gráfica se deja en blanco como los NaN
+    let valores_columnas: Vec<Vec<f64>> = (0..columnas.len())
+        .map(|c| filas.iter().map(|f| if f[c].is_finite() {f[c]} else {f64::NAN}).collect())
+        .collect();
+    let valores: Vec<String> = valores_columnas.iter().map(format_as_list).collect();
+    let etiquetas: Vec<&String> = columnas.iter().map(|(_, etiqueta)| etiqueta).collect();
+    let nombres: Vec<&str> = resultado.parametros.iter().map(|p| p.nombre()).collect();
     let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {yy})
-plt.ylabel("H/km/s/Mpc", fontsize=14)
-plt.xlabel("$t$/Giga años", fontsize=14)
-# plt.ticklabel_format(style='sci', axis='y', scilimits=(5,-5))
-plt.yscale("log")
-plt.savefig("plots/constante_hubble.svg")
-    "#, xx=format_as_list(&tt[0..tt.len()-1]), yy = format_as_list(&hh));
-    std::fs::write("plots/constante_hubble.py", py_program)?;
-    let mut hubble = Command::new("py").arg("plots/constante_hubble.py").spawn()?;
-    
-    // let xx: Vec<f64> = linspace(parametros.rango_radio_hubble.0, parametros.rango_radio_hubble.1, 10000);
-    let yy: Vec<_> = hh.iter().map(|a| parametros.c/a*parametros.legua_cosmica_a_mpc).collect();
+x = {x}
+y = {y}
+nombres = {nombres:?}
+etiquetas = {etiquetas:?}
+valores = [{valores}]
+columnas = 3
+filas = (len(etiquetas) + columnas - 1)//columnas
+fig, ejes = plt.subplots(filas, columnas, figsize=(5*columnas, 4*filas), squeeze=False)
+for eje, etiqueta, v in zip(ejes.flat, etiquetas, valores):
+    v = np.ma.masked_invalid(np.array(v, dtype=float))
+    if y:
+        malla = eje.pcolormesh(x, y, v.reshape(len(y), len(x)), shading="auto")
+        fig.colorbar(malla, ax=eje)
+        eje.set_title(etiqueta)
+        eje.set_ylabel(nombres[1])
+    else:
+        eje.plot(x, v)
+        eje.set_ylabel(etiqueta)
+    eje.set_xlabel(nombres[0])
+for eje in list(ejes.flat)[len(etiquetas):]:
+    eje.set_visible(False)
+fig.tight_layout()
+"#, x=format_as_list(&resultado.ejes[0]), y=resultado.ejes.get(1).map_or("[]".to_string(), format_as_list), valores=valores.join(", "));
+    dibujar_script(&configuracion.graficas, "barrido", &py_program, || {
+        let x = &resultado.ejes[0];
+        let mut ejes: Vec<EjesPgf> = etiquetas.iter().zip(valores_columnas).map(|(etiqueta, valores)| {
+            let mut ejes = EjesPgf::new(escapar_latex(nombres[0]), "");
+            match resultado.ejes.get(1){
+                Some(y) => {
+                    ejes.titulo = etiqueta_latex(etiqueta);
+                    ejes.etiqueta_y = escapar_latex(nombres[1]);
+                    ejes.barra_colores = true;
+                    let puntos = x.len()*y.len();
+                    ejes.series.push(SeriePgf{tipo: TipoSerie::Mapa{columnas: x.len(), colores: MapaColores::Viridis, opacidad: 1.}, color: String::new(),
+                                              leyenda: None, x: (0..puntos).map(|k| x[k%x.len()]).collect(),
+                                              y: (0..puntos).map(|k| y[k/x.len()]).collect(), z: valores});
+                }
+                None => {
+                    ejes.etiqueta_y = etiqueta_latex(etiqueta);
+                    ejes.series.push(SeriePgf::linea(x.clone(), valores, "C0", "-", configuracion.graficas.grosor));
+                }
+            }
+            ejes
+        }).collect();
+        let filas = etiquetas.len().div_ceil(3);
+        ejes.resize_with(3*filas, EjesPgf::vacio);
+        Ok(FiguraPgf::rejilla(ejes, 3, (15., 4.*filas as f64)))
+    })
+}
+
+/// Muestra los presets y sus parámetros
+fn listar_presets() -> Result<()>{
+    for preset in Preset::TODOS{
+        let v = preset.valores();
+        println!("{:<10} {}", preset.nombre(), preset.descripcion());
+        println!("           H0 = {} km/s/Mpc, Ωm = {}, Ωr = {:.4e}, ΩΛ = {}, w = {}, wa = {}, σ8 = {}, Ωb = {}",
+                 v.constante_hubble, v.densidad_materia, v.densidad_radiacion, v.densidad_energia_oscura, v.w, v.wa, v.sigma8, v.densidad_bariones);
+    }
+    println!("{}", texto!(UsoPresets));
+    Ok(())
+}
+
+/// Diagnóstico con el nombre del fichero delante, como los de los compiladores
+fn con_fichero(diagnostico: &Diagnostico) -> String{
+    match diagnostico.posicion{
+        Some(_) => format!("input_data.toml:{}", diagnostico),
+        None => format!("input_data.toml: {}", diagnostico),
+    }
+}
+
+/// Muestra los errores y avisos del fichero de entrada. Termina con error si hay alguno grave.
+fn validar(diagnosticos: &[Diagnostico]) -> Result<()>{
+    for diagnostico in diagnosticos{
+        println!("{}", con_fichero(diagnostico));
+    }
+    let errores = diagnosticos.iter().filter(|d| d.gravedad == Gravedad::Error).count();
+    let avisos = diagnosticos.len() - errores;
+    let avisos = if avisos == 1 {texto!(UnAviso)} else {texto!(VariosAvisos, avisos)};
+    match errores{
+        0 => {
+            println!("{}", texto!(FicheroValido, avisos));
+            Ok(())
+        }
+        1 => anyhow::bail!(texto!(ErroresYAvisos, texto!(UnError), avisos)),
+        _ => anyhow::bail!(texto!(ErroresYAvisos, texto!(VariosErrores, errores), avisos)),
+    }
+}
+
+/// El texto con la primera letra en mayúscula, para las leyendas
+fn con_mayuscula(texto: &str) -> String{
+    let mut letras = texto.chars();
+    letras.next().map(|primera| primera.to_uppercase().chain(letras).collect()).unwrap_or_default()
+}
+
+/// Evalúa f en los puntos dados hasta el primer error, que se muestra por pantalla.
+/// Devuelve los puntos en los que se ha podido calcular y sus valores.
+fn evaluar_hasta_error(xx: &[f64], f: impl Fn(f64) -> Result<f64, ErrorCosmologico>) -> (Vec<f64>, Vec<f64>){
+    let mut yy = Vec::with_capacity(xx.len());
+    for &x in xx{
+        match f(x){
+            Ok(y) => yy.push(y),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                break;
+            }
+        }
+    }
+    (xx[..yy.len()].to_vec(), yy)
+}
+
+/// Diagrama Ωm–ΩΛ con el tipo de universo, la línea de universo plano, la de q0 = 0 y contornos de edad
+fn dibujar_diagrama_fases(parametros: ParametrosCosmologicos, opciones: ConfiguracionDiagramaFases, graficas: &ConfiguracionGraficas) -> Result<()>{
+    let diagrama = diagrama_fases(parametros, opciones);
+    let tipos: Vec<f64> = diagrama.tipos.iter().map(|&t| t as u8 as f64).collect();
+    let tipos_universo = [(TipoUniverso::ExpansionEterna, Texto::ExpansionEterna), (TipoUniverso::Recolapso, Texto::Recolapso),
+                          (TipoUniverso::Rebote, Texto::Rebote), (TipoUniverso::Remoloneo, Texto::Remoloneo)];
+    for (tipo, nombre) in tipos_universo{
+        println!("{}", texto!(ModelosDeTipo, nombre, diagrama.tipos.iter().filter(|&&t| t == tipo).count()));
+    }
 
     let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {yy})
-plt.ticklabel_format(style='sci', axis='y', scilimits=(-3, 3))
-plt.ylabel(r"$D_{{\rm H}}$/Mpc", fontsize=14)
-plt.xlabel("$t$/Giga años", fontsize=14)
-plt.savefig("plots/radio de hubble.svg")
-    "#, xx=format_as_list(&tt[0..tt.len()-1]), yy = format_as_list(&yy));
-    std::fs::write("plots/radio_hubble.py", py_program)?;
-    let mut hubble_rad = Command::new("py").arg("plots/radio_hubble.py").spawn()?;
- 
+from matplotlib.colors import ListedColormap
+from matplotlib.patches import Patch
+om = np.array({om})
+ol = np.array({ol})
+tipos = np.array({tipos}).reshape(len(ol), len(om))
+edad = np.array({edad}).reshape(len(ol), len(om))
+colores = ["tab:blue", "tab:red", "tab:gray", "tab:green"]
+plt.pcolormesh(om, ol, tipos, cmap=ListedColormap(colores), vmin=-0.5, vmax=3.5, shading="nearest", alpha=0.35)
+cs = plt.contour(om, ol, edad, levels=[5, 10, 13.8, 20, 30], colors="k", linewidths=0.7)
+plt.clabel(cs, fmt="%g {ga}", fontsize=8)
+plt.plot(om, 1-om-{or_}, "k--", label={plano:?})
+plt.plot(om, -(om+2*{or_})/(1+3*{w}), "k:", label="$q_0=0$ ({aceleracion})")
+nombres = {nombres:?}
+handles, _ = plt.gca().get_legend_handles_labels()
+plt.legend(handles=handles + [Patch(color=c, alpha=0.35, label=n) for c, n in zip(colores, nombres)], fontsize=8)
+plt.xlim(om[0], om[-1])
+plt.ylim(ol[0], ol[-1])
+plt.xlabel(r"$\Omega_m$", fontsize=14)
+plt.ylabel(r"$\Omega_\Lambda$", fontsize=14)
+"#, om=format_as_list(&diagrama.materia), ol=format_as_list(&diagrama.energia_oscura), tipos=format_as_list(&tipos),
+        edad=format_as_list(&diagrama.edades), or_=parametros.densidad_radiacion, w=parametros.w, ga=Texto::GigaAnyosCorto,
+        plano=con_mayuscula(Texto::UniversoPlano.texto()), aceleracion=Texto::Aceleracion,
+        nombres=tipos_universo.map(|(_, nombre)| con_mayuscula(nombre.texto())));
+    dibujar_script(graficas, "diagrama_fases", &py_program, || {
+        let (om, ol) = (&diagrama.materia, &diagrama.energia_oscura);
+        let colores = ["C0", "C3", "C7", "C2"];
+        let mut ejes = EjesPgf{rango_x: Some((om[0], om[om.len()-1])), rango_y: Some((ol[0], ol[ol.len()-1])),
+                               ..EjesPgf::new(r"$\Omega_m$", r"$\Omega_\Lambda$")};
+        let puntos = om.len()*ol.len();
+        ejes.series.push(SeriePgf{tipo: TipoSerie::Mapa{columnas: om.len(), colores: MapaColores::Categorias(colores.map(String::from).to_vec()), opacidad: 0.35},
+                                  color: String::new(), leyenda: None, x: (0..puntos).map(|k| om[k%om.len()]).collect(),
+                                  y: (0..puntos).map(|k| ol[k/om.len()]).collect(), z: tipos.clone()});
+        for nivel in [5., 10., 13.8, 20., 30.]{
+            let segmentos = contorno(om, ol, &diagrama.edades, nivel);
+            if let Some(&((x, y), _)) = segmentos.get(segmentos.len()/2){
+                ejes.notas.push((x, y, format!("{} {}", nivel, Texto::GigaAnyosCorto)));
+            }
+            ejes.series.push(SeriePgf::segmentos(&segmentos, "black", "-", 0.7));
+        }
+        let o_r = parametros.densidad_radiacion;
+        ejes.series.push(SeriePgf::linea(om.clone(), om.iter().map(|m| 1. - m - o_r).collect(), "black", "--", graficas.grosor)
+            .con_leyenda(escapar_latex(&con_mayuscula(Texto::UniversoPlano.texto()))));
+        ejes.series.push(SeriePgf::linea(om.clone(), om.iter().map(|m| -(m + 2.*o_r)/(1. + 3.*parametros.w)).collect(), "black", ":", graficas.grosor)
+            .con_leyenda(format!("$q_0=0$ ({})", escapar_latex(Texto::Aceleracion.texto()))));
+        ejes.leyendas_area = colores.iter().zip(tipos_universo)
+            .map(|(color, (_, nombre))| (color.to_string(), 0.35, escapar_latex(&con_mayuscula(nombre.texto())))).collect();
+        Ok(FiguraPgf::new(ejes, graficas.tamanyo))
+    })
+}
 
-    // println!("root 2: {}", newton_method(|x| x.sqrt(), |x| 2.*x.sqrt(), 2., 1., 1e-6));
-    let (dist, tt, dd) = horizonte_de_partículas(parametros.t_horizonte_particulas, parametros);
+/// Ajuste de los parámetros libres a los datos configurados y gráfica de residuos de las supernovas
+fn ajuste(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Result<()>{
+    let verosimilitudes = configuracion.verosimilitudes()?;
+    let ajuste = ajustar(&verosimilitudes, parametros, &configuracion.ajuste.parametros);
+    println!("{}:", Texto::MejorAjuste);
+    for ((parametro, valor), error) in ajuste.libres.iter().zip(&ajuste.valores).zip(&ajuste.errores){
+        println!("    {} = {} ± {}", parametro.nombre(), valor, error);
+    }
+    // Con tantos parámetros libres como datos χ²/ν no está definido
+    match ajuste.grados_libertad{
+        0 => println!("{}", texto!(Chi2SinGrados, ajuste.chi2)),
+        nu => println!("{}", texto!(Chi2Grados, ajuste.chi2, nu, ajuste.chi2/nu as f64)),
+    }
+    if configuracion.bao.is_some(){
+        println!("{}", texto!(HorizonteArrastre, drag_sound_horizon(ajuste.parametros)));
+    }
+    if configuracion.cmb.is_some(){
+        match (decoupling_redshift(ajuste.parametros), shift_parameter(ajuste.parametros), acoustic_scale(ajuste.parametros)){
+            (Ok(z), Ok(r), Ok(l_a)) => println!("CMB: z_* = {}, R = {}, l_A = {}", z, r, l_a),
+            (Err(err), _, _) | (_, Err(err), _) | (_, _, Err(err)) => eprintln!("Error: {}", err),
+        }
+    }
 
-    println!("horizonte de particulas en t={} Giga años es: {} Mpc", parametros.t_horizonte_particulas, dist*parametros.legua_cosmica_a_mpc);
+    let Some(fuente) = &configuracion.supernovas else {return Ok(())};
+    let catalogo = CatalogoSupernovas::leer(&fuente.datos, fuente.covarianza.as_deref())?;
+    let desplazamiento = catalogo.desplazamiento_optimo(ajuste.parametros);
+    println!("{}", texto!(DesplazamientoSupernovas, desplazamiento));
+    let corregido: Vec<f64> = catalogo.valor.iter().map(|v| v - desplazamiento).collect();
+    let z_max = catalogo.z.iter().copied().fold(0., f64::max);
+    let (zz, mu) = evaluar_hasta_error(&linspace(z_max/1000., z_max, 500), |z| distance_modulus(z, ajuste.parametros));
+    let residuos = catalogo.residuos(ajuste.parametros);
 
     let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {yy})
-plt.ticklabel_format(style='sci', axis='y', scilimits=(-3, 3))
-plt.ylabel(r"$D_{{\rm HP}}$/Mpc", fontsize=14)
-plt.xlabel("$t$/Giga años", fontsize=14)
-plt.savefig("plots/horizonte particulas.svg")
-    "#, xx=format_as_list(&tt), yy = format_as_list(&dd.iter().map(|x| x*parametros.legua_cosmica_a_mpc).collect::<Vec<_>>()));
-    std::fs::write("plots/horizonte_de_partículas.py", py_program)?;
-    let mut horiz = Command::new("py").arg("plots/horizonte_de_partículas.py").spawn()?;
-
-    let mut xx = linspace(0., parametros.z_max_edad, 1000);
-    let age: Vec<_>;
-    if lookback_time(0., parametros).is_finite(){
-        age = xx.iter().map(|z| lookback_time(*z, parametros)).collect();
-        println!("Edad del universo en z={} es: {} giga años", parametros.z_max_edad, age[age.len()-1])
+fig, (ax1, ax2) = plt.subplots(2, 1, sharex=True, gridspec_kw={{"height_ratios": [3, 1]}})
+ax1.errorbar({z}, {mu_obs}, yerr={sigma}, fmt=".", c="tab:blue", label={supernovas:?})
+ax1.plot({zz}, {mu}, c="tab:orange", label={mejor_ajuste:?})
+ax1.set_ylabel(r"$\mu$", fontsize=14)
+ax1.legend()
+ax2.errorbar({z}, {residuos}, yerr={sigma}, fmt=".", c="tab:blue")
+ax2.axhline(0, c="tab:orange")
+ax2.set_xscale("log")
+ax2.set_xlabel("z", fontsize=14)
+ax2.set_ylabel(r"$\Delta\mu$", fontsize=14)
+"#, z=format_as_list(&catalogo.z), mu_obs=format_as_list(&corregido), sigma=format_as_list(&catalogo.sigma),
+        zz=format_as_list(&zz), mu=format_as_list(&mu), residuos=format_as_list(&residuos),
+        supernovas=Texto::Supernovas.texto(), mejor_ajuste=Texto::MejorAjuste.texto());
+    let graficas = &configuracion.graficas;
+    dibujar_script(graficas, "residuos_supernovas", &py_program, || {
+        let mut distancias = EjesPgf{escala_x: Escala::Log, ..EjesPgf::new("", r"$\mu$")};
+        distancias.series = vec![SeriePgf::puntos(catalogo.z.clone(), corregido, catalogo.sigma.clone(), "C0").con_leyenda(escapar_latex(Texto::Supernovas.texto())),
+                                 SeriePgf::linea(zz, mu, "C1", "-", graficas.grosor).con_leyenda(escapar_latex(Texto::MejorAjuste.texto()))];
+        let mut panel = EjesPgf{escala_x: Escala::Log, ..EjesPgf::new("$z$", r"$\Delta\mu$")};
+        let z_min = catalogo.z.iter().copied().fold(f64::INFINITY, f64::min);
+        panel.series = vec![SeriePgf::puntos(catalogo.z.clone(), residuos, catalogo.sigma.clone(), "C0"),
+                            SeriePgf::linea(vec![z_min, z_max], vec![0., 0.], "C1", "-", graficas.grosor)];
+        Ok(FiguraPgf{altos: vec![3., 1.], compartir_ejes: true, ..FiguraPgf::rejilla(vec![distancias, panel], 1, graficas.tamanyo)})
+    })
+}
+
+/// Muestreo de la distribución posterior con MCMC, resumen de los parámetros y gráfica de esquina
+fn mcmc(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos, continuar: bool) -> Result<()>{
+    let verosimilitudes = configuracion.verosimilitudes()?;
+    let opciones = &configuracion.mcmc;
+    let resultado = muestrear(&verosimilitudes, parametros, &configuracion.priors, opciones, continuar)?;
+    println!("{}", texto!(CadenaGuardada, opciones.salida.display()));
+    println!("{}", texto!(FraccionAceptacion, resultado.fraccion_aceptacion));
+    resumir_cadena(&resultado.cadena, &opciones.salida, opciones.quemado, &configuracion.graficas)
+}
+
+/// Muestreo de la distribución posterior con NUTS o HMC, con el mismo resumen y la misma gráfica que `mcmc`
+fn hmc(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Result<()>{
+    let verosimilitudes = configuracion.verosimilitudes()?;
+    let opciones = &configuracion.hmc;
+    let resultado = muestrear_hmc(&verosimilitudes, parametros, &configuracion.priors, opciones)?;
+    println!("{}", texto!(CadenaGuardada, opciones.salida.display()));
+    for (i, estadisticas) in resultado.estadisticas.iter().enumerate(){
+        println!("{}", texto!(EstadisticasCadena, i, estadisticas.paso_salto, estadisticas.aceptacion, estadisticas.saltos, estadisticas.divergencias));
     }
-    else{
-        xx = vec![];
-        age = vec![];
+    if resultado.estadisticas.iter().any(|e| e.divergencias > 0){
+        eprintln!("{}", texto!(AvisoDivergencias));
+    }
+    resumir_cadena(&resultado.cadena, &opciones.salida, opciones.adaptacion, &configuracion.graficas)
+}
+
+/// Media y desviación típica de cada parámetro y gráfica de esquina de una cadena, sin los pasos anteriores a `quemado`
+fn resumir_cadena(cadena: &Cadena, salida: &std::path::Path, quemado: usize, graficas: &ConfiguracionGraficas) -> Result<()>{
+    let muestras: Vec<&[f64]> = cadena.muestras(quemado).collect();
+    if muestras.len() < 2{
+        anyhow::bail!(texto!(SinMuestras, quemado));
+    }
+    for (i, parametro) in cadena.parametros.iter().enumerate(){
+        let media = muestras.iter().map(|m| m[i]).sum::<f64>()/muestras.len() as f64;
+        let varianza = muestras.iter().map(|m| (m[i] - media).powi(2)).sum::<f64>()/(muestras.len() as f64 - 1.);
+        println!("    {} = {} ± {}", parametro.nombre(), media, varianza.sqrt());
     }
+    dibujar_esquina(&cadena.parametros, salida, quemado, "corner", graficas)
+}
+
+/// Evidencia de cada modelo con muestreo anidado, tabla de comparación y gráfica de esquina de cada posterior
+fn anidado(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Result<()>{
+    let verosimilitudes = configuracion.verosimilitudes()?;
+    let opciones = &configuracion.anidado;
+    let modelos = opciones.modelos();
+    let resultados = comparar_modelos(&verosimilitudes, parametros, &configuracion.priors, opciones)?;
+    for (modelo, resultado) in modelos.iter().zip(&resultados){
+        let salida = opciones.salida_modelo(modelo);
+        println!("{}", texto!(ResumenAnidado, modelo.nombre, resultado.ln_evidencia, resultado.error_ln_evidencia, resultado.informacion, resultado.iteraciones, resultado.evaluaciones));
+        for (parametro, (media, desviacion)) in resultado.parametros.iter().zip(resultado.media_desviacion()){
+            println!("    {} = {} ± {}", parametro.nombre(), media, desviacion);
+        }
+        println!("{}", texto!(MuestrasEfectivas, resultado.muestras_efectivas().round(), salida.display()));
+        let grafica = format!("corner_{}", salida.file_stem().unwrap_or_default().to_string_lossy());
+        dibujar_esquina(&resultado.parametros, &salida, 0, &grafica, &configuracion.graficas)?;
+    }
+
+    println!("{}", texto!(ComparacionCon, modelos[0].nombre));
+    println!("{:<24} {:>3} {:>22} {:>22} {:>10}  {}", Texto::ColumnaModelo, "n", "ln Z", "ln B", "χ²_min", Texto::ColumnaEvidencia);
+    let referencia = &resultados[0];
+    for (i, (modelo, resultado)) in modelos.iter().zip(&resultados).enumerate(){
+        let ln_b = resultado.ln_evidencia - referencia.ln_evidencia;
+        let error_ln_b = if i == 0 {0.} else {resultado.error_ln_evidencia.hypot(referencia.error_ln_evidencia)};
+        println!("{:<24} {:>3} {:>12.3} ± {:<7.3} {:>12.3} ± {:<7.3} {:>10.3}  {}", modelo.nombre, resultado.parametros.len(),
+                 resultado.ln_evidencia, resultado.error_ln_evidencia, ln_b, error_ln_b, resultado.chi2_minimo(),
+                 if i == 0 {Texto::Referencia.texto()} else {escala_jeffreys(ln_b)});
+    }
+    println!("{}", texto!(TablaGuardada, opciones.tabla.display()));
+    Ok(())
+}
+
+/// Gráfica de esquina <grafica> de una cadena guardada en `salida`, sin los pasos anteriores a `quemado`
+fn dibujar_esquina(parametros: &[Parametro], salida: &std::path::Path, quemado: usize, grafica: &str, graficas: &ConfiguracionGraficas) -> Result<()>{
+    let salida = salida.display().to_string().replace('\\', "/");
+    let cargar = if salida.ends_with(".npy") {format!("np.load(\"{salida}\")")} else {format!("np.loadtxt(\"{salida}\", delimiter=\",\", skiprows=1)")};
+    let nombres: Vec<String> = parametros.iter().map(|p| format!("\"{}\"", p.nombre())).collect();
     let py_program = format!(r#"
-import numpy as np
-import matplotlib.pyplot as plt
-plt.plot({xx}, {yy})
-plt.ticklabel_format(style='plain')
-plt.ylabel("edad del universo en giga años", fontsize=14)
-plt.xlabel("$z$", fontsize=14)
-plt.savefig("plots/edad del universo.svg")
-    "#, xx=format_as_list(&xx), yy = format_as_list(&age));
-    std::fs::write("plots/edad_universo.py", py_program)?;
-    let mut edad = Command::new("py").arg("plots/edad_universo.py").spawn()?;
-
-
-
-    distancias.wait()?;
-    hubble.wait()?;
-    escala.wait()?;
-    hubble_rad.wait()?;
-    horiz.wait()?;
-    edad.wait()?;
-
-    println!("Pulse enter para salir...");
-    std::io::stdin().read_line(&mut String::new()).unwrap();
-    
+datos = {cargar}
+datos = datos[datos[:, 0] >= {quemado}]
+nombres = [{nombres}]
+n = len(nombres)
+fig, ejes = plt.subplots(n, n, figsize=(2.5*n, 2.5*n), squeeze=False)
+for i in range(n):
+    for j in range(n):
+        ax = ejes[i, j]
+        if j > i:
+            ax.axis("off")
+            continue
+        if i == j:
+            ax.hist(datos[:, 2+i], bins=40, histtype="step", color="k")
+            ax.set_yticks([])
+        else:
+            ax.hist2d(datos[:, 2+j], datos[:, 2+i], bins=40, cmap="Greys")
+        if i == n-1:
+            ax.set_xlabel(nombres[j].replace("_", " "))
+        if j == 0 and i > 0:
+            ax.set_ylabel(nombres[i].replace("_", " "))
+plt.tight_layout()
+"#, nombres=nombres.join(", "));
+    dibujar_script(graficas, grafica, &py_program, || {
+        let cadena = Cadena::leer(std::path::Path::new(&salida), parametros)?;
+        let muestras: Vec<&[f64]> = cadena.muestras(quemado).collect();
+        let columna = |i: usize| -> Vec<f64> {muestras.iter().map(|m| m[i]).collect()};
+        let rangos: Vec<(f64, f64)> = (0..parametros.len())
+            .map(|i| columna(i).iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| (min.min(v), max.max(v))))
+            .collect();
+        let n = parametros.len();
+        let mut ejes = Vec::with_capacity(n*n);
+        for i in 0..n{
+            for j in 0..n{
+                if j > i{
+                    ejes.push(EjesPgf::vacio());
+                    continue;
+                }
+                let etiqueta_x = if i == n-1 {escapar_latex(&parametros[j].nombre().replace('_', " "))} else {String::new()};
+                let mut celda = EjesPgf{rango_x: Some(rangos[j]), ..EjesPgf::new(etiqueta_x, "")};
+                if i == j{
+                    let (bordes, cuentas) = histograma(&columna(i), rangos[i], 40);
+                    // El último escalón tiene que llegar hasta el último borde
+                    let cuentas = cuentas.iter().copied().chain(cuentas.last().copied()).collect();
+                    celda.series.push(SeriePgf{tipo: TipoSerie::Escalones, color: "black".to_string(), leyenda: None, x: bordes, y: cuentas, z: Vec::new()});
+                    celda.sin_marcas_y = true;
+                }
+                else{
+                    if j == 0{
+                        celda.etiqueta_y = escapar_latex(&parametros[i].nombre().replace('_', " "));
+                    }
+                    celda.rango_y = Some(rangos[i]);
+                    celda.series.push(histograma_2d(&columna(j), &columna(i), rangos[j], rangos[i], 40));
+                }
+                ejes.push(celda);
+            }
+        }
+        Ok(FiguraPgf{compartir_ejes: true, ..FiguraPgf::rejilla(ejes, n, (2.5*n as f64, 2.5*n as f64))})
+    })
+}
+
+/// Histograma de `valores` en `bins` intervalos iguales de `rango`: los bins + 1 bordes y las cuentas de cada intervalo
+fn histograma(valores: &[f64], rango: (f64, f64), bins: usize) -> (Vec<f64>, Vec<f64>){
+    let (min, max) = rango;
+    let bordes = (0..=bins).map(|k| min + (max - min)*k as f64/bins as f64).collect();
+    let mut cuentas = vec![0.; bins];
+    for &v in valores{
+        cuentas[intervalo(v, rango, bins)] += 1.;
+    }
+    (bordes, cuentas)
+}
+
+/// Intervalo de `v` en `bins` intervalos de `rango`, con el máximo en el último
+fn intervalo(v: f64, (min, max): (f64, f64), bins: usize) -> usize{
+    if max > min {(((v - min)/(max - min)*bins as f64) as usize).min(bins - 1)} else {0}
+}
+
+/// Histograma de las parejas (x, y) como mapa de grises de `bins` × `bins` celdas, con el valor de cada una en su centro
+fn histograma_2d(x: &[f64], y: &[f64], rango_x: (f64, f64), rango_y: (f64, f64), bins: usize) -> SeriePgf{
+    let mut cuentas = vec![0.; bins*bins];
+    for (&x, &y) in x.iter().zip(y){
+        cuentas[intervalo(y, rango_y, bins)*bins + intervalo(x, rango_x, bins)] += 1.;
+    }
+    let centro = |(min, max): (f64, f64), k: usize| min + (max - min)*(k as f64 + 0.5)/bins as f64;
+    SeriePgf{tipo: TipoSerie::Mapa{columnas: bins, colores: MapaColores::Grises, opacidad: 1.}, color: String::new(), leyenda: None,
+             x: (0..bins*bins).map(|k| centro(rango_x, k%bins)).collect(), y: (0..bins*bins).map(|k| centro(rango_y, k/bins)).collect(), z: cuentas}
+}
+
+/// Previsión de errores de una encuesta con la matriz de Fisher: errores, elipses y figura de mérito
+fn previsión_fisher(parametros: ParametrosCosmologicos, encuesta: &Encuesta, graficas: &ConfiguracionGraficas) -> Result<()>{
+    let fisher = fisher(encuesta, parametros)?;
+    let marginalizados = fisher.errores_marginalizados();
+    let condicionados = fisher.errores_condicionados();
+    println!("{}", texto!(CabeceraFisher));
+    for (i, parametro) in fisher.parametros.iter().enumerate(){
+        println!("    {} = {} ± {} ({})", parametro.nombre(), fisher.fiduciales[i], marginalizados[i], condicionados[i]);
+    }
+    let n = fisher.parametros.len();
+    println!("{}", texto!(CabeceraElipses));
+    let mut elipses = Vec::new();
+    for i in 0..n{
+        for j in i+1..n{
+            let elipse = fisher.elipse(i, j);
+            let escala = DELTA_CHI2_68.sqrt();
+            println!("    {}–{}: {} × {}, {}°", fisher.parametros[i].nombre(), fisher.parametros[j].nombre(),
+                     escala*elipse.semieje_mayor, escala*elipse.semieje_menor, elipse.angulo.to_degrees());
+            elipses.extend([i as f64, j as f64, elipse.semieje_mayor, elipse.semieje_menor, elipse.angulo.to_degrees()]);
+        }
+    }
+    match fisher.figura_merito(){
+        Some(fom) => println!("{}", texto!(FiguraMerito, fom)),
+        None => println!("{}", texto!(SinFiguraMerito)),
+    }
+
+    let nombres: Vec<String> = fisher.parametros.iter().map(|p| format!("\"{}\"", p.nombre())).collect();
+    let py_program = format!(r#"
+from matplotlib.patches import Ellipse
+nombres = [{nombres}]
+fiducial = {fiducial}
+sigma = {sigma}
+elipses = np.array({elipses}).reshape(-1, 5)
+n = len(nombres)
+fig, axs = plt.subplots(n-1, n-1, figsize=(2.2*(n-1), 2.2*(n-1)), squeeze=False)
+for ax in axs.flat:
+    ax.set_visible(False)
+for i, j, a, b, angulo in elipses:
+    i, j = int(i), int(j)
+    ax = axs[j-1, i]
+    ax.set_visible(True)
+    for dchi2, alpha in [({dchi2_95}, 0.3), ({dchi2_68}, 0.6)]:
+        k = np.sqrt(dchi2)
+        ax.add_patch(Ellipse((fiducial[i], fiducial[j]), 2*k*a, 2*k*b, angle=angulo, color="tab:blue", alpha=alpha))
+    ax.set_xlim(fiducial[i] - 3*sigma[i], fiducial[i] + 3*sigma[i])
+    ax.set_ylim(fiducial[j] - 3*sigma[j], fiducial[j] + 3*sigma[j])
+    if j == n-1:
+        ax.set_xlabel(nombres[i])
+    else:
+        ax.set_xticklabels([])
+    if i == 0:
+        ax.set_ylabel(nombres[j])
+    else:
+        ax.set_yticklabels([])
+plt.tight_layout()
+"#, nombres=nombres.join(", "), fiducial=format_as_list(&fisher.fiduciales), sigma=format_as_list(&marginalizados),
+        elipses=format_as_list(&elipses), dchi2_68=DELTA_CHI2_68, dchi2_95=DELTA_CHI2_95);
+    dibujar_script(graficas, "fisher", &py_program, || {
+        let rango = |i: usize| Some((fisher.fiduciales[i] - 3.*marginalizados[i], fisher.fiduciales[i] + 3.*marginalizados[i]));
+        let mut ejes = Vec::with_capacity((n-1)*(n-1));
+        for j in 1..n{
+            for i in 0..n-1{
+                if i >= j{
+                    ejes.push(EjesPgf::vacio());
+                    continue;
+                }
+                let etiqueta_x = if j == n-1 {escapar_latex(fisher.parametros[i].nombre())} else {String::new()};
+                let etiqueta_y = if i == 0 {escapar_latex(fisher.parametros[j].nombre())} else {String::new()};
+                let mut celda = EjesPgf{rango_x: rango(i), rango_y: rango(j), ..EjesPgf::new(etiqueta_x, etiqueta_y)};
+                let elipse = fisher.elipse(i, j);
+                let (seno, coseno) = elipse.angulo.sin_cos();
+                for (dchi2, opacidad) in [(DELTA_CHI2_95, 0.3), (DELTA_CHI2_68, 0.6)]{
+                    let k = dchi2.sqrt();
+                    let (a, b) = (k*elipse.semieje_mayor, k*elipse.semieje_menor);
+                    let angulos = linspace(0., 2.*std::f64::consts::PI, 100);
+                    celda.series.push(SeriePgf{tipo: TipoSerie::Relleno{opacidad}, color: "C0".to_string(), leyenda: None,
+                                               x: angulos.iter().map(|t| fisher.fiduciales[i] + a*t.cos()*coseno - b*t.sin()*seno).collect(),
+                                               y: angulos.iter().map(|t| fisher.fiduciales[j] + a*t.cos()*seno + b*t.sin()*coseno).collect(),
+                                               z: Vec::new()});
+                }
+                ejes.push(celda);
+            }
+        }
+        let lado = 2.2*(n - 1) as f64;
+        Ok(FiguraPgf{compartir_ejes: true, ..FiguraPgf::rejilla(ejes, n-1, (lado, lado))})
+    })
+}
+
+/// Mapa de χ² en dos parámetros con los contornos de 1σ, 2σ y 3σ. Si los parámetros son Ωm y ΩΛ se dibujan encima
+/// las líneas del diagrama de fases: universo plano, q0 = 0 y la frontera de los universos sin Big Bang.
+/// Esta gráfica se dibuja directamente con plotters, sin pasar por python, así que sólo se guarda en SVG y PNG.
+fn dibujar_mapa_chi2(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Result<()>{
+    let verosimilitudes = configuracion.verosimilitudes()?;
+    let opciones = &configuracion.mapa_chi2;
+    let mapa = mapa_chi2(&verosimilitudes, parametros, opciones)?;
+    mapa.escribir(&opciones.salida)?;
+    let (x_min, y_min, chi2_min) = mapa.minimo();
+    let (px, py) = mapa.parametros;
+    println!("{}", texto!(MinimoRejilla, px.nombre(), x_min, py.nombre(), y_min, chi2_min));
+    println!("{}", texto!(RejillaGuardada, opciones.salida.display()));
+
+    let graficas = &configuracion.graficas;
+    fs::create_dir_all(&graficas.directorio)?;
+    for formato in &graficas.formatos{
+        let fichero = graficas.fichero("mapa_chi2", formato.extension());
+        match formato{
+            Formato::Svg => dibujar_mapa(SVGBackend::new(&fichero, (800, 700)).into_drawing_area(), &mapa, parametros, opciones)?,
+            Formato::Png => {
+                let escala = graficas.dpi as f64/100.;
+                let tamanyo = ((800.*escala).round() as u32, (700.*escala).round() as u32);
+                dibujar_mapa(BitMapBackend::new(&fichero, tamanyo).into_drawing_area(), &mapa, parametros, opciones)?
+            }
+            Formato::Pdf => {
+                eprintln!("{}", texto!(MapaSinPdf));
+                continue;
+            }
+            Formato::Tex | Formato::Pgf => continue,
+        }
+        println!("{}", texto!(GraficaGuardada, fichero.display()));
+    }
+    if graficas.latex(){
+        let ficheros = graficas.escribir_latex("mapa_chi2", &figura_mapa(&mapa, parametros, opciones))?;
+        let ficheros: Vec<String> = ficheros.iter().map(|fichero| fichero.display().to_string()).collect();
+        println!("{}", texto!(GraficaGuardada, ficheros.join(", ")));
+    }
+    Ok(())
+}
+
+/// El mapa de χ² de `dibujar_mapa_chi2` como figura de PGFPlots, con las mismas curvas que `dibujar_mapa`
+fn figura_mapa(mapa: &MapaChi2, parametros: ParametrosCosmologicos, opciones: &ConfiguracionMapa) -> FiguraPgf{
+    let (x_min, y_min, _) = mapa.minimo();
+    let (px, py) = mapa.parametros;
+    let mut ejes = EjesPgf{rango_x: Some(opciones.rango_x), rango_y: Some(opciones.rango_y), ..EjesPgf::new(escapar_latex(px.nombre()), escapar_latex(py.nombre()))};
+    let delta = mapa.delta_chi2();
+    for (nivel, nombre, color) in [(DELTA_CHI2_68, r"$1\sigma$", "{rgb,255:red,31;green,119;blue,180}"), (DELTA_CHI2_95, r"$2\sigma$", "{rgb,255:red,100;green,160;blue,210}"),
+                                   (DELTA_CHI2_997, r"$3\sigma$", "{rgb,255:red,170;green,200;blue,230}")]{
+        ejes.series.push(SeriePgf::segmentos(&contorno(&mapa.x, &mapa.y, &delta, nivel), color, "-", 1.5).con_leyenda(nombre));
+    }
+    ejes.series.push(SeriePgf{tipo: TipoSerie::Cruces, color: "black".to_string(), leyenda: Some(escapar_latex(Texto::Minimo.texto())),
+                              x: vec![x_min], y: vec![y_min], z: Vec::new()});
+    if (px, py) == (Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura){
+        let (x0, x1) = opciones.rango_x;
+        let materia: Vec<f64> = linspace(x0, x1, 200).into_iter().chain([x1]).collect();
+        let o_r = parametros.densidad_radiacion;
+        ejes.series.push(SeriePgf::linea(materia.clone(), materia.iter().map(|m| 1. - m - o_r).collect(), "black", "--", 0.8)
+            .con_leyenda(escapar_latex(Texto::UniversoPlano.texto())));
+        ejes.series.push(SeriePgf::linea(materia.clone(), materia.iter().map(|m| -(m + 2.*o_r)/(1. + 3.*parametros.w)).collect(), "black", ":", 0.8)
+            .con_leyenda(format!("$q_0=0$ ({})", escapar_latex(Texto::Aceleracion.texto()))));
+        let rejilla = |(a, b): (f64, f64)| -> Vec<f64> {linspace(a, b, 150).into_iter().chain([b]).collect()};
+        let frontera = frontera_rebote(parametros, &rejilla(opciones.rango_x), &rejilla(opciones.rango_y));
+        ejes.series.push(SeriePgf::segmentos(&frontera, "C3", "-", 1.5).con_leyenda(escapar_latex(Texto::SinBigBang.texto())));
+    }
+    FiguraPgf::new(ejes, (8., 7.))
+}
+
+/// Dibuja el mapa de χ² de `dibujar_mapa_chi2` en cualquier backend de plotters
+fn dibujar_mapa<DB: DrawingBackend>(raiz: DrawingArea<DB, plotters::coord::Shift>, mapa: &MapaChi2, parametros: ParametrosCosmologicos,
+                                    opciones: &ConfiguracionMapa) -> Result<()> where DB::ErrorType: 'static{
+    let (x_min, y_min, _) = mapa.minimo();
+    let (px, py) = mapa.parametros;
+    raiz.fill(&WHITE)?;
+    let (x0, x1) = opciones.rango_x;
+    let (y0, y1) = opciones.rango_y;
+    let mut grafica = ChartBuilder::on(&raiz).margin(15).x_label_area_size(45).y_label_area_size(55)
+        .build_cartesian_2d(x0..x1, y0..y1)?;
+    grafica.configure_mesh().disable_mesh().x_desc(px.nombre()).y_desc(py.nombre()).label_style(("sans-serif", 16)).draw()?;
+
+    let delta = mapa.delta_chi2();
+    for (nivel, nombre, color) in [(DELTA_CHI2_68, "1σ", RGBColor(31, 119, 180)), (DELTA_CHI2_95, "2σ", RGBColor(100, 160, 210)),
+                                   (DELTA_CHI2_997, "3σ", RGBColor(170, 200, 230))]{
+        let segmentos = contorno(&mapa.x, &mapa.y, &delta, nivel);
+        grafica.draw_series(segmentos.into_iter().map(|(a, b)| PathElement::new(vec![a, b], color.stroke_width(2))))?
+            .label(nombre).legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+    }
+    grafica.draw_series([Cross::new((x_min, y_min), 6, BLACK.stroke_width(2))])?
+        .label(Texto::Minimo.texto()).legend(|(x, y)| Cross::new((x + 10, y), 5, BLACK.stroke_width(2)));
+
+    if (px, py) == (Parametro::DensidadMateria, Parametro::DensidadEnergiaOscura){
+        let materia = linspace(x0, x1, 200).into_iter().chain([x1]);
+        let o_r = parametros.densidad_radiacion;
+        grafica.draw_series(DashedLineSeries::new(materia.clone().map(|m| (m, 1. - m - o_r)), 8, 5, BLACK.stroke_width(1)))?
+            .label(Texto::UniversoPlano.texto()).legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+        grafica.draw_series(DashedLineSeries::new(materia.map(|m| (m, -(m + 2.*o_r)/(1. + 3.*parametros.w))), 2, 4, BLACK.stroke_width(1)))?
+            .label(format!("q0 = 0 ({})", Texto::Aceleracion)).legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+        let rejilla = |(a, b): (f64, f64)| -> Vec<f64> {linspace(a, b, 150).into_iter().chain([b]).collect()};
+        let frontera = frontera_rebote(parametros, &rejilla(opciones.rango_x), &rejilla(opciones.rango_y));
+        let rojo = RGBColor(214, 39, 40);
+        grafica.draw_series(frontera.into_iter().map(|(a, b)| PathElement::new(vec![a, b], rojo.stroke_width(2))))?
+            .label(Texto::SinBigBang.texto()).legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], rojo.stroke_width(2)));
+    }
+    grafica.configure_series_labels().background_style(WHITE.mix(0.8)).border_style(BLACK).label_font(("sans-serif", 14)).draw()?;
+    raiz.present()?;
     Ok(())
 }
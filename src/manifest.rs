@@ -0,0 +1,312 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::consts::{PUNTOS_CURVAS, SUBPASOS_RUNGE_KUTTA};
+use crate::functions::{TOLERANCIA_DISTANCIA, TOLERANCIA_EDAD};
+use crate::bao::TOLERANCIA_HORIZONTE_SONIDO;
+use crate::analysis::MUESTRAS;
+use crate::locale::Idioma;
+use crate::texto;
+
+
+/// Línea que separa el fichero de entrada original del manifiesto que se le añade
+const SEPARADOR: &str = "# ---- manifiesto de la ejecución, generado automáticamente ----";
+
+/// Ajustes numéricos que están fijos en el código, para saber si un manifiesto se hizo con otros
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AjustesNumericos{
+    pub tolerancia_distancia: f64,
+    pub tolerancia_edad: f64,
+    pub tolerancia_horizonte_sonido: f64,
+    /// Pasos de Runge-Kutta entre cada par de puntos de a(t) y f(z)
+    pub subpasos_runge_kutta: u64,
+    /// Puntos de cada curva de la ejecución normal
+    pub puntos_curvas: i64,
+    /// Muestras en ln(a) para buscar los intervalos con E² ≥ 0
+    pub muestras_intervalos: i64,
+}
+
+impl AjustesNumericos{
+    pub fn actuales() -> Self{
+        AjustesNumericos{tolerancia_distancia: TOLERANCIA_DISTANCIA, tolerancia_edad: TOLERANCIA_EDAD,
+                         tolerancia_horizonte_sonido: TOLERANCIA_HORIZONTE_SONIDO, subpasos_runge_kutta: SUBPASOS_RUNGE_KUTTA,
+                         puntos_curvas: PUNTOS_CURVAS, muestras_intervalos: MUESTRAS}
+    }
+}
+
+/// Parámetros ya resueltos de un modelo (tras presets, parametrizaciones y cierre), tal y como se usaron
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModeloResuelto{
+    pub nombre: String,
+    pub parametros: ParametrosCosmologicos,
+}
+
+/// Un fichero escrito por la ejecución y su suma SHA-256 (la misma que `sha256sum`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Salida{
+    pub fichero: String,
+    pub sha256: String,
+    pub bytes: u64,
+}
+
+/// Registro de una ejecución para poder repetirla. Se guarda al final de una copia del fichero de entrada, así que el
+/// manifiesto es a su vez un fichero de entrada válido; con él los modelos se toman de `modelos` en lugar de resolverse
+/// otra vez, de forma que las cuentas se repiten bit a bit (orden `reproducir <manifiesto>`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Manifiesto{
+    /// Argumentos de la línea de órdenes, sin --preset (vacío en la ejecución normal)
+    pub argumentos: Vec<String>,
+    pub version: String,
+    /// Commit de git con el que se compiló, con "-dirty" si había cambios sin guardar
+    pub git: String,
+    /// Inicio de la ejecución, en UTC
+    pub fecha: String,
+    pub numerico: AjustesNumericos,
+    /// Idioma de los textos de las salidas, `reproducir` lo usa aunque el entorno tenga otro
+    #[serde(default)]
+    pub idioma: Idioma,
+    pub modelos: Vec<ModeloResuelto>,
+    pub salidas: Vec<Salida>,
+}
+
+/// El manifiesto va en la tabla [manifiesto] del fichero
+#[derive(Serialize, Deserialize)]
+struct ConManifiesto{
+    manifiesto: Option<Manifiesto>,
+}
+
+impl Manifiesto{
+    /// Manifiesto de una ejecución que empieza en `inicio`, todavía sin salidas
+    pub fn nuevo(argumentos: &[String], modelos: &[(String, ParametrosCosmologicos)], inicio: SystemTime) -> Self{
+        Manifiesto{argumentos: argumentos.to_vec(), version: env!("CARGO_PKG_VERSION").to_string(), git: env!("CALCULADORA_GIT").to_string(),
+                   fecha: fecha_utc(inicio), numerico: AjustesNumericos::actuales(), idioma: Idioma::actual(),
+                   modelos: modelos.iter().map(|(nombre, parametros)| ModeloResuelto{nombre: nombre.clone(), parametros: *parametros}).collect(),
+                   salidas: Vec::new()}
+    }
+
+    /// Manifiesto del fichero de entrada, si lo tiene
+    pub fn leer(texto: &str) -> anyhow::Result<Option<Self>>{
+        Ok(toml::from_str::<ConManifiesto>(texto)?.manifiesto)
+    }
+
+    /// Nombre y parámetros de cada modelo
+    pub fn modelos(&self) -> Vec<(String, ParametrosCosmologicos)>{
+        // El redshift máximo no se guarda; recalcular da exactamente los mismos valores para lo demás
+        self.modelos.iter().map(|modelo| {
+            let mut parametros = modelo.parametros;
+            parametros.recalcular_derivados();
+            (modelo.nombre.clone(), parametros)
+        }).collect()
+    }
+
+    /// Añade como salidas los ficheros de los directorios de `antes` que son nuevos o han cambiado desde entonces
+    pub fn registrar_salidas(&mut self, antes: &EstadoSalidas) -> anyhow::Result<()>{
+        let despues = EstadoSalidas::leer(&antes.directorios)?;
+        for (fichero, modificado) in &despues.ficheros{
+            if antes.ficheros.get(fichero) == Some(modificado){
+                continue;
+            }
+            let contenido = fs::read(fichero).with_context(|| texto!(NoSePudoLeer, fichero.display()))?;
+            let nombre = fichero.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+            self.salidas.push(Salida{fichero: nombre, sha256: hexadecimal(&sha256(&contenido)), bytes: contenido.len() as u64});
+        }
+        Ok(())
+    }
+
+    /// Escribe el manifiesto al final de una copia del fichero de entrada (sin el manifiesto que pudiera tener ya)
+    pub fn escribir(&self, entrada: &str, fichero: &Path) -> anyhow::Result<()>{
+        if let Some(directorio) = fichero.parent(){
+            fs::create_dir_all(directorio)?;
+        }
+        let original = entrada.split(SEPARADOR).next().unwrap_or_default().trim_end();
+        let manifiesto = toml::to_string(&ConManifiesto{manifiesto: Some(self.clone())})?;
+        fs::write(fichero, format!("{}\n\n{}\n{}", original, SEPARADOR, manifiesto))
+            .with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+    }
+
+    /// Diferencias con una ejecución anterior: versión, ajustes numéricos y salidas que faltan o han cambiado
+    pub fn diferencias(&self, anterior: &Manifiesto) -> Vec<String>{
+        let mut diferencias = Vec::new();
+        if self.git != anterior.git || self.version != anterior.version{
+            diferencias.push(texto!(VersionManifiesto, self.version, self.git, anterior.version, anterior.git));
+        }
+        if self.numerico != anterior.numerico{
+            diferencias.push(texto!(AjustesManifiesto, format!("{:?}", self.numerico), format!("{:?}", anterior.numerico)));
+        }
+        for salida in &anterior.salidas{
+            match self.salidas.iter().find(|s| s.fichero == salida.fichero){
+                Some(nueva) if nueva.sha256 == salida.sha256 => {}
+                Some(_) => diferencias.push(texto!(SalidaDistinta, salida.fichero)),
+                None => diferencias.push(texto!(SalidaNoEscrita, salida.fichero)),
+            }
+        }
+        diferencias
+    }
+}
+
+/// Fecha de modificación de cada fichero de los directorios de salida, para saber después cuáles ha escrito una ejecución
+#[derive(Clone, Debug, Default)]
+pub struct EstadoSalidas{
+    directorios: Vec<PathBuf>,
+    ficheros: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl EstadoSalidas{
+    /// Los directorios que no existen se toman como vacíos
+    pub fn leer(directorios: &[PathBuf]) -> anyhow::Result<Self>{
+        let mut estado = EstadoSalidas{directorios: directorios.to_vec(), ficheros: BTreeMap::new()};
+        for directorio in directorios.iter().filter(|d| d.is_dir()){
+            estado.leer_directorio(directorio)?;
+        }
+        Ok(estado)
+    }
+
+    fn leer_directorio(&mut self, directorio: &Path) -> anyhow::Result<()>{
+        for entrada in fs::read_dir(directorio)?{
+            let entrada = entrada?;
+            let tipo = entrada.file_type()?;
+            if tipo.is_dir(){
+                self.leer_directorio(&entrada.path())?;
+            }
+            else if tipo.is_file(){
+                self.ficheros.insert(entrada.path(), entrada.metadata()?.modified()?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fecha y hora UTC en ISO 8601, p.ej. 2024-05-01T12:00:00Z
+fn fecha_utc(instante: SystemTime) -> String{
+    let segundos = instante.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (dias, resto) = ((segundos/86400) as i64, segundos % 86400);
+    // Fecha civil a partir de los días desde 1970-01-01 (algoritmo de H. Hinnant)
+    let z = dias + 719468;
+    let era = z.div_euclid(146097);
+    let dia_era = z - era*146097;
+    let anyo_era = (dia_era - dia_era/1460 + dia_era/36524 - dia_era/146096)/365;
+    let dia_anyo = dia_era - (365*anyo_era + anyo_era/4 - anyo_era/100);
+    let mp = (5*dia_anyo + 2)/153;
+    let dia = dia_anyo - (153*mp + 2)/5 + 1;
+    let mes = if mp < 10 {mp + 3} else {mp - 9};
+    let anyo = anyo_era + era*400 + if mes <= 2 {1} else {0};
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", anyo, mes, dia, resto/3600, resto/60 % 60, resto % 60)
+}
+
+fn hexadecimal(bytes: &[u8]) -> String{
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+const K_SHA256: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 (FIPS 180-4)
+fn sha256(datos: &[u8]) -> [u8; 32]{
+    let mut h: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+    let mut mensaje = datos.to_vec();
+    mensaje.push(0x80);
+    while mensaje.len() % 64 != 56{
+        mensaje.push(0);
+    }
+    mensaje.extend_from_slice(&(datos.len() as u64*8).to_be_bytes());
+    for bloque in mensaje.chunks(64){
+        let mut w = [0u32; 64];
+        for (i, palabra) in bloque.chunks(4).enumerate(){
+            w[i] = u32::from_be_bytes([palabra[0], palabra[1], palabra[2], palabra[3]]);
+        }
+        for i in 16..64{
+            let s0 = w[i-15].rotate_right(7) ^ w[i-15].rotate_right(18) ^ (w[i-15] >> 3);
+            let s1 = w[i-2].rotate_right(17) ^ w[i-2].rotate_right(19) ^ (w[i-2] >> 10);
+            w[i] = w[i-16].wrapping_add(s0).wrapping_add(w[i-7]).wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64{
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = hh.wrapping_add(s1).wrapping_add(ch).wrapping_add(K_SHA256[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (x, y) in h.iter_mut().zip([a, b, c, d, e, f, g, hh]){
+            *x = x.wrapping_add(y);
+        }
+    }
+    let mut resumen = [0u8; 32];
+    for (trozo, x) in resumen.chunks_mut(4).zip(h){
+        trozo.copy_from_slice(&x.to_be_bytes());
+    }
+    resumen
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn sha256_vectores_nist(){
+        let casos: [(&[u8], &str); 4] = [
+            (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq", "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"),
+            (&[b'a'; 1_000_000], "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"),
+        ];
+        for (datos, resumen) in casos{
+            assert_eq!(hexadecimal(&sha256(datos)), resumen, "{} bytes", datos.len());
+        }
+    }
+
+    #[test]
+    fn sha256_bordes_del_relleno(){
+        // Con 55 bytes el relleno cabe en el bloque; con 56 a 64 hace falta otro
+        let casos = [
+            (55, "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"),
+            (56, "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"),
+            (63, "7d3e74a05d7db15bce4ad9ec0658ea98e3f06eeecf16b4c6fff2da457ddc2f34"),
+            (64, "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"),
+            (65, "635361c48bb9eab14198e76ea8ab7f1a41685d6ad62aa9146d301d4f17eb0ae0"),
+        ];
+        for (n, resumen) in casos{
+            assert_eq!(hexadecimal(&sha256(&vec![b'a'; n])), resumen, "{n} bytes");
+        }
+    }
+
+    #[test]
+    fn fechas(){
+        let casos = [
+            (0, "1970-01-01T00:00:00Z"),
+            (951782400, "2000-02-29T00:00:00Z"),
+            (1709208000, "2024-02-29T12:00:00Z"),
+            // 2100 no es bisiesto
+            (4107542399, "2100-02-28T23:59:59Z"),
+            (4107542400, "2100-03-01T00:00:00Z"),
+            (253402300799, "9999-12-31T23:59:59Z"),
+        ];
+        for (segundos, fecha) in casos{
+            assert_eq!(fecha_utc(UNIX_EPOCH + Duration::from_secs(segundos)), fecha);
+        }
+    }
+}
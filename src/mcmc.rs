@@ -0,0 +1,169 @@
+use core::f64;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::{con_valores, Parametro, Verosimilitud};
+use crate::priors::{log_prior, Priors};
+use crate::chains::Cadena;
+use crate::random::Rng;
+use crate::texto;
+
+
+/// Opciones del muestreador, tabla [mcmc] del fichero de entrada
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionMcmc{
+    pub caminantes: usize,
+    pub pasos: usize,
+    pub semilla: u64,
+    /// Fichero de la cadena, .csv o .npy
+    pub salida: PathBuf,
+    /// Pasos iniciales que se descartan en los resúmenes y en la gráfica
+    pub quemado: usize,
+    /// Cada cuántos pasos se guardan la cadena y el punto de control
+    pub intervalo_control: usize,
+}
+
+impl Default for ConfiguracionMcmc{
+    fn default() -> Self{
+        ConfiguracionMcmc{caminantes: 32, pasos: 2000, semilla: 1, salida: PathBuf::from("cadenas/cadena.csv"), quemado: 500, intervalo_control: 100}
+    }
+}
+
+/// Estado del muestreador para poder continuar una ejecución interrumpida. Se guarda en TOML junto a la cadena.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PuntoControl{
+    pub parametros: Vec<Parametro>,
+    pub paso: usize,
+    pub posiciones: Vec<Vec<f64>>,
+    pub log_posteriores: Vec<f64>,
+    pub aceptados: usize,
+    /// Estado del generador en hexadecimal (TOML no admite enteros de 64 bits sin signo)
+    pub rng: Vec<String>,
+}
+
+impl PuntoControl{
+    pub fn fichero(salida: &Path) -> PathBuf{
+        salida.with_extension("control.toml")
+    }
+
+    fn rng(&self) -> anyhow::Result<Rng>{
+        let estado = self.rng.iter().map(|x| u64::from_str_radix(x, 16)).collect::<Result<Vec<_>, _>>()?;
+        match estado[..]{
+            [a, b, c, d] => Ok(Rng::desde_estado([a, b, c, d])),
+            _ => bail!(texto!(EstadoGenerador)),
+        }
+    }
+}
+
+/// Log posterior = log prior - χ²/2. Si el prior es nulo no se calcula la verosimilitud.
+pub fn log_posterior(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, priors: &Priors, xx: &[f64]) -> f64{
+    let prior = log_prior(priors, xx);
+    if !prior.is_finite(){
+        return f64::NEG_INFINITY;
+    }
+    let libres: Vec<Parametro> = priors.keys().copied().collect();
+    let chi2 = verosimilitud.chi2(con_valores(&libres, xx, parametros));
+    if chi2.is_nan() {f64::NEG_INFINITY} else {prior - chi2/2.}
+}
+
+/// Resultado de un muestreo
+pub struct ResultadoMcmc{
+    pub cadena: Cadena,
+    pub fraccion_aceptacion: f64,
+}
+
+/// Muestreador por conjuntos invariante afín (Goodman & Weare 2010, el de emcee) con el movimiento "stretch".
+///
+/// Los parámetros libres son los que tienen prior. Los caminantes empiezan en una bola pequeña alrededor de los valores
+/// de `parametros`. Cada `intervalo_control` pasos se escriben la cadena y un punto de control con el estado del generador,
+/// así que una ejecución continuada con `continuar` da exactamente la misma cadena que una sin interrumpir.
+pub fn muestrear(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, priors: &Priors,
+                 configuracion: &ConfiguracionMcmc, continuar: bool) -> anyhow::Result<ResultadoMcmc>{
+    let libres: Vec<Parametro> = priors.keys().copied().collect();
+    let n = libres.len();
+    let k = configuracion.caminantes;
+    if n == 0{
+        bail!(texto!(SinPriors));
+    }
+    if k < 2*n{
+        bail!(texto!(Caminantes, 2*n, n));
+    }
+    let posterior = |xx: &[f64]| log_posterior(verosimilitud, parametros, priors, xx);
+    let fichero_control = PuntoControl::fichero(&configuracion.salida);
+
+    let (mut cadena, mut posiciones, mut log_posteriores, mut rng, mut aceptados, inicio) = if continuar{
+        let texto = fs::read_to_string(&fichero_control).with_context(|| texto!(NoSePudoLeer, fichero_control.display()))?;
+        let control: PuntoControl = toml::from_str(&texto)?;
+        if control.parametros != libres || control.posiciones.len() != k{
+            bail!(texto!(PuntoControlDistinto, fichero_control.display()));
+        }
+        let mut cadena = Cadena::leer(&configuracion.salida, &libres)?;
+        // Se descarta lo que se hubiera escrito después del punto de control
+        cadena.filas.retain(|f| (f[0] as usize) < control.paso);
+        cadena.escribir(&configuracion.salida)?;
+        let rng = control.rng()?;
+        (cadena, control.posiciones, control.log_posteriores, rng, control.aceptados, control.paso)
+    }
+    else{
+        let mut rng = Rng::new(configuracion.semilla);
+        let centro: Vec<f64> = libres.iter().map(|p| p.valor(&parametros)).collect();
+        if !log_prior(priors, &centro).is_finite(){
+            bail!(texto!(FueraDePriors, format!("{:?}", centro)));
+        }
+        let mut posiciones = Vec::with_capacity(k);
+        let mut log_posteriores = Vec::with_capacity(k);
+        let mut intentos = 0;
+        while posiciones.len() < k{
+            let x: Vec<f64> = centro.iter().map(|c| c + 1e-3*(c.abs() + 0.1)*rng.normal()).collect();
+            let lp = posterior(&x);
+            if lp.is_finite(){
+                posiciones.push(x);
+                log_posteriores.push(lp);
+                intentos = 0;
+                continue;
+            }
+            intentos += 1;
+            if intentos == 1000{
+                bail!(texto!(SinPuntoInicial, format!("{:?}", centro)));
+            }
+        }
+        (Cadena::new(&libres), posiciones, log_posteriores, rng, 0, 0)
+    };
+
+    // Filas que ya están en el fichero; en cada punto de control sólo se añaden las nuevas
+    let mut escritas = cadena.filas.len();
+    // Parámetro de escala del movimiento stretch, el valor habitual
+    let a: f64 = 2.;
+    for paso in inicio..configuracion.pasos{
+        for i in 0..k{
+            let mut j = rng.entero(k-1);
+            if j >= i {j += 1}
+            let z = ((a - 1.)*rng.uniforme() + 1.).powi(2)/a;
+            let propuesta: Vec<f64> = posiciones[j].iter().zip(&posiciones[i]).map(|(xj, xi)| xj + z*(xi - xj)).collect();
+            let lp = posterior(&propuesta);
+            let log_aceptacion = (n as f64 - 1.)*z.ln() + lp - log_posteriores[i];
+            if rng.uniforme().ln() < log_aceptacion{
+                posiciones[i] = propuesta;
+                log_posteriores[i] = lp;
+                aceptados += 1;
+            }
+            cadena.añadir(paso, i, &posiciones[i], log_posteriores[i]);
+        }
+
+        let siguiente = paso + 1;
+        if siguiente % configuracion.intervalo_control.max(1) == 0 || siguiente == configuracion.pasos{
+            cadena.escribir_desde(&configuracion.salida, escritas)?;
+            escritas = cadena.filas.len();
+            let control = PuntoControl{parametros: libres.clone(), paso: siguiente, posiciones: posiciones.clone(),
+                log_posteriores: log_posteriores.clone(), aceptados, rng: rng.estado().iter().map(|x| format!("{:016x}", x)).collect()};
+            fs::write(&fichero_control, toml::to_string(&control)?)?;
+        }
+    }
+    let fraccion_aceptacion = aceptados as f64/(configuracion.pasos.max(1)*k) as f64;
+    Ok(ResultadoMcmc{cadena, fraccion_aceptacion})
+}
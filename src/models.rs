@@ -0,0 +1,106 @@
+use serde::{Serialize, Deserialize};
+
+use crate::input::{InputFile, ParametrosCosmologicos, ErrorEntrada};
+use crate::presets::Preset;
+use crate::texto;
+
+
+/// Un modelo de una tabla `[[modelo]]`. Parte de los parámetros cosmológicos del fichero (o de su propio preset, si lo
+/// tiene) y cambia los que dé; los rangos de las gráficas y los métodos de integración son los del fichero.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EntradaModelo{
+    /// Nombre en las leyendas y en la salida por pantalla
+    pub nombre: String,
+    pub preset: Option<Preset>,
+    pub densidad_materia: Option<f64>,
+    pub densidad_radiacion: Option<f64>,
+    pub densidad_energia_oscura: Option<f64>,
+    pub constante_hubble: Option<f64>,
+    pub w: Option<f64>,
+    pub wa: Option<f64>,
+    pub sigma8: Option<f64>,
+    pub densidad_bariones: Option<f64>,
+    pub densidad_curvatura: Option<f64>,
+    pub densidad_fisica_bariones: Option<f64>,
+    pub densidad_fisica_cdm: Option<f64>,
+    pub h: Option<f64>,
+    pub cien_theta_estrella: Option<f64>,
+}
+
+/// Panel debajo de cada gráfica cuando hay varios modelos
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparacion{
+    /// Cada modelo dividido por el de referencia
+    #[default]
+    Cociente,
+    /// Cada modelo menos el de referencia
+    Diferencia,
+    /// Sin panel
+    Ninguna,
+}
+
+impl Comparacion{
+    pub fn nombre(self) -> &'static str{
+        match self{
+            Comparacion::Cociente => "cociente",
+            Comparacion::Diferencia => "diferencia",
+            Comparacion::Ninguna => "ninguna",
+        }
+    }
+
+    /// Valor de referencia del panel: 1 para el cociente y 0 para la diferencia
+    pub fn referencia(self) -> f64{
+        if self == Comparacion::Cociente {1.} else {0.}
+    }
+
+    /// La curva (x, y) comparada con la de referencia (xr, yr) interpolada linealmente en sus x. Sólo quedan los puntos
+    /// dentro del rango de la referencia; los NaN de xr se descartan.
+    pub fn comparar(self, xr: &[f64], yr: &[f64], x: &[f64], y: &[f64]) -> (Vec<f64>, Vec<f64>){
+        let mut referencia: Vec<(f64, f64)> = xr.iter().copied().zip(yr.iter().copied()).filter(|(x, _)| !x.is_nan()).collect();
+        referencia.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (Some(&(min, _)), Some(&(max, _))) = (referencia.first(), referencia.last()) else {return (Vec::new(), Vec::new())};
+        x.iter().zip(y).filter(|(&x, _)| (min..=max).contains(&x)).map(|(&x, &y)| {
+            let i = referencia.partition_point(|p| p.0 < x).max(1).min(referencia.len() - 1);
+            let valor = if referencia.len() == 1 {referencia[0].1} else {
+                let ((x0, y0), (x1, y1)) = (referencia[i-1], referencia[i]);
+                if x1 == x0 {y1} else {y0 + (y1 - y0)*(x - x0)/(x1 - x0)}
+            };
+            (x, if self == Comparacion::Cociente {y/valor} else {y - valor})
+        }).unzip()
+    }
+}
+
+/// Modelos que se dibujan juntos en la ejecución normal. El primero es la referencia de los paneles de comparación.
+/// ```toml
+/// comparacion = "cociente"
+///
+/// [[modelo]]
+/// nombre = "ΛCDM"
+/// preset = "planck18"
+///
+/// [[modelo]]
+/// nombre = "wCDM"
+/// preset = "planck18"
+/// w = -0.9
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConfiguracionModelos{
+    #[serde(default)]
+    pub modelo: Vec<EntradaModelo>,
+    #[serde(default)]
+    pub comparacion: Comparacion,
+}
+
+impl ConfiguracionModelos{
+    /// Nombre y parámetros de cada modelo. Sin tablas `[[modelo]]` hay uno solo, el del fichero, con el nombre de su preset.
+    pub fn resolver(&self, entrada: InputFile) -> Result<Vec<(String, ParametrosCosmologicos)>, ErrorEntrada>{
+        if self.modelo.is_empty(){
+            let nombre = entrada.preset().map_or_else(|| texto!(ColumnaModelo), |preset| preset.to_string());
+            return Ok(vec![(nombre, ParametrosCosmologicos::try_from(entrada)?)]);
+        }
+        self.modelo.iter()
+            .map(|modelo| Ok((modelo.nombre.clone(), ParametrosCosmologicos::try_from(entrada.con_modelo(modelo))?)))
+            .collect()
+    }
+}
@@ -0,0 +1,538 @@
+use core::f64;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use anyhow::{bail, Context};
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::{con_valores, Parametro, Verosimilitud};
+use crate::priors::{log_prior, Priors};
+use crate::chains::Cadena;
+use crate::random::Rng;
+use crate::linalg::{cholesky, resolver_cholesky};
+use crate::locale::Texto;
+use crate::texto;
+
+
+/// Cómo se busca un punto nuevo dentro del contorno de verosimilitud
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetodoAnidado{
+    /// Muestreo por rechazo dentro del elipsoide que contiene los puntos vivos, agrandado un poco (como MultiNest
+    /// con un solo elipsoide). Muy eficiente con pocos parámetros.
+    #[default]
+    Elipsoides,
+    /// Muestreo por rebanadas en direcciones aleatorias escaladas con el mismo elipsoide, partiendo de un punto vivo
+    /// (como PolyChord). Escala mejor con el número de parámetros y con posteriores curvadas.
+    Rebanadas,
+}
+
+/// Variante del modelo para comparar evidencias. Parte de los parámetros del fichero de entrada:
+/// ```toml
+/// [[anidado.modelos]]
+/// nombre = "wcdm_plano"
+/// plano = true                  # la energía oscura cierra el presupuesto, Ω_Λ = 1 - Ω_m - Ω_r
+/// fijos = {wa = 0}              # valores que cambian respecto al fichero de entrada
+/// priors = {densidad_materia = {uniforme = [0, 1]}, w = {uniforme = [-2, 0]}}
+/// ```
+/// Si no tiene priors se usan los de la tabla [priors].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct VarianteModelo{
+    pub nombre: String,
+    #[serde(default)]
+    pub plano: bool,
+    #[serde(default)]
+    pub fijos: BTreeMap<Parametro, f64>,
+    pub priors: Option<Priors>,
+}
+
+impl VarianteModelo{
+    /// Priors del modelo, los suyos o los de `por_defecto`
+    pub fn priors<'a>(&'a self, por_defecto: &'a Priors) -> &'a Priors{
+        self.priors.as_ref().unwrap_or(por_defecto)
+    }
+
+    /// Parámetros del modelo con los valores `xx` de los parámetros libres
+    pub fn parametros(&self, base: ParametrosCosmologicos, libres: &[Parametro], xx: &[f64]) -> ParametrosCosmologicos{
+        let todos: Vec<Parametro> = self.fijos.keys().chain(libres).copied().collect();
+        let valores: Vec<f64> = self.fijos.values().chain(xx).copied().collect();
+        let parametros = con_valores(&todos, &valores, base);
+        if self.plano{
+            Parametro::DensidadEnergiaOscura.con_valor(parametros, 1. - parametros.densidad_materia - parametros.densidad_radiacion)
+        }
+        else{
+            parametros
+        }
+    }
+
+    /// Error si los priors no son compatibles con el modelo
+    pub(crate) fn comprobar(&self, priors: &Priors) -> anyhow::Result<()>{
+        if priors.is_empty(){
+            bail!(texto!(ModeloSinPriors, self.nombre));
+        }
+        if self.plano && priors.contains_key(&Parametro::DensidadEnergiaOscura){
+            bail!(texto!(ModeloPlanoConPrior, self.nombre));
+        }
+        if let Some(parametro) = self.fijos.keys().find(|p| priors.contains_key(p)){
+            bail!(texto!(FijoConPrior, self.nombre, parametro.nombre()));
+        }
+        Ok(())
+    }
+}
+
+/// Opciones de la orden `anidado`, tabla [anidado] del fichero de entrada
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionAnidado{
+    pub metodo: MetodoAnidado,
+    pub puntos_vivos: usize,
+    /// Se para cuando lo que podrían aportar los puntos vivos a ln Z es menor que esto
+    pub tolerancia: f64,
+    /// Factor por el que se agrandan los ejes del elipsoide de los puntos vivos
+    pub expansion: f64,
+    /// Pasos de muestreo por rebanadas por cada parámetro libre, con `metodo = "rebanadas"`
+    pub pasos_rebanada: usize,
+    pub semilla: u64,
+    /// Muestras de la posterior (con pesos iguales) en el formato de las cadenas, .csv o .npy. Con varios modelos
+    /// se añade el nombre de cada uno, p.ej. cadenas/anidado_wcdm_plano.csv
+    pub salida: PathBuf,
+    /// Tabla CSV con la comparación de los modelos
+    pub tabla: PathBuf,
+    /// Modelos que se comparan, el primero es la referencia. Si no hay ninguno se usa el del fichero de entrada
+    /// con los priors de [priors].
+    pub modelos: Vec<VarianteModelo>,
+}
+
+impl Default for ConfiguracionAnidado{
+    fn default() -> Self{
+        ConfiguracionAnidado{metodo: MetodoAnidado::Elipsoides, puntos_vivos: 400, tolerancia: 0.01, expansion: 1.25, pasos_rebanada: 5,
+                             semilla: 1, salida: PathBuf::from("cadenas/anidado.csv"), tabla: PathBuf::from("cadenas/comparacion_modelos.csv"),
+                             modelos: Vec::new()}
+    }
+}
+
+impl ConfiguracionAnidado{
+    /// Los modelos que se comparan
+    pub fn modelos(&self) -> Vec<VarianteModelo>{
+        if self.modelos.is_empty(){
+            vec![VarianteModelo{nombre: "entrada".to_string(), ..Default::default()}]
+        }
+        else{
+            self.modelos.clone()
+        }
+    }
+
+    /// Fichero de las muestras de un modelo
+    pub fn salida_modelo(&self, modelo: &VarianteModelo) -> PathBuf{
+        if self.modelos.is_empty(){
+            return self.salida.clone();
+        }
+        let nombre: String = modelo.nombre.chars().map(|c| if c.is_ascii_alphanumeric() {c.to_ascii_lowercase()} else {'_'}).collect();
+        let raiz = self.salida.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        let mut fichero = self.salida.with_file_name(format!("{raiz}_{nombre}"));
+        if let Some(extension) = self.salida.extension(){
+            fichero.set_extension(extension);
+        }
+        fichero
+    }
+}
+
+/// Punto descartado (o vivo al final) con su peso en la posterior
+#[derive(Clone, Debug)]
+pub struct MuestraAnidada{
+    pub valores: Vec<f64>,
+    pub log_verosimilitud: f64,
+    /// Logaritmo del peso normalizado, L_i w_i / Z
+    pub log_peso: f64,
+}
+
+/// Resultado del muestreo anidado de un modelo
+#[derive(Clone, Debug)]
+pub struct ResultadoAnidado{
+    pub parametros: Vec<Parametro>,
+    /// ln Z, con la verosimilitud e^(-χ²/2) y los priors normalizados
+    pub ln_evidencia: f64,
+    /// Error estadístico de ln Z, √(H/N)
+    pub error_ln_evidencia: f64,
+    /// Información H (divergencia de Kullback-Leibler de la posterior respecto al prior) en nats
+    pub informacion: f64,
+    pub iteraciones: usize,
+    /// Evaluaciones de la verosimilitud
+    pub evaluaciones: usize,
+    pub muestras: Vec<MuestraAnidada>,
+}
+
+/// ln(e^a + e^b) sin desbordamientos
+fn suma_log(a: f64, b: f64) -> f64{
+    let m = a.max(b);
+    if m == f64::NEG_INFINITY {m} else {m + ((a - m).exp() + (b - m).exp()).ln()}
+}
+
+/// Añade a Z una contribución L w y actualiza la información H (Skilling 2006)
+fn acumular(ln_z: &mut f64, informacion: &mut f64, ln_w: f64, ln_l: f64){
+    if ln_l == f64::NEG_INFINITY{
+        return;
+    }
+    let nuevo = suma_log(*ln_z, ln_w + ln_l);
+    let anterior = if ln_z.is_finite() {(*ln_z - nuevo).exp()*(*informacion + *ln_z)} else {0.};
+    *informacion = (ln_w + ln_l - nuevo).exp()*ln_l + anterior - nuevo;
+    *ln_z = nuevo;
+}
+
+/// Elipsoide {centro + L z : |z| ≤ 1} que contiene a los puntos vivos
+struct Elipsoide{
+    centro: Vec<f64>,
+    l: Vec<f64>,
+}
+
+impl Elipsoide{
+    fn new(puntos: &[&[f64]], expansion: f64) -> Self{
+        let d = puntos[0].len();
+        let n = puntos.len() as f64;
+        let centro: Vec<f64> = (0..d).map(|i| puntos.iter().map(|u| u[i]).sum::<f64>()/n).collect();
+        let mut covarianza = vec![0.; d*d];
+        for u in puntos{
+            for i in 0..d{
+                for j in 0..d{
+                    covarianza[i*d+j] += (u[i] - centro[i])*(u[j] - centro[j])/(n - 1.);
+                }
+            }
+        }
+        // Si los puntos están en un subespacio (p.ej. todos iguales) se ensancha la diagonal hasta poder factorizar
+        let mut extra = 0.;
+        let l = loop{
+            let mut c = covarianza.clone();
+            for i in 0..d{
+                c[i*d+i] += extra;
+            }
+            match cholesky(&c, d){
+                Some(l) => break l,
+                None => extra = if extra == 0. {1e-12} else {extra*10.},
+            }
+        };
+        // Distancia de Mahalanobis del punto más alejado, para que el elipsoide los contenga a todos
+        let k = puntos.iter().map(|u| {
+            let m: Vec<f64> = u.iter().zip(&centro).map(|(x, c)| x - c).collect();
+            resolver_cholesky(&l, d, &m).iter().zip(&m).map(|(a, b)| a*b).sum::<f64>()
+        }).fold(0., f64::max);
+        let escala = k.sqrt().max(1e-10)*expansion;
+        Elipsoide{centro, l: l.into_iter().map(|x| x*escala).collect()}
+    }
+
+    /// L z, con z la dirección dada
+    fn transformar(&self, z: &[f64]) -> Vec<f64>{
+        let d = z.len();
+        (0..d).map(|i| (0..=i).map(|j| self.l[i*d+j]*z[j]).sum()).collect()
+    }
+
+    /// Punto uniforme dentro del elipsoide
+    fn muestra(&self, rng: &mut Rng) -> Vec<f64>{
+        let d = self.centro.len();
+        let direccion = direccion_aleatoria(d, rng);
+        let radio = rng.uniforme().powf(1./d as f64);
+        let z: Vec<f64> = direccion.iter().map(|x| x*radio).collect();
+        self.transformar(&z).iter().zip(&self.centro).map(|(x, c)| x + c).collect()
+    }
+}
+
+/// Vector unitario con dirección uniforme
+fn direccion_aleatoria(d: usize, rng: &mut Rng) -> Vec<f64>{
+    let z: Vec<f64> = (0..d).map(|_| rng.normal()).collect();
+    let norma = z.iter().map(|x| x*x).sum::<f64>().sqrt();
+    z.into_iter().map(|x| x/norma).collect()
+}
+
+fn en_cubo(u: &[f64]) -> bool{
+    u.iter().all(|&x| x > 0. && x < 1.)
+}
+
+/// Intentos seguidos sin encontrar un punto mejor antes de rendirse
+const MAX_INTENTOS: usize = 1_000_000;
+
+struct Muestreador<'a>{
+    log_verosimilitud: &'a dyn Fn(&[f64]) -> f64,
+    priors: &'a Priors,
+    configuracion: &'a ConfiguracionAnidado,
+    rng: Rng,
+    evaluaciones: usize,
+}
+
+impl Muestreador<'_>{
+    /// Valores de los parámetros de un punto del hipercubo unidad
+    fn valores(&self, u: &[f64]) -> Vec<f64>{
+        self.priors.values().zip(u).map(|(prior, &u)| prior.cuantil(u)).collect()
+    }
+
+    fn evaluar(&mut self, u: &[f64]) -> f64{
+        self.evaluaciones += 1;
+        let l = (self.log_verosimilitud)(&self.valores(u));
+        if l.is_nan() {f64::NEG_INFINITY} else {l}
+    }
+
+    /// u + t·direccion si está dentro del cubo y tiene verosimilitud mayor que `l_min`
+    fn en_contorno(&mut self, u: &[f64], direccion: &[f64], t: f64, l_min: f64) -> Option<(Vec<f64>, f64)>{
+        let v: Vec<f64> = u.iter().zip(direccion).map(|(x, d)| x + t*d).collect();
+        if !en_cubo(&v){
+            return None;
+        }
+        let l = self.evaluar(&v);
+        (l > l_min).then_some((v, l))
+    }
+
+    fn por_elipsoide(&mut self, elipsoide: &Elipsoide, l_min: f64) -> anyhow::Result<(Vec<f64>, f64)>{
+        for _ in 0..MAX_INTENTOS{
+            let u = elipsoide.muestra(&mut self.rng);
+            if !en_cubo(&u){
+                continue;
+            }
+            let l = self.evaluar(&u);
+            if l > l_min{
+                return Ok((u, l));
+            }
+        }
+        bail!(texto!(SinPuntoVerosimil, l_min, MAX_INTENTOS))
+    }
+
+    fn por_rebanadas(&mut self, elipsoide: &Elipsoide, mut u: Vec<f64>, mut l: f64, l_min: f64) -> (Vec<f64>, f64){
+        let d = u.len();
+        for _ in 0..self.configuracion.pasos_rebanada*d{
+            let direccion = elipsoide.transformar(&direccion_aleatoria(d, &mut self.rng));
+            // Intervalo de anchura 1 (el radio del elipsoide en esa dirección) que se amplía hasta salir del contorno
+            let r = self.rng.uniforme();
+            let (mut izquierda, mut derecha) = (-r, 1. - r);
+            for _ in 0..100{
+                if self.en_contorno(&u, &direccion, izquierda, l_min).is_none() {break}
+                izquierda -= 1.;
+            }
+            for _ in 0..100{
+                if self.en_contorno(&u, &direccion, derecha, l_min).is_none() {break}
+                derecha += 1.;
+            }
+            // Y se encoge hasta dar con un punto dentro
+            while derecha - izquierda > 1e-12{
+                let t = izquierda + (derecha - izquierda)*self.rng.uniforme();
+                if let Some((v, lv)) = self.en_contorno(&u, &direccion, t, l_min){
+                    (u, l) = (v, lv);
+                    break;
+                }
+                if t < 0. {izquierda = t} else {derecha = t}
+            }
+        }
+        (u, l)
+    }
+
+    /// Punto nuevo del prior con ln L > l_min, para sustituir al punto vivo `peor`
+    fn nuevo_punto(&mut self, vivos: &[(Vec<f64>, f64)], peor: usize, l_min: f64) -> anyhow::Result<(Vec<f64>, f64)>{
+        let puntos: Vec<&[f64]> = vivos.iter().map(|v| v.0.as_slice()).collect();
+        let elipsoide = Elipsoide::new(&puntos, self.configuracion.expansion);
+        let candidatos: Vec<usize> = (0..vivos.len()).filter(|&i| i != peor && vivos[i].1 > l_min).collect();
+        match self.configuracion.metodo{
+            MetodoAnidado::Rebanadas if !candidatos.is_empty() => {
+                let inicio = candidatos[self.rng.entero(candidatos.len())];
+                Ok(self.por_rebanadas(&elipsoide, vivos[inicio].0.clone(), vivos[inicio].1, l_min))
+            }
+            _ => self.por_elipsoide(&elipsoide, l_min),
+        }
+    }
+}
+
+/// Muestreo anidado (Skilling 2004) de la evidencia Z = ∫ L π y de la posterior.
+///
+/// Los parámetros libres son los que tienen prior y se muestrean en el hipercubo unidad a través de `Prior::cuantil`.
+/// `log_verosimilitud` recibe los valores de los parámetros en el orden de `priors`; NaN cuenta como verosimilitud nula.
+/// En cada iteración se descarta el punto vivo con menos verosimilitud, el volumen del prior que queda se reduce en
+/// e^(-1/N) y se busca un punto nuevo con más verosimilitud, por rechazo en un elipsoide o por rebanadas.
+pub fn muestreo_anidado(log_verosimilitud: &dyn Fn(&[f64]) -> f64, priors: &Priors, configuracion: &ConfiguracionAnidado,
+                        semilla: u64) -> anyhow::Result<ResultadoAnidado>{
+    let libres: Vec<Parametro> = priors.keys().copied().collect();
+    let d = libres.len();
+    let n = configuracion.puntos_vivos;
+    if d == 0{
+        bail!(texto!(SinPriors));
+    }
+    if n <= d{
+        bail!(texto!(PuntosVivos, d, d));
+    }
+    let mut muestreador = Muestreador{log_verosimilitud, priors, configuracion, rng: Rng::new(semilla), evaluaciones: 0};
+
+    let mut vivos: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n);
+    while vivos.len() < n{
+        let u: Vec<f64> = (0..d).map(|_| muestreador.rng.uniforme()).collect();
+        if en_cubo(&u){
+            let l = muestreador.evaluar(&u);
+            vivos.push((u, l));
+        }
+    }
+    if vivos.iter().all(|v| v.1 == f64::NEG_INFINITY){
+        bail!(texto!(PuntosInicialesSinVerosimilitud, n));
+    }
+
+    let mut ln_z = f64::NEG_INFINITY;
+    let mut informacion = 0.;
+    let mut ln_x = 0.;
+    // ln(1 - e^(-1/N)), el peso de cada punto descartado es X_i (1 - e^(-1/N))
+    let ln_contraccion = (-(-1./n as f64).exp_m1()).ln();
+    let mut muertos: Vec<(Vec<f64>, f64, f64)> = Vec::new();
+    let mut iteraciones = 0;
+    loop{
+        let (peor, l_min) = vivos.iter().enumerate().fold((0, f64::INFINITY), |(i, m), (j, v)| if v.1 < m {(j, v.1)} else {(i, m)});
+        let ln_w = ln_x + ln_contraccion;
+        acumular(&mut ln_z, &mut informacion, ln_w, l_min);
+        muertos.push((vivos[peor].0.clone(), l_min, ln_w));
+        ln_x -= 1./n as f64;
+        iteraciones += 1;
+
+        let l_max = vivos.iter().map(|v| v.1).fold(f64::NEG_INFINITY, f64::max);
+        if ln_z.is_finite() && suma_log(ln_z, l_max + ln_x) - ln_z < configuracion.tolerancia{
+            break;
+        }
+        vivos[peor] = muestreador.nuevo_punto(&vivos, peor, l_min)?;
+    }
+
+    // Los puntos vivos que quedan se reparten el volumen restante
+    vivos.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let ln_w = ln_x - (n as f64).ln();
+    for (u, l) in vivos{
+        acumular(&mut ln_z, &mut informacion, ln_w, l);
+        muertos.push((u, l, ln_w));
+    }
+
+    let muestras = muertos.into_iter().map(|(u, l, ln_w)| MuestraAnidada{valores: muestreador.valores(&u), log_verosimilitud: l, log_peso: ln_w + l - ln_z})
+        .collect();
+    let informacion = informacion.max(0.);
+    Ok(ResultadoAnidado{parametros: libres, ln_evidencia: ln_z, error_ln_evidencia: (informacion/n as f64).sqrt(), informacion,
+                        iteraciones, evaluaciones: muestreador.evaluaciones, muestras})
+}
+
+impl ResultadoAnidado{
+    /// Número efectivo de muestras independientes, (Σw)²/Σw²
+    pub fn muestras_efectivas(&self) -> f64{
+        1./self.muestras.iter().map(|m| (2.*m.log_peso).exp()).sum::<f64>()
+    }
+
+    /// χ² del mejor punto encontrado
+    pub fn chi2_minimo(&self) -> f64{
+        -2.*self.muestras.iter().map(|m| m.log_verosimilitud).fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Media y desviación típica de cada parámetro con los pesos de la posterior
+    pub fn media_desviacion(&self) -> Vec<(f64, f64)>{
+        (0..self.parametros.len()).map(|i| {
+            let media: f64 = self.muestras.iter().map(|m| m.log_peso.exp()*m.valores[i]).sum();
+            let varianza: f64 = self.muestras.iter().map(|m| m.log_peso.exp()*(m.valores[i] - media).powi(2)).sum();
+            (media, varianza.sqrt())
+        }).collect()
+    }
+
+    /// Muestras de la posterior con pesos iguales (remuestreo sistemático, tantas como `muestras_efectivas`)
+    /// en el formato de las cadenas: el paso es el número de muestra, el caminante 0 y la última columna el log posterior.
+    pub fn cadena(&self, priors: &Priors, semilla: u64) -> Cadena{
+        let mut cadena = Cadena::new(&self.parametros);
+        let total = self.muestras_efectivas().round().max(1.) as usize;
+        let mut rng = Rng::new(semilla);
+        let mut acumulado = 0.;
+        let mut siguiente = rng.uniforme();
+        for muestra in &self.muestras{
+            acumulado += muestra.log_peso.exp()*total as f64;
+            while siguiente < acumulado && cadena.filas.len() < total{
+                let paso = cadena.filas.len();
+                cadena.añadir(paso, 0, &muestra.valores, muestra.log_verosimilitud + log_prior(priors, &muestra.valores));
+                siguiente += 1.;
+            }
+        }
+        cadena
+    }
+}
+
+/// Evidencia de una variante del modelo con muestreo anidado
+pub fn evidencia_modelo(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, modelo: &VarianteModelo, priors: &Priors,
+                        configuracion: &ConfiguracionAnidado, semilla: u64) -> anyhow::Result<ResultadoAnidado>{
+    let priors = modelo.priors(priors);
+    modelo.comprobar(priors)?;
+    let libres: Vec<Parametro> = priors.keys().copied().collect();
+    let log_verosimilitud = |xx: &[f64]| -verosimilitud.chi2(modelo.parametros(parametros, &libres, xx))/2.;
+    muestreo_anidado(&log_verosimilitud, priors, configuracion, semilla)
+        .with_context(|| texto!(EnModelo, modelo.nombre))
+}
+
+/// Escala de Jeffreys (en la versión de Trotta 2008) para un ln B entre dos modelos
+pub fn escala_jeffreys(ln_b: f64) -> &'static str{
+    match ln_b.abs(){
+        x if x < 1. => Texto::NoConcluyente,
+        x if x < 2.5 => Texto::Debil,
+        x if x < 5. => Texto::Moderada,
+        _ => Texto::Fuerte,
+    }.texto()
+}
+
+/// Calcula la evidencia de cada modelo de la configuración, cada uno en su hilo, y escribe las muestras de la posterior
+/// de cada uno y la tabla de comparación. Los resultados están en el orden de `configuracion.modelos()`.
+pub fn comparar_modelos(verosimilitud: &dyn Verosimilitud, parametros: ParametrosCosmologicos, priors: &Priors,
+                        configuracion: &ConfiguracionAnidado) -> anyhow::Result<Vec<ResultadoAnidado>>{
+    let modelos = configuracion.modelos();
+    let resultados: Vec<ResultadoAnidado> = thread::scope(|s| {
+        let hilos: Vec<_> = modelos.iter().enumerate()
+            .map(|(i, modelo)| s.spawn(move || evidencia_modelo(verosimilitud, parametros, modelo, priors, configuracion,
+                                                                 configuracion.semilla.wrapping_add(i as u64))))
+            .collect();
+        hilos.into_iter().map(|h| h.join().expect("un hilo del muestreo anidado ha fallado")).collect::<anyhow::Result<_>>()
+    })?;
+    for (modelo, resultado) in modelos.iter().zip(&resultados){
+        resultado.cadena(modelo.priors(priors), configuracion.semilla).escribir(&configuracion.salida_modelo(modelo))?;
+    }
+    escribir_tabla(&modelos, &resultados, &configuracion.tabla)?;
+    Ok(resultados)
+}
+
+/// Tabla CSV con ln Z de cada modelo y ln B respecto al primero
+pub fn escribir_tabla(modelos: &[VarianteModelo], resultados: &[ResultadoAnidado], fichero: &Path) -> anyhow::Result<()>{
+    if let Some(directorio) = fichero.parent(){
+        fs::create_dir_all(directorio)?;
+    }
+    let mut texto = String::from("modelo,parametros,ln_z,error_ln_z,ln_b,error_ln_b,chi2_min,informacion,evaluaciones\n");
+    let referencia = &resultados[0];
+    for (modelo, r) in modelos.iter().zip(resultados){
+        let ln_b = r.ln_evidencia - referencia.ln_evidencia;
+        let error_ln_b = if std::ptr::eq(r, referencia) {0.} else {r.error_ln_evidencia.hypot(referencia.error_ln_evidencia)};
+        texto += &format!("{},{},{},{},{},{},{},{},{}\n", modelo.nombre, r.parametros.len(), r.ln_evidencia, r.error_ln_evidencia,
+                          ln_b, error_ln_b, r.chi2_minimo(), r.informacion, r.evaluaciones);
+    }
+    fs::write(fichero, texto).with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::priors::Prior;
+
+    #[test]
+    fn evidencia_gaussiana(){
+        // Gaussiana normalizada y correlacionada, muy dentro de un prior uniforme de volumen V: Z = 1/V
+        let media = [0.5, -1.];
+        let (sigma_x, sigma_y, rho): (f64, f64, f64) = (0.3, 0.5, 0.7);
+        let determinante = (sigma_x*sigma_y).powi(2)*(1. - rho*rho);
+        let log_verosimilitud = |xx: &[f64]| {
+            let (dx, dy) = (xx[0] - media[0], xx[1] - media[1]);
+            let chi2 = (dx*dx*sigma_y*sigma_y - 2.*rho*sigma_x*sigma_y*dx*dy + dy*dy*sigma_x*sigma_x)/determinante;
+            -chi2/2. - (2.*f64::consts::PI).ln() - determinante.ln()/2.
+        };
+        let priors: Priors = [(Parametro::W, Prior::Uniforme(-4., 6.)), (Parametro::Wa, Prior::Uniforme(-6., 4.))].into();
+        let exacta = -(100f64).ln();
+        for metodo in [MetodoAnidado::Elipsoides, MetodoAnidado::Rebanadas]{
+            let configuracion = ConfiguracionAnidado{metodo, ..Default::default()};
+            let resultado = muestreo_anidado(&log_verosimilitud, &priors, &configuracion, 3).unwrap();
+            // El error es una desviación típica (con otras semillas ~2/3 de los resultados caen a menos de 1σ), así que se
+            // admiten 3σ para que la prueba no dependa de la suerte
+            assert!((resultado.ln_evidencia - exacta).abs() < 3.*resultado.error_ln_evidencia,
+                    "{:?}: ln Z = {} ± {} != {exacta}", metodo, resultado.ln_evidencia, resultado.error_ln_evidencia);
+            assert!(resultado.error_ln_evidencia < 0.2, "{:?}: error {}", metodo, resultado.error_ln_evidencia);
+            for ((media_estimada, sigma_estimada), (media, sigma)) in resultado.media_desviacion().into_iter().zip([(media[0], sigma_x), (media[1], sigma_y)]){
+                assert!((media_estimada - media).abs() < 0.1*sigma, "{:?}: media {media_estimada} != {media}", metodo);
+                assert!((sigma_estimada/sigma - 1.).abs() < 0.1, "{:?}: σ {sigma_estimada} != {sigma}", metodo);
+            }
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::fmt::Write;
+
+use serde::{Serialize, Deserialize};
+
+use crate::contours::Segmento;
+use crate::figures::Escala;
+
+
+/// Colores del ciclo por defecto de matplotlib (C0, C1...), para que las figuras de LaTeX tengan los mismos que las de python
+const COLORES: [(&str, &str); 10] = [("C0", "1F77B4"), ("C1", "FF7F0E"), ("C2", "2CA02C"), ("C3", "D62728"), ("C4", "9467BD"),
+                                     ("C5", "8C564B"), ("C6", "E377C2"), ("C7", "7F7F7F"), ("C8", "BCBD22"), ("C9", "17BECF")];
+
+/// Dónde van los datos de las figuras de LaTeX
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatosLatex{
+    /// Dentro del .tex o .pgf, en el propio \addplot
+    #[default]
+    Incrustados,
+    /// En una tabla <figura>_<n>.dat por serie, junto al .tex o .pgf
+    Dat,
+}
+
+/// Opciones de las figuras de PGFPlots, tabla [graficas.latex]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionLatex{
+    pub datos: DatosLatex,
+    /// Líneas que se añaden al preámbulo de los .tex, p.ej. los paquetes de fuentes del artículo
+    pub preambulo: String,
+}
+
+/// Mapa de colores de las series `Mapa`
+#[derive(Clone, Debug, PartialEq)]
+pub enum MapaColores{
+    Viridis,
+    /// De blanco a negro
+    Grises,
+    /// Un color por categoría: el valor de cada punto es el índice del color
+    Categorias(Vec<String>),
+}
+
+/// Cómo se dibuja una serie
+#[derive(Clone, Debug, PartialEq)]
+pub enum TipoSerie{
+    /// Línea con un estilo de matplotlib ("-", "--", ":" o "-.") y un grosor en puntos
+    Linea{estilo: String, grosor: f64},
+    /// Línea en escalones, p.ej. un histograma: cada y vale hasta la x siguiente
+    Escalones,
+    /// Puntos con barras de error en y, que son la tercera columna
+    Puntos,
+    /// Una cruz en cada punto
+    Cruces,
+    /// Polígono relleno
+    Relleno{opacidad: f64},
+    /// Mapa de color de una rejilla: los puntos van por filas, con `columnas` puntos por fila, y la tercera columna es el valor
+    Mapa{columnas: usize, colores: MapaColores, opacidad: f64},
+}
+
+/// Serie de datos de unos ejes. Los NaN cortan las líneas.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriePgf{
+    pub tipo: TipoSerie,
+    /// Color de TikZ, p.ej. "C0", "black" o "{rgb,255:red,31;green,119;blue,180}"
+    pub color: String,
+    /// En LaTeX; sin leyenda la serie no sale en ella
+    pub leyenda: Option<String>,
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    /// Error de `Puntos` o valor de `Mapa`
+    pub z: Vec<f64>,
+}
+
+impl SeriePgf{
+    pub fn linea(x: Vec<f64>, y: Vec<f64>, color: &str, estilo: &str, grosor: f64) -> Self{
+        SeriePgf{tipo: TipoSerie::Linea{estilo: estilo.to_string(), grosor}, color: color.to_string(), leyenda: None, x, y, z: Vec::new()}
+    }
+
+    pub fn puntos(x: Vec<f64>, y: Vec<f64>, error: Vec<f64>, color: &str) -> Self{
+        SeriePgf{tipo: TipoSerie::Puntos, color: color.to_string(), leyenda: None, x, y, z: error}
+    }
+
+    /// Segmentos sueltos (p.ej. una curva de nivel) como una sola línea cortada con NaN entre ellos
+    pub fn segmentos(segmentos: &[Segmento], color: &str, estilo: &str, grosor: f64) -> Self{
+        let (mut x, mut y) = (Vec::with_capacity(3*segmentos.len()), Vec::with_capacity(3*segmentos.len()));
+        for &((x0, y0), (x1, y1)) in segmentos{
+            x.extend([x0, x1, f64::NAN]);
+            y.extend([y0, y1, f64::NAN]);
+        }
+        SeriePgf::linea(x, y, color, estilo, grosor)
+    }
+
+    pub fn con_leyenda(self, leyenda: impl Into<String>) -> Self{
+        SeriePgf{leyenda: Some(leyenda.into()), ..self}
+    }
+
+    fn opciones(&self) -> String{
+        let mut opciones = match &self.tipo{
+            TipoSerie::Linea{estilo, grosor} => format!("color={}, {}, no markers, line width={}pt", self.color, estilo_tikz(estilo), grosor),
+            TipoSerie::Escalones => format!("color={}, const plot, no markers", self.color),
+            TipoSerie::Puntos => format!("color={}, only marks, mark=*, mark size=1pt, error bars/y dir=both, error bars/y explicit", self.color),
+            TipoSerie::Cruces => format!("color={}, only marks, mark=x, mark size=4pt, thick", self.color),
+            TipoSerie::Relleno{opacidad} => format!("fill={}, fill opacity={}, draw=none", self.color, opacidad),
+            TipoSerie::Mapa{columnas, colores, opacidad} => {
+                let acceso = if matches!(colores, MapaColores::Categorias(_)) {", colormap access=direct"} else {""};
+                format!("matrix plot*, mesh/cols={}, point meta=explicit, opacity={}{}", columnas, opacidad, acceso)
+            }
+        };
+        if self.leyenda.is_none(){
+            opciones += ", forget plot";
+        }
+        opciones
+    }
+
+    /// Columnas de la tabla y opciones de `table` para leerlas
+    fn tabla(&self) -> (String, &'static str){
+        let (cabecera, columnas) = match self.tipo{
+            TipoSerie::Puntos => ("x y error", "x=x, y=y, y error=error"),
+            TipoSerie::Mapa{..} => ("x y valor", "x=x, y=y, meta=valor"),
+            _ => ("x y", "x=x, y=y"),
+        };
+        let mut tabla = format!("{}\n", cabecera);
+        for (i, (x, y)) in self.x.iter().zip(&self.y).enumerate(){
+            match self.z.get(i){
+                Some(z) => writeln!(tabla, "{} {} {}", numero(*x), numero(*y), numero(*z)),
+                None => writeln!(tabla, "{} {}", numero(*x), numero(*y)),
+            }.unwrap();
+        }
+        (tabla, columnas)
+    }
+}
+
+/// Unos ejes de una figura. Las etiquetas y el título van en LaTeX, así que los textos que no lo son hay que pasarlos
+/// por `escapar_latex`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EjesPgf{
+    pub etiqueta_x: String,
+    pub etiqueta_y: String,
+    pub titulo: String,
+    pub escala_x: Escala,
+    pub escala_y: Escala,
+    pub rango_x: Option<(f64, f64)>,
+    pub rango_y: Option<(f64, f64)>,
+    pub series: Vec<SeriePgf>,
+    /// Entradas de la leyenda sin serie: un recuadro de color con su opacidad, p.ej. para las categorías de un mapa
+    pub leyendas_area: Vec<(String, f64, String)>,
+    /// Textos en coordenadas de los datos
+    pub notas: Vec<(f64, f64, String)>,
+    pub barra_colores: bool,
+    pub sin_marcas_y: bool,
+    /// Sólo ocupa su sitio en la rejilla de la figura
+    pub vacio: bool,
+}
+
+impl EjesPgf{
+    pub fn new(etiqueta_x: impl Into<String>, etiqueta_y: impl Into<String>) -> Self{
+        EjesPgf{etiqueta_x: etiqueta_x.into(), etiqueta_y: etiqueta_y.into(), ..Default::default()}
+    }
+
+    pub fn vacio() -> Self{
+        EjesPgf{vacio: true, ..Default::default()}
+    }
+
+    fn opciones(&self, ancho: f64, alto: f64) -> String{
+        let mut opciones = vec![format!("width={:.3}in", ancho), format!("height={:.3}in", alto)];
+        for (clave, valor) in [("xlabel", &self.etiqueta_x), ("ylabel", &self.etiqueta_y), ("title", &self.titulo)]{
+            if !valor.is_empty(){
+                opciones.push(format!("{}={{{}}}", clave, valor));
+            }
+        }
+        for (eje, escala, rango) in [("x", self.escala_x, self.rango_x), ("y", self.escala_y, self.rango_y)]{
+            if escala == Escala::Log{
+                opciones.push(format!("{}mode=log", eje));
+            }
+            if let Some((min, max)) = rango{
+                opciones.push(format!("{eje}min={}, {eje}max={}", numero(min), numero(max)));
+            }
+        }
+        let mapa = self.series.iter().find_map(|s| match &s.tipo{
+            TipoSerie::Mapa{colores, ..} => Some(colores),
+            _ => None,
+        });
+        match mapa{
+            Some(MapaColores::Viridis) => opciones.push("colormap/viridis".to_string()),
+            Some(MapaColores::Grises) => opciones.push("colormap={grises}{gray(0cm)=(1); gray(1cm)=(0)}".to_string()),
+            Some(MapaColores::Categorias(colores)) => opciones.push(format!("colormap={{categorias}}{{{}}}",
+                colores.iter().enumerate().map(|(i, color)| format!("color({}cm)=({})", i, color)).collect::<Vec<_>>().join("; "))),
+            None => (),
+        }
+        if mapa.is_some(){
+            opciones.push("axis on top".to_string());
+        }
+        if self.barra_colores{
+            opciones.push("colorbar".to_string());
+        }
+        if self.sin_marcas_y{
+            opciones.push(r"ytick=\empty".to_string());
+        }
+        opciones.join(", ")
+    }
+}
+
+/// Figura de PGFPlots: una rejilla de ejes (un `groupplot`), por filas. Es lo que se exporta a .tex y .pgf; las mismas
+/// curvas que los scripts de python pero con las fuentes del documento en el que se incluye.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FiguraPgf{
+    pub ejes: Vec<EjesPgf>,
+    pub columnas: usize,
+    /// Ancho y alto en pulgadas
+    pub tamanyo: (f64, f64),
+    /// Alto relativo de cada fila, vacío si todas son iguales
+    pub altos: Vec<f64>,
+    /// Sólo las etiquetas x de la fila de abajo y las y de la columna de la izquierda
+    pub compartir_ejes: bool,
+}
+
+impl FiguraPgf{
+    /// Figura de unos solos ejes
+    pub fn new(ejes: EjesPgf, tamanyo: (f64, f64)) -> Self{
+        FiguraPgf{ejes: vec![ejes], columnas: 1, tamanyo, altos: Vec::new(), compartir_ejes: false}
+    }
+
+    /// Rejilla de ejes por filas, todas del mismo alto
+    pub fn rejilla(ejes: Vec<EjesPgf>, columnas: usize, tamanyo: (f64, f64)) -> Self{
+        FiguraPgf{ejes, columnas: columnas.max(1), tamanyo, altos: Vec::new(), compartir_ejes: false}
+    }
+
+    fn filas(&self) -> usize{
+        self.ejes.len().div_ceil(self.columnas).max(1)
+    }
+
+    /// El tikzpicture de la figura. Con `dat`, las tablas no van dentro sino en `<dat>_<n>.dat`, y se devuelven
+    /// (nombre y contenido) para escribirlas junto al .tex.
+    pub fn tikz(&self, dat: Option<&str>) -> (String, Vec<(String, String)>){
+        let filas = self.filas();
+        let altos = if self.altos.len() == filas {self.altos.clone()} else {vec![1.; filas]};
+        let total: f64 = altos.iter().sum();
+        // Lo que queda de cada celda es para las etiquetas y los números de los ejes
+        let ancho = 0.78*self.tamanyo.0/self.columnas as f64;
+        let mut tablas = Vec::new();
+        let mut texto = String::from("\\begin{tikzpicture}\n");
+        for (nombre, html) in COLORES{
+            writeln!(texto, "\\definecolor{{{}}}{{HTML}}{{{}}}", nombre, html).unwrap();
+        }
+        let descripciones = if self.compartir_ejes {", x descriptions at=edge bottom, y descriptions at=edge left"} else {""};
+        let separacion = if self.compartir_ejes {"0.15in"} else {"0.9in"};
+        writeln!(texto, "\\begin{{groupplot}}[group style={{group size={} by {}, horizontal sep={sep}, vertical sep={sep}{}}},\n    \
+                         scale only axis, unbounded coords=jump, legend cell align=left, legend style={{font=\\small}}]",
+                 self.columnas, filas, descripciones, sep=separacion).unwrap();
+        for (i, ejes) in self.ejes.iter().enumerate(){
+            if ejes.vacio{
+                texto += "\\nextgroupplot[group/empty plot]\n";
+                continue;
+            }
+            let alto = 0.78*self.tamanyo.1*altos[i/self.columnas]/total;
+            writeln!(texto, "\\nextgroupplot[{}]", ejes.opciones(ancho, alto)).unwrap();
+            for serie in &ejes.series{
+                let (tabla, columnas) = serie.tabla();
+                match dat{
+                    Some(raiz) => {
+                        let fichero = format!("{}_{}.dat", raiz, tablas.len() + 1);
+                        writeln!(texto, "\\addplot[{}] table[{}] {{{}}};", serie.opciones(), columnas, fichero).unwrap();
+                        tablas.push((fichero, tabla));
+                    }
+                    None => {
+                        let filas: String = tabla.lines().map(|l| format!("{}\\\\\n", l)).collect();
+                        writeln!(texto, "\\addplot[{}] table[{}, row sep=\\\\] {{\n{}}};", serie.opciones(), columnas, filas).unwrap();
+                    }
+                }
+                if let Some(leyenda) = &serie.leyenda{
+                    writeln!(texto, "\\addlegendentry{{{}}}", leyenda).unwrap();
+                }
+            }
+            for (color, opacidad, leyenda) in &ejes.leyendas_area{
+                writeln!(texto, "\\addlegendimage{{area legend, fill={}, fill opacity={}, draw=none}}\n\\addlegendentry{{{}}}",
+                         color, opacidad, leyenda).unwrap();
+            }
+            for (x, y, nota) in &ejes.notas{
+                writeln!(texto, "\\node[font=\\scriptsize, fill=white, inner sep=1pt] at (axis cs:{},{}) {{{}}};", numero(*x), numero(*y), nota).unwrap();
+            }
+        }
+        texto += "\\end{groupplot}\n\\end{tikzpicture}\n";
+        (texto, tablas)
+    }
+
+    /// Documento standalone que se compila solo, con `preambulo` después de cargar pgfplots
+    pub fn documento(&self, preambulo: &str, dat: Option<&str>) -> (String, Vec<(String, String)>){
+        let (tikz, tablas) = self.tikz(dat);
+        let documento = format!("% Compilar con pdflatex; con lualatex o xelatex si las etiquetas tienen caracteres que no son latinos\n\
+                                 \\documentclass{{standalone}}\n\\usepackage{{pgfplots}}\n\\usepgfplotslibrary{{groupplots}}\n\
+                                 \\pgfplotsset{{compat=1.16}}\n{}\\begin{{document}}\n{}\\end{{document}}\n",
+                                preambulo.trim().lines().map(|l| format!("{}\n", l)).collect::<String>(), tikz);
+        (documento, tablas)
+    }
+
+    /// El tikzpicture para incluir con \input en otro documento, con un comentario de lo que necesita
+    pub fn pgf(&self, dat: Option<&str>) -> (String, Vec<(String, String)>){
+        let (tikz, tablas) = self.tikz(dat);
+        (format!("% Necesita \\usepackage{{pgfplots}}, \\usepgfplotslibrary{{groupplots}} y \\pgfplotsset{{compat=1.16}} o posterior\n{}", tikz),
+         tablas)
+    }
+}
+
+/// Estilo de línea de TikZ equivalente a uno de matplotlib
+pub fn estilo_tikz(estilo: &str) -> &'static str{
+    match estilo{
+        "--" | "dashed" => "dashed",
+        ":" | "dotted" => "dotted",
+        "-." | "dashdot" => "dashdotted",
+        _ => "solid",
+    }
+}
+
+/// Texto normal dentro de LaTeX, con los caracteres especiales escapados
+pub fn escapar_latex(texto: &str) -> String{
+    let mut escapado = String::with_capacity(texto.len());
+    for c in texto.chars(){
+        match c{
+            '\\' => escapado += r"\textbackslash{}",
+            '~' => escapado += r"\textasciitilde{}",
+            '^' => escapado += r"\textasciicircum{}",
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escapado.push('\\');
+                escapado.push(c);
+            }
+            c => escapado.push(c),
+        }
+    }
+    escapado
+}
+
+/// Etiqueta de matplotlib en LaTeX: lo que va entre $ se deja como está y el resto se escapa
+pub fn etiqueta_latex(etiqueta: &str) -> String{
+    etiqueta.split('$').enumerate()
+        .map(|(i, trozo)| if i%2 == 1 {format!("${}$", trozo)} else {escapar_latex(trozo)})
+        .collect()
+}
+
+/// Número en una tabla de pgfplots: los NaN e infinitos como nan, inf y -inf, y los muy grandes o pequeños en notación científica
+fn numero(x: f64) -> String{
+    if x.is_nan(){
+        "nan".to_string()
+    }
+    else if x.is_infinite(){
+        if x > 0. {"inf".to_string()} else {"-inf".to_string()}
+    }
+    else if x != 0. && !(1e-4..1e7).contains(&x.abs()){
+        format!("{:e}", x)
+    }
+    else{
+        x.to_string()
+    }
+}
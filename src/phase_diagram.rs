@@ -0,0 +1,116 @@
+use core::f64;
+
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::analysis::intervalo_actual;
+use crate::functions::{e2, lookback_time};
+use crate::plotting::linspace;
+use crate::contours::{contorno, Segmento};
+
+
+/// Rango del plano (densidad_materia, densidad_energia_oscura) que se recorre para el diagrama de fases
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct ConfiguracionDiagramaFases{
+    pub rango_materia: (f64, f64),
+    pub rango_energia_oscura: (f64, f64),
+    pub puntos: usize,
+}
+
+impl Default for ConfiguracionDiagramaFases{
+    fn default() -> Self{
+        ConfiguracionDiagramaFases{rango_materia: (0., 3.), rango_energia_oscura: (-1., 3.), puntos: 100}
+    }
+}
+
+/// Tipos de universo según su historia de expansión
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TipoUniverso{
+    /// Big Bang y expansión para siempre
+    ExpansionEterna = 0,
+    /// Big Bang y, tras alcanzar el tamaño máximo, contracción hasta un Big Crunch
+    Recolapso = 1,
+    /// Sin Big Bang: en el pasado el universo se contraía hasta un tamaño mínimo y rebotó
+    Rebote = 2,
+    /// Big Bang y expansión para siempre, pero pasando una larga época casi estática (cerca del universo de Einstein)
+    Remoloneo = 3,
+}
+
+/// Por debajo de este valor de (ȧ/ȧ0)² en el pasado se considera que el universo "remolonea".
+/// Para ΛCDM con Ωm = 0.3 el mínimo es 0.75.
+pub const UMBRAL_REMOLONEO: f64 = 0.01;
+
+/// Mínimo de (ȧ/ȧ0)² = E²(x)/x² en el pasado (x = a0/a > 1). Sólo cuentan los mínimos interiores:
+/// si (ȧ/ȧ0)² decrece hasta el Big Bang (p.ej. de Sitter) no hay una época casi estática.
+fn minimo_velocidad_expansion(parametros: ParametrosCosmologicos) -> f64{
+    let valores: Vec<f64> = linspace(0., 30., 3000).into_iter().map(|ln_x| {
+        let x = f64::exp(ln_x);
+        e2(x, parametros)/(x*x)
+    }).collect();
+    let (i_min, minimo) = valores.iter().copied().enumerate()
+        .fold((0, f64::INFINITY), |(i, m), (j, v)| if v < m {(j, v)} else {(i, m)});
+    if i_min == valores.len()-1 {f64::INFINITY} else {minimo}
+}
+
+/// Clasifica un modelo según el intervalo de factor de escala en el que E² ≥ 0 que contiene el presente.
+/// El extremo superior de ese intervalo es el tamaño máximo, donde `edo_para_a` cambia el signo de la expansión,
+/// y si el extremo inferior no es 0 el universo no ha tenido Big Bang.
+pub fn clasificar(parametros: ParametrosCosmologicos) -> TipoUniverso{
+    let intervalo = intervalo_actual(parametros);
+    if !intervalo.tiene_big_bang(){
+        TipoUniverso::Rebote
+    }
+    else if !intervalo.se_expande_para_siempre(){
+        TipoUniverso::Recolapso
+    }
+    else if minimo_velocidad_expansion(parametros) < UMBRAL_REMOLONEO{
+        TipoUniverso::Remoloneo
+    }
+    else{
+        TipoUniverso::ExpansionEterna
+    }
+}
+
+/// Resultado de recorrer el plano Ωm–ΩΛ. `tipos` y `edades` están ordenados por filas:
+/// el índice i*materia.len() + j corresponde a (materia[j], energia_oscura[i]).
+/// La edad (en giga años) es NaN en los modelos sin Big Bang.
+#[derive(Clone, Debug)]
+pub struct DiagramaFases{
+    pub materia: Vec<f64>,
+    pub energia_oscura: Vec<f64>,
+    pub tipos: Vec<TipoUniverso>,
+    pub edades: Vec<f64>,
+}
+
+/// Recorre el plano (densidad_materia, densidad_energia_oscura) manteniendo el resto de parámetros
+/// (radiación, H0, ...) y clasifica cada modelo.
+pub fn diagrama_fases(parametros: ParametrosCosmologicos, configuracion: ConfiguracionDiagramaFases) -> DiagramaFases{
+    let puntos = configuracion.puntos as i64;
+    let (m_min, m_max) = configuracion.rango_materia;
+    let (l_min, l_max) = configuracion.rango_energia_oscura;
+    // linspace no incluye el extremo superior
+    let materia: Vec<f64> = linspace(m_min, m_max, puntos).into_iter().chain([m_max]).collect();
+    let energia_oscura: Vec<f64> = linspace(l_min, l_max, puntos).into_iter().chain([l_max]).collect();
+
+    let mut tipos = Vec::with_capacity(materia.len()*energia_oscura.len());
+    let mut edades = Vec::with_capacity(materia.len()*energia_oscura.len());
+    for &l in &energia_oscura{
+        for &m in &materia{
+            let modelo = parametros.con_densidades(m, l);
+            let tipo = clasificar(modelo);
+            tipos.push(tipo);
+            edades.push(if tipo == TipoUniverso::Rebote {f64::NAN} else {lookback_time(0., modelo).unwrap_or(f64::NAN)});
+        }
+    }
+    DiagramaFases{materia, energia_oscura, tipos, edades}
+}
+
+/// Frontera de la región sin Big Bang (universos con rebote) en el plano Ωm–ΩΛ, como segmentos.
+/// Se clasifica cada punto de la rejilla `materia` × `energia_oscura` y se traza la curva de nivel 1/2 del indicador.
+pub fn frontera_rebote(parametros: ParametrosCosmologicos, materia: &[f64], energia_oscura: &[f64]) -> Vec<Segmento>{
+    let rebote: Vec<f64> = energia_oscura.iter()
+        .flat_map(|&l| materia.iter().map(move |&m| (clasificar(parametros.con_densidades(m, l)) == TipoUniverso::Rebote) as u8 as f64))
+        .collect();
+    contorno(materia, energia_oscura, &rebote, 0.5)
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+
+use crate::input::{cierre, InputFile, ParametrosCosmologicos};
+use crate::consts::OMEGA_RADIACION_H2;
+use crate::locale::Texto;
+use crate::texto;
+
+
+/// Conjuntos de parámetros con nombre, para no tener que copiarlos a mano. En el fichero de entrada
+/// `preset = "planck18"`; los campos que también estén en el fichero sustituyen a los del preset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Preset{
+    /// Planck 2018, TT,TE,EE+lowE+lensing (Planck Collaboration 2020, A&A 641 A6, tabla 2)
+    #[serde(rename = "planck18")]
+    Planck18,
+    /// WMAP 9 años, ΛCDM sólo con WMAP (Hinshaw et al. 2013, ApJS 208 19)
+    #[serde(rename = "wmap9")]
+    Wmap9,
+    /// Einstein-de Sitter: plano y sólo materia, a ∝ t^(2/3)
+    #[serde(rename = "eds", alias = "einstein_de_sitter")]
+    EinsteinDeSitter,
+    /// de Sitter: plano y sólo constante cosmológica, a ∝ e^(H0 t), sin Big Bang
+    #[serde(rename = "de_sitter")]
+    DeSitter,
+    /// Milne: vacío y con curvatura negativa, a ∝ t
+    #[serde(rename = "milne")]
+    Milne,
+}
+
+/// Parámetros cosmológicos de un preset, con H0 en km/s/Mpc como en el fichero de entrada
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValoresPreset{
+    pub densidad_materia: f64,
+    pub densidad_radiacion: f64,
+    pub densidad_energia_oscura: f64,
+    pub constante_hubble: f64,
+    pub w: f64,
+    pub wa: f64,
+    pub sigma8: f64,
+    pub densidad_bariones: f64,
+}
+
+/// H0 de los modelos de libro de texto, en km/s/Mpc
+const H0_ANALITICOS: f64 = 70.;
+
+/// (Ω_r, Ω_Λ) de un modelo plano con Ω_m y la radiación estándar para h
+fn plano_con_radiacion(densidad_materia: f64, h: f64) -> (f64, f64){
+    cierre(densidad_materia, OMEGA_RADIACION_H2/(h*h), 0.)
+}
+
+impl Preset{
+    pub const TODOS: [Preset; 5] = [Preset::Planck18, Preset::Wmap9, Preset::EinsteinDeSitter, Preset::DeSitter, Preset::Milne];
+
+    /// Nombre en el fichero de entrada y en `--preset`
+    pub fn nombre(self) -> &'static str{
+        match self{
+            Preset::Planck18 => "planck18",
+            Preset::Wmap9 => "wmap9",
+            Preset::EinsteinDeSitter => "eds",
+            Preset::DeSitter => "de_sitter",
+            Preset::Milne => "milne",
+        }
+    }
+
+    pub fn descripcion(self) -> &'static str{
+        match self{
+            Preset::Planck18 => Texto::DescripcionPlanck18,
+            Preset::Wmap9 => Texto::DescripcionWmap9,
+            Preset::EinsteinDeSitter => Texto::DescripcionEinsteinDeSitter,
+            Preset::DeSitter => Texto::DescripcionDeSitter,
+            Preset::Milne => Texto::DescripcionMilne,
+        }.texto()
+    }
+
+    pub fn valores(self) -> ValoresPreset{
+        let vacio = ValoresPreset{densidad_materia: 0., densidad_radiacion: 0., densidad_energia_oscura: 0., constante_hubble: H0_ANALITICOS,
+                                  w: -1., wa: 0., sigma8: 0.8111, densidad_bariones: 0.};
+        match self{
+            Preset::Planck18 => {
+                let (densidad_radiacion, densidad_energia_oscura) = plano_con_radiacion(0.3153, 0.6736);
+                ValoresPreset{densidad_materia: 0.3153, densidad_radiacion, densidad_energia_oscura, constante_hubble: 67.36,
+                              sigma8: 0.8111, densidad_bariones: 0.0493, ..vacio}
+            }
+            Preset::Wmap9 => {
+                let (densidad_radiacion, densidad_energia_oscura) = plano_con_radiacion(0.279, 0.700);
+                ValoresPreset{densidad_materia: 0.279, densidad_radiacion, densidad_energia_oscura, constante_hubble: 70.0,
+                              sigma8: 0.821, densidad_bariones: 0.0463, ..vacio}
+            }
+            Preset::EinsteinDeSitter => ValoresPreset{densidad_materia: 1., densidad_bariones: 0.0493, ..vacio},
+            Preset::DeSitter => ValoresPreset{densidad_energia_oscura: 1., ..vacio},
+            Preset::Milne => vacio,
+        }
+    }
+
+    /// Parámetros del preset con el resto de opciones (rangos de las gráficas, métodos de integración) por defecto
+    pub fn parametros(self) -> ParametrosCosmologicos{
+        InputFile::desde_preset(self).try_into().expect("los presets tienen todos los parámetros")
+    }
+}
+
+impl fmt::Display for Preset{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "{}", self.nombre())
+    }
+}
+
+impl FromStr for Preset{
+    type Err = anyhow::Error;
+
+    /// Sin distinguir mayúsculas, p.ej. "Planck18", "WMAP9" o "EdS"
+    fn from_str(nombre: &str) -> Result<Self, Self::Err>{
+        let nombre = nombre.to_lowercase().replace('-', "_");
+        Preset::TODOS.into_iter().find(|p| p.nombre() == nombre || (nombre == "einstein_de_sitter" && *p == Preset::EinsteinDeSitter))
+            .ok_or_else(|| anyhow::anyhow!(texto!(PresetDesconocido, nombre, Preset::TODOS.map(Preset::nombre).join(", "))))
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::cmb::cien_theta;
+    use crate::error::{CausaError, ErrorCosmologico};
+    use crate::functions::{angular_distance, horizonte_de_partículas, horizonte_particulas_actual, lookback_time, luminosity_distance};
+    use crate::interpolation::TablaCosmologica;
+
+    #[test]
+    fn magnitudes_de_todos_los_presets(){
+        // Las magnitudes que muestra la ejecución normal: o salen finitas o el error explica por qué, sin cuadraturas
+        // que no convergen ni NaN (de Sitter no tiene Big Bang, Milne no tiene horizonte ni bariones)
+        for preset in Preset::TODOS{
+            let parametros = preset.parametros();
+            let resultados: [(&str, Result<f64, ErrorCosmologico>); 8] = [
+                ("edad", lookback_time(0., parametros)),
+                ("edad en z_max_edad", lookback_time(parametros.z_max_edad, parametros)),
+                ("D_L", luminosity_distance(parametros.z_max_dist, parametros)),
+                ("D_A", angular_distance(parametros.z_max_dist, parametros)),
+                ("horizonte", horizonte_particulas_actual(parametros)),
+                ("horizonte en t", horizonte_de_partículas(parametros.t_horizonte_particulas, parametros).map(|horizonte| horizonte.0)),
+                ("100θ_*", cien_theta(parametros)),
+                ("tabla", TablaCosmologica::new(parametros.z_max_dist.max(parametros.z_max_edad), parametros).map(|tabla| tabla.z_max)),
+            ];
+            for (nombre, resultado) in resultados{
+                match resultado{
+                    Ok(valor) => assert!(valor.is_finite(), "{} {}: {}", preset, nombre, valor),
+                    Err(err) => {
+                        assert!(!matches!(err.causa, CausaError::Integracion(_)), "{} {}: {}", preset, nombre, err);
+                        assert!(!err.to_string().contains("NaN"), "{} {}: {}", preset, nombre, err);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use core::f64;
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::fitting::Parametro;
+
+
+/// Distribución a priori de un parámetro. En el fichero de entrada:
+/// ```toml
+/// [priors]
+/// densidad_materia = {uniforme = [0, 1]}
+/// w = {gaussiano = {media = -1, sigma = 0.3}}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Prior{
+    Uniforme(f64, f64),
+    Gaussiano{media: f64, sigma: f64},
+}
+
+impl Prior{
+    /// Logaritmo de la densidad de probabilidad (normalizada), -∞ fuera del soporte
+    pub fn log_densidad(&self, x: f64) -> f64{
+        match *self{
+            Prior::Uniforme(min, max) => if (min..=max).contains(&x) {-(max-min).ln()} else {f64::NEG_INFINITY},
+            Prior::Gaussiano{media, sigma} => -0.5*((x-media)/sigma).powi(2) - (sigma*(2.*f64::consts::PI).sqrt()).ln(),
+        }
+    }
+
+    /// Derivada del logaritmo de la densidad, para los muestreadores con gradiente. Los uniformes son planos.
+    pub fn derivada_log_densidad(&self, x: f64) -> f64{
+        match *self{
+            Prior::Uniforme(..) => 0.,
+            Prior::Gaussiano{media, sigma} => -(x-media)/(sigma*sigma),
+        }
+    }
+
+    /// Valor con probabilidad acumulada u ∈ (0, 1), para muestrear el prior a partir del hipercubo unidad (ver `muestreo_anidado`)
+    pub fn cuantil(&self, u: f64) -> f64{
+        match *self{
+            Prior::Uniforme(min, max) => min + (max-min)*u,
+            Prior::Gaussiano{media, sigma} => media + sigma*cuantil_normal(u),
+        }
+    }
+}
+
+/// Inversa de la función de distribución de la normal estándar (aproximación racional de Acklam, error relativo < 1.2e-9)
+fn cuantil_normal(u: f64) -> f64{
+    const A: [f64; 6] = [-3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2, 1.38357751867269e2, -3.066479806614716e1, 2.506628277459239];
+    const B: [f64; 5] = [-5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2, 6.680131188771972e1, -1.328068155288572e1];
+    const C: [f64; 6] = [-7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838, -2.549732539343734, 4.374664141464968, 2.938163982698783];
+    const D: [f64; 4] = [7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996, 3.754408661907416];
+    let cola = |q: f64| (((((C[0]*q + C[1])*q + C[2])*q + C[3])*q + C[4])*q + C[5])/((((D[0]*q + D[1])*q + D[2])*q + D[3])*q + 1.);
+    if u < 0.02425{
+        cola((-2.*u.ln()).sqrt())
+    }
+    else if u > 1. - 0.02425{
+        -cola((-2.*(1.-u).ln()).sqrt())
+    }
+    else{
+        let q = u - 0.5;
+        let r = q*q;
+        (((((A[0]*r + A[1])*r + A[2])*r + A[3])*r + A[4])*r + A[5])*q/(((((B[0]*r + B[1])*r + B[2])*r + B[3])*r + B[4])*r + 1.)
+    }
+}
+
+/// Priors de los parámetros libres. El orden de los parámetros es el de `Parametro`, el mismo en todas las ejecuciones.
+pub type Priors = BTreeMap<Parametro, Prior>;
+
+/// Suma de los logaritmos de los priors de cada parámetro
+pub fn log_prior(priors: &Priors, xx: &[f64]) -> f64{
+    priors.values().zip(xx).map(|(prior, &x)| prior.log_densidad(x)).sum()
+}
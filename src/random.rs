@@ -0,0 +1,64 @@
+use core::f64;
+
+
+/// Generador de números pseudoaleatorios xoshiro256** (https://prng.di.unimi.it/).
+/// Se usa en lugar de un crate externo para que las cadenas sean reproducibles con la misma semilla
+/// en cualquier versión y para poder guardar el estado en los puntos de control.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng{
+    estado: [u64; 4],
+}
+
+impl Rng{
+    /// Inicializa el estado a partir de la semilla con splitmix64, como recomiendan los autores
+    pub fn new(semilla: u64) -> Self{
+        let mut x = semilla;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        Rng{estado: [splitmix(), splitmix(), splitmix(), splitmix()]}
+    }
+
+    /// Estado interno, para guardarlo en un punto de control
+    pub fn estado(&self) -> [u64; 4]{
+        self.estado
+    }
+
+    pub fn desde_estado(estado: [u64; 4]) -> Self{
+        Rng{estado}
+    }
+
+    pub fn next_u64(&mut self) -> u64{
+        let s = &mut self.estado;
+        let resultado = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        resultado
+    }
+
+    /// Uniforme en [0, 1)
+    pub fn uniforme(&mut self) -> f64{
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Entero uniforme en [0, n)
+    pub fn entero(&mut self, n: usize) -> usize{
+        ((self.uniforme() * n as f64) as usize).min(n-1)
+    }
+
+    /// Normal estándar (Box–Muller)
+    pub fn normal(&mut self) -> f64{
+        let u1 = 1. - self.uniforme(); // en (0, 1] para poder tomar el logaritmo
+        let u2 = self.uniforme();
+        (-2.*u1.ln()).sqrt() * (2.*f64::consts::PI*u2).cos()
+    }
+}
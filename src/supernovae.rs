@@ -0,0 +1,195 @@
+use core::f64;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::input::ParametrosCosmologicos;
+use crate::functions::{distance_modulus, con_radiacion_estandar};
+use crate::fitting::Verosimilitud;
+use crate::dual::{Dual, Flotante};
+use crate::linalg::{cholesky, invertir};
+use crate::texto;
+
+
+/// Magnitud observada de cada supernova
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservableSupernova{
+    /// Módulo de distancia μ
+    ModuloDistancia,
+    /// Magnitud aparente en el filtro B, μ = m_B - M
+    MagnitudAparente,
+}
+
+/// Catálogo de supernovas para el diagrama de Hubble.
+///
+/// Tanto con μ como con m_B hay una constante aditiva libre (la magnitud absoluta M, que está degenerada con H0
+/// porque D_L ∝ 1/H0) y el χ² se marginaliza analíticamente sobre ella con un prior plano:
+/// con Δ = observado - μ_modelo, A = Δᵀ C⁻¹ Δ, B = Σ (C⁻¹ Δ) y S = Σ C⁻¹ queda χ² = A - B²/S.
+#[derive(Clone, Debug)]
+pub struct CatalogoSupernovas{
+    pub z: Vec<f64>,
+    pub valor: Vec<f64>,
+    pub sigma: Vec<f64>,
+    pub observable: ObservableSupernova,
+    /// Inversa de la matriz de covarianza total (diag(σ²) más la covarianza del fichero si la hay), por filas
+    inversa_covarianza: Vec<f64>,
+    suma_inversa: f64,
+}
+
+// Nombres de columna reconocidos (en minúsculas), en orden de preferencia
+const COLUMNAS_Z: [&str; 4] = ["zhd", "zcmb", "z", "zhel"];
+const COLUMNAS_MU: [&str; 4] = ["mu", "mu_sh0es", "mu_obs", "distmod"];
+const COLUMNAS_MB: [&str; 4] = ["mb", "m_b", "m_b_corr", "mb_corr"];
+const COLUMNAS_SIGMA: [&str; 10] = ["sigma", "sigma_mu", "dmu", "mu_err", "e_mu", "err", "dmb", "m_b_corr_err_diag", "mu_sh0es_err_diag", "sigma_mb"];
+
+fn buscar_columna(cabecera: &[String], nombres: &[&str]) -> Option<usize>{
+    nombres.iter().find_map(|nombre| cabecera.iter().position(|c| c == nombre))
+}
+
+pub(crate) fn separar(linea: &str) -> Vec<&str>{
+    if linea.contains(','){
+        linea.split(',').map(str::trim).collect()
+    }
+    else{
+        linea.split_whitespace().collect()
+    }
+}
+
+/// Lee una matriz de covarianza en el formato de Pantheon: el número de datos N seguido de los N² elementos
+pub(crate) fn leer_covarianza(fichero: &Path, n: usize) -> anyhow::Result<Vec<f64>>{
+    let texto = fs::read_to_string(fichero).with_context(|| texto!(NoSePudoLeer, fichero.display()))?;
+    let numeros = texto.split_whitespace().map(|x| x.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>().with_context(|| texto!(CovarianzaNoNumerica, fichero.display()))?;
+    if numeros.first().map(|&x| x as usize) != Some(n) || numeros.len() != n*n+1{
+        bail!(texto!(FormatoCovarianza, fichero.display(), n, n*n));
+    }
+    Ok(numeros[1..].to_vec())
+}
+
+impl CatalogoSupernovas{
+    /// Lee un catálogo en texto o CSV. Las líneas vacías y las que empiezan por # se ignoran, salvo la primera
+    /// línea con una columna de redshift (z, zcmb, zhd...) antes de los datos, que se toma como cabecera con
+    /// los nombres de las columnas (estilo Pantheon+ o Union).
+    /// Si no hay cabecera se suponen las columnas z, μ, σ.
+    ///
+    /// La covarianza opcional sigue el formato de Pantheon: el número de supernovas N seguido de los N² elementos.
+    /// Se suma a diag(σ²), así que debe contener sólo la parte que no está en σ (p.ej. la sistemática).
+    pub fn leer(datos: &Path, covarianza: Option<&Path>) -> anyhow::Result<Self>{
+        let texto = fs::read_to_string(datos).with_context(|| texto!(NoSePudoLeer, datos.display()))?;
+        let mut cabecera: Option<Vec<String>> = None;
+        let mut filas: Vec<(usize, Vec<f64>)> = Vec::new();
+        for (n, linea) in texto.lines().enumerate(){
+            let linea = linea.trim();
+            if linea.is_empty(){
+                continue;
+            }
+            let campos = separar(linea.trim_start_matches('#'));
+            let numeros: Vec<Option<f64>> = campos.iter().map(|c| c.parse().ok()).collect();
+            let nombres: Vec<String> = campos.iter().map(|c| c.to_lowercase()).collect();
+            if cabecera.is_none() && filas.is_empty() && numeros.iter().any(Option::is_none) && buscar_columna(&nombres, &COLUMNAS_Z).is_some(){
+                cabecera = Some(nombres);
+                continue;
+            }
+            if linea.starts_with('#'){
+                continue;
+            }
+            // Las columnas de texto (nombres de supernovas) se guardan como NaN
+            filas.push((n+1, numeros.into_iter().map(|x| x.unwrap_or(f64::NAN)).collect()));
+        }
+
+        let (i_z, i_valor, i_sigma, observable) = match &cabecera{
+            Some(cabecera) => {
+                let i_z = buscar_columna(cabecera, &COLUMNAS_Z).ok_or(anyhow!(texto!(SinColumnaRedshift, datos.display())))?;
+                let (i_valor, observable) = match (buscar_columna(cabecera, &COLUMNAS_MU), buscar_columna(cabecera, &COLUMNAS_MB)){
+                    (Some(i), _) => (i, ObservableSupernova::ModuloDistancia),
+                    (None, Some(i)) => (i, ObservableSupernova::MagnitudAparente),
+                    (None, None) => bail!(texto!(SinColumnaMu, datos.display())),
+                };
+                let i_sigma = buscar_columna(cabecera, &COLUMNAS_SIGMA).ok_or(anyhow!(texto!(SinColumnaErrores, datos.display())))?;
+                (i_z, i_valor, i_sigma, observable)
+            }
+            None => (0, 1, 2, ObservableSupernova::ModuloDistancia),
+        };
+
+        let mut z = Vec::with_capacity(filas.len());
+        let mut valor = Vec::with_capacity(filas.len());
+        let mut sigma = Vec::with_capacity(filas.len());
+        for (n, fila) in filas{
+            let leer = |i: usize| fila.get(i).copied().filter(|x| x.is_finite())
+                .ok_or(anyhow!(texto!(FaltaColumna, datos.display(), n, i+1)));
+            z.push(leer(i_z)?);
+            valor.push(leer(i_valor)?);
+            sigma.push(leer(i_sigma)?);
+        }
+        if z.is_empty(){
+            bail!(texto!(SinSupernovas, datos.display()));
+        }
+
+        let n = z.len();
+        let mut matriz = match covarianza{
+            Some(covarianza) => leer_covarianza(covarianza, n)?,
+            None => vec![0.; n*n],
+        };
+        for i in 0..n{
+            matriz[i*n+i] += sigma[i].powi(2);
+        }
+        if cholesky(&matriz, n).is_none(){
+            bail!(texto!(CovarianzaNoDefinida, datos.display()));
+        }
+        let inversa_covarianza = invertir(&matriz, n).unwrap();
+        let suma_inversa = inversa_covarianza.iter().sum();
+        Ok(CatalogoSupernovas{z, valor, sigma, observable, inversa_covarianza, suma_inversa})
+    }
+
+    /// Observado menos μ del modelo, sin la constante aditiva. NaN si el modelo no llega a ese redshift.
+    pub fn diferencias<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> Vec<T>{
+        let parametros = con_radiacion_estandar(parametros);
+        self.z.iter().zip(&self.valor).map(|(&z, &v)| T::from(v) - distance_modulus(z, parametros).unwrap_or(T::from(f64::NAN))).collect()
+    }
+
+    fn chi2_marginalizado<T: Flotante>(&self, parametros: ParametrosCosmologicos<T>) -> T{
+        let n = self.z.len();
+        let diferencias = self.diferencias(parametros);
+        if diferencias.iter().any(|d| !d.is_finite()){
+            return T::from(f64::INFINITY);
+        }
+        let c_delta: Vec<T> = (0..n).map(|i| (0..n).map(|j| diferencias[j]*self.inversa_covarianza[i*n+j]).sum()).collect();
+        let a: T = diferencias.iter().zip(&c_delta).map(|(&d, &c)| d*c).sum();
+        let b: T = c_delta.iter().copied().sum();
+        a - b*b/self.suma_inversa
+    }
+
+    /// Constante aditiva (M, o el desplazamiento por H0 si el observable es μ) que minimiza el χ²
+    pub fn desplazamiento_optimo(&self, parametros: ParametrosCosmologicos) -> f64{
+        let n = self.z.len();
+        let diferencias = self.diferencias(parametros);
+        let b: f64 = (0..n).map(|i| (0..n).map(|j| self.inversa_covarianza[i*n+j]*diferencias[j]).sum::<f64>()).sum();
+        b/self.suma_inversa
+    }
+
+    /// Residuos respecto al modelo con la constante aditiva óptima
+    pub fn residuos(&self, parametros: ParametrosCosmologicos) -> Vec<f64>{
+        let desplazamiento = self.desplazamiento_optimo(parametros);
+        self.diferencias(parametros).into_iter().map(|d| d - desplazamiento).collect()
+    }
+}
+
+impl Verosimilitud for CatalogoSupernovas{
+    fn nombre(&self) -> &str{
+        "supernovas"
+    }
+
+    fn chi2(&self, parametros: ParametrosCosmologicos) -> f64{
+        self.chi2_marginalizado(parametros)
+    }
+
+    fn chi2_dual(&self, parametros: ParametrosCosmologicos<Dual>) -> Option<Dual>{
+        Some(self.chi2_marginalizado(parametros))
+    }
+
+    fn numero_datos(&self) -> usize{
+        // la constante aditiva marginalizada se lleva un grado de libertad
+        self.z.len() - 1
+    }
+}
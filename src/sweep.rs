@@ -0,0 +1,176 @@
+use core::f64;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use anyhow::{bail, Context};
+use serde::{Serialize, Deserialize};
+
+use crate::input::ParametrosCosmologicos;
+use crate::fitting::{con_valores, Parametro};
+use crate::functions::{horizonte_particulas_actual, lookback_time, luminosity_distance_mpc, tiempo_retorno};
+use crate::analysis::{intervalo_actual, z_aceleracion};
+use crate::plotting::linspace;
+use crate::units::{Longitud, Tiempo, Unidades};
+use crate::texto;
+
+
+/// Un eje del barrido: el parámetro y su rango
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct EjeBarrido{
+    pub parametro: Parametro,
+    pub rango: (f64, f64),
+}
+
+/// Opciones de la orden `barrido`, tabla [barrido] del fichero de entrada
+/// ```toml
+/// [barrido]
+/// parametros = [{parametro = "densidad_materia", rango = [0, 1]}, {parametro = "densidad_energia_oscura", rango = [-1, 2]}]
+/// puntos = 40
+/// z_distancia = [0.5, 1]
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfiguracionBarrido{
+    /// Uno o dos parámetros: con uno se dibujan curvas y con dos mapas de color
+    pub parametros: Vec<EjeBarrido>,
+    /// Puntos en cada eje
+    pub puntos: usize,
+    /// Redshifts a los que se da la distancia de luminosidad
+    pub z_distancia: Vec<f64>,
+    /// Hilos para evaluar la rejilla, 0 para usar todos los procesadores
+    pub hilos: usize,
+    /// Fichero CSV con la tabla
+    pub salida: PathBuf,
+}
+
+impl Default for ConfiguracionBarrido{
+    fn default() -> Self{
+        ConfiguracionBarrido{parametros: vec![EjeBarrido{parametro: Parametro::ConstanteHubble, rango: (60., 80.)}], puntos: 41,
+                             z_distancia: vec![1.], hilos: 0, salida: PathBuf::from("barridos/barrido.csv")}
+    }
+}
+
+/// Magnitudes derivadas de un modelo. Las que no existen (p.ej. el tiempo de retorno de un universo que se expande
+/// para siempre) o no se han podido calcular son NaN; a_max es infinito si el universo no deja de expandirse.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Derivadas{
+    /// Edad hoy
+    pub edad: Tiempo,
+    /// Factor de escala máximo
+    pub a_max: f64,
+    /// Tiempo desde el Big Bang hasta a_max
+    pub tiempo_retorno: Tiempo,
+    /// Horizonte de partículas hoy
+    pub horizonte_particulas: Longitud,
+    /// Distancia de luminosidad en cada z de `z_distancia`
+    pub distancias_luminosidad: Vec<Longitud>,
+    /// Redshift al que empezó la aceleración
+    pub z_aceleracion: f64,
+    /// h del modelo, para dar las distancias en Mpc/h
+    pub h: f64,
+}
+
+impl Derivadas{
+    pub fn calcular(parametros: ParametrosCosmologicos, z_distancia: &[f64]) -> Self{
+        Derivadas{
+            edad: Tiempo::giga_anyos(lookback_time(0., parametros).unwrap_or(f64::NAN)),
+            a_max: intervalo_actual(parametros).a_max,
+            tiempo_retorno: Tiempo::giga_anyos(tiempo_retorno(parametros).unwrap_or(f64::NAN)),
+            horizonte_particulas: Longitud::mpc(horizonte_particulas_actual(parametros).unwrap_or(f64::NAN)),
+            distancias_luminosidad: z_distancia.iter().map(|&z| Longitud::mpc(luminosity_distance_mpc(z, parametros).unwrap_or(f64::NAN))).collect(),
+            z_aceleracion: z_aceleracion(parametros).unwrap_or(f64::NAN),
+            h: parametros.h(),
+        }
+    }
+
+    /// Los valores en las unidades pedidas, en el orden de `columnas`
+    pub fn valores(&self, unidades: &Unidades) -> Vec<f64>{
+        let longitud = |l: Longitud| l.en(unidades.longitud, self.h);
+        [unidades.tiempo(self.edad), self.a_max, unidades.tiempo(self.tiempo_retorno), longitud(self.horizonte_particulas)].into_iter()
+            .chain(self.distancias_luminosidad.iter().map(|&d| longitud(d))).chain([self.z_aceleracion]).collect()
+    }
+}
+
+/// Nombre (para el CSV) y descripción con unidades (para las gráficas) de cada magnitud derivada
+pub fn columnas(z_distancia: &[f64], unidades: &Unidades) -> Vec<(String, String)>{
+    let mut columnas = vec![("edad".to_string(), texto!(EdadHoy, unidades.tiempo)), ("a_max".to_string(), "a_max".to_string()),
+                            ("tiempo_retorno".to_string(), texto!(TiempoRetornoColumna, unidades.tiempo)),
+                            ("horizonte_particulas".to_string(), texto!(HorizonteColumna, unidades.longitud))];
+    columnas.extend(z_distancia.iter().map(|z| (format!("distancia_luminosidad_z{}", z), format!("D_L(z={}) [{}]", z, unidades.longitud))));
+    columnas.push(("z_aceleracion".to_string(), texto!(ZAceleracion)));
+    columnas
+}
+
+/// Magnitudes derivadas en una rejilla de uno o dos parámetros, con el resto fijos. Con dos, `derivadas` está ordenado
+/// por filas como en `MapaChi2`: el índice i*ejes[0].len() + j corresponde a (ejes[0][j], ejes[1][i]).
+#[derive(Clone, Debug)]
+pub struct Barrido{
+    pub parametros: Vec<Parametro>,
+    pub ejes: Vec<Vec<f64>>,
+    pub z_distancia: Vec<f64>,
+    pub derivadas: Vec<Derivadas>,
+}
+
+/// Valores de los parámetros en el punto k de una rejilla de uno o dos ejes, ordenada por filas
+fn punto_rejilla(ejes: &[Vec<f64>], k: usize) -> Vec<f64>{
+    let nx = ejes[0].len();
+    ejes.iter().enumerate().map(|(i, eje)| if i == 0 {eje[k % nx]} else {eje[k / nx]}).collect()
+}
+
+/// Calcula las magnitudes derivadas en la rejilla repartiendo los puntos entre varios hilos
+pub fn barrido(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionBarrido) -> anyhow::Result<Barrido>{
+    let libres: Vec<Parametro> = configuracion.parametros.iter().map(|eje| eje.parametro).collect();
+    match libres.as_slice(){
+        [_] => {}
+        [x, y] if x != y => {}
+        [_, _] => bail!(texto!(ParametrosBarridoIguales)),
+        _ => bail!(texto!(NumeroParametrosBarrido, libres.len())),
+    }
+    if configuracion.puntos < 2{
+        bail!(texto!(PuntosPorEje));
+    }
+    // linspace no incluye el extremo superior
+    let ejes: Vec<Vec<f64>> = configuracion.parametros.iter()
+        .map(|eje| linspace(eje.rango.0, eje.rango.1, configuracion.puntos as i64 - 1).into_iter().chain([eje.rango.1]).collect())
+        .collect();
+    let hilos = match configuracion.hilos{
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        n => n,
+    };
+
+    let punto = |k: usize| -> Derivadas {
+        Derivadas::calcular(con_valores(&libres, &punto_rejilla(&ejes, k), parametros), &configuracion.z_distancia)
+    };
+    let total: usize = ejes.iter().map(Vec::len).product();
+    let por_hilo = total.div_ceil(hilos);
+    let derivadas = thread::scope(|s| {
+        let trozos: Vec<_> = (0..total).step_by(por_hilo)
+            .map(|inicio| s.spawn(move || (inicio..total.min(inicio + por_hilo)).map(punto).collect::<Vec<Derivadas>>()))
+            .collect();
+        trozos.into_iter().flat_map(|t| t.join().expect("un hilo del barrido ha fallado")).collect()
+    });
+    Ok(Barrido{parametros: libres, ejes, z_distancia: configuracion.z_distancia.clone(), derivadas})
+}
+
+impl Barrido{
+    /// Valores de los parámetros en el punto k de la rejilla
+    pub fn punto(&self, k: usize) -> Vec<f64>{
+        punto_rejilla(&self.ejes, k)
+    }
+
+    /// Escribe la tabla en CSV: una columna por parámetro y otra por magnitud derivada, en las unidades pedidas
+    pub fn escribir(&self, fichero: &Path, unidades: &Unidades) -> anyhow::Result<()>{
+        if let Some(directorio) = fichero.parent(){
+            fs::create_dir_all(directorio)?;
+        }
+        let cabecera: Vec<String> = self.parametros.iter().map(|p| p.nombre().to_string())
+            .chain(columnas(&self.z_distancia, unidades).into_iter().map(|(nombre, _)| nombre)).collect();
+        let mut texto = cabecera.join(",") + "\n";
+        for (k, derivadas) in self.derivadas.iter().enumerate(){
+            let fila: Vec<String> = self.punto(k).into_iter().chain(derivadas.valores(unidades)).map(|x| x.to_string()).collect();
+            texto += &(fila.join(",") + "\n");
+        }
+        fs::write(fichero, texto).with_context(|| texto!(NoSePudoEscribir, fichero.display()))
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::consts::{ANYO_EN_S, C_MPC_GY, G, KM_S_MPC_TO_GY, MASA_SOLAR_KG, MPC_EN_M, S_TO_GY};
+use crate::input::ParametrosCosmologicos;
+use crate::locale::Texto;
+
+
+/// Unidades de longitud de la salida
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnidadLongitud{
+    #[default]
+    Mpc,
+    Gpc,
+    /// Giga años luz
+    #[serde(alias = "gly")]
+    Gal,
+    #[serde(rename = "m")]
+    Metro,
+    /// Mpc/h, con h = H0/(100 km/s/Mpc) del modelo
+    MpcH,
+}
+
+/// Unidades de tiempo de la salida
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnidadTiempo{
+    #[default]
+    #[serde(rename = "gyr")]
+    GigaAnyo,
+    #[serde(rename = "yr")]
+    Anyo,
+    #[serde(rename = "s")]
+    Segundo,
+}
+
+/// Unidades de las tasas (H0, H(z)) de la salida
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnidadTasa{
+    #[default]
+    #[serde(rename = "km_s_mpc")]
+    KmSMpc,
+    #[serde(rename = "1_gyr")]
+    InversoGigaAnyo,
+}
+
+/// Unidades de densidad de la salida
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnidadDensidad{
+    #[default]
+    #[serde(rename = "kg_m3")]
+    KgM3,
+    #[serde(rename = "g_cm3")]
+    GCm3,
+    /// Masas solares por Mpc³
+    #[serde(rename = "msol_mpc3")]
+    MsolMpc3,
+}
+
+impl fmt::Display for UnidadLongitud{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "{}", match self{
+            UnidadLongitud::Mpc => Texto::Megaparsecs,
+            UnidadLongitud::Gpc => Texto::Gigaparsecs,
+            UnidadLongitud::Gal => Texto::GigaAnyosLuz,
+            UnidadLongitud::Metro => Texto::Metros,
+            UnidadLongitud::MpcH => Texto::MegaparsecsH,
+        })
+    }
+}
+
+impl fmt::Display for UnidadTiempo{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "{}", match self{
+            UnidadTiempo::GigaAnyo => Texto::GigaAnyos,
+            UnidadTiempo::Anyo => Texto::Anyos,
+            UnidadTiempo::Segundo => Texto::Segundos,
+        })
+    }
+}
+
+impl fmt::Display for UnidadTasa{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "{}", match self{
+            UnidadTasa::KmSMpc => Texto::KmSMpc,
+            UnidadTasa::InversoGigaAnyo => Texto::InversoGigaAnyo,
+        })
+    }
+}
+
+impl fmt::Display for UnidadDensidad{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        write!(f, "{}", match self{
+            UnidadDensidad::KgM3 => Texto::KgM3,
+            UnidadDensidad::GCm3 => Texto::GCm3,
+            UnidadDensidad::MsolMpc3 => Texto::MasasSolaresMpc3,
+        })
+    }
+}
+
+/// Unidades en las que se muestran los resultados, tabla [unidades] del fichero de entrada.
+/// Los cálculos internos siempre son en leguas cósmicas (o Mpc), giga años y 1/giga año.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Unidades{
+    pub longitud: UnidadLongitud,
+    pub tiempo: UnidadTiempo,
+    pub tasa: UnidadTasa,
+    pub densidad: UnidadDensidad,
+}
+
+/// Longitud, guardada en Mpc
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Longitud(f64);
+
+impl Longitud{
+    pub fn mpc(valor: f64) -> Self{
+        Longitud(valor)
+    }
+
+    pub fn metros(valor: f64) -> Self{
+        Longitud(valor/MPC_EN_M)
+    }
+
+    /// Longitud en leguas cósmicas, la unidad de las distancias de `functions`, que depende de la curvatura del modelo
+    pub fn leguas_cosmicas(valor: f64, parametros: &ParametrosCosmologicos) -> Self{
+        Longitud(valor*parametros.legua_cosmica_a_mpc)
+    }
+
+    /// Valor en la unidad pedida. `h` sólo se usa para Mpc/h.
+    pub fn en(self, unidad: UnidadLongitud, h: f64) -> f64{
+        match unidad{
+            UnidadLongitud::Mpc => self.0,
+            UnidadLongitud::Gpc => self.0/1e3,
+            UnidadLongitud::Gal => self.0/C_MPC_GY,
+            UnidadLongitud::Metro => self.0*MPC_EN_M,
+            UnidadLongitud::MpcH => self.0*h,
+        }
+    }
+}
+
+/// Intervalo de tiempo, guardado en giga años
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Tiempo(f64);
+
+impl Tiempo{
+    pub fn giga_anyos(valor: f64) -> Self{
+        Tiempo(valor)
+    }
+
+    pub fn segundos(valor: f64) -> Self{
+        Tiempo(valor*S_TO_GY)
+    }
+
+    pub fn en(self, unidad: UnidadTiempo) -> f64{
+        match unidad{
+            UnidadTiempo::GigaAnyo => self.0,
+            UnidadTiempo::Anyo => self.0*1e9,
+            UnidadTiempo::Segundo => self.0*1e9*ANYO_EN_S,
+        }
+    }
+}
+
+/// Tasa (H0, H(z)), guardada en 1/giga año
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Tasa(f64);
+
+impl Tasa{
+    pub fn por_giga_anyo(valor: f64) -> Self{
+        Tasa(valor)
+    }
+
+    pub fn km_s_mpc(valor: f64) -> Self{
+        Tasa(valor*KM_S_MPC_TO_GY)
+    }
+
+    pub fn en(self, unidad: UnidadTasa) -> f64{
+        match unidad{
+            UnidadTasa::KmSMpc => self.0/KM_S_MPC_TO_GY,
+            UnidadTasa::InversoGigaAnyo => self.0,
+        }
+    }
+
+    /// Distancia de Hubble c/H
+    pub fn distancia_hubble(self) -> Longitud{
+        Longitud(C_MPC_GY/self.0)
+    }
+
+    /// Tiempo de Hubble 1/H
+    pub fn tiempo_hubble(self) -> Tiempo{
+        Tiempo(1./self.0)
+    }
+}
+
+/// Densidad de masa (o de energía entre c²), guardada en kg/m³
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Densidad(f64);
+
+impl Densidad{
+    pub fn kg_m3(valor: f64) -> Self{
+        Densidad(valor)
+    }
+
+    /// Densidad crítica 3H²/(8πG) para el ritmo de expansión H
+    pub fn critica(hubble: Tasa) -> Self{
+        let h_s = hubble.0*S_TO_GY;
+        Densidad(3.*h_s*h_s/(8.*std::f64::consts::PI*G))
+    }
+
+    pub fn en(self, unidad: UnidadDensidad) -> f64{
+        match unidad{
+            UnidadDensidad::KgM3 => self.0,
+            UnidadDensidad::GCm3 => self.0*1e-3,
+            UnidadDensidad::MsolMpc3 => self.0*MPC_EN_M.powi(3)/MASA_SOLAR_KG,
+        }
+    }
+}
+
+impl std::ops::Mul<f64> for Densidad{
+    type Output = Densidad;
+
+    fn mul(self, factor: f64) -> Densidad{
+        Densidad(self.0*factor)
+    }
+}
+
+impl Unidades{
+    /// Valor de una longitud en las unidades de salida, con el h de los parámetros para Mpc/h
+    pub fn longitud(&self, longitud: Longitud, parametros: &ParametrosCosmologicos) -> f64{
+        longitud.en(self.longitud, parametros.h())
+    }
+
+    pub fn tiempo(&self, tiempo: Tiempo) -> f64{
+        tiempo.en(self.tiempo)
+    }
+
+    pub fn tasa(&self, tasa: Tasa) -> f64{
+        tasa.en(self.tasa)
+    }
+
+    pub fn densidad(&self, densidad: Densidad) -> f64{
+        densidad.en(self.densidad)
+    }
+}
@@ -0,0 +1,496 @@
+use core::f64;
+use std::fmt;
+
+use crate::input::{InputFile, ParametrosCosmologicos};
+use crate::fitting::{ConfiguracionDatos, Parametro};
+use crate::priors::Prior;
+use crate::analysis::intervalo_actual;
+use crate::functions::lookback_time;
+use crate::presets::Preset;
+use crate::models::ConfiguracionModelos;
+use crate::figures::{Escala, TipoGrafica, VariableX};
+use crate::pgfplots::ConfiguracionLatex;
+use crate::locale::Texto;
+use crate::texto;
+
+
+/// Claves de primer nivel que se leen del fichero de entrada, para avisar de las que no se usan (erratas)
+const CLAVES_CONOCIDAS: &[&str] = &["preset", "densidad_materia", "densidad_radiacion", "densidad_energia_oscura", "constante_hubble", "w", "wa",
+    "sigma8", "densidad_bariones", "densidad_curvatura", "densidad_fisica_bariones", "densidad_fisica_cdm", "h", "cien_theta_estrella",
+    "rango_factor_escala", "t_horizonte_particulas", "z_max_dist", "z_max_edad", "metodo_distancia", "metodo_edad", "diagrama_fases", "supernovas", "bao", "cronometros", "cmb", "ajuste", "priors", "mcmc", "mapa_chi2", "hmc",
+    "anidado", "barrido", "unidades", "graficas", "modelo", "comparacion", "manifiesto", "idioma"];
+
+/// Claves de cada tabla `[[modelo]]`
+const CLAVES_MODELO: &[&str] = &["nombre", "preset", "densidad_materia", "densidad_radiacion", "densidad_energia_oscura", "constante_hubble",
+    "w", "wa", "sigma8", "densidad_bariones", "densidad_curvatura", "densidad_fisica_bariones", "densidad_fisica_cdm", "h", "cien_theta_estrella"];
+
+/// Si el rango (min, max) está bien ordenado (y no tiene NaN)
+fn creciente((min, max): (f64, f64)) -> bool{
+    min < max
+}
+
+/// Gravedad de un diagnóstico del fichero de entrada
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gravedad{
+    /// El fichero se puede usar pero algún resultado puede no ser el esperado
+    Aviso,
+    /// Con este fichero los cálculos darían NaN, fallarían o no tendrían sentido
+    Error,
+}
+
+/// Problema encontrado en el fichero de entrada. `campo` es la ruta de la clave con puntos (p.ej. "mcmc.caminantes")
+/// y `posicion` la línea y la columna (empezando en 1) donde está, si se ha encontrado.
+#[derive(Clone, Debug)]
+pub struct Diagnostico{
+    pub gravedad: Gravedad,
+    pub campo: String,
+    pub mensaje: String,
+    pub posicion: Option<(usize, usize)>,
+}
+
+impl fmt::Display for Diagnostico{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        if let Some((linea, columna)) = self.posicion{
+            write!(f, "{}:{}: ", linea, columna)?;
+        }
+        write!(f, "{}", if self.gravedad == Gravedad::Error {Texto::Error} else {Texto::Aviso})?;
+        if !self.campo.is_empty(){
+            write!(f, "{}", texto!(EnCampo, self.campo))?;
+        }
+        write!(f, ": {}", self.mensaje)
+    }
+}
+
+impl Diagnostico{
+    /// Si es de una de las tablas de datos de observaciones, p.ej. "cronometros.datos"
+    pub fn en_tabla_datos(&self) -> bool{
+        ConfiguracionDatos::TABLAS_DATOS.contains(&self.campo.split(['.', '[']).next().unwrap_or_default())
+    }
+}
+
+/// Diagnósticos que se van acumulando
+#[derive(Default)]
+struct Diagnosticos(Vec<Diagnostico>);
+
+impl Diagnosticos{
+    fn añadir(&mut self, gravedad: Gravedad, campo: &str, mensaje: String){
+        self.0.push(Diagnostico{gravedad, campo: campo.to_string(), mensaje, posicion: None});
+    }
+
+    fn error(&mut self, campo: &str, mensaje: String){
+        self.añadir(Gravedad::Error, campo, mensaje);
+    }
+
+    fn aviso(&mut self, campo: &str, mensaje: String){
+        self.añadir(Gravedad::Aviso, campo, mensaje);
+    }
+}
+
+/// Comprueba que los parámetros cosmológicos tienen sentido físico y que se pueden calcular las magnitudes de la
+/// ejecución normal (edad, horizonte, distancias)
+pub fn validar_parametros(parametros: ParametrosCosmologicos) -> Vec<Diagnostico>{
+    let mut d = Diagnosticos::default();
+    let p = parametros;
+    let h0 = Parametro::ConstanteHubble.valor(&p);
+
+    for parametro in [Parametro::DensidadMateria, Parametro::DensidadRadiacion, Parametro::DensidadEnergiaOscura, Parametro::ConstanteHubble,
+                      Parametro::W, Parametro::Wa, Parametro::Sigma8, Parametro::DensidadBariones]{
+        if !parametro.valor(&p).is_finite(){
+            d.error(parametro.nombre(), texto!(NoFinito, parametro.valor(&p)));
+        }
+    }
+    if p.densidad_materia < 0.{
+        d.error("densidad_materia", texto!(MateriaNegativa, p.densidad_materia));
+    }
+    if p.densidad_radiacion < 0.{
+        d.error("densidad_radiacion", texto!(RadiacionNegativa, p.densidad_radiacion));
+    }
+    else if p.densidad_radiacion == 0.{
+        d.aviso("densidad_radiacion", texto!(SinRadiacion));
+    }
+    if h0 <= 0.{
+        d.error("constante_hubble", texto!(HubbleNoPositiva, h0));
+    }
+    else if !(20. ..=150.).contains(&h0){
+        d.aviso("constante_hubble", texto!(HubbleLejana, h0));
+    }
+    if p.densidad_bariones < 0.{
+        d.error("densidad_bariones", texto!(BarionesFueraDeRango, p.densidad_materia, p.densidad_bariones));
+    }
+    if p.sigma8 <= 0.{
+        d.error("sigma8", texto!(DebePositiva, p.sigma8));
+    }
+    if p.w < -1. || p.w + p.wa < -1.{
+        d.aviso("w", texto!(Fantasma));
+    }
+    if p.densidad_curvatura != 0. && p.densidad_curvatura.abs() < 1e-10{
+        d.aviso("densidad_energia_oscura", texto!(CasiPlano, format!("{:e}", p.densidad_curvatura),
+                                                          format!("{:e}", p.legua_cosmica_a_mpc)));
+    }
+
+    let (min, max) = p.rango_factor_escala;
+    if !creciente((min, max)){
+        d.error("rango_factor_escala", texto!(ExtremosDesordenados, min, max));
+    }
+    if p.z_max_dist <= 0. || p.z_max_dist.is_nan(){
+        d.error("z_max_dist", texto!(DebePositivo, p.z_max_dist));
+    }
+    if p.z_max_edad <= 0. || p.z_max_edad.is_nan(){
+        d.error("z_max_edad", texto!(DebePositivo, p.z_max_edad));
+    }
+    // El resto sólo tiene sentido si el modelo es físico
+    if d.0.iter().any(|x| x.gravedad == Gravedad::Error){
+        return d.0;
+    }
+    let intervalo = intervalo_actual(p);
+    if !intervalo.tiene_big_bang(){
+        d.aviso("densidad_energia_oscura", texto!(SinBigBangValidacion, intervalo.a_min, intervalo.z_max(p.factor_escala)));
+        if p.z_max_dist > intervalo.z_max(p.factor_escala){
+            d.aviso("z_max_dist", texto!(DistanciasHasta, intervalo.z_max(p.factor_escala)));
+        }
+        return d.0;
+    }
+    match lookback_time(0., p){
+        Ok(edad) => {
+            if p.t_horizonte_particulas < -edad{
+                d.error("t_horizonte_particulas", texto!(AntesDelBigBang, p.t_horizonte_particulas, edad));
+            }
+            if min < -edad{
+                d.aviso("rango_factor_escala", texto!(RangoAntesDelBigBang, edad));
+            }
+        }
+        // Sin materia ni radiación (de Sitter) la edad es infinita aunque E² > 0 hasta a = 0
+        Err(err) => d.aviso("", texto!(EdadInfinita, err)),
+    }
+    d.0
+}
+
+/// Comprueba las tablas de datos y de los muestreadores
+pub fn validar_configuracion(parametros: ParametrosCosmologicos, configuracion: &ConfiguracionDatos) -> Vec<Diagnostico>{
+    let mut d = Diagnosticos::default();
+    let ficheros = [("supernovas.datos", configuracion.supernovas.as_ref().map(|f| &f.datos)),
+                    ("supernovas.covarianza", configuracion.supernovas.as_ref().and_then(|f| f.covarianza.as_ref())),
+                    ("bao.datos", configuracion.bao.as_ref().map(|f| &f.datos)),
+                    ("cronometros.datos", configuracion.cronometros.as_ref().map(|f| &f.datos)),
+                    ("cronometros.covarianza", configuracion.cronometros.as_ref().and_then(|f| f.covarianza.as_ref()))];
+    for (campo, fichero) in ficheros{
+        if let Some(fichero) = fichero.filter(|f| !f.is_file()){
+            d.error(campo, texto!(NoExisteFichero, fichero.display()));
+        }
+    }
+
+    let mut priors: Vec<(String, &std::collections::BTreeMap<Parametro, Prior>)> = vec![("priors".to_string(), &configuracion.priors)];
+    for (i, modelo) in configuracion.anidado.modelos.iter().enumerate(){
+        if let Some(p) = &modelo.priors{
+            priors.push((format!("anidado.modelos[{}].priors", i), p));
+        }
+        if let Err(err) = modelo.comprobar(modelo.priors(&configuracion.priors)){
+            d.error(&format!("anidado.modelos[{}]", i), err.to_string());
+        }
+    }
+    for (tabla, priors) in &priors{
+        for (parametro, prior) in priors.iter(){
+            let campo = format!("{}.{}", tabla, parametro.nombre());
+            match *prior{
+                Prior::Uniforme(min, max) if !creciente((min, max)) => d.error(&campo, texto!(PriorDesordenado, min, max)),
+                Prior::Gaussiano{sigma, ..} if sigma <= 0. || sigma.is_nan() => d.error(&campo, texto!(SigmaNoPositiva, sigma)),
+                _ => {}
+            }
+        }
+    }
+    for (parametro, prior) in &configuracion.priors{
+        let valor = parametro.valor(&parametros);
+        if !prior.log_densidad(valor).is_finite(){
+            d.aviso(&format!("priors.{}", parametro.nombre()), texto!(FueraDelPrior, valor));
+        }
+    }
+
+    let libres = configuracion.priors.len();
+    let mcmc = &configuracion.mcmc;
+    if libres > 0 && mcmc.caminantes < 2*libres{
+        d.error("mcmc.caminantes", texto!(Caminantes, 2*libres, libres));
+    }
+    if mcmc.quemado >= mcmc.pasos{
+        d.error("mcmc.quemado", texto!(QuemadoExcesivo, mcmc.quemado, mcmc.pasos));
+    }
+    if mcmc.intervalo_control == 0{
+        d.error("mcmc.intervalo_control", texto!(AlMenosUno));
+    }
+    let hmc = &configuracion.hmc;
+    if !creciente((0., hmc.aceptacion_objetivo)) || !creciente((hmc.aceptacion_objetivo, 1.)){
+        d.error("hmc.aceptacion_objetivo", texto!(EntreCeroYUno, hmc.aceptacion_objetivo));
+    }
+    if hmc.cadenas == 0 || hmc.pasos == 0{
+        d.error("hmc.pasos", texto!(CadenasYPasos));
+    }
+    let mapa = &configuracion.mapa_chi2;
+    if mapa.parametros.0 == mapa.parametros.1{
+        d.error("mapa_chi2.parametros", texto!(ParametrosMapaIguales));
+    }
+    if mapa.puntos < 2{
+        d.error("mapa_chi2.puntos", texto!(AlMenosDosPuntosPorEje, mapa.puntos));
+    }
+    for (campo, (min, max)) in [("mapa_chi2.rango_x", mapa.rango_x), ("mapa_chi2.rango_y", mapa.rango_y)]{
+        if !creciente((min, max)){
+            d.error(campo, texto!(ExtremosDesordenados, min, max));
+        }
+    }
+    let barrido = &configuracion.barrido;
+    match barrido.parametros.as_slice(){
+        [_] => {}
+        [x, y] if x.parametro != y.parametro => {}
+        [_, _] => d.error("barrido.parametros", texto!(ParametrosBarridoIguales)),
+        otros => d.error("barrido.parametros", texto!(NumeroParametrosBarrido, otros.len())),
+    }
+    for (i, eje) in barrido.parametros.iter().enumerate(){
+        if !creciente(eje.rango){
+            d.error(&format!("barrido.parametros[{}]", i), texto!(ExtremosDesordenadosDe, eje.parametro.nombre(),
+                                                                  eje.rango.0, eje.rango.1));
+        }
+    }
+    if barrido.puntos < 2{
+        d.error("barrido.puntos", texto!(AlMenosDosPuntosPorEje, barrido.puntos));
+    }
+    if let Some(z) = barrido.z_distancia.iter().find(|z| !creciente((0., **z))){
+        d.error("barrido.z_distancia", texto!(RedshiftsPositivos, z));
+    }
+    let anidado = &configuracion.anidado;
+    if !creciente((0., anidado.tolerancia)){
+        d.error("anidado.tolerancia", texto!(DebePositiva, anidado.tolerancia));
+    }
+    if anidado.expansion < 1. || anidado.expansion.is_nan(){
+        d.error("anidado.expansion", texto!(Expansion, anidado.expansion));
+    }
+    // Los bariones sólo intervienen en el horizonte de sonido; sin BAO ni CMB basta con avisar, p.ej. con Ω_m = 0 y
+    // la Ω_b por defecto
+    if parametros.densidad_bariones > parametros.densidad_materia{
+        if configuracion.bao.is_some() || configuracion.cmb.is_some(){
+            d.error("densidad_bariones", texto!(BarionesFueraDeRango, parametros.densidad_materia, parametros.densidad_bariones));
+        }
+        else{
+            d.aviso("densidad_bariones", texto!(BarionesMasQueMateria, parametros.densidad_bariones, parametros.densidad_materia));
+        }
+    }
+    let diagrama = configuracion.diagrama_fases;
+    if !creciente(diagrama.rango_materia){
+        d.error("diagrama_fases.rango_materia", texto!(ExtremosDesordenados, diagrama.rango_materia.0, diagrama.rango_materia.1));
+    }
+    if !creciente(diagrama.rango_energia_oscura){
+        d.error("diagrama_fases.rango_energia_oscura", texto!(ExtremosDesordenados, diagrama.rango_energia_oscura.0, diagrama.rango_energia_oscura.1));
+    }
+    if diagrama.puntos < 2{
+        d.error("diagrama_fases.puntos", texto!(AlMenosDosPuntos, diagrama.puntos));
+    }
+    let graficas = &configuracion.graficas;
+    if graficas.formatos.is_empty(){
+        d.aviso("graficas.formatos", texto!(SinFormatos));
+    }
+    if graficas.latex != ConfiguracionLatex::default() && !graficas.latex(){
+        d.aviso("graficas.latex", texto!(LatexSinFormatos));
+    }
+    if graficas.dpi == 0{
+        d.error("graficas.dpi", texto!(AlMenosUno));
+    }
+    if !creciente((0., graficas.tamanyo.0)) || !creciente((0., graficas.tamanyo.1)){
+        d.error("graficas.tamanyo", texto!(TamanyoNoPositivo, graficas.tamanyo.0, graficas.tamanyo.1));
+    }
+    if !creciente((0., graficas.grosor)){
+        d.error("graficas.grosor", texto!(DebePositivo, graficas.grosor));
+    }
+    for tipo in TipoGrafica::TODAS{
+        let opciones = graficas.opciones(tipo);
+        let tabla = format!("graficas.{}", tipo.nombre());
+        let x = opciones.x.unwrap_or(tipo.x_por_defecto());
+        let escala_x = opciones.escala_x.unwrap_or_default();
+        let escala_y = opciones.escala_y.unwrap_or(tipo.escala_y_por_defecto());
+        for (eje, rango, escala) in [("rango_x", opciones.rango_x, escala_x), ("rango_y", opciones.rango_y, escala_y)]{
+            let Some((min, max)) = rango else {continue};
+            if !creciente((min, max)){
+                d.error(&format!("{}.{}", tabla, eje), texto!(ExtremosDesordenados, min, max));
+            }
+            else if escala == Escala::Log && min <= 0.{
+                d.error(&format!("{}.{}", tabla, eje), texto!(RangoLogaritmico, min));
+            }
+        }
+        if escala_x == Escala::Log && matches!(x, VariableX::Tiempo | VariableX::TiempoConforme){
+            d.aviso(&format!("{}.escala_x", tabla), texto!(TiempoLogaritmico));
+        }
+        if let Some(grosor) = opciones.grosor.filter(|g| !creciente((0., *g))){
+            d.error(&format!("{}.grosor", tabla), texto!(DebePositivo, grosor));
+        }
+    }
+    d.0
+}
+
+/// Valida el texto del fichero de entrada: errores de sintaxis y de tipos (con su posición), claves desconocidas y
+/// las comprobaciones de `validar_parametros` y `validar_configuracion`. `preset` es el de la línea de órdenes, que
+/// sustituye a los parámetros del fichero (ver `InputFile::con_preset`). Los diagnósticos salen ordenados por línea.
+pub fn validar_texto(texto: &str, preset: Option<Preset>) -> Vec<Diagnostico>{
+    let tabla = match texto.parse::<toml::Table>(){
+        Ok(tabla) => tabla,
+        Err(err) => return vec![diagnostico_toml(texto, &err)],
+    };
+    let mut diagnosticos = Vec::new();
+    for clave in tabla.keys().filter(|c| !CLAVES_CONOCIDAS.contains(&c.as_str())){
+        diagnosticos.push(Diagnostico{gravedad: Gravedad::Aviso, campo: clave.clone(), mensaje: texto!(ClaveDesconocida),
+                                      posicion: None});
+    }
+    let entrada = match toml::from_str::<InputFile>(texto){
+        Ok(entrada) => entrada,
+        Err(err) => return vec![diagnostico_toml(texto, &err)],
+    };
+    let entrada = match preset{
+        Some(preset) => entrada.con_preset(preset),
+        None => entrada,
+    };
+    let modelos = match toml::from_str::<ConfiguracionModelos>(texto){
+        Ok(modelos) => modelos,
+        Err(err) => return vec![diagnostico_toml(texto, &err)],
+    };
+    let parametros = if modelos.modelo.is_empty(){
+        let parametros = match ParametrosCosmologicos::try_from(entrada){
+            Ok(parametros) => parametros,
+            Err(err) => return finalizar(texto, vec![Diagnostico{gravedad: Gravedad::Error, campo: err.campo().to_string(),
+                                                                  mensaje: err.to_string(), posicion: None}]),
+        };
+        diagnosticos.extend(validar_parametros(parametros));
+        parametros
+    }
+    else{
+        let claves = tabla.get("modelo").and_then(toml::Value::as_array).into_iter().flatten().filter_map(toml::Value::as_table);
+        for (i, claves) in claves.enumerate(){
+            for clave in claves.keys().filter(|c| !CLAVES_MODELO.contains(&c.as_str())){
+                diagnosticos.push(Diagnostico{gravedad: Gravedad::Aviso, campo: format!("modelo[{}].{}", i, clave),
+                                              mensaje: texto!(ClaveDesconocida), posicion: None});
+            }
+        }
+        let (validos, errores) = validar_modelos(entrada, &modelos);
+        diagnosticos.extend(errores);
+        // Las órdenes distintas de la ejecución normal usan los parámetros del fichero; si no están completos, los del primer modelo
+        match ParametrosCosmologicos::try_from(entrada).ok().or(validos.first().copied()){
+            Some(parametros) => parametros,
+            None => return finalizar(texto, diagnosticos),
+        }
+    };
+    match toml::from_str::<ConfiguracionDatos>(texto){
+        Ok(configuracion) => diagnosticos.extend(validar_configuracion(parametros, &configuracion)),
+        Err(err) => diagnosticos.push(diagnostico_toml(texto, &err)),
+    }
+    finalizar(texto, diagnosticos)
+}
+
+/// Comprueba los modelos de las tablas `[[modelo]]`: nombres, parámetros que faltan y los mismos controles que
+/// `validar_parametros`, con el campo dentro de la tabla del modelo. Devuelve también los parámetros de los modelos completos.
+fn validar_modelos(entrada: InputFile, modelos: &ConfiguracionModelos) -> (Vec<ParametrosCosmologicos>, Vec<Diagnostico>){
+    let mut validos = Vec::new();
+    let mut diagnosticos = Vec::new();
+    for (i, modelo) in modelos.modelo.iter().enumerate(){
+        let tabla = format!("modelo[{}]", i);
+        let error = |campo: &str, mensaje: String| Diagnostico{gravedad: Gravedad::Error, campo: format!("{}.{}", tabla, campo), mensaje, posicion: None};
+        if modelo.nombre.trim().is_empty(){
+            diagnosticos.push(error("nombre", texto!(NombreVacio)));
+        }
+        else if modelos.modelo[..i].iter().any(|m| m.nombre == modelo.nombre){
+            diagnosticos.push(error("nombre", texto!(NombreRepetido, modelo.nombre)));
+        }
+        match ParametrosCosmologicos::try_from(entrada.con_modelo(modelo)){
+            Ok(parametros) => {
+                validos.push(parametros);
+                diagnosticos.extend(validar_parametros(parametros).into_iter()
+                    .map(|d| Diagnostico{campo: format!("{}.{}", tabla, d.campo), mensaje: texto!(DeModelo, d.mensaje, modelo.nombre), ..d}));
+            }
+            Err(err) => diagnosticos.push(error(err.campo(), texto!(DeModelo, err, modelo.nombre))),
+        }
+    }
+    (validos, diagnosticos)
+}
+
+/// Añade la posición de los diagnósticos que no la tienen y los ordena por línea
+fn finalizar(texto: &str, mut diagnosticos: Vec<Diagnostico>) -> Vec<Diagnostico>{
+    for diagnostico in &mut diagnosticos{
+        if diagnostico.posicion.is_none(){
+            diagnostico.posicion = posicion_campo(texto, &diagnostico.campo);
+        }
+    }
+    diagnosticos.sort_by_key(|d| d.posicion.unwrap_or((usize::MAX, 0)));
+    diagnosticos
+}
+
+/// Error de sintaxis o de tipos de toml, con la posición y la clave en la que está
+fn diagnostico_toml(texto: &str, err: &toml::de::Error) -> Diagnostico{
+    let posicion = err.span().map(|span| {
+        let antes = &texto[..span.start.min(texto.len())];
+        let linea = antes.matches('\n').count() + 1;
+        let columna = antes.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        (linea, columna)
+    });
+    let campo = posicion.map(|(linea, _)| campo_en_linea(texto, linea)).unwrap_or_default();
+    Diagnostico{gravedad: Gravedad::Error, campo, mensaje: err.message().trim().to_string(), posicion}
+}
+
+/// Recorre las líneas del fichero con la tabla en la que está cada una, p.ej. "mcmc" o "anidado.modelos[1]"
+/// para la segunda tabla [[anidado.modelos]]. Las líneas antes de la primera cabecera están en la tabla "".
+fn lineas_con_tabla(texto: &str) -> Vec<(String, &str)>{
+    let mut tabla = String::new();
+    let mut repeticiones = std::collections::HashMap::new();
+    let mut lineas = Vec::new();
+    for linea in texto.lines(){
+        let limpia = linea.trim();
+        if let Some(nombre) = limpia.strip_prefix("[[").and_then(|l| l.split("]]").next()){
+            let n = repeticiones.entry(nombre.trim().to_string()).or_insert(0);
+            tabla = format!("{}[{}]", nombre.trim(), n);
+            *n += 1;
+        }
+        else if let Some(nombre) = limpia.strip_prefix('[').and_then(|l| l.split(']').next()){
+            tabla = nombre.trim().to_string();
+        }
+        lineas.push((tabla.clone(), linea));
+    }
+    lineas
+}
+
+/// Clave de una línea "clave = valor"
+fn clave_linea(linea: &str) -> Option<&str>{
+    let (clave, _) = linea.split_once('=')?;
+    let clave = clave.trim().trim_matches('"');
+    (!clave.is_empty() && !clave.starts_with('#') && !clave.starts_with('[')).then_some(clave)
+}
+
+/// Posición (línea, columna) de un campo como "mcmc.caminantes" o "anidado.modelos[0].priors". Si no está la clave
+/// (p.ej. porque se usa su valor por defecto) se da la cabecera de su tabla.
+fn posicion_campo(texto: &str, campo: &str) -> Option<(usize, usize)>{
+    if campo.is_empty(){
+        return None;
+    }
+    let lineas = lineas_con_tabla(texto);
+    let columna = |linea: &str| linea.len() - linea.trim_start().len() + 1;
+    // Se prueba a partir el campo por cada punto, de derecha a izquierda: "priors.densidad_materia" puede ser la clave
+    // densidad_materia de [priors]
+    let mut cortes: Vec<usize> = campo.match_indices('.').map(|(i, _)| i).collect();
+    cortes.reverse();
+    for corte in cortes.iter().map(|&i| Some(i)).chain([None]){
+        let (tabla, clave) = match corte{
+            Some(i) => (&campo[..i], &campo[i+1..]),
+            None => ("", campo),
+        };
+        let primera = clave.split('.').next().unwrap_or(clave);
+        if let Some(n) = lineas.iter().position(|(t, l)| t == tabla && clave_linea(l) == Some(primera)){
+            return Some((n + 1, columna(lineas[n].1)));
+        }
+    }
+    // La cabecera de la tabla más interna que esté, p.ej. la del modelo si hereda el campo del fichero
+    [campo].into_iter().chain(cortes.iter().map(|&i| &campo[..i]))
+        .find_map(|tabla| lineas.iter().position(|(t, l)| t == tabla && l.trim_start().starts_with('[')))
+        .map(|n| (n + 1, columna(lineas[n].1)))
+}
+
+/// Campo (tabla y clave) que se define en la línea `linea`
+fn campo_en_linea(texto: &str, linea: usize) -> String{
+    let lineas = lineas_con_tabla(texto);
+    let Some((tabla, texto_linea)) = lineas.get(linea - 1) else {return String::new()};
+    match clave_linea(texto_linea){
+        Some(clave) if tabla.is_empty() => clave.to_string(),
+        Some(clave) => format!("{}.{}", tabla, clave),
+        None => tabla.clone(),
+    }
+}